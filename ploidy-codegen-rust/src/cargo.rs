@@ -8,12 +8,19 @@ use std::{
 
 use itertools::Itertools;
 use miette::SourceSpan;
-use ploidy_core::{codegen::Code, ir::View};
+use ploidy_core::{
+    codegen::Code,
+    ir::{PrimitiveType, StructFieldName, View},
+};
 use semver::Version;
 use serde::{Deserialize, de::IntoDeserializer};
 use toml_edit::{Array, DocumentMut, InlineTable, Table, TableLike, value};
 
-use super::{config::CodegenConfig, graph::CodegenGraph, naming::AsFeatureName};
+use super::{
+    config::{CodegenConfig, CodegenScope},
+    graph::CodegenGraph,
+    naming::AsFeatureName,
+};
 
 const PLOIDY_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -70,7 +77,8 @@ impl<'a> CodegenCargoManifest<'a> {
 
             // Build the `features` section of the manifest.
             let mut features = BTreeMap::new();
-            if !deps_by_resource.is_empty() {
+            // Types-only crates don't have a client, or resource features.
+            if self.graph.scope() != CodegenScope::Types {
                 features.extend(deps_by_resource.iter().map(|(resource, deps)| {
                     (
                         AsFeatureName(*resource).to_string(),
@@ -81,13 +89,27 @@ impl<'a> CodegenCargoManifest<'a> {
                         ),
                     )
                 }));
-                // `default` enables all resource features.
+                // `reqwest` enables the default HTTP client.
+                features.insert(
+                    "reqwest".to_owned(),
+                    FeatureDependencies(
+                        itertools::chain!(
+                            ["ploidy-util/reqwest".to_owned()],
+                            self.graph
+                                .blocking()
+                                .then(|| "ploidy-util/blocking".to_owned()),
+                        )
+                        .collect_vec(),
+                    ),
+                );
+                // `default` enables all resource features, and Reqwest.
                 features.insert(
                     "default".to_owned(),
                     FeatureDependencies(
                         deps_by_resource
                             .keys()
                             .map(|resource| AsFeatureName(*resource).to_string())
+                            .chain(["reqwest".to_owned()])
                             .collect_vec(),
                     ),
                 );
@@ -105,19 +127,60 @@ impl<'a> CodegenCargoManifest<'a> {
                     "ploidy-util/trace-context".to_owned(),
                 ]),
             );
+            // `mocks` enables the `wiremock` helpers, if generated.
+            if self.graph.emit_mocks() {
+                features.insert(
+                    "mocks".to_owned(),
+                    FeatureDependencies(vec!["dep:wiremock".to_owned()]),
+                );
+            }
             features
         };
 
+        // `ploidy-util` is our only required runtime dependency.
+        // Decimal types need its optional `decimal` feature, and
+        // `patternProperties` validation needs its `regex` feature.
+        let mut ploidy_util_features = vec![];
+        if self
+            .graph
+            .primitives()
+            .any(|p| p.ty() == PrimitiveType::Decimal)
+        {
+            ploidy_util_features.push("decimal".to_owned());
+        }
+        if self.graph.structs().any(|view| {
+            view.fields()
+                .any(|field| matches!(field.name(), StructFieldName::PatternProperties(_)))
+        }) {
+            ploidy_util_features.push("regex".to_owned());
+        }
+        let ploidy_util = if ploidy_util_features.is_empty() {
+            Dependency::Simple(PLOIDY_VERSION.parse().unwrap())
+        } else {
+            Dependency::Detailed(DependencyDetail {
+                version: PLOIDY_VERSION.parse().unwrap(),
+                path: None,
+                optional: false,
+                features: ploidy_util_features,
+            })
+        };
+        let mut dependencies = BTreeMap::from_iter([("ploidy-util".to_owned(), ploidy_util)]);
+        if self.graph.emit_mocks() {
+            dependencies.insert(
+                "wiremock".to_owned(),
+                Dependency::Detailed(DependencyDetail {
+                    version: Version::new(0, 6, 0),
+                    path: None,
+                    optional: true,
+                    features: vec![],
+                }),
+            );
+        }
+
         self.manifest.clone().apply(CargoManifestDiff {
             // Ploidy generates Rust 2024-compatible code.
             edition: Some(RustEdition::E2024),
-            dependencies: Some(BTreeMap::from_iter([
-                // `ploidy-util` is our only runtime dependency.
-                (
-                    "ploidy-util".to_owned(),
-                    Dependency::Simple(PLOIDY_VERSION.parse().unwrap()),
-                ),
-            ])),
+            dependencies: Some(dependencies),
             features: Some(features),
             ..Default::default()
         })
@@ -396,6 +459,13 @@ impl Dependency {
                 if let Some(path) = detail.path {
                     table.insert("path", value(path));
                 }
+                if detail.optional {
+                    table.insert("optional", value(true));
+                }
+                if !detail.features.is_empty() {
+                    let entry = table.entry("features").or_insert(toml_edit::Item::None);
+                    FeatureDependencies(detail.features).merge_into(entry);
+                }
             }
         }
     }
@@ -405,6 +475,8 @@ impl Dependency {
 pub struct DependencyDetail {
     pub version: Version,
     pub path: Option<String>,
+    pub optional: bool,
+    pub features: Vec<String>,
 }
 
 /// A set of feature dependencies to merge into a `[features]` entry.
@@ -462,7 +534,10 @@ mod tests {
         parse::Document,
     };
 
-    use crate::{config::DateTimeFormat, tests::assert_matches};
+    use crate::{
+        config::{CodegenConfig, CodegenScope, DateTimeFormat, MethodCase},
+        tests::assert_matches,
+    };
 
     fn default_manifest() -> CargoManifest {
         CargoManifest::new("test-client", Version::new(0, 1, 0))
@@ -561,6 +636,8 @@ mod tests {
             Dependency::Detailed(DependencyDetail {
                 version: Version::new(0, 10, 0),
                 path: Some("../ploidy-util".to_owned()),
+                optional: false,
+                features: vec![],
             }),
         );
         let manifest = CargoManifest::new("pkg", Version::new(1, 0, 0)).apply(CargoManifestDiff {
@@ -622,6 +699,8 @@ mod tests {
                 ploidy-util = "{PLOIDY_VERSION}"
 
                 [features]
+                default = ["reqwest"]
+                reqwest = ["ploidy-util/reqwest"]
                 trace-context = ["tracing", "ploidy-util/trace-context"]
                 tracing = ["ploidy-util/tracing"]
             "#},
@@ -692,11 +771,62 @@ mod tests {
 
             [package.metadata.ploidy]
             date-time-format = "unix-seconds"
+            method-case = "camel"
+            types-crate = "pets-types"
+
+            [package.metadata.ploidy.format-overrides]
+            phone = "crate::phone::PhoneNumber"
         "#})
         .unwrap();
         let pkg = manifest.package().unwrap();
         let config = pkg.config().unwrap().unwrap();
         assert_eq!(config.date_time_format, DateTimeFormat::UnixSeconds);
+        assert_eq!(config.method_case, MethodCase::Camel);
+        assert_eq!(
+            config.types_crate.map(String::from).as_deref(),
+            Some("pets-types")
+        );
+        let overrides = config
+            .format_overrides
+            .into_iter()
+            .map(|(format, path)| (format, String::from(path)))
+            .collect_vec();
+        assert_eq!(
+            overrides,
+            [("phone".to_owned(), "crate::phone::PhoneNumber".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_format_override() {
+        let manifest = CargoManifest::parse(indoc::indoc! {r#"
+            [package]
+            name = "pkg"
+            version = "1.0.0"
+            edition = "2024"
+
+            [package.metadata.ploidy.format-overrides]
+            phone = "not a path"
+        "#})
+        .unwrap();
+        let pkg = manifest.package().unwrap();
+        assert!(pkg.config().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_types_crate() {
+        let manifest = CargoManifest::parse(indoc::indoc! {r#"
+            [package]
+            name = "pkg"
+            version = "1.0.0"
+            edition = "2024"
+
+            [package.metadata.ploidy]
+            types-crate = "my.types"
+        "#})
+        .unwrap();
+        let pkg = manifest.package().unwrap();
+        assert!(pkg.config().is_err());
     }
 
     // MARK: Feature collection
@@ -726,7 +856,10 @@ mod tests {
 
         let features = manifest.features();
         let keys = features.keys().copied().collect_vec();
-        assert_matches!(&*keys, ["customer", "default", "trace-context", "tracing"]);
+        assert_matches!(
+            &*keys,
+            ["customer", "default", "reqwest", "trace-context", "tracing"]
+        );
     }
 
     #[test]
@@ -754,7 +887,10 @@ mod tests {
 
         let features = manifest.features();
         let keys = features.keys().copied().collect_vec();
-        assert_matches!(&*keys, ["default", "pets", "trace-context", "tracing"]);
+        assert_matches!(
+            &*keys,
+            ["default", "pets", "reqwest", "trace-context", "tracing"]
+        );
     }
 
     #[test]
@@ -796,11 +932,53 @@ mod tests {
                 "default",
                 "oauth-2-token-2",
                 "oauth2-token",
+                "reqwest",
                 "trace-context",
                 "tracing"
             ]
         );
-        assert_eq!(features["default"], ["oauth-2-token-2", "oauth2-token"]);
+        assert_eq!(
+            features["default"],
+            ["oauth-2-token-2", "oauth2-token", "reqwest"]
+        );
+    }
+
+    #[test]
+    fn test_tag_resource_becomes_feature_name() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  tags: [Pet Store]
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let features = manifest.features();
+        let keys = features.keys().copied().collect_vec();
+        assert_matches!(
+            &*keys,
+            [
+                "default",
+                "pet-store",
+                "reqwest",
+                "trace-context",
+                "tracing"
+            ]
+        );
+        assert_eq!(features["default"], ["pet-store", "reqwest"]);
     }
 
     #[test]
@@ -830,11 +1008,61 @@ mod tests {
         // resource's feature is uniquified to `tracing-2`.
         let features = manifest.features();
         let keys = features.keys().copied().collect_vec();
-        assert_matches!(&*keys, ["default", "trace-context", "tracing", "tracing-2"]);
-        assert_eq!(features["default"], ["tracing-2"]);
+        assert_matches!(
+            &*keys,
+            [
+                "default",
+                "reqwest",
+                "trace-context",
+                "tracing",
+                "tracing-2"
+            ]
+        );
+        assert_eq!(features["default"], ["tracing-2", "reqwest"]);
         assert_eq!(features["tracing"], ["ploidy-util/tracing"]);
     }
 
+    #[test]
+    fn test_resource_named_reqwest_does_not_collide_with_reqwest_feature() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            components:
+              schemas:
+                Request:
+                  type: object
+                  x-resourceId: reqwest
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let features = &manifest.0["features"];
+        let default = features["default"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(default, ["reqwest-2", "reqwest"]);
+        let reqwest = features["reqwest"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(reqwest, ["ploidy-util/reqwest"]);
+        assert!(features.get("reqwest-2").is_some());
+    }
+
     #[test]
     fn test_unnamed_schema_creates_no_resource_features() {
         let doc = Document::from_yaml(indoc::indoc! {"
@@ -859,7 +1087,7 @@ mod tests {
 
         let features = manifest.features();
         let keys = features.keys().copied().collect_vec();
-        assert_matches!(&*keys, ["trace-context", "tracing"]);
+        assert_matches!(&*keys, ["default", "reqwest", "trace-context", "tracing"]);
     }
 
     // MARK: Schema feature dependencies
@@ -1036,7 +1264,7 @@ mod tests {
 
         let features = manifest.features();
         assert_matches!(&*features["default-2"], []);
-        assert_matches!(&*features["default"], ["default-2"]);
+        assert_matches!(&*features["default"], ["default-2", "reqwest"]);
     }
 
     // MARK: Operation feature dependencies
@@ -1175,7 +1403,7 @@ mod tests {
 
         let features = manifest.features();
         assert_matches!(&*features["default-2"], []);
-        assert_matches!(&*features["default"], ["default-2"]);
+        assert_matches!(&*features["default"], ["default-2", "reqwest"]);
     }
 
     // MARK: Diamond dependencies
@@ -1288,7 +1516,7 @@ mod tests {
         assert_eq!(features["c"], ["a"]);
 
         // `default` should include both named features.
-        assert_eq!(features["default"], ["a", "c"]);
+        assert_eq!(features["default"], ["a", "c", "reqwest"]);
     }
 
     #[test]
@@ -1340,7 +1568,7 @@ mod tests {
         assert_eq!(features["c"], ["a", "b"]);
 
         // `default` should include all three.
-        assert_eq!(features["default"], ["a", "b", "c"]);
+        assert_eq!(features["default"], ["a", "b", "c", "reqwest"]);
     }
 
     // MARK: Default feature
@@ -1385,7 +1613,10 @@ mod tests {
         // The `default` feature should include all other features,
         // but not itself.
         let features = manifest.features();
-        assert_eq!(features["default"], ["customer", "orders", "pets"]);
+        assert_eq!(
+            features["default"],
+            ["customer", "orders", "pets", "reqwest"]
+        );
     }
 
     #[test]
@@ -1413,6 +1644,217 @@ mod tests {
 
         // The `default` feature should include all named features.
         let features = manifest.features();
-        assert_eq!(features["default"], ["customer"]);
+        assert_eq!(features["default"], ["customer", "reqwest"]);
+    }
+
+    // MARK: Scopes
+
+    #[test]
+    fn test_types_only_creates_no_resource_features() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            components:
+              schemas:
+                Customer:
+                  type: object
+                  x-resourceId: customer
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            scope: CodegenScope::Types,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let features = manifest.features();
+        let keys = features.keys().copied().collect_vec();
+        assert_matches!(&*keys, ["trace-context", "tracing"]);
+    }
+
+    // MARK: Mocks
+
+    #[test]
+    fn test_emit_mocks_adds_optional_wiremock_dependency() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            emit_mocks: true,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let features = manifest.features();
+        assert_eq!(features.get("mocks"), Some(&vec!["dep:wiremock"]));
+        let wiremock = &manifest.0["dependencies"]["wiremock"];
+        assert_eq!(wiremock["version"].as_str(), Some("0.6.0"));
+        assert_eq!(wiremock["optional"].as_bool(), Some(true));
+    }
+
+    // MARK: Decimals
+
+    #[test]
+    fn test_decimal_enables_ploidy_util_decimal_feature() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Price:
+                  type: string
+                  format: decimal
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let ploidy_util = &manifest.0["dependencies"]["ploidy-util"];
+        assert_eq!(ploidy_util["version"].as_str(), Some(PLOIDY_VERSION));
+        let features = ploidy_util["features"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(features, ["decimal"]);
+    }
+
+    #[test]
+    fn test_decimal_preserves_existing_ploidy_util_features() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Price:
+                  type: number
+                  format: money
+        "})
+        .unwrap();
+
+        let manifest = CargoManifest::parse(indoc::indoc! {r#"
+            [package]
+            name = "test-client"
+            version = "0.1.0"
+
+            [dependencies]
+            ploidy-util = { version = "0.1.0", path = "../ploidy-util", features = ["did-you-mean"] }
+        "#})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let manifest = CodegenCargoManifest::new(&graph, &manifest).to_manifest();
+
+        let ploidy_util = &manifest.0["dependencies"]["ploidy-util"];
+        assert_eq!(ploidy_util["version"].as_str(), Some(PLOIDY_VERSION));
+        assert_eq!(ploidy_util["path"].as_str(), Some("../ploidy-util"));
+        let features = ploidy_util["features"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(features, ["did-you-mean", "decimal"]);
+    }
+
+    #[test]
+    fn test_pattern_properties_enable_ploidy_util_regex_feature() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Labels:
+                  type: object
+                  patternProperties:
+                    '^[a-z]+$':
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let ploidy_util = &manifest.0["dependencies"]["ploidy-util"];
+        assert_eq!(ploidy_util["version"].as_str(), Some(PLOIDY_VERSION));
+        let features = ploidy_util["features"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(features, ["regex"]);
+    }
+
+    // MARK: Blocking client
+
+    #[test]
+    fn test_blocking_enables_ploidy_util_blocking_feature() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            blocking: true,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+        let manifest = CodegenCargoManifest::new(&graph, &default_manifest()).to_manifest();
+
+        let reqwest = manifest.0["features"]["reqwest"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|feature| feature.as_str())
+            .collect_vec();
+        assert_eq!(reqwest, ["ploidy-util/reqwest", "ploidy-util/blocking"]);
+        // The `reqwest` feature enables `blocking`, so the dependency
+        // doesn't need to.
+        assert_eq!(
+            manifest.0["dependencies"]["ploidy-util"].as_str(),
+            Some(PLOIDY_VERSION)
+        );
     }
 }
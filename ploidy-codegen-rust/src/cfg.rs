@@ -1,7 +1,8 @@
 //! Feature-gating for conditional compilation.
 //!
 //! Ploidy infers Cargo features from resource markers (`x-resourceId` on types;
-//! `x-resource-name` on operations), and propagates them forward and backward.
+//! `x-resource-name` or the first tag on operations), and propagates them
+//! forward and backward.
 //!
 //! In **forward propagation**, `x-resourceId` fields become `#[cfg(...)]` attributes
 //! on types and the operations that use them. Transitivity is handled by
@@ -28,6 +29,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
 use super::{
+    config::CodegenScope,
     graph::CodegenGraph,
     naming::{AsFeatureName, ResourceGroup, UniqueIdent},
 };
@@ -57,6 +59,11 @@ impl<'a> CfgFeature<'a> {
         graph: &CodegenGraph<'a>,
         view: &SchemaTypeView<'_, 'a>,
     ) -> Option<Self> {
+        // Types-only crates don't have resource features.
+        if graph.scope() == CodegenScope::Types {
+            return None;
+        }
+
         // Types in the default resource group aren't feature-gated.
         // If this type is in the default group, or is depended on by a type
         // in that group, then it can't have a feature gate, either.
@@ -95,6 +102,10 @@ impl<'a> CfgFeature<'a> {
         graph: &CodegenGraph<'a>,
         view: &InlineTypeView<'_, 'a>,
     ) -> Option<Self> {
+        if graph.scope() == CodegenScope::Types {
+            return None;
+        }
+
         // If this type is depended on by a type in the default resource group,
         // then it can't have a feature gate.
         if view
@@ -278,7 +289,11 @@ mod tests {
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::{UniqueIdents, graph::CodegenGraph};
+    use crate::{
+        UniqueIdents,
+        config::{CodegenConfig, CodegenScope},
+        graph::CodegenGraph,
+    };
 
     // MARK: Predicates
 
@@ -1635,4 +1650,36 @@ mod tests {
         let expected: syn::Attribute = parse_quote!(#[cfg(feature = "a")]);
         assert_eq!(actual, expected);
     }
+
+    // MARK: Scopes
+
+    #[test]
+    fn test_for_schema_type_returns_none_when_types_only() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            components:
+              schemas:
+                Customer:
+                  type: object
+                  x-resourceId: customer
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            scope: CodegenScope::Types,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let customer = graph.schema("Customer").unwrap();
+        assert_eq!(CfgFeature::for_schema_type(&graph, &customer), None);
+    }
 }
@@ -1,6 +1,7 @@
 use ploidy_core::codegen::IntoCode;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
+use url::Url;
 
 use super::{
     cfg::CfgFeature,
@@ -8,60 +9,166 @@ use super::{
     naming::{CodegenIdentUsage, ResourceGroup},
 };
 
-/// Generates the `client/mod.rs` source file.
+/// Generates the `client/mod.rs` source file, or
+/// `client/blocking/mod.rs` for the blocking client.
 #[derive(Debug)]
 pub struct CodegenClientModule<'a> {
     graph: &'a CodegenGraph<'a>,
     resources: &'a [ResourceGroup<'a>],
+    blocking: bool,
 }
 
 impl<'a> CodegenClientModule<'a> {
     pub fn new(graph: &'a CodegenGraph<'a>, resources: &'a [ResourceGroup<'a>]) -> Self {
-        Self { graph, resources }
+        Self {
+            graph,
+            resources,
+            blocking: false,
+        }
+    }
+
+    /// Generates the blocking client module instead.
+    pub fn blocking(graph: &'a CodegenGraph<'a>, resources: &'a [ResourceGroup<'a>]) -> Self {
+        Self {
+            graph,
+            resources,
+            blocking: true,
+        }
     }
 }
 
 impl ToTokens for CodegenClientModule<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let client_doc = self.graph.info().label().map(|label| {
+            let kind = if self.blocking {
+                "Blocking API client"
+            } else {
+                "API client"
+            };
             let doc = match label.version {
-                Some(version) => format!("API client for {} (version {version})", label.title),
-                None => format!("API client for {}", label.title),
+                Some(version) => format!("{kind} for {} (version {version})", label.title),
+                None => format!("{kind} for {}", label.title),
             };
             quote! { #[doc = #doc] }
         });
 
+        // The async client sends requests with an `HttpClient`, and
+        // the blocking client with a `BlockingHttpClient`. Both use
+        // Reqwest if the `reqwest` feature is enabled.
+        let (http_client, reqwest_client, asyncness, await_, execute, blocking_mod) =
+            if self.blocking {
+                (
+                    quote!(::ploidy_util::transport::BlockingHttpClient),
+                    quote!(reqwest::blocking::Client),
+                    None,
+                    None,
+                    quote!(execute_blocking),
+                    None,
+                )
+            } else {
+                // The async client module declares the blocking module.
+                let blocking_mod = self.graph.blocking().then(|| {
+                    quote!(
+                        pub mod blocking;
+                    )
+                });
+                (
+                    quote!(::ploidy_util::transport::HttpClient),
+                    quote!(reqwest::Client),
+                    Some(quote!(async)),
+                    Some(quote!(.await)),
+                    quote!(execute),
+                    blocking_mod,
+                )
+            };
+
+        // Relative server URLs are resolved against the document's URL,
+        // so only absolute URLs that can be a base can be a default base URL.
+        let (new, new_example) = match self
+            .graph
+            .server()
+            .filter(|url| Url::parse(url).is_ok_and(|url| !url.cannot_be_a_base()))
+        {
+            Some(url) => (
+                quote! {
+                    /// The URL of the API's first server.
+                    pub const DEFAULT_BASE_URL: &str = #url;
+
+                    /// Creates a new client that uses Reqwest, and sends requests
+                    /// to [`DEFAULT_BASE_URL`][Self::DEFAULT_BASE_URL].
+                    #[cfg(feature = "reqwest")]
+                    pub fn new() -> Result<Self, crate::error::Error> {
+                        Ok(Self::with_reqwest_client(
+                            ::ploidy_util::#reqwest_client::new(),
+                            Self::DEFAULT_BASE_URL.parse()?,
+                        ))
+                    }
+                },
+                " let client = Client::new()?",
+            ),
+            None => (
+                quote! {
+                    /// Creates a new client that uses Reqwest.
+                    #[cfg(feature = "reqwest")]
+                    pub fn new(base_url: impl AsRef<str>) -> Result<Self, crate::error::Error> {
+                        Ok(Self::with_reqwest_client(
+                            ::ploidy_util::#reqwest_client::new(),
+                            base_url.as_ref().parse()?,
+                        ))
+                    }
+                },
+                r#" let client = Client::new("https://api.example.com")?"#,
+            ),
+        };
+
         let mods = ResourceModules(self.resources);
+        let vis = self.graph.visibility();
 
         tokens.append_all(quote! {
             #client_doc
             #[derive(Clone, Debug)]
-            pub struct Client {
-                client: ::ploidy_util::reqwest::Client,
+            #vis struct Client {
+                client: ::std::sync::Arc<dyn #http_client>,
                 headers: ::ploidy_util::http::HeaderMap,
                 base_url: ::ploidy_util::url::Url,
+                timeout: Option<::std::time::Duration>,
+                retry: ::ploidy_util::retry::RetryPolicy,
             }
 
             impl Client {
-                /// Creates a new client.
-                pub fn new(base_url: impl AsRef<str>) -> Result<Self, crate::error::Error> {
-                    Ok(Self::with_reqwest_client(
-                        ::ploidy_util::reqwest::Client::new(),
-                        base_url.as_ref().parse()?,
-                    ))
-                }
+                #new
 
+                /// Creates a new client that uses the given Reqwest client.
+                #[cfg(feature = "reqwest")]
                 pub fn with_reqwest_client(
-                    client: crate::util::reqwest::Client,
+                    client: crate::util::#reqwest_client,
+                    base_url: crate::util::url::Url,
+                ) -> Self {
+                    Self::with_http_client(client, base_url)
+                }
+
+                /// Creates a new client that sends requests with the given HTTP client.
+                pub fn with_http_client(
+                    client: impl #http_client + 'static,
                     base_url: crate::util::url::Url,
                 ) -> Self {
                     Self {
-                        client,
+                        client: ::std::sync::Arc::new(client),
                         headers: ::ploidy_util::http::HeaderMap::new(),
                         base_url,
+                        timeout: None,
+                        retry: ::ploidy_util::retry::RetryPolicy::default(),
                     }
                 }
 
+                /// Sets the base URL for each request.
+                pub fn with_base_url(self, base_url: impl AsRef<str>) -> Result<Self, crate::error::Error> {
+                    Ok(Self {
+                        base_url: base_url.as_ref().parse()?,
+                        ..self
+                    })
+                }
+
                 /// Adds a header to each request.
                 pub fn with_header<K, V>(mut self, name: K, value: V) -> Result<Self, crate::error::Error>
                 where
@@ -81,6 +188,8 @@ impl ToTokens for CodegenClientModule<'_> {
                         client: self.client,
                         headers: self.headers,
                         base_url: self.base_url,
+                        timeout: self.timeout,
+                        retry: self.retry,
                     })
                 }
 
@@ -91,9 +200,9 @@ impl ToTokens for CodegenClientModule<'_> {
                 /// # Example
                 ///
                 /// ```rust,ignore
-                /// use reqwest::header::AUTHORIZATION;
+                /// use ploidy_util::http::header::AUTHORIZATION;
                 ///
-                /// let client = Client::new("https://api.example.com")?
+                #[doc = #new_example]
                 ///     .with_sensitive_header(AUTHORIZATION, "Bearer decafbadcafed00d")?;
                 /// ```
                 pub fn with_sensitive_header<K, V>(self, name: K, value: V) -> Result<Self, crate::error::Error>
@@ -121,6 +230,22 @@ impl ToTokens for CodegenClientModule<'_> {
                     self.with_header(::ploidy_util::http::header::USER_AGENT, value)
                 }
 
+                /// Sets the default timeout for each request. Operations that
+                /// declare an `x-timeout-seconds` extension use their own
+                /// timeout instead.
+                pub fn with_timeout(self, timeout: ::std::time::Duration) -> Self {
+                    Self {
+                        timeout: Some(timeout),
+                        ..self
+                    }
+                }
+
+                /// Sets how to retry requests that fail with a transient status.
+                /// By default, requests aren't retried.
+                pub fn with_retry(self, retry: crate::util::retry::RetryPolicy) -> Self {
+                    Self { retry, ..self }
+                }
+
                 /// Returns a raw [`RequestBuilder`].
                 ///
                 /// Constructs the request URL by appending `path_and_query`
@@ -132,32 +257,41 @@ impl ToTokens for CodegenClientModule<'_> {
                 /// `https://api.example.com/v1` and `path_and_query` is
                 /// `/pets/list?limit=10`, the request URL is
                 /// `https://api.example.com/v1/pets/list?limit=10`.
-                /// Prefer using the builder's [`query`] method to append
-                /// dynamic query parameters; use `path_and_query` for static
-                /// parameters.
                 ///
                 /// The request includes the client's default headers.
                 ///
-                /// Use this for requests that the client's operation methods
-                /// don't cover.
+                /// Use this and [`send`] for requests that the client's
+                /// operation methods don't cover.
                 ///
-                /// [`RequestBuilder`]: crate::util::reqwest::RequestBuilder
-                /// [`query`]: crate::util::reqwest::RequestBuilder::query
+                /// [`RequestBuilder`]: crate::util::transport::RequestBuilder
+                /// [`send`]: Self::send
                 pub fn request(
                     &self,
-                    method: crate::util::reqwest::Method,
+                    method: crate::util::http::Method,
                     path_and_query: &str,
-                ) -> Result<crate::util::reqwest::RequestBuilder, crate::error::Error> {
+                ) -> Result<crate::util::transport::RequestBuilder, crate::error::Error> {
                     let url = ::ploidy_util::url::UrlExt::with_path_and_query(
                         self.base_url.clone(),
                         path_and_query,
                     )?;
-                    Ok(self.client
-                        .request(method, url)
+                    Ok(::ploidy_util::transport::RequestBuilder::new(method, url)
                         .headers(self.headers.clone()))
                 }
+
+                /// Sends a raw request, and returns the response.
+                /// Like the operation methods, this retries the request
+                /// according to the client's retry policy. Unlike the
+                /// operation methods, this doesn't return an error for
+                /// an unsuccessful status.
+                pub #asyncness fn send(
+                    &self,
+                    request: crate::util::transport::RequestBuilder,
+                ) -> Result<crate::util::transport::Response, crate::error::Error> {
+                    Ok(self.retry.#execute(&*self.client, request.build()?)#await_?)
+                }
             }
 
+            #blocking_mod
             #mods
         });
     }
@@ -167,7 +301,12 @@ impl IntoCode for CodegenClientModule<'_> {
     type Code = (&'static str, TokenStream);
 
     fn into_code(self) -> Self::Code {
-        ("src/client/mod.rs", self.into_token_stream())
+        let path = if self.blocking {
+            "src/client/blocking/mod.rs"
+        } else {
+            "src/client/mod.rs"
+        };
+        (path, self.into_token_stream())
     }
 }
 
@@ -196,11 +335,16 @@ impl ToTokens for ResourceModules<'_> {
 mod tests {
     use super::*;
 
-    use ploidy_core::arena::Arena;
+    use itertools::Itertools;
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::naming::UniqueIdents;
+    use crate::{CodegenConfig, Visibility, naming::UniqueIdents};
 
     #[test]
     fn test_resource_modules_gates_named_resources_and_keeps_default_ungated() {
@@ -221,4 +365,315 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_client_uses_configured_visibility() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                visibility: Visibility::PubCrate,
+                ..Default::default()
+            },
+        );
+        let codegen = CodegenClientModule::new(&graph, &[]);
+
+        let file: syn::File = parse_quote!(#codegen);
+        let Some(syn::Item::Struct(client)) = file.items.first() else {
+            panic!("expected `Client` struct; got `{:?}`", file.items.first());
+        };
+        let expected: syn::Visibility = parse_quote!(pub(crate));
+        assert_eq!(client.vis, expected);
+    }
+
+    #[test]
+    fn test_client_declares_blocking_module() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                blocking: true,
+                ..Default::default()
+            },
+        );
+        let resources = [ResourceGroup::Default];
+
+        let codegen = CodegenClientModule::new(&graph, &resources);
+        let file: syn::File = parse_quote!(#codegen);
+        let mods = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Mod(item) => Some(item.ident.to_string()),
+                _ => None,
+            })
+            .collect_vec();
+        assert_eq!(mods, ["blocking", "default"]);
+
+        // The blocking client wraps a blocking HTTP client,
+        // and doesn't declare another `blocking` module.
+        let codegen = CodegenClientModule::blocking(&graph, &resources);
+        let file: syn::File = parse_quote!(#codegen);
+        let Some(syn::Item::Struct(client)) = file.items.first() else {
+            panic!("expected `Client` struct; got `{:?}`", file.items.first());
+        };
+        let field = &client.fields.iter().next().unwrap().ty;
+        let expected: syn::Type =
+            parse_quote!(::std::sync::Arc<dyn ::ploidy_util::transport::BlockingHttpClient>);
+        assert_eq!(*field, expected);
+        let mods = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Mod(item) => Some(item.ident.to_string()),
+                _ => None,
+            })
+            .collect_vec();
+        assert_eq!(mods, ["default"]);
+    }
+
+    #[test]
+    fn test_resource_named_blocking_does_not_collide_with_blocking_module() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /jobs:
+                get:
+                  operationId: listJobs
+                  x-resource-name: blocking
+                  responses:
+                    '204':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                blocking: true,
+                ..Default::default()
+            },
+        );
+        let op = graph.operations().next().unwrap();
+        let resources = [graph.resource_for(&op)];
+
+        let codegen = CodegenClientModule::new(&graph, &resources);
+        let file: syn::File = parse_quote!(#codegen);
+        let mods = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Mod(item) => Some(item.ident.to_string()),
+                _ => None,
+            })
+            .collect_vec();
+        assert_eq!(mods, ["blocking", "blocking_2"]);
+    }
+
+    // MARK: Default base URL
+
+    #[test]
+    fn test_client_defaults_to_first_server_url() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            servers:
+              - url: https://{region}.example.com/v1
+                variables:
+                  region:
+                    default: us-east
+              - url: https://eu.example.com/v1
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let codegen = CodegenClientModule::new(&graph, &[]);
+
+        let file: syn::File = parse_quote!(#codegen);
+        let [syn::Item::Struct(_), syn::Item::Impl(client), ..] = file.items.as_slice() else {
+            panic!(
+                "expected `Client` struct and `impl`; got `{:?}`",
+                file.items
+            );
+        };
+        let [syn::ImplItem::Const(url), syn::ImplItem::Fn(new), ..] = client.items.as_slice()
+        else {
+            panic!(
+                "expected `DEFAULT_BASE_URL` constant and `new`; got `{:?}`",
+                client.items
+            );
+        };
+        let expected: syn::Expr = parse_quote!("https://us-east.example.com/v1");
+        assert_eq!(url.expr, expected);
+
+        let expected: syn::Signature = parse_quote!(fn new() -> Result<Self, crate::error::Error>);
+        assert_eq!(new.sig, expected);
+    }
+
+    #[test]
+    fn test_client_requires_base_url_without_servers() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let codegen = CodegenClientModule::new(&graph, &[]);
+
+        let file: syn::File = parse_quote!(#codegen);
+        let [syn::Item::Struct(_), syn::Item::Impl(client), ..] = file.items.as_slice() else {
+            panic!(
+                "expected `Client` struct and `impl`; got `{:?}`",
+                file.items
+            );
+        };
+        let [syn::ImplItem::Fn(new), ..] = client.items.as_slice() else {
+            panic!("expected `new`; got `{:?}`", client.items);
+        };
+        let expected: syn::Signature =
+            parse_quote!(fn new(base_url: impl AsRef<str>) -> Result<Self, crate::error::Error>);
+        assert_eq!(new.sig, expected);
+
+        // `with_base_url` overrides the base URL either way.
+        assert!(
+            client
+                .items
+                .iter()
+                .any(|item| matches!(item, syn::ImplItem::Fn(f) if f.sig.ident == "with_base_url"))
+        );
+    }
+
+    #[test]
+    fn test_client_requires_base_url_with_relative_server_url() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            servers:
+              - url: /v1
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let codegen = CodegenClientModule::new(&graph, &[]);
+
+        let file: syn::File = parse_quote!(#codegen);
+        let [syn::Item::Struct(_), syn::Item::Impl(client), ..] = file.items.as_slice() else {
+            panic!(
+                "expected `Client` struct and `impl`; got `{:?}`",
+                file.items
+            );
+        };
+        let [syn::ImplItem::Fn(new), ..] = client.items.as_slice() else {
+            panic!("expected `new`; got `{:?}`", client.items);
+        };
+        let expected: syn::Signature =
+            parse_quote!(fn new(base_url: impl AsRef<str>) -> Result<Self, crate::error::Error>);
+        assert_eq!(new.sig, expected);
+    }
+
+    #[test]
+    fn test_client_sends_raw_requests_with_retry_policy() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let codegen = CodegenClientModule::new(&graph, &[]);
+        let file: syn::File = parse_quote!(#codegen);
+        let [syn::Item::Struct(_), syn::Item::Impl(client), ..] = file.items.as_slice() else {
+            panic!(
+                "expected `Client` struct and `impl`; got `{:?}`",
+                file.items
+            );
+        };
+        let Some(with_retry) = client.items.iter().find_map(|item| match item {
+            syn::ImplItem::Fn(f) if f.sig.ident == "with_retry" => Some(f),
+            _ => None,
+        }) else {
+            panic!("expected `Client::with_retry`; got `{:?}`", client.items);
+        };
+        let expected: syn::Signature =
+            parse_quote!(fn with_retry(self, retry: crate::util::retry::RetryPolicy) -> Self);
+        assert_eq!(with_retry.sig, expected);
+        let Some(send) = client.items.iter().find_map(|item| match item {
+            syn::ImplItem::Fn(f) if f.sig.ident == "send" => Some(f),
+            _ => None,
+        }) else {
+            panic!("expected `Client::send`; got `{:?}`", client.items);
+        };
+        let expected: syn::Block =
+            parse_quote!({ Ok(self.retry.execute(&*self.client, request.build()?).await?) });
+        assert_eq!(send.block, expected);
+
+        // The blocking client retries on the calling thread.
+        let codegen = CodegenClientModule::blocking(&graph, &[]);
+        let file: syn::File = parse_quote!(#codegen);
+        let [syn::Item::Struct(_), syn::Item::Impl(client), ..] = file.items.as_slice() else {
+            panic!(
+                "expected `Client` struct and `impl`; got `{:?}`",
+                file.items
+            );
+        };
+        let Some(send) = client.items.iter().find_map(|item| match item {
+            syn::ImplItem::Fn(f) if f.sig.ident == "send" => Some(f),
+            _ => None,
+        }) else {
+            panic!("expected `Client::send`; got `{:?}`", client.items);
+        };
+        let expected: syn::Block = parse_quote!({
+            Ok(self
+                .retry
+                .execute_blocking(&*self.client, request.build()?)?)
+        });
+        assert_eq!(send.block, expected);
+    }
 }
@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+
+use ploidy_core::ir::SpecConfig;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt, quote};
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Rust code generation, read from `[package.metadata.ploidy]`
@@ -7,6 +12,146 @@ use serde::{Deserialize, Serialize};
 pub struct CodegenConfig {
     #[serde(default)]
     pub date_time_format: DateTimeFormat,
+    #[serde(default)]
+    pub method_case: MethodCase,
+    /// The name of the crate that provides the `types` module,
+    /// when generating only the client.
+    #[serde(default)]
+    pub types_crate: Option<CrateName>,
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// Whether to generate a builder for each struct.
+    #[serde(default)]
+    pub builders: bool,
+    /// Whether to mark enums and tagged unions as `#[non_exhaustive]`.
+    #[serde(default)]
+    pub non_exhaustive: bool,
+    /// Whether to add a catch-all variant to tagged unions, for
+    /// discriminator values that the spec doesn't declare.
+    #[serde(default)]
+    pub unknown_variants: bool,
+    /// Whether client methods should return the response headers
+    /// alongside the body.
+    #[serde(default)]
+    pub response_headers: bool,
+    /// Whether to generate a blocking client alongside the async client.
+    #[serde(default)]
+    pub blocking: bool,
+    /// How to detect cursor-paginated operations, for which
+    /// to generate a method that streams the items on all pages.
+    #[serde(default)]
+    pub pagination: Option<PaginationConfig>,
+    /// The Rust types to use for strings with `format`s that Ploidy
+    /// doesn't recognize, keyed by format name. Strings with other
+    /// unrecognized formats are `String`s.
+    #[serde(default)]
+    pub format_overrides: BTreeMap<String, TypePath>,
+    /// Whether to use the narrowest integer type that fits the `minimum`
+    /// and `maximum` of an `integer` without a `format`, instead of `i32`.
+    #[serde(default)]
+    pub narrow_integers: bool,
+    /// Which modules to generate. This is set from the command line,
+    /// not from the manifest.
+    #[serde(skip)]
+    pub scope: CodegenScope,
+    /// Whether to generate `wiremock` helpers for operations.
+    /// This is set from the command line, not from the manifest.
+    #[serde(skip)]
+    pub emit_mocks: bool,
+}
+
+impl From<&CodegenConfig> for SpecConfig {
+    fn from(config: &CodegenConfig) -> Self {
+        Self {
+            narrow_integers: config.narrow_integers,
+            custom_formats: config.format_overrides.keys().cloned().collect(),
+            strict: false,
+        }
+    }
+}
+
+/// The naming convention for cursor-paginated operations.
+///
+/// An operation is paginated if it has an optional query parameter named
+/// in `cursor-params`, and its response is a struct with a field named in
+/// `next-cursor-fields` of the same primitive type, and an array field
+/// named in `items-fields`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PaginationConfig {
+    /// The names of query parameters that take the cursor for a page.
+    pub cursor_params: Vec<String>,
+    /// The names of response fields that hold the cursor for the next page.
+    pub next_cursor_fields: Vec<String>,
+    /// The names of response fields that hold the items on a page.
+    pub items_fields: Vec<String>,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            cursor_params: vec!["cursor".to_owned()],
+            next_cursor_fields: vec!["next_cursor".to_owned()],
+            items_fields: vec!["items".to_owned(), "data".to_owned()],
+        }
+    }
+}
+
+/// The name of a Rust crate, like `pets-types`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CrateName(String);
+
+impl TryFrom<String> for CrateName {
+    type Error = syn::Error;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        // Dashes in crate names become underscores in paths.
+        syn::parse_str::<syn::Ident>(&name.replace('-', "_"))?;
+        Ok(Self(name))
+    }
+}
+
+impl From<CrateName> for String {
+    fn from(name: CrateName) -> Self {
+        name.0
+    }
+}
+
+impl ToTokens for CrateName {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // `try_from` checked that the name is an identifier.
+        let ident: syn::Ident = syn::parse_str(&self.0.replace('-', "_")).unwrap();
+        ident.to_tokens(tokens);
+    }
+}
+
+/// A path to a Rust type, like `crate::phone::PhoneNumber`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TypePath(String);
+
+impl TryFrom<String> for TypePath {
+    type Error = syn::Error;
+
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        syn::parse_str::<syn::Path>(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl From<TypePath> for String {
+    fn from(path: TypePath) -> Self {
+        path.0
+    }
+}
+
+impl ToTokens for TypePath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // `try_from` checked that the path parses.
+        let path: syn::Path = syn::parse_str(&self.0).unwrap();
+        path.to_tokens(tokens);
+    }
 }
 
 /// The format to use for `date-time` types.
@@ -31,3 +176,56 @@ pub enum DateTimeFormat {
     /// Unix timestamps in nanoseconds, using `ploidy_util::UnixNanoseconds`.
     UnixNanoseconds,
 }
+
+/// The case to use for client method names derived from `operationId`s.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MethodCase {
+    /// `snake_case`, like `get_user`.
+    #[default]
+    Snake,
+
+    /// `camelCase`, like `getUser`.
+    Camel,
+
+    /// The `operationId` as written, with characters that aren't valid in
+    /// Rust identifiers replaced by `_`.
+    Verbatim,
+}
+
+/// The visibility of generated types and the client.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Visibility {
+    /// `pub`, for use by consumers of the generated crate.
+    #[default]
+    #[serde(rename = "pub")]
+    Pub,
+
+    /// `pub(crate)`, for embedding the generated code privately.
+    #[serde(rename = "pub(crate)")]
+    PubCrate,
+}
+
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(match self {
+            Self::Pub => quote!(pub),
+            Self::PubCrate => quote!(pub(crate)),
+        });
+    }
+}
+
+/// Which modules of the crate to generate.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CodegenScope {
+    /// Both the `types` and `client` modules.
+    #[default]
+    All,
+
+    /// Only the `types` module, without the client or resource features.
+    Types,
+
+    /// Only the `client` module, referencing types from the crate
+    /// named by `types-crate`.
+    Client,
+}
@@ -26,15 +26,19 @@ impl ToTokens for CodegenEnum<'_> {
             // emit a type alias for the enum instead.
             let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
             let doc_attrs = self.ty.description().map(doc_attrs);
+            let examples = self.graph.examples(self.ty.id());
+            let vis = self.graph.visibility();
             tokens.append_all(quote! {
                 #doc_attrs
-                pub type #type_name = ::std::string::String;
+                #examples
+                #vis type #type_name = ::std::string::String;
             });
         } else {
             // Otherwise, emit a Rust enum.
             let mut variants = vec![];
             let mut display_arms = vec![];
             let mut from_str_arms = vec![];
+            let default = self.ty.default_variant();
 
             for variant in self.ty.variants() {
                 match variant {
@@ -43,7 +47,8 @@ impl ToTokens for CodegenEnum<'_> {
                             self.graph
                                 .ident(IdentMapping::EnumVariant(self.ty.id(), name)),
                         );
-                        variants.push(quote! { #variant_name });
+                        let default_attr = (default == Some(*variant)).then(|| quote!(#[default]));
+                        variants.push(quote! { #default_attr #variant_name });
                         display_arms.push(quote! { Self::#variant_name => #name });
                         from_str_arms.push(quote! { #name => Self::#variant_name });
                     }
@@ -61,20 +66,40 @@ impl ToTokens for CodegenEnum<'_> {
             let expecting = format!("a variant of `{}`", type_name.display());
 
             let doc_attrs = self.ty.description().map(doc_attrs);
+            let examples = self.graph.examples(self.ty.id());
+            let non_exhaustive = self
+                .graph
+                .non_exhaustive()
+                .then(|| quote!(#[non_exhaustive]));
+            let vis = self.graph.visibility();
+
+            // An enum with a `default` variant derives `Default`;
+            // others default to an empty `Other` variant.
+            let (derive_default, default_impl) = match default {
+                Some(_) => (Some(quote!(Default,)), None),
+                None => (
+                    None,
+                    Some(quote! {
+                        impl ::std::default::Default for #type_name {
+                            fn default() -> Self {
+                                Self::#other_name(::std::string::String::default())
+                            }
+                        }
+                    }),
+                ),
+            };
 
             tokens.append_all(quote! {
                 #doc_attrs
-                #[derive(Clone, Debug, Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+                #examples
+                #[derive(Clone, Debug, #derive_default Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
                 #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-                pub enum #type_name {
+                #non_exhaustive
+                #vis enum #type_name {
                     #(#variants),*
                 }
 
-                impl ::std::default::Default for #type_name {
-                    fn default() -> Self {
-                        Self::#other_name(::std::string::String::default())
-                    }
-                }
+                #default_impl
 
                 impl ::std::fmt::Display for #type_name {
                     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
@@ -143,7 +168,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::{CodegenGraph, tests::assert_matches};
+    use crate::{CodegenConfig, CodegenGraph, tests::assert_matches};
 
     // MARK: String variants
 
@@ -357,6 +382,217 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enum_non_exhaustive() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Status:
+                  type: string
+                  enum: [active]
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                non_exhaustive: true,
+                ..Default::default()
+            },
+        );
+
+        let schema = graph.schema("Status").unwrap();
+        let SchemaTypeView::Enum(_, enum_view) = schema else {
+            panic!("expected enum `Status`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenEnum::new(&graph, &enum_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemEnum = parse_quote! {
+            #[derive(Clone, Debug, Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            #[non_exhaustive]
+            pub enum Status {
+                Active,
+                OtherStatus(String)
+            }
+        };
+        assert_matches!(
+            actual.items.as_slice(),
+            [syn::Item::Enum(actual), ..] if actual == &expected
+        );
+    }
+
+    #[test]
+    fn test_enum_default_variant() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Status:
+                  type: string
+                  enum: [active, pending]
+                  default: pending
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Status").unwrap();
+        let SchemaTypeView::Enum(_, enum_view) = schema else {
+            panic!("expected enum `Status`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenEnum::new(&graph, &enum_view);
+
+        // `Default` is derived, so there's no `Default` impl
+        // before the `Display`, `FromStr`, and serde impls.
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemEnum = parse_quote! {
+            #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub enum Status {
+                Active,
+                #[default]
+                Pending,
+                OtherStatus(String)
+            }
+        };
+        assert_matches!(
+            actual.items.as_slice(),
+            [
+                syn::Item::Enum(actual),
+                syn::Item::Impl(_),
+                syn::Item::Impl(_),
+                syn::Item::Impl(_),
+                syn::Item::Impl(_),
+            ] if actual == &expected
+        );
+    }
+
+    // MARK: Variant names
+
+    #[test]
+    fn test_enum_variant_names_from_x_enum_varnames() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                OrderStatus:
+                  type: string
+                  enum: ['01', '02', '+']
+                  x-enum-varnames: [Created, Shipped, Expedited]
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("OrderStatus").unwrap();
+        let SchemaTypeView::Enum(_, enum_view) = schema else {
+            panic!("expected enum `OrderStatus`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenEnum::new(&graph, &enum_view);
+
+        // Variants are named after `x-enum-varnames`, but keep
+        // their original values on the wire.
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected_enum: syn::ItemEnum = parse_quote! {
+            #[derive(Clone, Debug, Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub enum OrderStatus {
+                Created,
+                Shipped,
+                Expedited,
+                OtherOrderStatus(String)
+            }
+        };
+        let expected_from_str: syn::ItemImpl = parse_quote! {
+            impl ::std::str::FromStr for OrderStatus {
+                type Err = ::std::convert::Infallible;
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    ::std::result::Result::Ok(
+                        match s {
+                            "01" => Self::Created,
+                            "02" => Self::Shipped,
+                            "+" => Self::Expedited,
+                            _ => Self::OtherOrderStatus(s.to_owned())
+                        }
+                    )
+                }
+            }
+        };
+        assert_matches!(
+            actual.items.as_slice(),
+            [syn::Item::Enum(actual_enum), _, _, syn::Item::Impl(actual_from_str), ..]
+                if actual_enum == &expected_enum && actual_from_str == &expected_from_str
+        );
+    }
+
+    #[test]
+    fn test_enum_variant_names_from_x_enum_names() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Level:
+                  type: string
+                  enum: [lo, hi]
+                  x-enumNames: [Low, High]
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Level").unwrap();
+        let SchemaTypeView::Enum(_, enum_view) = schema else {
+            panic!("expected enum `Level`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenEnum::new(&graph, &enum_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemEnum = parse_quote! {
+            #[derive(Clone, Debug, Eq, Hash, PartialEq, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub enum Level {
+                Low,
+                High,
+                OtherLevel(String)
+            }
+        };
+        assert_matches!(
+            actual.items.as_slice(),
+            [syn::Item::Enum(actual), ..] if actual == &expected
+        );
+    }
+
     // MARK: Unrepresentable variants
 
     #[test]
@@ -1,7 +1,8 @@
 use ploidy_core::ir::{
-    ContainerView, EnumVariant, EnumView, ParameterView, QueryParameter, StructFieldView,
-    TaggedFieldView, TypeView, UntaggedFieldView,
+    EnumVariant, EnumView, InlineTypeView, InnerView, ParameterView, PrimitiveType, QueryParameter,
+    SchemaTypeView, StructFieldView, TaggedFieldView, TypeView, UntaggedFieldView,
 };
+use unicode_ident::is_xid_continue;
 
 /// Rust-specific extensions to [`EnumView`].
 pub(crate) trait EnumViewExt {
@@ -13,15 +14,42 @@ pub(crate) trait EnumViewExt {
 
 impl EnumViewExt for EnumView<'_, '_> {
     fn representable(&self) -> bool {
-        self.variants().iter().all(|variant| match variant {
-            // Only non-empty string variants with at least one identifier
-            // character are representable as Rust enum variants.
-            EnumVariant::String(s) => s.chars().any(unicode_ident::is_xid_continue),
+        self.named_variants().all(|variant| match variant {
+            // Only string variants with at least one identifier character
+            // in their name or value are representable as Rust enum variants.
+            (EnumVariant::String(s), name) => name
+                .into_iter()
+                .chain([s])
+                .any(|s| s.chars().any(is_xid_continue)),
             _ => false,
         })
     }
 }
 
+/// Rust-specific extensions to [`InnerView`].
+pub(crate) trait InnerViewExt {
+    /// Returns `true` if the contained type implements [`Ord`].
+    /// Sets of ordered types become [`BTreeSet`]s; all other sets
+    /// become [`Vec`]s.
+    ///
+    /// [`BTreeSet`]: std::collections::BTreeSet
+    fn ordered(&self) -> bool;
+}
+
+impl InnerViewExt for InnerView<'_, '_> {
+    fn ordered(&self) -> bool {
+        // All primitives except floating-point numbers are ordered.
+        // Generated types don't derive `Ord`.
+        match self.ty() {
+            TypeView::Schema(SchemaTypeView::Primitive(_, view))
+            | TypeView::Inline(InlineTypeView::Primitive(_, view)) => {
+                !matches!(view.ty(), PrimitiveType::F32 | PrimitiveType::F64)
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Rust-specific extensions to struct, tagged, and untagged union field views.
 pub(crate) trait FieldViewExt<'graph, 'a> {
     /// Returns the inner type after peeling all `Optional` layers
@@ -29,29 +57,21 @@ pub(crate) trait FieldViewExt<'graph, 'a> {
     fn inner(&self) -> TypeView<'graph, 'a>;
 }
 
-/// Peels all [`ContainerView::Optional`] wrapper layers from a type.
-fn peel<'graph, 'a>(mut ty: TypeView<'graph, 'a>) -> TypeView<'graph, 'a> {
-    while let Some(ContainerView::Optional(inner)) = ty.as_container() {
-        ty = inner.ty();
-    }
-    ty
-}
-
 impl<'view, 'graph, 'a> FieldViewExt<'graph, 'a> for StructFieldView<'view, 'graph, 'a> {
     fn inner(&self) -> TypeView<'graph, 'a> {
-        peel(self.ty())
+        self.ty().unwrap_optional()
     }
 }
 
 impl<'view, 'graph, 'a> FieldViewExt<'graph, 'a> for TaggedFieldView<'view, 'graph, 'a> {
     fn inner(&self) -> TypeView<'graph, 'a> {
-        peel(self.ty())
+        self.ty().unwrap_optional()
     }
 }
 
 impl<'view, 'graph, 'a> FieldViewExt<'graph, 'a> for UntaggedFieldView<'view, 'graph, 'a> {
     fn inner(&self) -> TypeView<'graph, 'a> {
-        peel(self.ty())
+        self.ty().unwrap_optional()
     }
 }
 
@@ -60,12 +80,12 @@ pub(crate) trait ParameterViewExt {
     /// Returns `true` if the struct field for this parameter
     /// should be wrapped in an [`Option`]. This is the case when
     /// the parameter isn't required and its schema type isn't
-    /// already [`Optional`][ContainerView::Optional].
+    /// already [nullable][TypeView::is_nullable].
     fn optional(&self) -> bool;
 }
 
 impl<'view, 'graph, 'a> ParameterViewExt for ParameterView<'view, 'graph, 'a, QueryParameter> {
     fn optional(&self) -> bool {
-        !self.required() && !matches!(self.ty().as_container(), Some(ContainerView::Optional(_)))
+        !self.required() && !self.ty().is_nullable()
     }
 }
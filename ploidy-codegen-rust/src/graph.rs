@@ -1,5 +1,11 @@
-use std::{collections::BTreeSet, fmt::Write, num::NonZeroUsize, ops::Deref};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    num::NonZeroUsize,
+    ops::Deref,
+};
 
+use itertools::Either;
 use ploidy_core::{
     arena::Arena,
     ir::{
@@ -10,10 +16,17 @@ use ploidy_core::{
     },
     parse::ParameterLocation,
 };
+use proc_macro2::TokenStream;
+use quote::quote;
 use rustc_hash::FxHashMap;
+use syn::parse_quote;
+use unicode_ident::is_xid_continue;
 
 use super::{
-    config::{CodegenConfig, DateTimeFormat},
+    config::{
+        CodegenConfig, CodegenScope, CrateName, DateTimeFormat, MethodCase, PaginationConfig,
+        TypePath, Visibility,
+    },
     naming::{CodegenIdentUsage, ResourceGroup, UniqueIdent, UniqueIdents},
 };
 
@@ -23,6 +36,18 @@ pub struct CodegenGraph<'a> {
     cooked: CookedGraph<'a>,
     idents: IdentMap<'a>,
     date_time_format: DateTimeFormat,
+    method_case: MethodCase,
+    types_crate: Option<CrateName>,
+    visibility: Visibility,
+    builders: bool,
+    non_exhaustive: bool,
+    unknown_variants: bool,
+    response_headers: bool,
+    blocking: bool,
+    pagination: Option<PaginationConfig>,
+    format_overrides: BTreeMap<String, TypePath>,
+    scope: CodegenScope,
+    emit_mocks: bool,
 }
 
 impl<'a> CodegenGraph<'a> {
@@ -40,6 +65,18 @@ impl<'a> CodegenGraph<'a> {
             cooked,
             idents,
             date_time_format: config.date_time_format,
+            method_case: config.method_case,
+            types_crate: config.types_crate.clone(),
+            visibility: config.visibility,
+            builders: config.builders,
+            non_exhaustive: config.non_exhaustive,
+            unknown_variants: config.unknown_variants,
+            response_headers: config.response_headers,
+            blocking: config.blocking,
+            pagination: config.pagination.clone(),
+            format_overrides: config.format_overrides.clone(),
+            scope: config.scope,
+            emit_mocks: config.emit_mocks,
         }
     }
 
@@ -52,6 +89,9 @@ impl<'a> CodegenGraph<'a> {
             Operation(op) => self.idents[&Key::Operation(op)],
             Path(op, name) => self.idents[&Key::Parameter(op, ParameterLocation::Path, name)],
             Query(op, name) => self.idents[&Key::Parameter(op, ParameterLocation::Query, name)],
+            Header(op, name) => self.idents[&Key::Parameter(op, ParameterLocation::Header, name)],
+            Cookie(op, name) => self.idents[&Key::Parameter(op, ParameterLocation::Cookie, name)],
+            ResponseHeader(op, name) => self.idents[&Key::ResponseHeader(op, name)],
             Type(id) => self.idents[&Key::Type(id)],
             StructField(id, name) => self.idents[&Key::StructField(id, name)],
             EnumVariant(id, name) => self.idents[&Key::EnumVariant(id, name)],
@@ -74,6 +114,115 @@ impl<'a> CodegenGraph<'a> {
     pub fn date_time_format(&self) -> DateTimeFormat {
         self.date_time_format
     }
+
+    /// Returns the Rust type to use for strings with the given
+    /// unrecognized `format`, if the configuration overrides it.
+    #[inline]
+    pub fn format_override(&self, format: &str) -> Option<&TypePath> {
+        self.format_overrides.get(format)
+    }
+
+    /// Returns the case to use for client method names.
+    #[inline]
+    pub fn method_case(&self) -> MethodCase {
+        self.method_case
+    }
+
+    /// Returns the visibility of generated types and the client.
+    #[inline]
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    /// Returns `true` if structs should have builders.
+    #[inline]
+    pub fn builders(&self) -> bool {
+        self.builders
+    }
+
+    /// Returns `true` if enums and tagged unions should be
+    /// `#[non_exhaustive]`.
+    #[inline]
+    pub fn non_exhaustive(&self) -> bool {
+        self.non_exhaustive
+    }
+
+    /// Returns `true` if tagged unions should have a catch-all variant.
+    #[inline]
+    pub fn unknown_variants(&self) -> bool {
+        self.unknown_variants
+    }
+
+    /// Returns `true` if client methods should return the
+    /// response headers alongside the body.
+    #[inline]
+    pub fn response_headers(&self) -> bool {
+        self.response_headers
+    }
+
+    /// Returns `true` if the client should have a blocking variant.
+    #[inline]
+    pub fn blocking(&self) -> bool {
+        self.blocking
+    }
+
+    /// Returns the naming convention for paginated operations,
+    /// if the client should stream their items.
+    #[inline]
+    pub fn pagination(&self) -> Option<&PaginationConfig> {
+        self.pagination.as_ref()
+    }
+
+    /// Returns which modules of the crate to generate.
+    #[inline]
+    pub fn scope(&self) -> CodegenScope {
+        self.scope
+    }
+
+    /// Returns `true` if the crate should include `wiremock` helpers
+    /// for its operations.
+    #[inline]
+    pub fn emit_mocks(&self) -> bool {
+        self.emit_mocks && self.scope != CodegenScope::Types
+    }
+
+    /// Returns `#[doc]` attributes for an `# Examples` section that follows
+    /// a type's description, with each of a named schema type's examples
+    /// in a fenced JSON code block. Inline types don't have examples.
+    pub fn examples(&self, id: TypeId) -> Option<TokenStream> {
+        let Either::Right(schema) = self.view(id).into_schema() else {
+            return None;
+        };
+        let examples = schema.info().examples;
+        if examples.is_empty() {
+            return None;
+        }
+        let examples = examples.iter().map(|example| {
+            let lines = example.json.lines().map(|line| format!(" {line}"));
+            quote! {
+                #[doc = ""]
+                #[doc = " ```json"]
+                #(#[doc = #lines])*
+                #[doc = " ```"]
+            }
+        });
+        Some(quote! {
+            #[doc = ""]
+            #[doc = " # Examples"]
+            #(#examples)*
+        })
+    }
+
+    /// Returns the path to the module that contains the schema types.
+    ///
+    /// This is `crate::types`, unless only the client is generated,
+    /// in which case it's the `types` module of the `types-crate`.
+    pub fn types_module(&self) -> syn::Path {
+        match (self.scope, &self.types_crate) {
+            (CodegenScope::Client, Some(krate)) => parse_quote!(::#krate::types),
+            _ => parse_quote!(crate::types),
+        }
+    }
 }
 
 impl<'a> Deref for CodegenGraph<'a> {
@@ -95,6 +244,12 @@ pub enum IdentMapping<'a> {
     Path(&'a OperationId, &'a str),
     /// A query parameter for an operation.
     Query(&'a OperationId, &'a str),
+    /// A header parameter for an operation.
+    Header(&'a OperationId, &'a str),
+    /// A cookie parameter for an operation.
+    Cookie(&'a OperationId, &'a str),
+    /// A response header for an operation.
+    ResponseHeader(&'a OperationId, &'a str),
     /// A struct field.
     StructField(TypeId, StructFieldName<'a>),
     /// A string enum variant.
@@ -135,7 +290,24 @@ fn ident_map<'a>(cooked: &CookedGraph<'a>) -> IdentMap<'a> {
             .map(move |ty| (IdentMapKey::Type(ty.id()), scope.claim(ty.name())))
     });
     idents.extend({
-        let mut scope = UniqueIdents::new(cooked.arena());
+        // Operations become `Client` methods, alongside
+        // the client's own inherent methods.
+        let mut scope = UniqueIdents::with_reserved(
+            cooked.arena(),
+            &[
+                "new",
+                "with_base_url",
+                "with_http_client",
+                "with_reqwest_client",
+                "with_header",
+                "with_sensitive_header",
+                "with_user_agent",
+                "with_timeout",
+                "with_retry",
+                "request",
+                "send",
+            ],
+        );
         cooked
             .operations()
             .map(move |op| (IdentMapKey::Operation(op.id()), scope.claim(op.id())))
@@ -146,22 +318,25 @@ fn ident_map<'a>(cooked: &CookedGraph<'a>) -> IdentMap<'a> {
             .filter_map(|op| op.resource())
             .chain(cooked.schemas().filter_map(|ty| ty.resource()))
             .collect();
-        // Resources become feature names; `default`, `tracing`, and
-        // `trace-context` are special feature names.
-        let mut scope =
-            UniqueIdents::with_reserved(cooked.arena(), &["default", "tracing", "trace-context"]);
+        // Resources become feature names; `default`, `reqwest`, `tracing`,
+        // and `trace-context` are special feature names. Resources also
+        // become client modules, alongside the `blocking` module.
+        let mut scope = UniqueIdents::with_reserved(
+            cooked.arena(),
+            &["default", "reqwest", "tracing", "trace-context", "blocking"],
+        );
         resources
             .into_iter()
             .map(move |name| (IdentMapKey::Resource(name), scope.claim(name)))
     });
     for op in cooked.operations() {
         {
-            // Path parameters become arguments, so we need to reserve
-            // local variable and argument names that we use in the
-            // generated operation method body.
+            // Path, header, and cookie parameters become arguments,
+            // so we need to reserve local variable and argument names
+            // that we use in the generated operation method body.
             let mut scope = UniqueIdents::with_reserved(
                 cooked.arena(),
-                &["query", "request", "form", "url", "response"],
+                &["query", "request", "form", "url", "response", "cookies"],
             );
             for param in op.path().params() {
                 let ident = scope.claim(param.name());
@@ -170,6 +345,20 @@ fn ident_map<'a>(cooked: &CookedGraph<'a>) -> IdentMap<'a> {
                     ident,
                 );
             }
+            for param in op.headers() {
+                let ident = scope.claim(param.name());
+                idents.insert(
+                    IdentMapKey::Parameter(op.id(), ParameterLocation::Header, param.name()),
+                    ident,
+                );
+            }
+            for param in op.cookies() {
+                let ident = scope.claim(param.name());
+                idents.insert(
+                    IdentMapKey::Parameter(op.id(), ParameterLocation::Cookie, param.name()),
+                    ident,
+                );
+            }
         }
         {
             // Query parameters become regular struct fields.
@@ -182,6 +371,14 @@ fn ident_map<'a>(cooked: &CookedGraph<'a>) -> IdentMap<'a> {
                 );
             }
         }
+        {
+            // Response headers become accessor methods.
+            let mut scope = UniqueIdents::new(cooked.arena());
+            for header in op.response_headers() {
+                let ident = scope.claim(header.name());
+                idents.insert(IdentMapKey::ResponseHeader(op.id(), header.name()), ident);
+            }
+        }
     }
 
     for schema in cooked.schemas() {
@@ -235,6 +432,7 @@ enum IdentMapKey<'a> {
     Type(TypeId),
     Operation(&'a OperationId),
     Parameter(&'a OperationId, ParameterLocation, &'a str),
+    ResponseHeader(&'a OperationId, &'a str),
     Resource(&'a str),
     StructField(TypeId, StructFieldName<'a>),
     EnumVariant(TypeId, &'a str),
@@ -299,6 +497,7 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                         StructFieldName::AdditionalProperties => {
                             scope.claim("additional_properties")
                         }
+                        StructFieldName::PatternProperties(_) => scope.claim("pattern_properties"),
                     };
                     map.insert(IdentMapKey::StructField(id, name), ident);
                 }
@@ -311,9 +510,14 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                         CodegenIdentUsage::Type(idents[&IdentMapKey::Type(id)]).display()
                     )],
                 );
-                for &variant in view.variants() {
-                    if let EnumVariant::String(name) = variant {
-                        map.insert(IdentMapKey::EnumVariant(id, name), scope.claim(name));
+                for (variant, name) in view.named_variants() {
+                    if let EnumVariant::String(value) = variant {
+                        // Name the variant from its `x-enum-varnames` name if it
+                        // has any identifier characters, or its value otherwise.
+                        let source = name
+                            .filter(|name| name.chars().any(is_xid_continue))
+                            .unwrap_or(value);
+                        map.insert(IdentMapKey::EnumVariant(id, value), scope.claim(source));
                     }
                 }
             }
@@ -321,7 +525,14 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                 // Tagged variant names and common fields form different scopes:
                 // variant names must be unique within the generated enum;
                 // common fields are for naming inline types.
-                let mut scope = UniqueIdents::new(arena);
+                // Reserve the name of the optional catch-all variant.
+                let mut scope = UniqueIdents::with_reserved(
+                    arena,
+                    &[&format!(
+                        "Other{}",
+                        CodegenIdentUsage::Type(idents[&IdentMapKey::Type(id)]).display()
+                    )],
+                );
                 for variant in view.variants() {
                     let name = variant.name();
                     let ident = scope.claim(name);
@@ -342,6 +553,7 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                         StructFieldName::AdditionalProperties => {
                             scope.claim("additional_properties")
                         }
+                        StructFieldName::PatternProperties(_) => scope.claim("pattern_properties"),
                     };
                     map.insert(IdentMapKey::StructField(id, name), ident);
                 }
@@ -369,6 +581,7 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                                 PrimitiveType::U64 => "U64",
                                 PrimitiveType::F32 => "F32",
                                 PrimitiveType::F64 => "F64",
+                                PrimitiveType::Decimal => "Decimal",
                                 PrimitiveType::Bool => "Bool",
                                 PrimitiveType::DateTime => "DateTime",
                                 PrimitiveType::UnixTime => "UnixTime",
@@ -377,6 +590,7 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                                 PrimitiveType::Uuid => "Uuid",
                                 PrimitiveType::Bytes => "Bytes",
                                 PrimitiveType::Binary => "Binary",
+                                PrimitiveType::Custom(format) => format,
                             })
                         }
                         Some(Inline(Container(_, Array(_)))) => scope.claim("Array"),
@@ -408,6 +622,7 @@ impl<'graph, 'a> MemberIdentDomain<'graph, 'a> {
                         StructFieldName::AdditionalProperties => {
                             scope.claim("additional_properties")
                         }
+                        StructFieldName::PatternProperties(_) => scope.claim("pattern_properties"),
                     };
                     map.insert(IdentMapKey::StructField(id, name), ident);
                 }
@@ -437,6 +652,9 @@ fn inline_type_candidate_name<'a>(
                 let ident = idents[&IdentMapKey::UntaggedVariant(parent, ordinal)];
                 write!(name, "{}", CodegenIdentUsage::Variant(ident).display()).unwrap();
             }
+            InlineTypePathSegment::TupleElement(ordinal) => {
+                write!(name, "Item{ordinal}").unwrap();
+            }
             InlineTypePathSegment::ArrayItem => name.push_str("Item"),
             InlineTypePathSegment::MapValue => name.push_str("Value"),
             InlineTypePathSegment::Optional => {
@@ -475,8 +693,27 @@ fn inline_type_candidate_name<'a>(
                         idents[&IdentMapKey::Parameter(id, ParameterLocation::Query, param)];
                     write!(full, "Query{}", CodegenIdentUsage::Type(ident).display()).unwrap();
                 }
+                OperationUsage::Header(param) => {
+                    let ident =
+                        idents[&IdentMapKey::Parameter(id, ParameterLocation::Header, param)];
+                    write!(full, "Header{}", CodegenIdentUsage::Type(ident).display()).unwrap();
+                }
+                OperationUsage::Cookie(param) => {
+                    let ident =
+                        idents[&IdentMapKey::Parameter(id, ParameterLocation::Cookie, param)];
+                    write!(full, "Cookie{}", CodegenIdentUsage::Type(ident).display()).unwrap();
+                }
                 OperationUsage::Request => full.push_str("Request"),
                 OperationUsage::Response => full.push_str("Response"),
+                OperationUsage::ResponseHeader(header) => {
+                    let ident = idents[&IdentMapKey::ResponseHeader(id, header)];
+                    write!(
+                        full,
+                        "ResponseHeader{}",
+                        CodegenIdentUsage::Type(ident).display()
+                    )
+                    .unwrap();
+                }
             }
             full.push_str(&name);
 
@@ -5,7 +5,7 @@ use quote::{ToTokens, TokenStreamExt, quote};
 
 use super::{
     cfg::CfgFeature, enum_::CodegenEnum, graph::CodegenGraph, naming::CodegenIdentUsage,
-    struct_::CodegenStruct, tagged::CodegenTagged, untagged::CodegenUntagged,
+    struct_::CodegenStruct, tagged::CodegenTagged, tuple::CodegenTuple, untagged::CodegenUntagged,
 };
 
 /// Generates a `mod types` for inline structs, enums, and unions.
@@ -67,6 +67,10 @@ impl ToTokens for CodegenInlines<'_> {
                         graph.ident(view.id()),
                         CodegenUntagged::new(graph, view).into_token_stream(),
                     ),
+                    InlineTypeView::Tuple(_, view) => (
+                        graph.ident(view.id()),
+                        CodegenTuple::new(graph, view).into_token_stream(),
+                    ),
                     InlineTypeView::Container(..)
                     | InlineTypeView::Primitive(..)
                     | InlineTypeView::Any(..) => {
@@ -3,6 +3,7 @@ use std::{collections::BTreeMap, path::Path};
 use itertools::Itertools;
 use proc_macro2::TokenStream;
 use quote::quote;
+use rayon::prelude::*;
 
 use ploidy_core::codegen::{IntoCode, WrittenFile, write_to_disk};
 
@@ -15,8 +16,10 @@ mod enum_;
 mod ext;
 mod graph;
 mod inlines;
+mod mock;
 mod naming;
 mod operation;
+mod output;
 mod primitive;
 mod query;
 mod ref_;
@@ -25,6 +28,7 @@ mod schema;
 mod statics;
 mod struct_;
 mod tagged;
+mod tuple;
 mod types;
 mod untagged;
 
@@ -36,6 +40,7 @@ pub use cfg::*;
 pub use client::*;
 pub use config::*;
 pub use graph::*;
+pub use mock::*;
 pub use naming::*;
 pub use operation::*;
 pub use primitive::*;
@@ -49,12 +54,24 @@ pub fn write_types_to_disk(
     output: &Path,
     graph: &CodegenGraph<'_>,
 ) -> miette::Result<Vec<WrittenFile>> {
-    let mut written = Vec::new();
-
-    for schema in graph.schemas() {
-        let code = CodegenSchemaType::new(graph, &schema).into_code();
-        written.push(write_to_disk(output, code)?);
-    }
+    // Neither the graph nor token streams can cross threads, so render each
+    // schema's tokens here, then format and write the files in parallel.
+    let sources = graph
+        .schemas()
+        .map(|schema| {
+            let (path, tokens) = CodegenSchemaType::new(graph, &schema).into_code();
+            (path, tokens.to_string())
+        })
+        .collect_vec();
+    let mut written = sources
+        .into_par_iter()
+        .map(|(path, source)| {
+            let tokens: TokenStream = source
+                .parse()
+                .map_err(|err| miette::miette!("Failed to format `{path}`: {err}"))?;
+            write_to_disk(output, (path, tokens))
+        })
+        .collect::<miette::Result<Vec<_>>>()?;
 
     written.push(write_to_disk(output, CodegenTypesModule::new(graph))?);
 
@@ -90,6 +107,21 @@ pub fn write_client_to_disk(
         CodegenClientModule::new(graph, &idents),
     )?);
 
+    // Write the blocking client, if enabled. Its resource modules
+    // reuse the async client's parameter and inline types.
+    if graph.blocking() {
+        for (ident, ops) in &ops_by_resource {
+            written.push(write_to_disk(
+                output,
+                CodegenResource::blocking(graph, *ident, ops),
+            )?);
+        }
+        written.push(write_to_disk(
+            output,
+            CodegenClientModule::blocking(graph, &idents),
+        )?);
+    }
+
     Ok(written)
 }
 
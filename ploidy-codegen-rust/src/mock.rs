@@ -0,0 +1,259 @@
+use itertools::Itertools;
+use ploidy_core::{
+    codegen::IntoCode,
+    ir::{OperationView, ResponseView},
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+
+use super::{cfg::CfgFeature, graph::CodegenGraph, naming::CodegenIdentUsage, ref_::CodegenRef};
+
+/// Generates a `wiremock` helper that mounts a mock for an API operation.
+///
+/// The mock matches the operation's method and path template,
+/// and responds with the operation's declared success status and
+/// the given response body.
+pub struct CodegenMock<'a> {
+    graph: &'a CodegenGraph<'a>,
+    op: &'a OperationView<'a, 'a>,
+}
+
+impl<'a> CodegenMock<'a> {
+    pub fn new(graph: &'a CodegenGraph<'a>, op: &'a OperationView<'a, 'a>) -> Self {
+        Self { graph, op }
+    }
+}
+
+impl ToTokens for CodegenMock<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = format_ident!(
+            "mock_{}",
+            CodegenIdentUsage::Method(self.graph.ident(self.op.id()))
+        );
+        let method = self.op.method().as_str();
+
+        // Match the path template as a regular expression, with
+        // a wildcard for each parameter. The pattern is anchored
+        // at both ends, so it expects the client's base URL to be
+        // the mock server's URI.
+        let pattern = {
+            let template = self.op.path().to_string();
+            // Literal `?`s are percent-encoded, so the first `?`
+            // starts the literal query parameters.
+            let path = template.split('?').next().unwrap_or_default();
+            let mut pattern = String::from("^");
+            let mut in_param = false;
+            for c in path.chars() {
+                match c {
+                    '{' => {
+                        in_param = true;
+                        pattern.push_str("[^/]+");
+                    }
+                    '}' => in_param = false,
+                    _ if in_param => {}
+                    c => {
+                        if r"\.+*?()|[]{}^$#&-~".contains(c) {
+                            pattern.push('\\');
+                        }
+                        pattern.push(c);
+                    }
+                }
+            }
+            pattern.push('$');
+            pattern
+        };
+
+        let query = self.op.path().query().map(|param| {
+            let name = param.name;
+            let value = param.value;
+            quote!(.and(::wiremock::matchers::query_param(#name, #value)))
+        });
+
+        // Respond with the declared success status, or `200` if
+        // the operation only declares a `2XX` or `default` response.
+        let status = Literal::u16_unsuffixed(self.op.response_status().unwrap_or(200));
+        let (param, template) = match self.op.response() {
+            Some(ResponseView::Json(view)) => {
+                let ty = CodegenRef::new(self.graph, &view);
+                (
+                    Some(quote!(response: &#ty)),
+                    quote!(::wiremock::ResponseTemplate::new(#status).set_body_json(response)),
+                )
+            }
+            Some(ResponseView::Text) => (
+                Some(quote!(response: &str)),
+                quote!(::wiremock::ResponseTemplate::new(#status).set_body_string(response)),
+            ),
+            Some(ResponseView::Bytes) => (
+                Some(quote!(response: &[u8])),
+                quote!(::wiremock::ResponseTemplate::new(#status).set_body_bytes(response)),
+            ),
+            None => (None, quote!(::wiremock::ResponseTemplate::new(#status))),
+        };
+
+        let param = param.into_iter();
+
+        let doc = format!(
+            " Mounts a mock for `{} {}` on the given server.",
+            method,
+            self.op.path()
+        );
+        let cfg = CfgFeature::for_operation(self.graph, self.op);
+
+        tokens.append_all(quote! {
+            #[doc = #doc]
+            #cfg
+            pub async fn #name(server: &::wiremock::MockServer #(, #param)*) {
+                ::wiremock::Mock::given(::wiremock::matchers::method(#method))
+                    .and(::wiremock::matchers::path_regex(#pattern))
+                    #(#query)*
+                    .respond_with(#template)
+                    .mount(server)
+                    .await;
+            }
+        });
+    }
+}
+
+/// Generates the `mocks.rs` source file, with a `wiremock` helper
+/// for each operation.
+#[derive(Clone, Copy, Debug)]
+pub struct CodegenMocksModule<'a> {
+    graph: &'a CodegenGraph<'a>,
+}
+
+impl<'a> CodegenMocksModule<'a> {
+    pub fn new(graph: &'a CodegenGraph<'a>) -> Self {
+        Self { graph }
+    }
+}
+
+impl ToTokens for CodegenMocksModule<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ops = self.graph.operations().collect_vec();
+        let mocks = ops.iter().map(|op| CodegenMock::new(self.graph, op));
+        tokens.append_all(quote! {
+            #(#mocks)*
+        });
+    }
+}
+
+impl IntoCode for CodegenMocksModule<'_> {
+    type Code = (&'static str, TokenStream);
+
+    fn into_code(self) -> Self::Code {
+        ("src/mocks.rs", self.into_token_stream())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    // MARK: Mocks
+
+    #[test]
+    fn test_mock_with_path_param_and_response() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /v1.0/items/{item_id}:
+                get:
+                  operationId: getItem
+                  parameters:
+                    - name: item_id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Item'
+            components:
+              schemas:
+                Item:
+                  type: object
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let mock = CodegenMock::new(&graph, &op);
+
+        let actual: syn::ItemFn = parse_quote!(#mock);
+        let expected: syn::ItemFn = parse_quote! {
+            #[doc = " Mounts a mock for `GET /v1.0/items/{item_id}` on the given server."]
+            pub async fn mock_get_item(
+                server: &::wiremock::MockServer,
+                response: &crate::types::Item
+            ) {
+                ::wiremock::Mock::given(::wiremock::matchers::method("GET"))
+                    .and(::wiremock::matchers::path_regex("^/v1\\.0/items/[^/]+$"))
+                    .respond_with(::wiremock::ResponseTemplate::new(200).set_body_json(response))
+                    .mount(server)
+                    .await;
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_mock_without_response_matches_literal_query() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items?action=purge:
+                post:
+                  operationId: purgeItems
+                  responses:
+                    '204':
+                      description: No Content
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let mock = CodegenMock::new(&graph, &op);
+
+        let actual: syn::ItemFn = parse_quote!(#mock);
+        let expected: syn::ItemFn = parse_quote! {
+            #[doc = " Mounts a mock for `POST /items?action=purge` on the given server."]
+            pub async fn mock_purge_items(server: &::wiremock::MockServer) {
+                ::wiremock::Mock::given(::wiremock::matchers::method("POST"))
+                    .and(::wiremock::matchers::path_regex("^/items$"))
+                    .and(::wiremock::matchers::query_param("action", "purge"))
+                    .respond_with(::wiremock::ResponseTemplate::new(204))
+                    .mount(server)
+                    .await;
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+}
@@ -2,7 +2,10 @@ use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use ploidy_core::{
     arena::Arena,
-    codegen::{AsKebabCase, AsPascalCase, AsSnakeCase, NamePart, UniqueName, UniqueNames},
+    codegen::{
+        AsCamelCase, AsKebabCase, AsPascalCase, AsSnakeCase, AsVerbatimCase, NamePart, UniqueName,
+        UniqueNames,
+    },
 };
 
 use proc_macro2::{Ident, Span, TokenStream};
@@ -24,7 +27,9 @@ pub struct UniqueIdent<'a>(UniqueName<'a>);
 ///
 /// Each [`CodegenIdentUsage`] variant determines the case transformation
 /// applied to the identifier: module, field, parameter, and method names
-/// become snake_case; type and enum variant names become PascalCase.
+/// become snake_case; type and enum variant names become PascalCase;
+/// camel-case method names become camelCase; and verbatim method names
+/// keep their source case.
 ///
 /// Implements [`ToTokens`] for use in [`quote`] macros. For string interpolation,
 /// use [`display`](Self::display).
@@ -36,6 +41,8 @@ pub enum CodegenIdentUsage<'a> {
     Variant(UniqueIdent<'a>),
     Param(UniqueIdent<'a>),
     Method(UniqueIdent<'a>),
+    CamelCaseMethod(UniqueIdent<'a>),
+    VerbatimMethod(UniqueIdent<'a>),
 }
 
 impl<'a> CodegenIdentUsage<'a> {
@@ -66,6 +73,12 @@ impl<'a> CodegenIdentUsage<'a> {
                     | CodegenIdentUsage::Method(_) => {
                         write!(f, "{}", AsSnakeCase(name))
                     }
+                    CodegenIdentUsage::CamelCaseMethod(_) => {
+                        write!(f, "{}", AsCamelCase(name))
+                    }
+                    CodegenIdentUsage::VerbatimMethod(_) => {
+                        write!(f, "{}", AsVerbatimCase(name))
+                    }
                 }
             }
         }
@@ -81,6 +94,8 @@ impl<'a> CodegenIdentUsage<'a> {
             CodegenIdentUsage::Field(s) => s.0,
             CodegenIdentUsage::Param(s) => s.0,
             CodegenIdentUsage::Method(s) => s.0,
+            CodegenIdentUsage::CamelCaseMethod(s) => s.0,
+            CodegenIdentUsage::VerbatimMethod(s) => s.0,
         }
     }
 }
@@ -276,6 +291,17 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_codegen_ident_camel_case_method() {
+        let arena = Arena::new();
+        let mut scope = UniqueIdents::new(&arena);
+        let ident = scope.claim("get_user_by_id");
+        let usage = CodegenIdentUsage::CamelCaseMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(getUserById);
+        assert_eq!(actual, expected);
+    }
+
     // MARK: Special characters
 
     #[test]
@@ -328,6 +354,80 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    // MARK: Verbatim identifiers
+
+    #[test]
+    fn test_codegen_ident_verbatim_method_preserves_case() {
+        let arena = Arena::new();
+        let mut scope = UniqueIdents::new(&arena);
+        let ident = scope.claim("getUserByID");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(getUserByID);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codegen_ident_verbatim_method_replaces_invalid_chars() {
+        let arena = Arena::new();
+        let mut scope = UniqueIdents::new(&arena);
+
+        let ident = scope.claim("users.get-by-id");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(users_get_by_id);
+        assert_eq!(actual, expected);
+
+        let ident = scope.claim("1099K");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(_1099K);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codegen_ident_verbatim_method_uniquifies_collisions() {
+        let arena = Arena::new();
+        let mut scope = UniqueIdents::with_reserved(&arena, &["new"]);
+
+        // Both names become `users_get_by_id` once invalid characters
+        // are replaced, so the second one gets a suffix.
+        let first = scope.claim("users.get-by-id");
+        let second = scope.claim("users_get_by_id");
+        let usage = CodegenIdentUsage::VerbatimMethod(first);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(users_get_by_id);
+        assert_eq!(actual, expected);
+        let usage = CodegenIdentUsage::VerbatimMethod(second);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(users_get_by_id_2);
+        assert_eq!(actual, expected);
+
+        let ident = scope.claim("new");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(new_2);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codegen_ident_verbatim_method_handles_rust_keywords() {
+        let arena = Arena::new();
+        let mut scope = UniqueIdents::new(&arena);
+
+        let ident = scope.claim("type");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(r#type);
+        assert_eq!(actual, expected);
+
+        let ident = scope.claim("self");
+        let usage = CodegenIdentUsage::VerbatimMethod(ident);
+        let actual: syn::Ident = parse_quote!(#usage);
+        let expected: syn::Ident = parse_quote!(self_2);
+        assert_eq!(actual, expected);
+    }
+
     // MARK: `clean()`
 
     #[test]
@@ -1,19 +1,25 @@
 use itertools::Itertools;
 use ploidy_core::{
-    ir::{OperationView, RequestView, ResponseView},
+    ir::{
+        ContainerView, HasTypeId, InlineTypeView, OperationView, ParameterStyle, RequestView,
+        Required, ResponseView, SchemaTypeView, StructFieldName, StructFieldView, TypeView,
+    },
     parse::{
         Method,
         path::{PathFragment, PathRun},
     },
 };
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+use rustc_hash::FxHashSet;
 use syn::Ident;
 
 use super::{
+    config::MethodCase,
     doc_attrs,
+    ext::{FieldViewExt, InnerViewExt},
     graph::{CodegenGraph, IdentMapping},
-    naming::CodegenIdentUsage,
+    naming::{CodegenIdentUsage, ResourceGroup},
     ref_::CodegenRef,
 };
 
@@ -21,57 +27,356 @@ use super::{
 pub struct CodegenOperation<'a> {
     graph: &'a CodegenGraph<'a>,
     op: &'a OperationView<'a, 'a>,
+    blocking: bool,
 }
 
 impl<'a> CodegenOperation<'a> {
     pub fn new(graph: &'a CodegenGraph<'a>, op: &'a OperationView<'a, 'a>) -> Self {
-        Self { graph, op }
+        Self {
+            graph,
+            op,
+            blocking: false,
+        }
+    }
+
+    /// Generates a method for the blocking client instead.
+    /// Blocking methods build requests the same way, but
+    /// aren't `async`.
+    pub fn blocking(graph: &'a CodegenGraph<'a>, op: &'a OperationView<'a, 'a>) -> Self {
+        Self {
+            graph,
+            op,
+            blocking: true,
+        }
+    }
+
+    /// Returns the path to the resource's `parameters` module.
+    fn parameters(&self) -> TokenStream {
+        if !self.blocking {
+            // Async methods are defined alongside their parameters.
+            return quote!(parameters);
+        }
+        match self.graph.resource_for(self.op) {
+            ResourceGroup::Named(name) => {
+                let mod_name = CodegenIdentUsage::Module(name);
+                quote!(crate::client::#mod_name::parameters)
+            }
+            ResourceGroup::Default => quote!(crate::client::default::parameters),
+        }
+    }
+
+    /// Returns the names and types of the method's arguments.
+    fn params(&self) -> Vec<(TokenStream, TokenStream)> {
+        let parameters = self.parameters();
+        let mut params = vec![];
+
+        for param in self.op.path().params() {
+            let ident = CodegenIdentUsage::Param(
+                self.graph
+                    .ident(IdentMapping::Path(self.op.id(), param.name())),
+            );
+            let ty = param.ty();
+            let ty = match &ty {
+                // Take slices instead of `Vec`s.
+                TypeView::Inline(InlineTypeView::Container(_, ContainerView::Array(inner))) => {
+                    let inner_ty = inner.ty();
+                    let inner_ref = CodegenRef::new(self.graph, &inner_ty);
+                    quote! { [#inner_ref] }
+                }
+                TypeView::Inline(InlineTypeView::Container(_, ContainerView::Set(inner)))
+                    if !inner.ordered() =>
+                {
+                    let inner_ty = inner.ty();
+                    let inner_ref = CodegenRef::new(self.graph, &inner_ty);
+                    quote! { [#inner_ref] }
+                }
+                // Pass arrays and objects by reference, to be serialized.
+                TypeView::Inline(
+                    InlineTypeView::Container(_, ContainerView::Set(_) | ContainerView::Map(_))
+                    | InlineTypeView::Struct(..)
+                    | InlineTypeView::Tuple(..),
+                )
+                | TypeView::Schema(
+                    SchemaTypeView::Container(
+                        _,
+                        ContainerView::Array(_) | ContainerView::Set(_) | ContainerView::Map(_),
+                    )
+                    | SchemaTypeView::Struct(..)
+                    | SchemaTypeView::Tuple(..),
+                ) => CodegenRef::new(self.graph, &ty).into_token_stream(),
+                // Pass everything else as a string.
+                _ => quote! { str },
+            };
+            params.push((quote!(#ident), quote! { &#ty }));
+        }
+
+        // Optional header and cookie parameters are passed as `Option`s.
+        for param in self.op.headers() {
+            let ident = CodegenIdentUsage::Param(
+                self.graph
+                    .ident(IdentMapping::Header(self.op.id(), param.name())),
+            );
+            params.push((
+                quote!(#ident),
+                if param.required() {
+                    quote! { &str }
+                } else {
+                    quote! { ::std::option::Option<&str> }
+                },
+            ));
+        }
+        for param in self.op.cookies() {
+            let ident = CodegenIdentUsage::Param(
+                self.graph
+                    .ident(IdentMapping::Cookie(self.op.id(), param.name())),
+            );
+            params.push((
+                quote!(#ident),
+                if param.required() {
+                    quote! { &str }
+                } else {
+                    quote! { ::std::option::Option<&str> }
+                },
+            ));
+        }
+
+        if self.op.query().next().is_some() {
+            // Include the `query` argument if we have
+            // at least one query parameter.
+            let query_type_name = format_ident!(
+                "{}Query",
+                CodegenIdentUsage::Type(self.graph.ident(self.op.id()))
+            );
+            params.push((quote!(query), quote! { &#parameters::#query_type_name }));
+        }
+
+        if let Some(request) = self.op.request() {
+            // Optional request bodies are passed as `Option`s,
+            // because `impl Into<T>` can't infer `T` from `None`.
+            let (ident, ty) = match (request, self.op.request_required()) {
+                (RequestView::Json(view), true) => {
+                    let param_type = CodegenRef::new(self.graph, &view);
+                    (quote!(request), quote! { impl Into<#param_type> })
+                }
+                (RequestView::Json(view), false) => {
+                    let param_type = CodegenRef::new(self.graph, &view);
+                    (
+                        quote!(request),
+                        quote! { ::std::option::Option<#param_type> },
+                    )
+                }
+                (RequestView::Multipart, true) => {
+                    (quote!(form), quote! { crate::util::multipart::Form })
+                }
+                (RequestView::Multipart, false) => (
+                    quote!(form),
+                    quote! { ::std::option::Option<crate::util::multipart::Form> },
+                ),
+                (RequestView::Text, true) => {
+                    (quote!(request), quote! { impl Into<::std::string::String> })
+                }
+                (RequestView::Text, false) => (
+                    quote!(request),
+                    quote! { ::std::option::Option<::std::string::String> },
+                ),
+                (RequestView::Bytes, true) => (
+                    quote!(request),
+                    quote! { impl Into<crate::util::bytes::Bytes> },
+                ),
+                (RequestView::Bytes, false) => (
+                    quote!(request),
+                    quote! { ::std::option::Option<crate::util::bytes::Bytes> },
+                ),
+            };
+            params.push((ident, ty));
+        }
+
+        params
+    }
+
+    /// Returns the method name, and an `#[allow(...)]` attribute
+    /// if the name isn't `snake_case`.
+    fn method_name(&self) -> (TokenStream, TokenStream) {
+        match self.graph.method_case() {
+            MethodCase::Snake => (
+                CodegenIdentUsage::Method(self.graph.ident(self.op.id())).into_token_stream(),
+                quote!(),
+            ),
+            MethodCase::Camel => (
+                CodegenIdentUsage::CamelCaseMethod(self.graph.ident(self.op.id()))
+                    .into_token_stream(),
+                quote!(#[allow(non_snake_case)]),
+            ),
+            MethodCase::Verbatim => (
+                CodegenIdentUsage::VerbatimMethod(self.graph.ident(self.op.id()))
+                    .into_token_stream(),
+                quote!(#[allow(non_snake_case)]),
+            ),
+        }
     }
 
     /// Generates code to build and interpolate path and query parameters
     /// into the request URL.
     fn url(&self) -> TokenStream {
-        // Path parameters and literal segments from the path template.
-        let segments = self.op.path().runs().map(|run| match run {
-            PathRun::Literals(literals) => match &*literals {
-                [one] => quote! { .push(#one) },
-                many => quote! { .extend(&[#(#many),*]) },
-            },
-            PathRun::Templated([PathFragment::Param(name)]) => {
-                let param = CodegenIdentUsage::Param(
-                    self.graph.ident(IdentMapping::Path(self.op.id(), name)),
+        // Serialize path parameters that are arrays or objects, or that
+        // use the `label` or `matrix` style, into strings.
+        let mut serialized = FxHashSet::default();
+        let styled = self
+            .op
+            .path()
+            .params()
+            .filter_map(|param| {
+                // Arrays and objects are serialized.
+                let typed = matches!(
+                    param.ty().as_container(),
+                    Some(ContainerView::Array(_) | ContainerView::Set(_) | ContainerView::Map(_))
+                ) || matches!(
+                    param.ty(),
+                    TypeView::Schema(SchemaTypeView::Struct(..) | SchemaTypeView::Tuple(..))
+                        | TypeView::Inline(InlineTypeView::Struct(..) | InlineTypeView::Tuple(..))
                 );
-                quote! { .push(#param) }
-            }
-            PathRun::Templated(fragments) => {
-                // Build a format string, with placeholders for parameter fragments.
-                let format = fragments.iter().fold(String::new(), |mut f, fragment| {
-                    match fragment {
-                        PathFragment::Literal(text) => {
-                            f.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                let style = match (param.style(), typed) {
+                    (Some(ParameterStyle::Label { exploded }), _) => {
+                        quote!(::ploidy_util::PathStyle::Label { exploded: #exploded })
+                    }
+                    (Some(ParameterStyle::Matrix { exploded }), _) => {
+                        quote!(::ploidy_util::PathStyle::Matrix { exploded: #exploded })
+                    }
+                    (Some(ParameterStyle::Simple { exploded }), true) => {
+                        quote!(::ploidy_util::PathStyle::Simple { exploded: #exploded })
+                    }
+                    (_, true) => quote!(::ploidy_util::PathStyle::Simple { exploded: false }),
+                    // `simple` primitive values are interpolated as-is.
+                    (_, false) => return None,
+                };
+                serialized.insert(param.name());
+                let name = param.name();
+                let ident = CodegenIdentUsage::Param(
+                    self.graph
+                        .ident(IdentMapping::Path(self.op.id(), param.name())),
+                );
+                Some(quote! {
+                    let #ident = ::ploidy_util::serde::Serialize::serialize(
+                        #ident,
+                        ::ploidy_util::PathParamSerializer::new(#name, #style),
+                    )?;
+                })
+            })
+            .collect_vec();
+
+        // Path parameters and literal segments from the path template.
+        // `url::PathSegmentsMut::push` percent-encodes each segment,
+        // so segments with serialized parameters, which are already
+        // encoded, are appended to the path directly instead.
+        let mut segments = vec![];
+        let mut chain = vec![quote!(.pop_if_empty())];
+        for run in self.op.path().runs() {
+            let raw = match &run {
+                PathRun::Literals(_) => false,
+                PathRun::Templated(fragments) => fragments.iter().any(|fragment| {
+                    matches!(fragment, PathFragment::Param(name) if serialized.contains(name))
+                }),
+            };
+            let segment = match run {
+                PathRun::Literals(literals) => match &*literals {
+                    [one] => quote! { .push(#one) },
+                    many => quote! { .extend(&[#(#many),*]) },
+                },
+                PathRun::Templated([PathFragment::Param(name)]) if raw => {
+                    let param = CodegenIdentUsage::Param(
+                        self.graph.ident(IdentMapping::Path(self.op.id(), name)),
+                    );
+                    quote! {
+                        url.set_path(&format!(
+                            "{}/{}",
+                            url.path().strip_suffix('/').unwrap_or(url.path()),
+                            #param,
+                        ));
+                    }
+                }
+                PathRun::Templated([PathFragment::Param(name)]) => {
+                    let param = CodegenIdentUsage::Param(
+                        self.graph.ident(IdentMapping::Path(self.op.id(), name)),
+                    );
+                    quote! { .push(#param) }
+                }
+                PathRun::Templated(fragments) => {
+                    // Build a format string, with placeholders for parameter fragments.
+                    let format = fragments.iter().fold(String::new(), |mut f, fragment| {
+                        match fragment {
+                            PathFragment::Literal(text) => {
+                                f.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                            }
+                            PathFragment::Param(_) => f.push_str("{}"),
                         }
-                        PathFragment::Param(_) => f.push_str("{}"),
+                        f
+                    });
+                    let args = fragments
+                        .iter()
+                        .filter_map(|fragment| match fragment {
+                            PathFragment::Param(name) => Some(name),
+                            PathFragment::Literal(_) => None,
+                        })
+                        .map(|name| {
+                            let param = CodegenIdentUsage::Param(
+                                self.graph.ident(IdentMapping::Path(self.op.id(), name)),
+                            );
+                            if !raw || serialized.contains(name) {
+                                // `url::PathSegmentsMut::push` percent-encodes
+                                // the full segment, and serialized parameters
+                                // are already encoded, so we can interpolate
+                                // these fragments directly.
+                                return quote!(#param);
+                            }
+                            // Encode primitive fragments that share a segment
+                            // with serialized parameters.
+                            quote! {
+                                ::ploidy_util::serde::Serialize::serialize(
+                                    #param,
+                                    ::ploidy_util::PathParamSerializer::new(
+                                        #name,
+                                        ::ploidy_util::PathStyle::Simple { exploded: false },
+                                    ),
+                                )?
+                            }
+                        });
+                    if raw {
+                        let format = format!("{{}}/{format}");
+                        quote! {
+                            url.set_path(&format!(
+                                #format,
+                                url.path().strip_suffix('/').unwrap_or(url.path()),
+                                #(#args),*
+                            ));
+                        }
+                    } else {
+                        quote! { .push(&format!(#format, #(#args),*)) }
                     }
-                    f
-                });
-                let args = fragments
-                    .iter()
-                    .filter_map(|fragment| match fragment {
-                        PathFragment::Param(name) => Some(name),
-                        PathFragment::Literal(_) => None,
-                    })
-                    .map(|name| {
-                        // `url::PathSegmentsMut::push` percent-encodes the
-                        // full segment, so we can interpolate fragments
-                        // directly.
-                        let param = CodegenIdentUsage::Param(
-                            self.graph.ident(IdentMapping::Path(self.op.id(), name)),
-                        );
-                        quote!(#param)
+                }
+            };
+            if raw {
+                // End the current chain of pushed segments, then append
+                // this segment.
+                if !chain.is_empty() {
+                    segments.push(quote! {
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            #(#chain)*;
                     });
-                quote! { .push(&format!(#format, #(#args),*)) }
+                    chain.clear();
+                }
+                segments.push(segment);
+            } else {
+                chain.push(segment);
             }
-        });
+        }
+        if !chain.is_empty() {
+            segments.push(quote! {
+                url.path_segments_mut()
+                    .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                    #(#chain)*;
+            });
+        }
 
         // Literal query pairs from the path template.
         let pairs = self
@@ -93,6 +398,7 @@ impl<'a> CodegenOperation<'a> {
 
         // Operation query parameters.
         let query = self.op.query().next().is_some().then(|| {
+            let parameters = self.parameters();
             let query_name = format_ident!(
                 "{}Query",
                 CodegenIdentUsage::Type(self.graph.ident(self.op.id()))
@@ -102,19 +408,24 @@ impl<'a> CodegenOperation<'a> {
                     query,
                     ::ploidy_util::QuerySerializer::new(
                         url,
-                        parameters::#query_name::STYLES,
+                        #parameters::#query_name::STYLES,
                     ),
                 )?;
             }
         });
 
+        // Resolve the operation's server against the base URL, so that
+        // relative server URLs inherit the base URL's scheme and host.
+        let base_url = match self.op.server() {
+            Some(server) => quote! { self.base_url.join(#server)? },
+            None => quote! { self.base_url.clone() },
+        };
+
         quote! {
+            #(#styled)*
             let url = {
-                let mut url = self.base_url.clone();
-                url.path_segments_mut()
-                    .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
-                    .pop_if_empty()
-                    #(#segments)*;
+                let mut url = #base_url;
+                #(#segments)*
                 #pairs
                 #query
                 #[cfg(feature = "tracing")]
@@ -135,105 +446,253 @@ impl<'a> CodegenOperation<'a> {
 
 impl ToTokens for CodegenOperation<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let mut params = vec![];
+        let parameters = self.parameters();
+        // Blocking methods aren't `async`.
+        let (asyncness, await_, execute) = if self.blocking {
+            (None, None, quote!(execute_blocking))
+        } else {
+            (Some(quote!(async)), Some(quote!(.await)), quote!(execute))
+        };
 
+        let params = self
+            .params()
+            .into_iter()
+            .map(|(ident, ty)| quote!(#ident: #ty));
         let paths = self.op.path().params().collect_vec();
-        for param in &paths {
-            let param = CodegenIdentUsage::Param(
-                self.graph
-                    .ident(IdentMapping::Path(self.op.id(), param.name())),
-            );
-            params.push(quote! { #param: &str });
-        }
 
-        if self.op.query().next().is_some() {
-            // Include the `query` argument if we have
-            // at least one query parameter.
-            let query_type_name = format_ident!(
-                "{}Query",
+        let output_type_name = self.graph.response_headers().then(|| {
+            format_ident!(
+                "{}Output",
                 CodegenIdentUsage::Type(self.graph.ident(self.op.id()))
-            );
-            params.push(quote! { query: &parameters::#query_type_name });
-        }
+            )
+        });
 
-        if let Some(request) = self.op.request() {
-            match request {
-                RequestView::Json(view) => {
-                    let param_type = CodegenRef::new(self.graph, &view);
-                    params.push(quote! { request: impl Into<#param_type> });
-                }
-                RequestView::Multipart => {
-                    params.push(quote! { form: crate::util::reqwest::multipart::Form });
-                }
+        let return_type = match (&output_type_name, self.op.response()) {
+            (Some(output_type_name), _) => quote! { #parameters::#output_type_name },
+            (None, Some(ResponseView::Json(view))) => {
+                CodegenRef::new(self.graph, &view).into_token_stream()
             }
-        }
-
-        let return_type = match self.op.response() {
-            Some(response) => match response {
-                ResponseView::Json(view) => CodegenRef::new(self.graph, &view).into_token_stream(),
-            },
-            None => quote! { () },
+            (None, Some(ResponseView::Text)) => quote! { ::std::string::String },
+            (None, Some(ResponseView::Bytes)) => quote! { crate::util::bytes::Bytes },
+            (None, None) => quote! { () },
         };
 
         let url = self.url();
 
         let request = {
             let method = CodegenMethod(self.op.method());
-            let builder = match self.op.request() {
-                Some(RequestView::Json(_)) => quote! {
-                    let request = self.client
-                        .#method(url)
+            let builder = match (self.op.request(), self.op.request_required()) {
+                (Some(RequestView::Json(_)), true) => quote! {
+                    let request = ::ploidy_util::transport::RequestBuilder::new(#method, url)
                         .headers(self.headers.clone())
                         .json(&request.into());
                 },
-                Some(RequestView::Multipart) => quote! {
-                    let request = self.client
-                        .#method(url)
+                (Some(RequestView::Json(_)), false) => quote! {
+                    let request = {
+                        let builder = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                            .headers(self.headers.clone());
+                        match request {
+                            Some(request) => builder.json(&request),
+                            None => builder,
+                        }
+                    };
+                },
+                (Some(RequestView::Multipart), true) => quote! {
+                    let request = ::ploidy_util::transport::RequestBuilder::new(#method, url)
                         .headers(self.headers.clone())
                         .multipart(form);
                 },
-                None => quote! {
-                    let request = self.client
-                        .#method(url)
+                (Some(RequestView::Multipart), false) => quote! {
+                    let request = {
+                        let builder = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                            .headers(self.headers.clone());
+                        match form {
+                            Some(form) => builder.multipart(form),
+                            None => builder,
+                        }
+                    };
+                },
+                (Some(RequestView::Text), true) => quote! {
+                    let request = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                        .headers(self.headers.clone())
+                        .text(request);
+                },
+                (Some(RequestView::Text), false) => quote! {
+                    let request = {
+                        let builder = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                            .headers(self.headers.clone());
+                        match request {
+                            Some(request) => builder.text(request),
+                            None => builder,
+                        }
+                    };
+                },
+                (Some(RequestView::Bytes), true) => quote! {
+                    let request = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                        .headers(self.headers.clone())
+                        .bytes(request);
+                },
+                (Some(RequestView::Bytes), false) => quote! {
+                    let request = {
+                        let builder = ::ploidy_util::transport::RequestBuilder::new(#method, url)
+                            .headers(self.headers.clone());
+                        match request {
+                            Some(request) => builder.bytes(request),
+                            None => builder,
+                        }
+                    };
+                },
+                (None, _) => quote! {
+                    let request = ::ploidy_util::transport::RequestBuilder::new(#method, url)
                         .headers(self.headers.clone());
                 },
             };
+            // Ask for the body that we can decode if the response
+            // offers other media types.
+            let accept = self
+                .op
+                .response_content_type()
+                .filter(|_| self.op.response_content_types().nth(1).is_some())
+                .map(|content_type| {
+                    quote! {
+                        let request = request.header(
+                            crate::util::http::header::ACCEPT,
+                            #content_type,
+                        );
+                    }
+                });
+            let headers = self.op.headers().map(|param| {
+                let name = param.name();
+                let ident = CodegenIdentUsage::Param(
+                    self.graph
+                        .ident(IdentMapping::Header(self.op.id(), param.name())),
+                );
+                if param.required() {
+                    quote! {
+                        let request = request.header(#name, #ident);
+                    }
+                } else {
+                    quote! {
+                        let request = match #ident {
+                            Some(value) => request.header(#name, value),
+                            None => request,
+                        };
+                    }
+                }
+            });
+            // Fold all cookie parameters into a single `Cookie` header.
+            let cookies = self.op.cookies().next().is_some().then(|| {
+                let pairs = self.op.cookies().map(|param| {
+                    let name = param.name();
+                    let ident = CodegenIdentUsage::Param(
+                        self.graph
+                            .ident(IdentMapping::Cookie(self.op.id(), param.name())),
+                    );
+                    if param.required() {
+                        quote! { ::std::option::Option::Some((#name, #ident)) }
+                    } else {
+                        quote! { #ident.map(|value| (#name, value)) }
+                    }
+                });
+                quote! {
+                    let request = {
+                        let cookies = [#(#pairs),*]
+                            .into_iter()
+                            .flatten()
+                            .map(|(name, value)| format!("{name}={value}"))
+                            .collect::<::std::vec::Vec<_>>();
+                        if cookies.is_empty() {
+                            request
+                        } else {
+                            request.header(
+                                crate::util::http::header::COOKIE,
+                                cookies.join("; "),
+                            )
+                        }
+                    };
+                }
+            });
+            let timeout = match self.op.timeout() {
+                Some(timeout) => {
+                    let secs = Literal::f64_unsuffixed(timeout.as_secs_f64());
+                    quote! {
+                        let request = request.timeout(::std::time::Duration::from_secs_f64(#secs));
+                    }
+                }
+                None => quote! {
+                    let request = match self.timeout {
+                        Some(timeout) => request.timeout(timeout),
+                        None => request,
+                    };
+                },
+            };
             quote! {
                 let request = {
                     #builder
+                    #accept
+                    #(#headers)*
+                    #cookies
+                    #timeout
                     #[cfg(feature = "trace-context")]
                     let request = ::ploidy_util::trace::propagate(
                         ::tracing::Span::current(),
                         request,
                     );
-                    request
+                    request.build()?
                 };
-                let response = request.send().await?;
+                let response = self.retry.#execute(&*self.client, request)#await_?;
                 #[cfg(feature = "tracing")]
                 {
                     ::tracing::record_all!(::tracing::Span::current(),
                         http.response.status_code = response.status().as_u16()
                     );
                 }
-                let response = response.error_for_status()?;
+                let response = ::ploidy_util::transport::error_for_status(response)?;
             }
         };
 
-        let response = if self.op.response().is_some() {
-            quote! {
-                let body = response.bytes().await?;
+        let response = match (&output_type_name, self.op.response()) {
+            (Some(output_type_name), Some(ResponseView::Json(_))) => quote! {
+                let headers = response.headers().clone();
+                let body = response.into_body();
+                let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
+                let body = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
+                Ok(#parameters::#output_type_name { body, headers })
+            },
+            (Some(output_type_name), Some(ResponseView::Text)) => quote! {
+                let headers = response.headers().clone();
+                let body = ::std::string::String::from_utf8(response.into_body().into())?;
+                Ok(#parameters::#output_type_name { body, headers })
+            },
+            (Some(output_type_name), Some(ResponseView::Bytes)) => quote! {
+                let headers = response.headers().clone();
+                let body = response.into_body();
+                Ok(#parameters::#output_type_name { body, headers })
+            },
+            (Some(output_type_name), None) => quote! {
+                let headers = response.headers().clone();
+                Ok(#parameters::#output_type_name { headers })
+            },
+            (None, Some(ResponseView::Json(_))) => quote! {
+                let body = response.into_body();
                 let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
                 let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
                 Ok(result)
-            }
-        } else {
-            quote! {
+            },
+            (None, Some(ResponseView::Text)) => quote! {
+                let result = ::std::string::String::from_utf8(response.into_body().into())?;
+                Ok(result)
+            },
+            (None, Some(ResponseView::Bytes)) => quote! {
+                Ok(response.into_body())
+            },
+            (None, None) => quote! {
                 let _ = response;
                 Ok(())
-            }
+            },
         };
 
-        let method_name = CodegenIdentUsage::Method(self.graph.ident(self.op.id()));
+        let (method_name, allow) = self.method_name();
 
         let instrument = {
             let name = format!("{} {}", self.op.method().as_str(), self.op.path());
@@ -247,11 +706,23 @@ impl ToTokens for CodegenOperation<'_> {
                 quote!(server.address, server.port, url.full, http.response.status_code, error.type),
             ];
             fields.extend(paths.iter().map(|param| {
-                let param = CodegenIdentUsage::Param(
+                let ident = CodegenIdentUsage::Param(
                     self.graph
                         .ident(IdentMapping::Path(self.op.id(), param.name())),
                 );
-                quote!(#param = %#param)
+                // Arrays and objects don't implement `Display`.
+                if matches!(
+                    param.ty().as_container(),
+                    Some(ContainerView::Array(_) | ContainerView::Set(_) | ContainerView::Map(_))
+                ) || matches!(
+                    param.ty(),
+                    TypeView::Schema(SchemaTypeView::Struct(..) | SchemaTypeView::Tuple(..))
+                        | TypeView::Inline(InlineTypeView::Struct(..) | InlineTypeView::Tuple(..))
+                ) {
+                    quote!(#ident = ?#ident)
+                } else {
+                    quote!(#ident = %#ident)
+                }
             }));
             quote! {
                 #[cfg_attr(feature = "tracing", ::tracing::instrument(
@@ -263,7 +734,7 @@ impl ToTokens for CodegenOperation<'_> {
 
         let doc = {
             let url = format!(" {} {}", self.op.method().as_str(), self.op.path());
-            match self.op.description() {
+            let description = match self.op.description() {
                 Some(description) => {
                     let attrs = doc_attrs(description);
                     quote! {
@@ -275,21 +746,75 @@ impl ToTokens for CodegenOperation<'_> {
                 None => {
                     quote!(#[doc = #url])
                 }
+            };
+            let examples = [
+                ("Request examples", self.op.request_examples()),
+                ("Response examples", self.op.response_examples()),
+            ]
+            .into_iter()
+            .filter(|(_, examples)| !examples.is_empty())
+            .map(|(heading, examples)| {
+                let heading = format!(" # {heading}");
+                let examples = examples.iter().map(|example| {
+                    let summary = example.summary.map(|summary| {
+                        let attrs = doc_attrs(summary);
+                        quote! {
+                            #attrs
+                            #[doc = ""]
+                        }
+                    });
+                    let lines = example.json.lines().map(|line| format!(" {line}"));
+                    quote! {
+                        #[doc = ""]
+                        #summary
+                        #[doc = " ```json"]
+                        #(#[doc = #lines])*
+                        #[doc = " ```"]
+                    }
+                });
+                quote! {
+                    #[doc = ""]
+                    #[doc = #heading]
+                    #(#examples)*
+                }
+            });
+            quote! {
+                #description
+                #(#examples)*
+            }
+        };
+
+        let deprecated = self.op.deprecated().then(|| quote!(#[deprecated]));
+
+        let result = if self.blocking {
+            quote! {
+                #[allow(clippy::redundant_closure_call)]
+                let result: Result<_, crate::error::Error> = (move || {
+                    #url
+                    #request
+                    #response
+                })();
+            }
+        } else {
+            quote! {
+                let result: Result<_, crate::error::Error> = async move {
+                    #url
+                    #request
+                    #response
+                }.await;
             }
         };
 
         tokens.append_all(quote! {
             #doc
+            #deprecated
             #instrument
-            pub async fn #method_name(
+            #allow
+            pub #asyncness fn #method_name(
                 &self,
                 #(#params),*
             ) -> Result<#return_type, crate::error::Error> {
-                let result: Result<_, crate::error::Error> = async move {
-                    #url
-                    #request
-                    #response
-                }.await;
+                #result
                 #[cfg(feature = "tracing")]
                 if let Err(err) = &result {
                     ::tracing::record_all!(::tracing::Span::current(),
@@ -302,43 +827,214 @@ impl ToTokens for CodegenOperation<'_> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct CodegenMethod(pub Method);
-
-impl ToTokens for CodegenMethod {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.append(match self.0 {
-            Method::Get => Ident::new("get", Span::call_site()),
-            Method::Post => Ident::new("post", Span::call_site()),
-            Method::Put => Ident::new("put", Span::call_site()),
-            Method::Patch => Ident::new("patch", Span::call_site()),
-            Method::Delete => Ident::new("delete", Span::call_site()),
-        });
-    }
+/// Generates a client method that streams the items on all pages
+/// of a cursor-paginated operation.
+pub struct CodegenPaginatedOperation<'a> {
+    op: CodegenOperation<'a>,
+    /// The query parameter field that takes the cursor.
+    cursor: CodegenIdentUsage<'a>,
+    /// Moves the next cursor, as an `Option`, out of the `response` body.
+    next_cursor: TokenStream,
+    /// Moves the items, as a `Vec`, out of the `response` body.
+    items: TokenStream,
+    /// The type of each item.
+    item: TokenStream,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<'a> CodegenPaginatedOperation<'a> {
+    /// Returns a generator for the operation's streaming method, or `None`
+    /// if the operation doesn't follow the configured pagination convention.
+    pub fn new(graph: &'a CodegenGraph<'a>, op: &'a OperationView<'a, 'a>) -> Option<Self> {
+        let config = graph.pagination()?;
 
-    use ploidy_core::{
-        arena::Arena,
-        ir::{RawGraph, Spec},
-        parse::Document,
-    };
-    use pretty_assertions::assert_eq;
-    use syn::parse_quote;
+        // Streaming would send the same body for every page.
+        if op.request().is_some() {
+            return None;
+        }
 
-    use crate::CodegenGraph;
+        let cursor = op.query().find(|param| {
+            !param.required() && config.cursor_params.iter().any(|name| name == param.name())
+        })?;
+        let cursor_ty = match cursor.ty().unwrap_optional() {
+            TypeView::Schema(SchemaTypeView::Primitive(_, view))
+            | TypeView::Inline(InlineTypeView::Primitive(_, view)) => view.ty(),
+            _ => return None,
+        };
 
-    // MARK: With query params
+        let view = match op.response()? {
+            ResponseView::Json(
+                TypeView::Schema(SchemaTypeView::Struct(_, view))
+                | TypeView::Inline(InlineTypeView::Struct(_, view)),
+            ) => view,
+            _ => return None,
+        };
+        let body = graph.response_headers().then(|| quote!(.body));
+        let field_name = |field: &StructFieldView<'_, '_, '_>| {
+            let ident = CodegenIdentUsage::Field(
+                graph.ident(IdentMapping::StructField(view.id(), field.name())),
+            );
+            quote!(response #body.#ident)
+        };
+        let named = |field: &StructFieldView<'_, '_, '_>, names: &[String]| {
+            !field.flattened()
+                && matches!(
+                    field.name(),
+                    StructFieldName::Name(name) if names.iter().any(|n| n == name)
+                )
+        };
 
-    #[test]
-    fn test_operation_with_path_and_query_params() {
-        let doc = Document::from_yaml(indoc::indoc! {"
-            openapi: 3.0.0
-            info:
-              title: Test API
+        // The last page has no next cursor, so the field
+        // must be optional or nullable.
+        let next_cursor = view.fields().find_map(|field| {
+            if !named(&field, &config.next_cursor_fields)
+                || !matches!(
+                    field.inner(),
+                    TypeView::Schema(SchemaTypeView::Primitive(_, view))
+                        | TypeView::Inline(InlineTypeView::Primitive(_, view))
+                        if view.ty() == cursor_ty
+                )
+            {
+                return None;
+            }
+            let field_name = field_name(&field);
+            match field.required() {
+                Required::Optional => Some(quote!(#field_name.into_option())),
+                Required::Required { nullable: true } => Some(field_name),
+                Required::Required { nullable: false } => None,
+            }
+        })?;
+
+        let (items, item) = view.fields().find_map(|field| {
+            if !named(&field, &config.items_fields) {
+                return None;
+            }
+            let ty = field.inner();
+            let Some(ContainerView::Array(inner)) = ty.as_container() else {
+                return None;
+            };
+            let field_name = field_name(&field);
+            let items = match field.required() {
+                Required::Optional | Required::Required { nullable: true } => {
+                    quote!(#field_name.unwrap_or_default())
+                }
+                Required::Required { nullable: false } => field_name,
+            };
+            let item = CodegenRef::new(graph, &inner.ty()).into_token_stream();
+            Some((items, item))
+        })?;
+
+        Some(Self {
+            op: CodegenOperation::new(graph, op),
+            cursor: CodegenIdentUsage::Field(
+                graph.ident(IdentMapping::Query(op.id(), cursor.name())),
+            ),
+            next_cursor,
+            items,
+            item,
+        })
+    }
+}
+
+impl ToTokens for CodegenPaginatedOperation<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let graph = self.op.graph;
+        let op = self.op.op;
+
+        let params = self.op.params();
+        let args = params.iter().map(|(ident, _)| ident);
+        let params = params.iter().map(|(ident, ty)| quote!(#ident: #ty));
+
+        let (method_name, allow) = self.op.method_name();
+        let stream_name = match graph.method_case() {
+            MethodCase::Snake => {
+                format_ident!("{}_stream", CodegenIdentUsage::Method(graph.ident(op.id())))
+                    .into_token_stream()
+            }
+            MethodCase::Camel => format_ident!(
+                "{}Stream",
+                CodegenIdentUsage::CamelCaseMethod(graph.ident(op.id()))
+            )
+            .into_token_stream(),
+            MethodCase::Verbatim => format_ident!(
+                "{}_stream",
+                CodegenIdentUsage::VerbatimMethod(graph.ident(op.id()))
+            )
+            .into_token_stream(),
+        };
+
+        let doc = format!(
+            " Returns a stream of the items on all pages of [`Self::{}`].",
+            method_name
+        );
+        let deprecated = op.deprecated().then(|| quote!(#[deprecated]));
+        let Self {
+            cursor,
+            next_cursor,
+            items,
+            item,
+            ..
+        } = self;
+
+        tokens.append_all(quote! {
+            #[doc = #doc]
+            #deprecated
+            #allow
+            pub fn #stream_name(
+                &self,
+                #(#params),*
+            ) -> impl ::ploidy_util::pagination::Stream<
+                Item = Result<#item, crate::error::Error>,
+            > {
+                ::ploidy_util::pagination::paginate(query.clone(), move |query| async move {
+                    let response = {
+                        let query = &query;
+                        self.#method_name(#(#args),*).await?
+                    };
+                    let items = #items;
+                    let next = #next_cursor.map(|cursor| {
+                        let mut query = query;
+                        query.#cursor = Some(cursor);
+                        query
+                    });
+                    Ok((items, next))
+                })
+            }
+        });
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CodegenMethod(pub Method);
+
+impl ToTokens for CodegenMethod {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let method = Ident::new(self.0.as_str(), Span::call_site());
+        tokens.append_all(quote!(::ploidy_util::http::Method::#method));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use crate::{CodegenConfig, CodegenGraph, PaginationConfig};
+
+    // MARK: With query params
+
+    #[test]
+    fn test_operation_with_path_and_query_params() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
               version: 1.0.0
             paths:
               /items/{item_id}:
@@ -422,27 +1118,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -537,27 +1233,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -660,27 +1356,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -720,6 +1416,7 @@ mod tests {
                       schema:
                         type: boolean
                   requestBody:
+                    required: true
                     content:
                       application/json:
                         schema:
@@ -801,29 +1498,29 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .put(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::PUT, url)
                             .headers(self.headers.clone())
                             .json(&request.into());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
                     let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
                     let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
                     Ok(result)
@@ -840,6 +1537,129 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    // MARK: Optional request body
+
+    #[test]
+    fn test_operation_with_optional_request_body() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items:
+                post:
+                  operationId: createItem
+                  requestBody:
+                    required: false
+                    content:
+                      application/json:
+                        schema:
+                          $ref: '#/components/schemas/Item'
+                  responses:
+                    '204':
+                      description: No Content
+            components:
+              schemas:
+                Item:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " POST /items"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "POST /items",
+                        otel.kind = "client",
+                        url.template = "/items",
+                        http.request.method = "POST",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type
+                    )
+                )
+            )]
+            pub async fn create_item(
+                &self,
+                request: ::std::option::Option<crate::types::Item>
+            ) -> Result<(), crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .push("items");
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = {
+                            let builder = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::POST, url)
+                                .headers(self.headers.clone());
+                            match request {
+                                Some(request) => builder.json(&request),
+                                None => builder,
+                            }
+                        };
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let _ = response;
+                    Ok(())
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
     // MARK: Without query params
 
     #[test]
@@ -916,27 +1736,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -952,19 +1772,38 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
-    // MARK: Synthesized path params
+    // MARK: Header and cookie params
 
     #[test]
-    fn test_operation_with_synthesized_path_param() {
+    fn test_operation_with_header_and_cookie_params() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
               title: Test API
               version: 1.0.0
             paths:
-              /items/{item_id}:
+              /items:
                 get:
-                  operationId: getItem
+                  operationId: listItems
+                  parameters:
+                    - name: X-Request-Id
+                      in: header
+                      required: true
+                      schema:
+                        type: string
+                    - name: If-None-Match
+                      in: header
+                      schema:
+                        type: string
+                    - name: session
+                      in: cookie
+                      required: true
+                      schema:
+                        type: string
+                    - name: theme
+                      in: cookie
+                      schema:
+                        type: string
                   responses:
                     '200':
                       description: OK
@@ -980,28 +1819,30 @@ mod tests {
 
         let actual: syn::ImplItemFn = parse_quote!(#codegen);
         let expected: syn::ImplItemFn = parse_quote! {
-            #[doc = " GET /items/{item_id}"]
+            #[doc = " GET /items"]
             #[cfg_attr(
                 feature = "tracing",
                 ::tracing::instrument(
                     skip_all,
                     fields(
-                        otel.name = "GET /items/{item_id}",
+                        otel.name = "GET /items",
                         otel.kind = "client",
-                        url.template = "/items/{item_id}",
+                        url.template = "/items",
                         http.request.method = "GET",
                         server.address,
                         server.port,
                         url.full,
                         http.response.status_code,
-                        error.type,
-                        item_id = %item_id
+                        error.type
                     )
                 )
             )]
-            pub async fn get_item(
+            pub async fn list_items(
                 &self,
-                item_id: &str
+                x_request_id: &str,
+                if_none_match: ::std::option::Option<&str>,
+                session: &str,
+                theme: ::std::option::Option<&str>
             ) -> Result<(), crate::error::Error> {
                 let result: Result<_, crate::error::Error> = async move {
                     let url = {
@@ -1009,8 +1850,7 @@ mod tests {
                         url.path_segments_mut()
                             .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
                             .pop_if_empty()
-                            .push("items")
-                            .push(item_id);
+                            .push("items");
                         #[cfg(feature = "tracing")]
                         {
                             ::tracing::record_all!(::tracing::Span::current(),
@@ -1022,27 +1862,50 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = request.header("X-Request-Id", x_request_id);
+                        let request = match if_none_match {
+                            Some(value) => request.header("If-None-Match", value),
+                            None => request,
+                        };
+                        let request = {
+                            let cookies = [
+                                ::std::option::Option::Some(("session", session)),
+                                theme.map(|value| ("theme", value))
+                            ]
+                            .into_iter()
+                            .flatten()
+                            .map(|(name, value)| format!("{name}={value}"))
+                            .collect::<::std::vec::Vec<_>>();
+                            if cookies.is_empty() {
+                                request
+                            } else {
+                                request.header(
+                                    crate::util::http::header::COOKIE,
+                                    cookies.join("; "),
+                                )
+                            }
+                        };
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -1058,38 +1921,1250 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
-    // MARK: Literal query params in path
+    // MARK: Path parameter styles
 
     #[test]
-    fn test_operation_with_literal_query_params() {
+    fn test_operation_path_parameter_styles() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
               title: Test API
               version: 1.0.0
             paths:
-              /v1/messages?beta=true&expand:
-                post:
-                  operationId: betaCreateMessage
-                  requestBody:
-                    content:
-                      application/json:
-                        schema:
-                          $ref: '#/components/schemas/Message'
+              /items/{id}/{ids}{kind}/{color}.{format}:
+                get:
+                  operationId: getItems
+                  parameters:
+                    - name: id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                    - name: ids
+                      in: path
+                      required: true
+                      schema:
+                        type: array
+                        items:
+                          type: string
+                      style: label
+                      explode: true
+                    - name: kind
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                      style: matrix
+                    - name: color
+                      in: path
+                      required: true
+                      schema:
+                        type: object
+                        additionalProperties:
+                          type: integer
+                    - name: format
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        // Arrays and objects are typed arguments; primitives are strings.
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Signature = parse_quote! {
+            async fn get_items(
+                &self,
+                id: &str,
+                ids: &[::std::string::String],
+                kind: &str,
+                color: &::std::collections::BTreeMap<::std::string::String, i32>,
+                format: &str
+            ) -> Result<(), crate::error::Error>
+        };
+        assert_eq!(actual.sig, expected);
+
+        let url = codegen.url();
+        let actual: syn::Block = parse_quote!({ #url });
+        let expected: syn::Block = parse_quote! {{
+            let ids = ::ploidy_util::serde::Serialize::serialize(
+                ids,
+                ::ploidy_util::PathParamSerializer::new(
+                    "ids",
+                    ::ploidy_util::PathStyle::Label { exploded: true }
+                ),
+            )?;
+            let kind = ::ploidy_util::serde::Serialize::serialize(
+                kind,
+                ::ploidy_util::PathParamSerializer::new(
+                    "kind",
+                    ::ploidy_util::PathStyle::Matrix { exploded: false }
+                ),
+            )?;
+            let color = ::ploidy_util::serde::Serialize::serialize(
+                color,
+                ::ploidy_util::PathParamSerializer::new(
+                    "color",
+                    ::ploidy_util::PathStyle::Simple { exploded: false }
+                ),
+            )?;
+            let url = {
+                let mut url = self.base_url.clone();
+                url.path_segments_mut()
+                    .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                    .pop_if_empty()
+                    .push("items")
+                    .push(id);
+                url.set_path(&format!(
+                    "{}/{}{}",
+                    url.path().strip_suffix('/').unwrap_or(url.path()),
+                    ids,
+                    kind
+                ));
+                url.set_path(&format!(
+                    "{}/{}.{}",
+                    url.path().strip_suffix('/').unwrap_or(url.path()),
+                    color,
+                    ::ploidy_util::serde::Serialize::serialize(
+                        format,
+                        ::ploidy_util::PathParamSerializer::new(
+                            "format",
+                            ::ploidy_util::PathStyle::Simple { exploded: false },
+                        ),
+                    )?
+                ));
+                #[cfg(feature = "tracing")]
+                {
+                    ::tracing::record_all!(
+                        ::tracing::Span::current(),
+                        server.address = url.host_str(),
+                        server.port = url.port_or_known_default(),
+                        url.full = url.as_str(),
+                    );
+                }
+                url
+            };
+        }};
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Synthesized path params
+
+    #[test]
+    fn test_operation_with_synthesized_path_param() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items/{item_id}:
+                get:
+                  operationId: getItem
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " GET /items/{item_id}"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "GET /items/{item_id}",
+                        otel.kind = "client",
+                        url.template = "/items/{item_id}",
+                        http.request.method = "GET",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type,
+                        item_id = %item_id
+                    )
+                )
+            )]
+            pub async fn get_item(
+                &self,
+                item_id: &str
+            ) -> Result<(), crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .push("items")
+                            .push(item_id);
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
+                            .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let _ = response;
+                    Ok(())
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Literal query params in path
+
+    #[test]
+    fn test_operation_with_literal_query_params() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /v1/messages?beta=true&expand:
+                post:
+                  operationId: betaCreateMessage
+                  requestBody:
+                    required: true
+                    content:
+                      application/json:
+                        schema:
+                          $ref: '#/components/schemas/Message'
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Message'
+            components:
+              schemas:
+                Message:
+                  type: object
+                  properties:
+                    content:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " POST /v1/messages?beta=true&expand="]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "POST /v1/messages?beta=true&expand=",
+                        otel.kind = "client",
+                        url.template = "/v1/messages?beta=true&expand=",
+                        http.request.method = "POST",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type
+                    )
+                )
+            )]
+            pub async fn beta_create_message(
+                &self,
+                request: impl Into<crate::types::Message>
+            ) -> Result<crate::types::Message, crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .extend(&["v1", "messages"]);
+                        url.query_pairs_mut()
+                            .append_pair("beta", "true")
+                            .append_pair("expand", "");
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::POST, url)
+                            .headers(self.headers.clone())
+                            .json(&request.into());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
+                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
+                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
+                    Ok(result)
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_operation_with_literal_and_declared_query_params() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /v1/messages?beta=true:
+                post:
+                  operationId: betaCreateMessage
+                  parameters:
+                    - name: limit
+                      in: query
+                      schema:
+                        type: integer
+                        format: int32
+                  requestBody:
+                    required: true
+                    content:
+                      application/json:
+                        schema:
+                          $ref: '#/components/schemas/Message'
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Message'
+            components:
+              schemas:
+                Message:
+                  type: object
+                  properties:
+                    content:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " POST /v1/messages?beta=true"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "POST /v1/messages?beta=true",
+                        otel.kind = "client",
+                        url.template = "/v1/messages?beta=true",
+                        http.request.method = "POST",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type
+                    )
+                )
+            )]
+            pub async fn beta_create_message(
+                &self,
+                query: &parameters::BetaCreateMessageQuery,
+                request: impl Into<crate::types::Message>
+            ) -> Result<crate::types::Message, crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .extend(&["v1", "messages"]);
+                        url.query_pairs_mut()
+                            .append_pair("beta", "true");
+                        let url = ::ploidy_util::serde::Serialize::serialize(
+                            query,
+                            ::ploidy_util::QuerySerializer::new(
+                                url,
+                                parameters::BetaCreateMessageQuery::STYLES,
+                            ),
+                        )?;
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::POST, url)
+                            .headers(self.headers.clone())
+                            .json(&request.into());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
+                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
+                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
+                    Ok(result)
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_operation_with_path_params_and_literal_and_declared_query_params() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /v1/models/{model_id}?beta=true:
+                get:
+                  operationId: betaGetModel
+                  parameters:
+                    - name: model_id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                    - name: expand
+                      in: query
+                      schema:
+                        type: boolean
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Model'
+            components:
+              schemas:
+                Model:
+                  type: object
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " GET /v1/models/{model_id}?beta=true"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "GET /v1/models/{model_id}?beta=true",
+                        otel.kind = "client",
+                        url.template = "/v1/models/{model_id}?beta=true",
+                        http.request.method = "GET",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type,
+                        model_id = %model_id
+                    )
+                )
+            )]
+            pub async fn beta_get_model(
+                &self,
+                model_id: &str,
+                query: &parameters::BetaGetModelQuery
+            ) -> Result<crate::types::Model, crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .extend(&["v1", "models"])
+                            .push(model_id);
+                        url.query_pairs_mut()
+                            .append_pair("beta", "true");
+                        let url = ::ploidy_util::serde::Serialize::serialize(
+                            query,
+                            ::ploidy_util::QuerySerializer::new(
+                                url,
+                                parameters::BetaGetModelQuery::STYLES,
+                            ),
+                        )?;
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
+                            .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
+                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
+                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
+                    Ok(result)
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Blocking client
+
+    #[test]
+    fn test_blocking_operation() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items:
+                get:
+                  operationId: getItems
+                  x-resource-name: item
+                  parameters:
+                    - name: limit
+                      in: query
+                      schema:
+                        type: integer
+                        format: int32
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            type: array
+                            items:
+                              type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::blocking(&graph, &op);
+
+        // Blocking methods aren't `async`, and refer to the
+        // async client's `parameters` module by its full path.
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " GET /items"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "GET /items",
+                        otel.kind = "client",
+                        url.template = "/items",
+                        http.request.method = "GET",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type
+                    )
+                )
+            )]
+            pub fn get_items(
+                &self,
+                query: &crate::client::item::parameters::GetItemsQuery
+            ) -> Result<::std::vec::Vec<::std::string::String>, crate::error::Error> {
+                #[allow(clippy::redundant_closure_call)]
+                let result: Result<_, crate::error::Error> = (move || {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .push("items");
+                        let url = ::ploidy_util::serde::Serialize::serialize(
+                            query,
+                            ::ploidy_util::QuerySerializer::new(
+                                url,
+                                crate::client::item::parameters::GetItemsQuery::STYLES,
+                            ),
+                        )?;
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
+                            .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute_blocking(&*self.client, request)?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
+                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
+                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
+                    Ok(result)
+                })();
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_blocking_operation_takes_multipart_form() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /uploads:
+                post:
+                  operationId: upload
+                  requestBody:
+                    required: true
+                    content:
+                      multipart/form-data:
+                        schema:
+                          type: object
+                  responses:
+                    '204':
+                      description: Uploaded
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::blocking(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Signature = parse_quote! {
+            fn upload(
+                &self,
+                form: crate::util::multipart::Form
+            ) -> Result<(), crate::error::Error>
+        };
+        assert_eq!(actual.sig, expected);
+    }
+
+    // MARK: Text and binary bodies
+
+    #[test]
+    fn test_operation_takes_text_request_body() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /notes:
+                put:
+                  operationId: putNote
+                  requestBody:
+                    required: true
+                    content:
+                      text/plain:
+                        schema:
+                          type: string
+                  responses:
+                    '200':
+                      description: Saved
+                      content:
+                        text/plain:
+                          schema:
+                            type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Signature = parse_quote! {
+            async fn put_note(
+                &self,
+                request: impl Into<::std::string::String>
+            ) -> Result<::std::string::String, crate::error::Error>
+        };
+        assert_eq!(actual.sig, expected);
+    }
+
+    #[test]
+    fn test_operation_returns_bytes_response() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /download:
+                get:
+                  operationId: download
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/octet-stream:
+                          schema:
+                            type: string
+                            format: binary
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " GET /download"]
+            #[cfg_attr(
+                feature = "tracing",
+                ::tracing::instrument(
+                    skip_all,
+                    fields(
+                        otel.name = "GET /download",
+                        otel.kind = "client",
+                        url.template = "/download",
+                        http.request.method = "GET",
+                        server.address,
+                        server.port,
+                        url.full,
+                        http.response.status_code,
+                        error.type
+                    )
+                )
+            )]
+            pub async fn download(
+                &self,
+            ) -> Result<crate::util::bytes::Bytes, crate::error::Error> {
+                let result: Result<_, crate::error::Error> = async move {
+                    let url = {
+                        let mut url = self.base_url.clone();
+                        url.path_segments_mut()
+                            .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
+                            .pop_if_empty()
+                            .push("download");
+                        #[cfg(feature = "tracing")]
+                        {
+                            ::tracing::record_all!(::tracing::Span::current(),
+                                server.address = url.host_str(),
+                                server.port = url.port_or_known_default(),
+                                url.full = url.as_str(),
+                            );
+                        }
+                        url
+                    };
+                    let request = {
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
+                            .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
+                        #[cfg(feature = "trace-context")]
+                        let request = ::ploidy_util::trace::propagate(
+                            ::tracing::Span::current(),
+                            request,
+                        );
+                        request.build()?
+                    };
+                    let response = self.retry.execute(&*self.client, request).await?;
+                    #[cfg(feature = "tracing")]
+                    {
+                        ::tracing::record_all!(::tracing::Span::current(),
+                            http.response.status_code = response.status().as_u16()
+                        );
+                    }
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    Ok(response.into_body())
+                }.await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = &result {
+                    ::tracing::record_all!(::tracing::Span::current(),
+                        error.type = %err.category(),
+                    );
+                }
+                result
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Method case
+
+    #[test]
+    fn test_operation_with_snake_method_case() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /users/{id}:
+                get:
+                  operationId: getUserByID
+                  parameters:
+                    - name: id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                method_case: MethodCase::Snake,
+                ..Default::default()
+            },
+        );
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Ident = parse_quote!(get_user_by_id);
+        assert_eq!(actual.sig.ident, expected);
+        let allow: syn::Attribute = parse_quote!(#[allow(non_snake_case)]);
+        assert!(!actual.attrs.contains(&allow));
+    }
+
+    #[test]
+    fn test_operation_with_camel_method_case() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /users/{id}:
+                get:
+                  operationId: getUserByID
+                  parameters:
+                    - name: id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                method_case: MethodCase::Camel,
+                ..Default::default()
+            },
+        );
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Ident = parse_quote!(getUserById);
+        assert_eq!(actual.sig.ident, expected);
+        let allow: syn::Attribute = parse_quote!(#[allow(non_snake_case)]);
+        assert!(actual.attrs.contains(&allow));
+    }
+
+    #[test]
+    fn test_operation_with_verbatim_method_case() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /users/{id}:
+                get:
+                  operationId: getUserByID
+                  parameters:
+                    - name: id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                method_case: MethodCase::Verbatim,
+                ..Default::default()
+            },
+        );
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Ident = parse_quote!(getUserByID);
+        assert_eq!(actual.sig.ident, expected);
+        let allow: syn::Attribute = parse_quote!(#[allow(non_snake_case)]);
+        assert!(actual.attrs.contains(&allow));
+    }
+
+    #[test]
+    fn test_operation_named_send_does_not_collide_with_client_method() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /messages:
+                post:
+                  operationId: send
+                  responses:
+                    '204':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        // `Client::send` sends raw requests.
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::Ident = parse_quote!(send_2);
+        assert_eq!(actual.sig.ident, expected);
+    }
+
+    // MARK: Deprecation
+
+    #[test]
+    fn test_deprecated_operation_emits_deprecated_method() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /users:
+                get:
+                  operationId: listUsers
+                  deprecated: true
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let deprecated: syn::Attribute = parse_quote!(#[deprecated]);
+        assert!(actual.attrs.contains(&deprecated));
+    }
+
+    // MARK: Examples
+
+    #[test]
+    fn test_operation_examples_emit_doc_sections() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /users:
+                post:
+                  operationId: createUser
+                  description: Creates a user.
+                  requestBody:
+                    content:
+                      application/json:
+                        schema:
+                          type: string
+                        examples:
+                          alice:
+                            summary: A user named Alice.
+                            value: alice
+                  responses:
+                    '201':
+                      description: Created
+                      content:
+                        application/json:
+                          schema:
+                            type: integer
+                          example: 1
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let docs = actual
+            .attrs
+            .into_iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect_vec();
+        let expected: Vec<syn::Attribute> = vec![
+            parse_quote!(#[doc = " Creates a user."]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " POST /users"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " # Request examples"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " A user named Alice."]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " ```json"]),
+            parse_quote!(#[doc = " \"alice\""]),
+            parse_quote!(#[doc = " ```"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " # Response examples"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " ```json"]),
+            parse_quote!(#[doc = " 1"]),
+            parse_quote!(#[doc = " ```"]),
+        ];
+        assert_eq!(docs, expected);
+    }
+
+    // MARK: Timeouts
+
+    #[test]
+    fn test_operation_with_timeout_extension_sets_timeout() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /reports:
+                post:
+                  operationId: createReport
+                  x-timeout-seconds: 2.5
                   responses:
                     '200':
                       description: OK
-                      content:
-                        application/json:
-                          schema:
-                            $ref: '#/components/schemas/Message'
-            components:
-              schemas:
-                Message:
-                  type: object
-                  properties:
-                    content:
-                      type: string
         "})
         .unwrap();
 
@@ -1102,15 +3177,15 @@ mod tests {
 
         let actual: syn::ImplItemFn = parse_quote!(#codegen);
         let expected: syn::ImplItemFn = parse_quote! {
-            #[doc = " POST /v1/messages?beta=true&expand="]
+            #[doc = " POST /reports"]
             #[cfg_attr(
                 feature = "tracing",
                 ::tracing::instrument(
                     skip_all,
                     fields(
-                        otel.name = "POST /v1/messages?beta=true&expand=",
+                        otel.name = "POST /reports",
                         otel.kind = "client",
-                        url.template = "/v1/messages?beta=true&expand=",
+                        url.template = "/reports",
                         http.request.method = "POST",
                         server.address,
                         server.port,
@@ -1120,20 +3195,16 @@ mod tests {
                     )
                 )
             )]
-            pub async fn beta_create_message(
+            pub async fn create_report(
                 &self,
-                request: impl Into<crate::types::Message>
-            ) -> Result<crate::types::Message, crate::error::Error> {
+            ) -> Result<(), crate::error::Error> {
                 let result: Result<_, crate::error::Error> = async move {
                     let url = {
                         let mut url = self.base_url.clone();
                         url.path_segments_mut()
                             .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
                             .pop_if_empty()
-                            .extend(&["v1", "messages"]);
-                        url.query_pairs_mut()
-                            .append_pair("beta", "true")
-                            .append_pair("expand", "");
+                            .push("reports");
                         #[cfg(feature = "tracing")]
                         {
                             ::tracing::record_all!(::tracing::Span::current(),
@@ -1145,32 +3216,26 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .post(url)
-                            .headers(self.headers.clone())
-                            .json(&request.into());
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::POST, url)
+                            .headers(self.headers.clone());
+                        let request = request.timeout(::std::time::Duration::from_secs_f64(2.5));
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
-                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
-                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
-                    Ok(result)
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let _ = response;
+                    Ok(())
                 }.await;
                 #[cfg(feature = "tracing")]
                 if let Err(err) = &result {
@@ -1184,42 +3249,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    // MARK: Servers
+
     #[test]
-    fn test_operation_with_literal_and_declared_query_params() {
+    fn test_operation_with_server_resolves_against_base_url() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
               title: Test API
               version: 1.0.0
             paths:
-              /v1/messages?beta=true:
+              /reports:
                 post:
-                  operationId: betaCreateMessage
-                  parameters:
-                    - name: limit
-                      in: query
-                      schema:
-                        type: integer
-                        format: int32
-                  requestBody:
-                    content:
-                      application/json:
-                        schema:
-                          $ref: '#/components/schemas/Message'
+                  operationId: createReport
+                  servers:
+                    - url: https://reports.example.com/v2
                   responses:
                     '200':
                       description: OK
-                      content:
-                        application/json:
-                          schema:
-                            $ref: '#/components/schemas/Message'
-            components:
-              schemas:
-                Message:
-                  type: object
-                  properties:
-                    content:
-                      type: string
         "})
         .unwrap();
 
@@ -1232,15 +3279,15 @@ mod tests {
 
         let actual: syn::ImplItemFn = parse_quote!(#codegen);
         let expected: syn::ImplItemFn = parse_quote! {
-            #[doc = " POST /v1/messages?beta=true"]
+            #[doc = " POST /reports"]
             #[cfg_attr(
                 feature = "tracing",
                 ::tracing::instrument(
                     skip_all,
                     fields(
-                        otel.name = "POST /v1/messages?beta=true",
+                        otel.name = "POST /reports",
                         otel.kind = "client",
-                        url.template = "/v1/messages?beta=true",
+                        url.template = "/reports",
                         http.request.method = "POST",
                         server.address,
                         server.port,
@@ -1250,27 +3297,16 @@ mod tests {
                     )
                 )
             )]
-            pub async fn beta_create_message(
+            pub async fn create_report(
                 &self,
-                query: &parameters::BetaCreateMessageQuery,
-                request: impl Into<crate::types::Message>
-            ) -> Result<crate::types::Message, crate::error::Error> {
+            ) -> Result<(), crate::error::Error> {
                 let result: Result<_, crate::error::Error> = async move {
                     let url = {
-                        let mut url = self.base_url.clone();
+                        let mut url = self.base_url.join("https://reports.example.com/v2")?;
                         url.path_segments_mut()
                             .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
                             .pop_if_empty()
-                            .extend(&["v1", "messages"]);
-                        url.query_pairs_mut()
-                            .append_pair("beta", "true");
-                        let url = ::ploidy_util::serde::Serialize::serialize(
-                            query,
-                            ::ploidy_util::QuerySerializer::new(
-                                url,
-                                parameters::BetaCreateMessageQuery::STYLES,
-                            ),
-                        )?;
+                            .push("reports");
                         #[cfg(feature = "tracing")]
                         {
                             ::tracing::record_all!(::tracing::Span::current(),
@@ -1282,32 +3318,29 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .post(url)
-                            .headers(self.headers.clone())
-                            .json(&request.into());
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::POST, url)
+                            .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
-                    let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
-                    let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
-                    Ok(result)
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let _ = response;
+                    Ok(())
                 }.await;
                 #[cfg(feature = "tracing")]
                 if let Err(err) = &result {
@@ -1321,41 +3354,121 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    // MARK: Responses
+
     #[test]
-    fn test_operation_with_path_params_and_literal_and_declared_query_params() {
+    fn test_operation_returns_2xx_range_response_over_default() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
               title: Test API
               version: 1.0.0
             paths:
-              /v1/models/{model_id}?beta=true:
+              /items:
                 get:
-                  operationId: betaGetModel
-                  parameters:
-                    - name: model_id
-                      in: path
-                      required: true
-                      schema:
-                        type: string
-                    - name: expand
-                      in: query
-                      schema:
-                        type: boolean
+                  operationId: listItems
                   responses:
-                    '200':
+                    '2XX':
                       description: OK
                       content:
                         application/json:
                           schema:
-                            $ref: '#/components/schemas/Model'
+                            $ref: '#/components/schemas/Item'
+                    default:
+                      description: Error
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Error'
             components:
               schemas:
-                Model:
+                Item:
                   type: object
                   properties:
                     id:
                       type: string
+                Error:
+                  type: object
+                  properties:
+                    message:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ReturnType =
+            parse_quote!(-> Result<crate::types::Item, crate::error::Error>);
+        assert_eq!(actual.sig.output, expected);
+    }
+
+    #[test]
+    fn test_operation_returns_output_with_response_headers() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items:
+                get:
+                  operationId: listItems
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                response_headers: true,
+                ..Default::default()
+            },
+        );
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOperation::new(&graph, &op);
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ReturnType =
+            parse_quote!(-> Result<parameters::ListItemsOutput, crate::error::Error>);
+        assert_eq!(actual.sig.output, expected);
+    }
+
+    #[test]
+    fn test_operation_accepts_json_response_over_csv() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /report:
+                get:
+                  operationId: getReport
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            type: string
+                        text/csv:
+                          schema:
+                            type: string
         "})
         .unwrap();
 
@@ -1368,47 +3481,34 @@ mod tests {
 
         let actual: syn::ImplItemFn = parse_quote!(#codegen);
         let expected: syn::ImplItemFn = parse_quote! {
-            #[doc = " GET /v1/models/{model_id}?beta=true"]
+            #[doc = " GET /report"]
             #[cfg_attr(
                 feature = "tracing",
                 ::tracing::instrument(
                     skip_all,
                     fields(
-                        otel.name = "GET /v1/models/{model_id}?beta=true",
+                        otel.name = "GET /report",
                         otel.kind = "client",
-                        url.template = "/v1/models/{model_id}?beta=true",
+                        url.template = "/report",
                         http.request.method = "GET",
                         server.address,
                         server.port,
                         url.full,
                         http.response.status_code,
-                        error.type,
-                        model_id = %model_id
+                        error.type
                     )
                 )
             )]
-            pub async fn beta_get_model(
+            pub async fn get_report(
                 &self,
-                model_id: &str,
-                query: &parameters::BetaGetModelQuery
-            ) -> Result<crate::types::Model, crate::error::Error> {
+            ) -> Result<::std::string::String, crate::error::Error> {
                 let result: Result<_, crate::error::Error> = async move {
                     let url = {
                         let mut url = self.base_url.clone();
                         url.path_segments_mut()
                             .map_err(|()| ::ploidy_util::url::PathAndQueryError::UrlCannotBeABase)?
                             .pop_if_empty()
-                            .extend(&["v1", "models"])
-                            .push(model_id);
-                        url.query_pairs_mut()
-                            .append_pair("beta", "true");
-                        let url = ::ploidy_util::serde::Serialize::serialize(
-                            query,
-                            ::ploidy_util::QuerySerializer::new(
-                                url,
-                                parameters::BetaGetModelQuery::STYLES,
-                            ),
-                        )?;
+                            .push("report");
                         #[cfg(feature = "tracing")]
                         {
                             ::tracing::record_all!(::tracing::Span::current(),
@@ -1420,28 +3520,32 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = request.header(
+                            crate::util::http::header::ACCEPT,
+                            "application/json",
+                        );
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
                     let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
                     let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
                     Ok(result)
@@ -1457,4 +3561,148 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    // MARK: Pagination
+
+    #[test]
+    fn test_paginated_operation_streams_items() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /owners/{ownerId}/pets:
+                get:
+                  operationId: listPets
+                  parameters:
+                    - name: ownerId
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                    - name: cursor
+                      in: query
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/PetPage'
+            components:
+              schemas:
+                PetPage:
+                  type: object
+                  required: [items]
+                  properties:
+                    items:
+                      type: array
+                      items:
+                        $ref: '#/components/schemas/Pet'
+                    next_cursor:
+                      type: string
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                pagination: Some(PaginationConfig::default()),
+                ..Default::default()
+            },
+        );
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenPaginatedOperation::new(&graph, &op).unwrap();
+
+        let actual: syn::ImplItemFn = parse_quote!(#codegen);
+        let expected: syn::ImplItemFn = parse_quote! {
+            #[doc = " Returns a stream of the items on all pages of [`Self::list_pets`]."]
+            pub fn list_pets_stream(
+                &self,
+                owner_id: &str,
+                query: &parameters::ListPetsQuery
+            ) -> impl ::ploidy_util::pagination::Stream<
+                Item = Result<crate::types::Pet, crate::error::Error>,
+            > {
+                ::ploidy_util::pagination::paginate(query.clone(), move |query| async move {
+                    let response = {
+                        let query = &query;
+                        self.list_pets(owner_id, query).await?
+                    };
+                    let items = response.items;
+                    let next = response.next_cursor.into_option().map(|cursor| {
+                        let mut query = query;
+                        query.cursor = Some(cursor);
+                        query
+                    });
+                    Ok((items, next))
+                })
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_paginated_operation_requires_optional_next_cursor() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  parameters:
+                    - name: page_token
+                      in: query
+                      schema:
+                        type: string
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            type: object
+                            required: [data, next_page_token]
+                            properties:
+                              data:
+                                type: array
+                                items:
+                                  type: string
+                              next_page_token:
+                                type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                pagination: Some(PaginationConfig {
+                    cursor_params: vec!["page_token".to_owned()],
+                    next_cursor_fields: vec!["next_page_token".to_owned()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        );
+
+        // A required, non-nullable next cursor can't mark the last page.
+        let op = graph.operations().next().unwrap();
+        assert!(CodegenPaginatedOperation::new(&graph, &op).is_none());
+    }
 }
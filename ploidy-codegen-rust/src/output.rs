@@ -0,0 +1,276 @@
+use itertools::Itertools;
+use ploidy_core::ir::{
+    InlineTypeView, OperationView, PrimitiveType, ResponseView, SchemaTypeView, TypeView,
+};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+
+use super::{
+    doc_attrs,
+    graph::{CodegenGraph, IdentMapping},
+    naming::CodegenIdentUsage,
+    ref_::CodegenRef,
+};
+
+/// Generates a response struct for an API operation.
+///
+/// The generated struct is named `{OperationId}Output`. It has the
+/// deserialized `body`, if the operation has one; the raw `headers`;
+/// and an accessor method for each response header in the spec.
+#[derive(Debug)]
+pub struct CodegenOutput<'a> {
+    graph: &'a CodegenGraph<'a>,
+    op: &'a OperationView<'a, 'a>,
+}
+
+impl<'a> CodegenOutput<'a> {
+    /// Creates a new response struct for the given operation.
+    #[inline]
+    pub fn new(graph: &'a CodegenGraph<'a>, op: &'a OperationView<'a, 'a>) -> Self {
+        Self { graph, op }
+    }
+}
+
+impl ToTokens for CodegenOutput<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let output_name = format_ident!(
+            "{}Output",
+            CodegenIdentUsage::Type(self.graph.ident(self.op.id()))
+        );
+
+        let body = self.op.response().map(|response| {
+            let ty = match response {
+                ResponseView::Json(view) => CodegenRef::new(self.graph, &view).into_token_stream(),
+                ResponseView::Text => quote! { ::std::string::String },
+                ResponseView::Bytes => quote! { crate::util::bytes::Bytes },
+            };
+            quote! {
+                #[doc = " The response body."]
+                pub body: #ty,
+            }
+        });
+
+        let accessors = self
+            .op
+            .response_headers()
+            .map(|header| {
+                let method_name = CodegenIdentUsage::Method(
+                    self.graph
+                        .ident(IdentMapping::ResponseHeader(self.op.id(), header.name())),
+                );
+                let header_name = header.name().to_ascii_lowercase();
+                let doc = {
+                    let summary = format!(" Returns the `{}` header, if present.", header.name());
+                    match header.description() {
+                        Some(description) => {
+                            let attrs = doc_attrs(description);
+                            quote! {
+                                #attrs
+                                #[doc = ""]
+                                #[doc = #summary]
+                            }
+                        }
+                        None => quote!(#[doc = #summary]),
+                    }
+                };
+                let ty = header.ty();
+                // Enums are either Rust enums with a `FromStr` impl,
+                // or `String` type aliases.
+                let parses_from_str = match &ty {
+                    TypeView::Schema(SchemaTypeView::Enum(..))
+                    | TypeView::Inline(InlineTypeView::Enum(..)) => true,
+                    TypeView::Schema(SchemaTypeView::Primitive(_, view))
+                    | TypeView::Inline(InlineTypeView::Primitive(_, view)) => matches!(
+                        view.ty(),
+                        PrimitiveType::String
+                            | PrimitiveType::I8
+                            | PrimitiveType::U8
+                            | PrimitiveType::I16
+                            | PrimitiveType::U16
+                            | PrimitiveType::I32
+                            | PrimitiveType::U32
+                            | PrimitiveType::I64
+                            | PrimitiveType::U64
+                            | PrimitiveType::F32
+                            | PrimitiveType::F64
+                            | PrimitiveType::Decimal
+                            | PrimitiveType::Bool
+                            | PrimitiveType::Date
+                            | PrimitiveType::Url
+                            | PrimitiveType::Uuid
+                    ),
+                    _ => false,
+                };
+                if parses_from_str {
+                    let path = CodegenRef::new(self.graph, &ty);
+                    quote! {
+                        #doc
+                        pub fn #method_name(&self) -> ::std::option::Option<#path> {
+                            self.headers.get(#header_name)?.to_str().ok()?.parse().ok()
+                        }
+                    }
+                } else {
+                    // Headers of other types are returned as strings.
+                    quote! {
+                        #doc
+                        pub fn #method_name(&self) -> ::std::option::Option<&str> {
+                            self.headers.get(#header_name)?.to_str().ok()
+                        }
+                    }
+                }
+            })
+            .collect_vec();
+        let accessors = (!accessors.is_empty()).then(|| {
+            quote! {
+                impl #output_name {
+                    #(#accessors)*
+                }
+            }
+        });
+
+        let vis = self.graph.visibility();
+
+        tokens.append_all(quote! {
+            #[derive(Debug, Clone)]
+            #vis struct #output_name {
+                #body
+                #[doc = " The response headers."]
+                pub headers: crate::util::http::HeaderMap,
+            }
+
+            #accessors
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use crate::CodegenGraph;
+
+    #[test]
+    fn test_output_with_body_and_headers() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items:
+                get:
+                  operationId: listItems
+                  responses:
+                    '200':
+                      description: OK
+                      headers:
+                        X-Next-Cursor:
+                          description: The cursor for the next page.
+                          schema:
+                            type: string
+                        X-RateLimit-Remaining:
+                          schema:
+                            type: integer
+                            format: int32
+                        X-Trace:
+                          schema:
+                            type: array
+                            items:
+                              type: string
+                      content:
+                        application/json:
+                          schema:
+                            type: array
+                            items:
+                              type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOutput::new(&graph, &op);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone)]
+            pub struct ListItemsOutput {
+                #[doc = " The response body."]
+                pub body: ::std::vec::Vec<::std::string::String>,
+                #[doc = " The response headers."]
+                pub headers: crate::util::http::HeaderMap,
+            }
+
+            impl ListItemsOutput {
+                #[doc = " The cursor for the next page."]
+                #[doc = ""]
+                #[doc = " Returns the `X-Next-Cursor` header, if present."]
+                pub fn x_next_cursor(&self) -> ::std::option::Option<::std::string::String> {
+                    self.headers.get("x-next-cursor")?.to_str().ok()?.parse().ok()
+                }
+
+                #[doc = " Returns the `X-RateLimit-Remaining` header, if present."]
+                pub fn x_rate_limit_remaining(&self) -> ::std::option::Option<i32> {
+                    self.headers.get("x-ratelimit-remaining")?.to_str().ok()?.parse().ok()
+                }
+
+                #[doc = " Returns the `X-Trace` header, if present."]
+                pub fn x_trace(&self) -> ::std::option::Option<&str> {
+                    self.headers.get("x-trace")?.to_str().ok()
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_output_without_body_or_headers() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /items/{id}:
+                delete:
+                  operationId: deleteItem
+                  parameters:
+                    - name: id
+                      in: path
+                      required: true
+                      schema:
+                        type: string
+                  responses:
+                    '204':
+                      description: Deleted
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let op = graph.operations().next().unwrap();
+        let codegen = CodegenOutput::new(&graph, &op);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone)]
+            pub struct DeleteItemOutput {
+                #[doc = " The response headers."]
+                pub headers: crate::util::http::HeaderMap,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+}
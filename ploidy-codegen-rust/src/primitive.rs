@@ -30,6 +30,7 @@ impl<'a> ToTokens for CodegenPrimitive<'a> {
             PrimitiveType::U64 => quote! { u64 },
             PrimitiveType::F32 => quote! { f32 },
             PrimitiveType::F64 => quote! { f64 },
+            PrimitiveType::Decimal => quote! { ::ploidy_util::rust_decimal::Decimal },
             PrimitiveType::Bool => quote! { bool },
             PrimitiveType::DateTime => {
                 let format = self.graph.date_time_format();
@@ -57,6 +58,10 @@ impl<'a> ToTokens for CodegenPrimitive<'a> {
             PrimitiveType::Uuid => quote! { ::ploidy_util::uuid::Uuid },
             PrimitiveType::Bytes => quote! { ::ploidy_util::binary::Base64 },
             PrimitiveType::Binary => quote! { ::ploidy_util::serde_bytes::ByteBuf },
+            PrimitiveType::Custom(format) => match self.graph.format_override(format) {
+                Some(path) => quote! { #path },
+                None => quote! { ::std::string::String },
+            },
         });
     }
 }
@@ -68,10 +73,11 @@ mod tests {
     use itertools::Itertools;
     use ploidy_core::{
         arena::Arena,
-        ir::{RawGraph, Spec},
+        ir::{RawGraph, Spec, SpecConfig},
         parse::Document,
     };
     use pretty_assertions::assert_eq;
+    use rustc_hash::FxHashMap;
     use syn::parse_quote;
 
     use crate::{CodegenConfig, CodegenGraph, DateTimeFormat};
@@ -423,6 +429,38 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_codegen_primitive_decimal() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Test:
+                  type: object
+                  required: [value]
+                  properties:
+                    value:
+                      type: string
+                      format: decimal
+        "})
+        .unwrap();
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+        let primitives = graph.primitives().collect_vec();
+        let [ty] = &*primitives else {
+            panic!("expected decimal; got `{primitives:?}`");
+        };
+        let p = CodegenPrimitive::new(&graph, ty);
+        let actual: syn::Type = parse_quote!(#p);
+        let expected: syn::Type = parse_quote!(::ploidy_util::rust_decimal::Decimal);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_codegen_primitive_bool() {
         let doc = Document::from_yaml(indoc::indoc! {"
@@ -513,6 +551,7 @@ mod tests {
             RawGraph::new(&arena, &spec).cook(),
             &CodegenConfig {
                 date_time_format: DateTimeFormat::UnixMilliseconds,
+                ..Default::default()
             },
         );
         let primitives = graph.primitives().collect_vec();
@@ -550,6 +589,7 @@ mod tests {
             RawGraph::new(&arena, &spec).cook(),
             &CodegenConfig {
                 date_time_format: DateTimeFormat::UnixSeconds,
+                ..Default::default()
             },
         );
         let primitives = graph.primitives().collect_vec();
@@ -587,6 +627,7 @@ mod tests {
             RawGraph::new(&arena, &spec).cook(),
             &CodegenConfig {
                 date_time_format: DateTimeFormat::UnixMicroseconds,
+                ..Default::default()
             },
         );
         let primitives = graph.primitives().collect_vec();
@@ -624,6 +665,7 @@ mod tests {
             RawGraph::new(&arena, &spec).cook(),
             &CodegenConfig {
                 date_time_format: DateTimeFormat::UnixNanoseconds,
+                ..Default::default()
             },
         );
         let primitives = graph.primitives().collect_vec();
@@ -795,4 +837,67 @@ mod tests {
         let expected: syn::Type = parse_quote!(::ploidy_util::serde_bytes::ByteBuf);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_codegen_primitive_custom_formats() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Test:
+                  type: object
+                  required: [phone, color, amount]
+                  properties:
+                    phone:
+                      type: string
+                      format: phone
+                    color:
+                      type: string
+                      format: color
+                    amount:
+                      type: string
+                      format: money
+        "})
+        .unwrap();
+        let config = CodegenConfig {
+            format_overrides: [
+                (
+                    "phone".to_owned(),
+                    "::phone::PhoneNumber".to_owned().try_into().unwrap(),
+                ),
+                (
+                    "money".to_owned(),
+                    "::money::Money".to_owned().try_into().unwrap(),
+                ),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        let arena = Arena::new();
+        let spec = Spec::from_doc_with_config(&arena, &doc, SpecConfig::from(&config)).unwrap();
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+        let actual: FxHashMap<_, syn::Type> = graph
+            .primitives()
+            .map(|ty| {
+                let p = CodegenPrimitive::new(&graph, &ty);
+                (ty.ty(), parse_quote!(#p))
+            })
+            .collect();
+
+        // Unrecognized formats with overrides use the override.
+        let expected: syn::Type = parse_quote!(::phone::PhoneNumber);
+        assert_eq!(actual[&PrimitiveType::Custom("phone")], expected);
+
+        // Overrides take precedence over recognized formats.
+        let expected: syn::Type = parse_quote!(::money::Money);
+        assert_eq!(actual[&PrimitiveType::Custom("money")], expected);
+
+        // Unrecognized formats without overrides are strings.
+        let expected: syn::Type = parse_quote!(::std::string::String);
+        assert_eq!(actual[&PrimitiveType::Custom("color")], expected);
+    }
 }
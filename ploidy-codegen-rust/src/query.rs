@@ -84,7 +84,7 @@ impl ToTokens for CodegenQueryParameters<'_> {
             .op
             .query()
             .filter_map(|param| Some((param.name(), param.style()?)))
-            .map(|(name, style)| {
+            .filter_map(|(name, style)| {
                 let style = match style {
                     ParameterStyle::DeepObject => {
                         quote!(::ploidy_util::QueryStyle::DeepObject)
@@ -98,14 +98,20 @@ impl ToTokens for CodegenQueryParameters<'_> {
                     ParameterStyle::Form { exploded } => {
                         quote!(::ploidy_util::QueryStyle::Form { exploded: #exploded })
                     }
+                    // Path parameter styles don't apply to query parameters.
+                    ParameterStyle::Simple { .. }
+                    | ParameterStyle::Label { .. }
+                    | ParameterStyle::Matrix { .. } => return None,
                 };
-                quote!((#name, #style))
+                Some(quote!((#name, #style)))
             });
 
+        let vis = self.graph.visibility();
+
         tokens.append_all(quote! {
             #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize)]
             #[serde(crate = "::ploidy_util::serde")]
-            pub struct #query_name {
+            #vis struct #query_name {
                 #(#fields)*
             }
 
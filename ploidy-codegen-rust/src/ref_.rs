@@ -4,6 +4,7 @@ use quote::{ToTokens, TokenStreamExt, quote};
 use syn::parse_quote;
 
 use super::{
+    ext::InnerViewExt,
     graph::{CodegenGraph, IdentMapping},
     naming::CodegenIdentUsage,
     primitive::CodegenPrimitive,
@@ -32,6 +33,15 @@ impl ToTokens for CodegenRef<'_> {
                 let inner_ref = CodegenRef::new(self.graph, &inner_ty);
                 quote! { ::std::vec::Vec<#inner_ref> }
             }
+            TypeView::Inline(InlineTypeView::Container(_, ContainerView::Set(inner))) => {
+                let inner_ty = inner.ty();
+                let inner_ref = CodegenRef::new(self.graph, &inner_ty);
+                if inner.ordered() {
+                    quote! { ::std::collections::BTreeSet<#inner_ref> }
+                } else {
+                    quote! { ::std::vec::Vec<#inner_ref> }
+                }
+            }
             TypeView::Inline(InlineTypeView::Container(_, ContainerView::Map(inner))) => {
                 let inner_ty = inner.ty();
                 let inner_ref = CodegenRef::new(self.graph, &inner_ty);
@@ -52,8 +62,9 @@ impl ToTokens for CodegenRef<'_> {
             TypeView::Inline(ty) => {
                 let root: syn::Path = match ty.path().root() {
                     InlineTypePathRoot::Schema(id) => {
+                        let types = self.graph.types_module();
                         let mod_name = CodegenIdentUsage::Module(self.graph.ident(id));
-                        parse_quote!(crate::types::#mod_name::types)
+                        parse_quote!(#types::#mod_name::types)
                     }
                     InlineTypePathRoot::Operation { resource, .. } => match resource {
                         Some(resource) => {
@@ -69,8 +80,9 @@ impl ToTokens for CodegenRef<'_> {
                 parse_quote!(#root::#ty_name)
             }
             TypeView::Schema(ty) => {
+                let types = self.graph.types_module();
                 let ty_name = CodegenIdentUsage::Type(self.graph.ident(ty.id()));
-                quote! { crate::types::#ty_name }
+                quote! { #types::#ty_name }
             }
         })
     }
@@ -91,7 +103,11 @@ mod tests {
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::{CodegenGraph, tests::assert_matches};
+    use crate::{
+        CodegenGraph,
+        config::{CodegenConfig, CodegenScope},
+        tests::assert_matches,
+    };
 
     #[test]
     fn test_codegen_ref_any() {
@@ -218,6 +234,91 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_codegen_ref_set_of_strings() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Container:
+                  type: object
+                  required:
+                    - items
+                  properties:
+                    items:
+                      type: array
+                      items:
+                        type: string
+                      uniqueItems: true
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Container").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = schema else {
+            panic!("expected struct `Container`; got `{schema:?}`");
+        };
+        let field = struct_view
+            .fields()
+            .find(|f| matches!(f.name(), StructFieldName::Name("items")))
+            .unwrap();
+        let ty = field.ty();
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        let expected: syn::Type = parse_quote!(::std::collections::BTreeSet<::std::string::String>);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codegen_ref_set_of_f64() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Container:
+                  type: object
+                  required:
+                    - items
+                  properties:
+                    items:
+                      type: array
+                      items:
+                        type: number
+                      uniqueItems: true
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Container").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = schema else {
+            panic!("expected struct `Container`; got `{schema:?}`");
+        };
+        let field = struct_view
+            .fields()
+            .find(|f| matches!(f.name(), StructFieldName::Name("items")))
+            .unwrap();
+        let ty = field.ty();
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        // `f64` isn't `Ord`, so the set falls back to a `Vec`.
+        let expected: syn::Type = parse_quote!(::std::vec::Vec<f64>);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_codegen_ref_map_of_strings() {
         let doc = Document::from_yaml(indoc::indoc! {"
@@ -487,6 +588,72 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_codegen_ref_nullable_array_of_nullable_items() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Container:
+                  type: object
+                  required:
+                    - items
+                    - nullable_items
+                  properties:
+                    items:
+                      type: array
+                      items:
+                        type: string
+                        nullable: true
+                    nullable_items:
+                      type: array
+                      items:
+                        type: string
+                        nullable: true
+                      nullable: true
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Container").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = schema else {
+            panic!("expected struct `Container`; got `{schema:?}`");
+        };
+
+        let field = struct_view
+            .fields()
+            .find(|f| matches!(f.name(), StructFieldName::Name("items")))
+            .unwrap();
+        let ty = field.ty();
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        let expected: syn::Type = parse_quote! {
+            ::std::vec::Vec<::std::option::Option<::std::string::String>>
+        };
+        assert_eq!(actual, expected);
+
+        let field = struct_view
+            .fields()
+            .find(|f| matches!(f.name(), StructFieldName::Name("nullable_items")))
+            .unwrap();
+        let ty = field.ty();
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        let expected: syn::Type = parse_quote! {
+            ::std::option::Option<
+                ::std::vec::Vec<::std::option::Option<::std::string::String>>
+            >
+        };
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_codegen_ref_map_of_arrays() {
         let doc = Document::from_yaml(indoc::indoc! {"
@@ -787,4 +954,75 @@ mod tests {
         let expected: syn::Type = parse_quote!(crate::client::default::types::DoSomethingRequest);
         assert_eq!(actual, expected);
     }
+
+    // MARK: Scopes
+
+    #[test]
+    fn test_codegen_ref_uses_types_crate_when_client_only() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            types_crate: Some("pets-types".to_owned().try_into().unwrap()),
+            scope: CodegenScope::Client,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let schema = graph.schema("Pet").unwrap();
+        let ty = TypeView::Schema(schema);
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        let expected: syn::Type = parse_quote!(::pets_types::types::Pet);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codegen_ref_ignores_types_crate_when_generating_all() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            types_crate: Some("pets-types".to_owned().try_into().unwrap()),
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let schema = graph.schema("Pet").unwrap();
+        let ty = TypeView::Schema(schema);
+        let ref_ = CodegenRef::new(&graph, &ty);
+        let actual: syn::Type = parse_quote!(#ref_);
+        let expected: syn::Type = parse_quote!(crate::types::Pet);
+        assert_eq!(actual, expected);
+    }
 }
@@ -10,7 +10,8 @@ use super::{
     graph::CodegenGraph,
     inlines::CodegenInlines,
     naming::{CodegenIdentUsage, ResourceGroup},
-    operation::CodegenOperation,
+    operation::{CodegenOperation, CodegenPaginatedOperation},
+    output::CodegenOutput,
     query::CodegenQueryParameters,
 };
 
@@ -20,6 +21,7 @@ pub struct CodegenResource<'a> {
     graph: &'a CodegenGraph<'a>,
     resource: ResourceGroup<'a>,
     ops: &'a [OperationView<'a, 'a>],
+    blocking: bool,
 }
 
 impl<'a> CodegenResource<'a> {
@@ -32,26 +34,63 @@ impl<'a> CodegenResource<'a> {
             graph,
             resource,
             ops,
+            blocking: false,
+        }
+    }
+
+    /// Generates an `impl` block for the blocking client instead.
+    /// Blocking methods share the async client's parameter and
+    /// inline types, so the block doesn't include them.
+    pub fn blocking(
+        graph: &'a CodegenGraph<'a>,
+        resource: ResourceGroup<'a>,
+        ops: &'a [OperationView<'a, 'a>],
+    ) -> Self {
+        Self {
+            graph,
+            resource,
+            ops,
+            blocking: true,
         }
     }
 }
 
 impl ToTokens for CodegenResource<'_> {
-    #[allow(
-        clippy::filter_map_bool_then,
-        reason = "`filter_map` + `then` reads cleaner here"
-    )]
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let methods = self.ops.iter().map(|op| {
             // Each method gets its own `#[cfg(...)]` attribute.
             let cfg = CfgFeature::for_operation(self.graph, op);
-            let method = CodegenOperation::new(self.graph, op);
+            let method = if self.blocking {
+                CodegenOperation::blocking(self.graph, op)
+            } else {
+                CodegenOperation::new(self.graph, op)
+            };
+            // Paginated operations also get a method that streams their items.
+            let stream = (!self.blocking)
+                .then(|| CodegenPaginatedOperation::new(self.graph, op))
+                .flatten()
+                .map(|stream| {
+                    quote! {
+                        #cfg
+                        #stream
+                    }
+                });
             quote! {
                 #cfg
                 #method
+                #stream
             }
         });
 
+        if self.blocking {
+            tokens.append_all(quote! {
+                impl crate::client::blocking::Client {
+                    #(#methods)*
+                }
+            });
+            return;
+        }
+
         let inlines = CodegenInlines::for_resource_inlines(
             self.graph,
             self.ops.iter().flat_map(|op| op.inlines()).collect(),
@@ -60,25 +99,45 @@ impl ToTokens for CodegenResource<'_> {
         let params = self
             .ops
             .iter()
-            .filter_map(|op| {
+            .flat_map(|op| {
+                let cfg = CfgFeature::for_operation(self.graph, op);
                 // Collect query parameter structs for operations
                 // that have at least one query parameter.
-                op.query().next().is_some().then(|| {
-                    let cfg = CfgFeature::for_operation(self.graph, op);
+                let query = op.query().next().is_some().then(|| {
                     let query = CodegenQueryParameters::new(self.graph, op);
                     let mod_name = format_ident!(
                         "{}_query",
                         CodegenIdentUsage::Module(self.graph.ident(op.id()))
                     );
+                    let vis = self.graph.visibility();
                     quote! {
                         #cfg
                         mod #mod_name {
                             #query
                         }
                         #cfg
-                        pub use #mod_name::*;
+                        #vis use #mod_name::*;
                     }
-                })
+                });
+                // Collect response structs for all operations
+                // if methods return response headers.
+                let output = self.graph.response_headers().then(|| {
+                    let output = CodegenOutput::new(self.graph, op);
+                    let mod_name = format_ident!(
+                        "{}_output",
+                        CodegenIdentUsage::Module(self.graph.ident(op.id()))
+                    );
+                    let vis = self.graph.visibility();
+                    quote! {
+                        #cfg
+                        mod #mod_name {
+                            #output
+                        }
+                        #cfg
+                        #vis use #mod_name::*;
+                    }
+                });
+                itertools::chain!(query, output)
             })
             .reduce(|a, b| quote!(#a #b))
             .map(|params| {
@@ -103,13 +162,17 @@ impl IntoCode for CodegenResource<'_> {
     type Code = (String, TokenStream);
 
     fn into_code(self) -> Self::Code {
+        let dir = if self.blocking {
+            "src/client/blocking"
+        } else {
+            "src/client"
+        };
         (
             match self.resource {
-                ResourceGroup::Named(name) => format!(
-                    "src/client/{}.rs",
-                    CodegenIdentUsage::Module(name).display()
-                ),
-                ResourceGroup::Default => "src/client/default.rs".to_owned(),
+                ResourceGroup::Named(name) => {
+                    format!("{dir}/{}.rs", CodegenIdentUsage::Module(name).display())
+                }
+                ResourceGroup::Default => format!("{dir}/default.rs"),
             },
             self.into_token_stream(),
         )
@@ -129,7 +192,10 @@ mod tests {
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::graph::CodegenGraph;
+    use crate::{
+        config::{CodegenConfig, Visibility},
+        graph::CodegenGraph,
+    };
 
     // MARK: Feature gating
 
@@ -224,28 +290,28 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
                     let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
                     let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
                     Ok(result)
@@ -356,28 +422,28 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
-                    let body = response.bytes().await?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
+                    let body = response.into_body();
                     let deserializer = &mut ::ploidy_util::serde_json::Deserializer::from_slice(&body);
                     let result = ::ploidy_util::serde_path_to_error::deserialize(deserializer)?;
                     Ok(result)
@@ -482,27 +548,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -632,27 +698,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -711,27 +777,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -844,27 +910,27 @@ mod tests {
                         url
                     };
                     let request = {
-                        let request = self
-                            .client
-                            .get(url)
+                        let request = ::ploidy_util::transport::RequestBuilder::new(::ploidy_util::http::Method::GET, url)
                             .headers(self.headers.clone());
+                        let request = match self.timeout {
+                            Some(timeout) => request.timeout(timeout),
+                            None => request,
+                        };
                         #[cfg(feature = "trace-context")]
                         let request = ::ploidy_util::trace::propagate(
                             ::tracing::Span::current(),
                             request,
                         );
-                        request
+                        request.build()?
                     };
-                    let response = request
-                        .send()
-                        .await?;
+                    let response = self.retry.execute(&*self.client, request).await?;
                     #[cfg(feature = "tracing")]
                     {
                         ::tracing::record_all!(::tracing::Span::current(),
                             http.response.status_code = response.status().as_u16()
                         );
                     }
-                    let response = response.error_for_status()?;
+                    let response = ::ploidy_util::transport::error_for_status(response)?;
                     let _ = response;
                     Ok(())
                 }.await;
@@ -880,4 +946,121 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    // MARK: Visibility
+
+    #[test]
+    fn test_resource_reexports_output_with_configured_visibility() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths:
+              /customers:
+                get:
+                  operationId: listCustomers
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                visibility: Visibility::PubCrate,
+                response_headers: true,
+                ..Default::default()
+            },
+        );
+
+        let ops = graph.operations().collect_vec();
+        let [op] = &*ops else {
+            panic!("expected one operation; got `{ops:?}`");
+        };
+        let resource =
+            CodegenResource::new(&graph, graph.resource_for(op), std::slice::from_ref(op));
+
+        let file: syn::File = parse_quote!(#resource);
+        let Some(syn::Item::Mod(syn::ItemMod {
+            content: Some((_, items)),
+            ..
+        })) = file.items.get(1)
+        else {
+            panic!(
+                "expected `parameters` module; got `{:?}`",
+                file.items.get(1)
+            );
+        };
+        let Some(syn::Item::Use(actual)) = items.last() else {
+            panic!("expected re-export; got `{:?}`", items.last());
+        };
+        let expected: syn::ItemUse = parse_quote!(
+            pub(crate) use list_customers_output::*;
+        );
+        assert_eq!(*actual, expected);
+    }
+
+    #[test]
+    fn test_resource_reexports_query_with_configured_visibility() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test
+              version: 1.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  parameters:
+                    - name: limit
+                      in: query
+                      schema:
+                        type: integer
+                        format: int32
+                  responses:
+                    '200':
+                      description: OK
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                visibility: Visibility::PubCrate,
+                ..Default::default()
+            },
+        );
+
+        let ops = graph.operations().collect_vec();
+        let [op] = &*ops else {
+            panic!("expected one operation; got `{ops:?}`");
+        };
+        let resource =
+            CodegenResource::new(&graph, graph.resource_for(op), std::slice::from_ref(op));
+
+        let file: syn::File = parse_quote!(#resource);
+        let Some(syn::Item::Mod(syn::ItemMod {
+            content: Some((_, items)),
+            ..
+        })) = file.items.get(1)
+        else {
+            panic!(
+                "expected `parameters` module; got `{:?}`",
+                file.items.get(1)
+            );
+        };
+        let Some(syn::Item::Use(actual)) = items.last() else {
+            panic!("expected re-export; got `{:?}`", items.last());
+        };
+        let expected: syn::ItemUse = parse_quote!(
+            pub(crate) use list_pets_query::*;
+        );
+        assert_eq!(*actual, expected);
+    }
 }
@@ -6,9 +6,9 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
 
 use super::{
-    doc_attrs, enum_::CodegenEnum, graph::CodegenGraph, inlines::CodegenInlines,
+    doc_attrs, enum_::CodegenEnum, ext::InnerViewExt, graph::CodegenGraph, inlines::CodegenInlines,
     naming::CodegenIdentUsage, primitive::CodegenPrimitive, ref_::CodegenRef,
-    struct_::CodegenStruct, tagged::CodegenTagged, untagged::CodegenUntagged,
+    struct_::CodegenStruct, tagged::CodegenTagged, tuple::CodegenTuple, untagged::CodegenUntagged,
 };
 
 /// Generates a module for a named schema type.
@@ -26,6 +26,24 @@ impl<'a> CodegenSchemaType<'a> {
 
 impl ToTokens for CodegenSchemaType<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let info = self.ty.info();
+        let vis = self.graph.visibility();
+
+        // A schema that's been replaced becomes a deprecated alias
+        // for its replacement, so that existing code keeps compiling.
+        if let Some(replacement) = info.replaced_by.and_then(|name| self.graph.schema(name)) {
+            let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+            let replacement_name = CodegenIdentUsage::Type(self.graph.ident(replacement.id()));
+            let note = format!("use `{}` instead", replacement_name.display());
+            tokens.append_all(quote! {
+                #[deprecated(note = #note)]
+                #vis type #type_name = crate::types::#replacement_name;
+            });
+            return;
+        }
+
+        let deprecated = info.deprecated.then(|| quote!(#[deprecated]));
+        let examples = self.graph.examples(self.ty.id());
         let ty = match self.ty {
             SchemaTypeView::Struct(_, view) => {
                 CodegenStruct::new(self.graph, view).into_token_stream()
@@ -37,6 +55,9 @@ impl ToTokens for CodegenSchemaType<'_> {
             SchemaTypeView::Untagged(_, view) => {
                 CodegenUntagged::new(self.graph, view).into_token_stream()
             }
+            SchemaTypeView::Tuple(_, view) => {
+                CodegenTuple::new(self.graph, view).into_token_stream()
+            }
             SchemaTypeView::Container(_, ContainerView::Array(inner)) => {
                 let doc_attrs = inner.description().map(doc_attrs);
                 let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
@@ -44,7 +65,24 @@ impl ToTokens for CodegenSchemaType<'_> {
                 let inner_ref = CodegenRef::new(self.graph, &inner_ty);
                 quote! {
                     #doc_attrs
-                    pub type #type_name = ::std::vec::Vec<#inner_ref>;
+                    #examples
+                    #vis type #type_name = ::std::vec::Vec<#inner_ref>;
+                }
+            }
+            SchemaTypeView::Container(_, ContainerView::Set(inner)) => {
+                let doc_attrs = inner.description().map(doc_attrs);
+                let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+                let inner_ty = inner.ty();
+                let inner_ref = CodegenRef::new(self.graph, &inner_ty);
+                let ty = if inner.ordered() {
+                    quote! { ::std::collections::BTreeSet<#inner_ref> }
+                } else {
+                    quote! { ::std::vec::Vec<#inner_ref> }
+                };
+                quote! {
+                    #doc_attrs
+                    #examples
+                    #vis type #type_name = #ty;
                 }
             }
             SchemaTypeView::Container(_, ContainerView::Map(inner)) => {
@@ -54,7 +92,8 @@ impl ToTokens for CodegenSchemaType<'_> {
                 let inner_ref = CodegenRef::new(self.graph, &inner_ty);
                 quote! {
                     #doc_attrs
-                    pub type #type_name = ::std::collections::BTreeMap<::std::string::String, #inner_ref>;
+                    #examples
+                    #vis type #type_name = ::std::collections::BTreeMap<::std::string::String, #inner_ref>;
                 }
             }
             SchemaTypeView::Container(_, ContainerView::Optional(inner)) => {
@@ -64,25 +103,29 @@ impl ToTokens for CodegenSchemaType<'_> {
                 let inner_ref = CodegenRef::new(self.graph, &inner_ty);
                 quote! {
                     #doc_attrs
-                    pub type #type_name = ::std::option::Option<#inner_ref>;
+                    #examples
+                    #vis type #type_name = ::std::option::Option<#inner_ref>;
                 }
             }
             SchemaTypeView::Primitive(_, view) => {
                 let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
                 let primitive = CodegenPrimitive::new(self.graph, view);
                 quote! {
-                    pub type #type_name = #primitive;
+                    #examples
+                    #vis type #type_name = #primitive;
                 }
             }
             SchemaTypeView::Any(_, _) => {
                 let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
                 quote! {
-                    pub type #type_name = ::ploidy_util::serde_json::Value;
+                    #examples
+                    #vis type #type_name = ::ploidy_util::serde_json::Value;
                 }
             }
         };
         let inlines = CodegenInlines::for_schema_inlines(self.graph, self.ty.inlines().collect());
         tokens.append_all(quote! {
+            #deprecated
             #ty
             #inlines
         });
@@ -521,6 +564,16 @@ mod tests {
                 Pet1(crate::types::pet::types::Pet1),
                 Pet2(crate::types::pet::types::Pet2)
             }
+            impl ::std::convert::From<crate::types::pet::types::Pet1> for Pet {
+                fn from(value: crate::types::pet::types::Pet1) -> Self {
+                    Self::Pet1(value)
+                }
+            }
+            impl ::std::convert::From<crate::types::pet::types::Pet2> for Pet {
+                fn from(value: crate::types::pet::types::Pet2) -> Self {
+                    Self::Pet2(value)
+                }
+            }
             pub mod types {
                 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
                 #[serde(crate = "::ploidy_util::serde")]
@@ -869,4 +922,176 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    // MARK: Deprecation
+
+    #[test]
+    fn test_deprecated_schema_emits_deprecated_type() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Legacy:
+                  type: object
+                  deprecated: true
+                  required:
+                    - id
+                  properties:
+                    id:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Legacy").unwrap();
+        let codegen = CodegenSchemaType::new(&graph, &schema);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[deprecated]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Legacy {
+                pub id: ::std::string::String,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_replaced_schema_emits_deprecated_alias() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                OldPet:
+                  type: object
+                  deprecated: true
+                  x-replaced-by: Pet
+                  properties:
+                    name:
+                      type: string
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("OldPet").unwrap();
+        let codegen = CodegenSchemaType::new(&graph, &schema);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[deprecated(note = "use `Pet` instead")]
+            pub type OldPet = crate::types::Pet;
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Examples
+
+    #[test]
+    fn test_schema_with_examples_emits_examples_section() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  description: A pet.
+                  example:
+                    name: Fido
+                  required:
+                    - name
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let codegen = CodegenSchemaType::new(&graph, &schema);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[doc = " A pet."]
+            #[doc = ""]
+            #[doc = " # Examples"]
+            #[doc = ""]
+            #[doc = " ```json"]
+            #[doc = " {"]
+            #[doc = "   \"name\": \"Fido\""]
+            #[doc = " }"]
+            #[doc = " ```"]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Pet {
+                pub name: ::std::string::String,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_primitive_schema_with_examples_emits_examples_section() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                PetId:
+                  type: string
+                  example: fido-1
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("PetId").unwrap();
+        let codegen = CodegenSchemaType::new(&graph, &schema);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[doc = ""]
+            #[doc = " # Examples"]
+            #[doc = ""]
+            #[doc = " ```json"]
+            #[doc = " \"fido-1\""]
+            #[doc = " ```"]
+            pub type PetId = ::std::string::String;
+        };
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,15 +1,80 @@
+use itertools::Itertools;
 use ploidy_core::codegen::IntoCode;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
+use textwrap::{Options, dedent, wrap};
+
+use super::{config::CodegenScope, graph::CodegenGraph};
 
 #[derive(Clone, Copy, Debug)]
-pub struct CodegenLibrary;
+pub struct CodegenLibrary<'a> {
+    graph: &'a CodegenGraph<'a>,
+}
 
-impl ToTokens for CodegenLibrary {
+impl<'a> CodegenLibrary<'a> {
+    pub fn new(graph: &'a CodegenGraph<'a>) -> Self {
+        Self { graph }
+    }
+}
+
+impl ToTokens for CodegenLibrary<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let info = self.graph.info();
+        let docs = {
+            let label = info.label().map(|label| match label.version {
+                Some(version) => format!("{} (version {version})", label.title),
+                None => label.title.to_owned(),
+            });
+            let description = info.description.as_deref().filter(|s| !s.is_empty());
+            let text = label
+                .into_iter()
+                .chain(description.map(str::to_owned))
+                .join("\n\n");
+            // Wrap like `doc_attrs`, but as inner attributes.
+            (!text.is_empty()).then(|| {
+                let dedented = dedent(&text);
+                let lines = wrap(
+                    &dedented,
+                    Options::new(80)
+                        .initial_indent(" ")
+                        .subsequent_indent(" ")
+                        .break_words(false),
+                )
+                .into_iter()
+                .map(|line| quote!(#![doc = #line]));
+                quote! { #(#lines)* }
+            })
+        };
+
+        let scope = self.graph.scope();
+        let types = (scope != CodegenScope::Client).then(|| {
+            quote!(
+                pub mod types;
+            )
+        });
+        let client = (scope != CodegenScope::Types).then(|| {
+            let vis = self.graph.visibility();
+            quote! {
+                pub mod client;
+                #vis use client::Client;
+            }
+        });
+        let mocks = self.graph.emit_mocks().then(|| {
+            quote! {
+                #[cfg(feature = "mocks")]
+                pub mod mocks;
+            }
+        });
         tokens.append_all(quote! {
-            pub mod types;
-            pub mod client;
+            #docs
+
+            // Generated code refers to deprecated types internally;
+            // only warn consumers that use them.
+            #![allow(deprecated)]
+
+            #types
+            #client
+            #mocks
             pub mod error;
 
             #[cfg(feature = "tracing")]
@@ -22,13 +87,12 @@ impl ToTokens for CodegenLibrary {
             // depend on it directly.
             pub use ::ploidy_util as util;
 
-            pub use client::Client;
             pub use error::Error;
         });
     }
 }
 
-impl IntoCode for CodegenLibrary {
+impl IntoCode for CodegenLibrary<'_> {
     type Code = (&'static str, TokenStream);
 
     fn into_code(self) -> Self::Code {
@@ -54,3 +118,240 @@ impl IntoCode for CodegenErrorModule {
         ("src/error.rs", self.into_token_stream())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use crate::config::CodegenConfig;
+
+    // MARK: Library
+
+    #[test]
+    fn test_library_declares_all_modules() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        let expected: syn::File = parse_quote! {
+            #![doc = " Test API (version 1.0.0)"]
+            #![allow(deprecated)]
+
+            pub mod types;
+            pub mod client;
+            pub use client::Client;
+            pub mod error;
+
+            #[cfg(feature = "tracing")]
+            extern crate self as tracing;
+
+            #[cfg(feature = "tracing")]
+            pub(crate) use ::ploidy_util::tracing::*;
+
+            pub use ::ploidy_util as util;
+
+            pub use error::Error;
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_library_declares_mocks_when_emitting_mocks() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            emit_mocks: true,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        let expected: syn::File = parse_quote! {
+            #![doc = " Test API (version 1.0.0)"]
+            #![allow(deprecated)]
+
+            pub mod types;
+            pub mod client;
+            pub use client::Client;
+            #[cfg(feature = "mocks")]
+            pub mod mocks;
+            pub mod error;
+
+            #[cfg(feature = "tracing")]
+            extern crate self as tracing;
+
+            #[cfg(feature = "tracing")]
+            pub(crate) use ::ploidy_util::tracing::*;
+
+            pub use ::ploidy_util as util;
+
+            pub use error::Error;
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_library_omits_client_when_types_only() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            scope: CodegenScope::Types,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        let expected: syn::File = parse_quote! {
+            #![doc = " Test API (version 1.0.0)"]
+            #![allow(deprecated)]
+
+            pub mod types;
+            pub mod error;
+
+            #[cfg(feature = "tracing")]
+            extern crate self as tracing;
+
+            #[cfg(feature = "tracing")]
+            pub(crate) use ::ploidy_util::tracing::*;
+
+            pub use ::ploidy_util as util;
+
+            pub use error::Error;
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_library_omits_types_when_client_only() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let config = CodegenConfig {
+            types_crate: Some("pets-types".to_owned().try_into().unwrap()),
+            scope: CodegenScope::Client,
+            ..Default::default()
+        };
+        let graph = CodegenGraph::with_config(RawGraph::new(&arena, &spec).cook(), &config);
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        let expected: syn::File = parse_quote! {
+            #![doc = " Test API (version 1.0.0)"]
+            #![allow(deprecated)]
+
+            pub mod client;
+            pub use client::Client;
+            pub mod error;
+
+            #[cfg(feature = "tracing")]
+            extern crate self as tracing;
+
+            #[cfg(feature = "tracing")]
+            pub(crate) use ::ploidy_util::tracing::*;
+
+            pub use ::ploidy_util as util;
+
+            pub use error::Error;
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Crate docs
+
+    #[test]
+    fn test_library_docs_include_title_version_and_description() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Pet Store
+              description: Manages pets.
+              version: 2.1.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        let docs = actual
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect_vec();
+        let expected: [syn::Attribute; 3] = [
+            parse_quote!(#![doc = " Pet Store (version 2.1.0)"]),
+            parse_quote!(#![doc = ""]),
+            parse_quote!(#![doc = " Manages pets."]),
+        ];
+        assert_eq!(docs, expected.iter().collect_vec());
+    }
+
+    #[test]
+    fn test_library_omits_docs_without_title_or_description() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              version: 1.0.0
+            paths: {}
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let library = CodegenLibrary::new(&graph);
+        let actual: syn::File = parse_quote!(#library);
+        assert!(!actual.attrs.iter().any(|attr| attr.path().is_ident("doc")));
+    }
+}
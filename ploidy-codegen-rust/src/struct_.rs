@@ -1,11 +1,21 @@
+use std::ops::RangeInclusive;
+
 use itertools::Itertools;
-use ploidy_core::ir::{HasTypeId, Required, StructFieldName, StructFieldView, StructView, View};
-use proc_macro2::TokenStream;
-use quote::{ToTokens, TokenStreamExt, quote};
+use ploidy_core::ir::{
+    ContainerView, FieldDefault, HasTypeId, InlineTypeView, PrimitiveType, Required,
+    SchemaTypeView, StructFieldName, StructFieldView, StructView, TypeView, View,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
 
 use super::{
-    derives::ExtraDerive, doc_attrs, ext::FieldViewExt, graph::CodegenGraph, graph::IdentMapping,
-    naming::CodegenIdentUsage, ref_::CodegenRef,
+    derives::ExtraDerive,
+    doc_attrs,
+    ext::{FieldViewExt, InnerViewExt},
+    graph::CodegenGraph,
+    graph::IdentMapping,
+    naming::CodegenIdentUsage,
+    ref_::CodegenRef,
 };
 
 #[derive(Clone, Debug)]
@@ -22,6 +32,21 @@ impl<'a> CodegenStruct<'a> {
 
 impl ToTokens for CodegenStruct<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+
+        // Fields with a `default` get a function that returns it,
+        // for `#[serde(default = "...")]` and the `Default` impl.
+        let mut default_fns = vec![];
+        let mut default_inits = vec![];
+
+        // Builders take required fields as constructor arguments,
+        // and have a `with_*` method for every field.
+        let mut builder_params = vec![];
+        let mut builder_inits = vec![];
+        let mut builder_setters = vec![];
+
+        let mut validations = vec![];
+
         let fields = self
             .ty
             .fields()
@@ -33,11 +58,338 @@ impl ToTokens for CodegenStruct<'_> {
                     self.graph
                         .ident(IdentMapping::StructField(self.ty.id(), field.name())),
                 );
-                let field_attrs = StructFieldAttrs::new(field_name, &field);
                 let ty = CodegenField::new(self.graph, &field);
+                let deprecated = field.deprecated().then(|| quote!(#[deprecated]));
+
+                // The range of values that the field's type can hold,
+                // if it's an integer type.
+                let int_range: Option<RangeInclusive<i128>> = match field.inner() {
+                    TypeView::Schema(SchemaTypeView::Primitive(_, view))
+                    | TypeView::Inline(InlineTypeView::Primitive(_, view)) => match view.ty() {
+                        PrimitiveType::I8 => Some(i8::MIN.into()..=i8::MAX.into()),
+                        PrimitiveType::U8 => Some(u8::MIN.into()..=u8::MAX.into()),
+                        PrimitiveType::I16 => Some(i16::MIN.into()..=i16::MAX.into()),
+                        PrimitiveType::U16 => Some(u16::MIN.into()..=u16::MAX.into()),
+                        PrimitiveType::I32 => Some(i32::MIN.into()..=i32::MAX.into()),
+                        PrimitiveType::U32 => Some(u32::MIN.into()..=u32::MAX.into()),
+                        PrimitiveType::I64 => Some(i64::MIN.into()..=i64::MAX.into()),
+                        PrimitiveType::U64 => Some(u64::MIN.into()..=u64::MAX.into()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                // An expression for the field's `default`,
+                // if it has one that fits its type.
+                let default = field.default().and_then(|default| {
+                    let value = match field.inner() {
+                        TypeView::Schema(SchemaTypeView::Primitive(_, view))
+                        | TypeView::Inline(InlineTypeView::Primitive(_, view)) => {
+                            match (view.ty(), default) {
+                                (PrimitiveType::String, FieldDefault::String(s)) => {
+                                    quote! { ::std::string::String::from(#s) }
+                                }
+                                (PrimitiveType::Bool, FieldDefault::Bool(b)) => quote! { #b },
+                                (_, FieldDefault::I64(n))
+                                    if int_range
+                                        .as_ref()
+                                        .is_some_and(|range| range.contains(&n.into())) =>
+                                {
+                                    Literal::i64_unsuffixed(n).into_token_stream()
+                                }
+                                (_, FieldDefault::U64(n))
+                                    if int_range
+                                        .as_ref()
+                                        .is_some_and(|range| range.contains(&n.into())) =>
+                                {
+                                    Literal::u64_unsuffixed(n).into_token_stream()
+                                }
+                                (PrimitiveType::F32 | PrimitiveType::F64, FieldDefault::I64(n)) => {
+                                    Literal::f64_unsuffixed(n as f64).into_token_stream()
+                                }
+                                (PrimitiveType::F32 | PrimitiveType::F64, FieldDefault::U64(n)) => {
+                                    Literal::f64_unsuffixed(n as f64).into_token_stream()
+                                }
+                                (PrimitiveType::F32 | PrimitiveType::F64, FieldDefault::F64(f)) => {
+                                    Literal::f64_unsuffixed(f.to_f64()).into_token_stream()
+                                }
+                                _ => return None,
+                            }
+                        }
+                        TypeView::Schema(SchemaTypeView::Enum(..))
+                        | TypeView::Inline(InlineTypeView::Enum(..)) => {
+                            let FieldDefault::String(s) = default else {
+                                return None;
+                            };
+                            // Enums parse infallibly from strings.
+                            quote! {{
+                                let ::std::result::Result::Ok(v) = ::std::str::FromStr::from_str(#s);
+                                v
+                            }}
+                        }
+                        _ => return None,
+                    };
+                    // Wrap the value in the field's `Box`, `AbsentOr`, or `Option`.
+                    let value = if field.needs_box() {
+                        quote! { ::std::boxed::Box::new(#value) }
+                    } else {
+                        value
+                    };
+                    Some(match field.required() {
+                        Required::Optional => {
+                            quote! { ::ploidy_util::absent::AbsentOr::Present(#value) }
+                        }
+                        Required::Required { nullable: true } => {
+                            quote! { ::std::option::Option::Some(#value) }
+                        }
+                        Required::Required { nullable: false } => value,
+                    })
+                });
+                let default_fn = default.map(|value| {
+                    let default_fn = format_ident!("default_{}", field_name);
+                    default_fns.push(quote! {
+                        fn #default_fn() -> #ty {
+                            #value
+                        }
+                    });
+                    default_fn
+                });
+                match &default_fn {
+                    Some(default_fn) => {
+                        default_inits.push(quote! { #field_name: Self::#default_fn() });
+                    }
+                    None => {
+                        default_inits
+                            .push(quote! { #field_name: ::std::default::Default::default() });
+                    }
+                }
+
+                if self.graph.builders() {
+                    if matches!(field.required(), Required::Required { .. }) && !field.flattened() {
+                        builder_params.push(quote! { #field_name: #ty });
+                        builder_inits.push(quote! { #field_name });
+                    } else if let Some(default_fn) = &default_fn {
+                        builder_inits.push(quote! { #field_name: #type_name::#default_fn() });
+                    } else {
+                        builder_inits
+                            .push(quote! { #field_name: ::std::default::Default::default() });
+                    }
+
+                    let setter = format_ident!("with_{}", field_name);
+                    let inner_ty = field.inner();
+                    let inner = CodegenRef::new(self.graph, &inner_ty);
+                    // Wrap the value in the field's `Box`, `AbsentOr`, or `Option`.
+                    let value = if field.needs_box() {
+                        quote! { ::std::boxed::Box::new(value) }
+                    } else {
+                        quote! { value }
+                    };
+                    let value = match field.required() {
+                        Required::Optional => {
+                            quote! { ::ploidy_util::absent::AbsentOr::Present(#value) }
+                        }
+                        Required::Required { nullable: true } => {
+                            quote! { ::std::option::Option::Some(#value) }
+                        }
+                        Required::Required { nullable: false } => value,
+                    };
+                    builder_setters.push(quote! {
+                        #deprecated
+                        pub fn #setter(mut self, value: #inner) -> Self {
+                            self.0.#field_name = #value;
+                            self
+                        }
+                    });
+                }
+
+                // Check the field's value against its constraints.
+                match field.name() {
+                    StructFieldName::Name(name) => {
+                        let constraints = field.constraints();
+                        let fail = |kind: TokenStream| {
+                            quote! {
+                                return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                    #name,
+                                    ::ploidy_util::error::ValidationErrorKind::#kind,
+                                ));
+                            }
+                        };
+
+                        let mut checks = vec![];
+                        match field.inner() {
+                            TypeView::Schema(SchemaTypeView::Primitive(_, view))
+                            | TypeView::Inline(InlineTypeView::Primitive(_, view)) => match view.ty() {
+                                PrimitiveType::String => {
+                                    if let Some(min) = constraints.min_length {
+                                        let min = Literal::u64_unsuffixed(min);
+                                        let fail = fail(quote! { MinLength(#min) });
+                                        checks.push((quote! { value.chars().count() < #min }, fail));
+                                    }
+                                    if let Some(max) = constraints.max_length {
+                                        let max = Literal::u64_unsuffixed(max);
+                                        let fail = fail(quote! { MaxLength(#max) });
+                                        checks.push((quote! { value.chars().count() > #max }, fail));
+                                    }
+                                }
+                                PrimitiveType::F32 | PrimitiveType::F64 => {
+                                    if let Some(min) = constraints.minimum {
+                                        let min = Literal::f64_unsuffixed(min.to_f64());
+                                        let fail = fail(quote! { Minimum(#min) });
+                                        checks.push((quote! { *value < #min }, fail));
+                                    }
+                                    if let Some(max) = constraints.maximum {
+                                        let max = Literal::f64_unsuffixed(max.to_f64());
+                                        let fail = fail(quote! { Maximum(#max) });
+                                        checks.push((quote! { *value > #max }, fail));
+                                    }
+                                }
+                                // Integers compare against the nearest whole bound. Bounds
+                                // at or outside the edges of the type's range are skipped,
+                                // because every value of the type satisfies them.
+                                _ => {
+                                    if let Some(min) = constraints.minimum
+                                        && let Some(range) = &int_range
+                                        && let bound = min.to_f64().ceil()
+                                        && range.contains(&(bound as i128))
+                                        && bound as i128 != *range.start()
+                                    {
+                                        let bound = Literal::i128_unsuffixed(bound as i128);
+                                        let min = Literal::f64_unsuffixed(min.to_f64());
+                                        let fail = fail(quote! { Minimum(#min) });
+                                        checks.push((quote! { *value < #bound }, fail));
+                                    }
+                                    if let Some(max) = constraints.maximum
+                                        && let Some(range) = &int_range
+                                        && let bound = max.to_f64().floor()
+                                        && range.contains(&(bound as i128))
+                                        && bound as i128 != *range.end()
+                                    {
+                                        let bound = Literal::i128_unsuffixed(bound as i128);
+                                        let max = Literal::f64_unsuffixed(max.to_f64());
+                                        let fail = fail(quote! { Maximum(#max) });
+                                        checks.push((quote! { *value > #bound }, fail));
+                                    }
+                                }
+                            },
+                            TypeView::Schema(SchemaTypeView::Container(
+                                _,
+                                container @ (ContainerView::Array(_) | ContainerView::Set(_)),
+                            ))
+                            | TypeView::Inline(InlineTypeView::Container(
+                                _,
+                                container @ (ContainerView::Array(_) | ContainerView::Set(_)),
+                            )) => {
+                                if let Some(min) = constraints.min_items {
+                                    let cond = match min {
+                                        1 => quote! { value.is_empty() },
+                                        min => {
+                                            let min = Literal::u64_unsuffixed(min);
+                                            quote! { value.len() < #min }
+                                        }
+                                    };
+                                    let min = Literal::u64_unsuffixed(min);
+                                    let fail = fail(quote! { MinItems(#min) });
+                                    checks.push((cond, fail));
+                                }
+                                if let Some(max) = constraints.max_items {
+                                    let max = Literal::u64_unsuffixed(max);
+                                    let fail = fail(quote! { MaxItems(#max) });
+                                    checks.push((quote! { value.len() > #max }, fail));
+                                }
+                                // Sets of unordered items are `Vec`s, so
+                                // uniqueness must be checked at runtime.
+                                if let ContainerView::Set(inner) = &container
+                                    && !inner.ordered()
+                                {
+                                    let fail = fail(quote! { UniqueItems });
+                                    checks.push((
+                                        quote! { value.iter().enumerate().any(|(i, item)| value[..i].contains(item)) },
+                                        fail,
+                                    ));
+                                }
+                            }
+                            TypeView::Schema(SchemaTypeView::Container(_, ContainerView::Map(_)))
+                            | TypeView::Inline(InlineTypeView::Container(_, ContainerView::Map(_))) => {
+                                if let Some(min) = constraints.min_properties {
+                                    let cond = match min {
+                                        1 => quote! { value.is_empty() },
+                                        min => {
+                                            let min = Literal::u64_unsuffixed(min);
+                                            quote! { value.len() < #min }
+                                        }
+                                    };
+                                    let min = Literal::u64_unsuffixed(min);
+                                    let fail = fail(quote! { MinProperties(#min) });
+                                    checks.push((cond, fail));
+                                }
+                                if let Some(max) = constraints.max_properties {
+                                    let max = Literal::u64_unsuffixed(max);
+                                    let fail = fail(quote! { MaxProperties(#max) });
+                                    checks.push((quote! { value.len() > #max }, fail));
+                                }
+                            }
+                            _ => (),
+                        }
+
+                        if !checks.is_empty() {
+                            let (conds, fails): (Vec<_>, Vec<_>) = checks.into_iter().unzip();
+                            validations.push(match field.required() {
+                                Required::Optional => quote! {
+                                    #(if ::std::matches!(
+                                        &self.#field_name,
+                                        ::ploidy_util::absent::AbsentOr::Present(value) if #conds
+                                    ) {
+                                        #fails
+                                    })*
+                                },
+                                Required::Required { nullable: true } => quote! {
+                                    #(if ::std::matches!(
+                                        &self.#field_name,
+                                        ::std::option::Option::Some(value) if #conds
+                                    ) {
+                                        #fails
+                                    })*
+                                },
+                                Required::Required { nullable: false } => quote! {
+                                    {
+                                        let value = &self.#field_name;
+                                        #(if #conds {
+                                            #fails
+                                        })*
+                                    }
+                                },
+                            });
+                        }
+                    }
+                    // Check that every key in a `patternProperties` map
+                    // matches at least one of its patterns.
+                    StructFieldName::PatternProperties(patterns) => {
+                        validations.push(quote! {
+                            {
+                                // The transform drops patterns that don't parse.
+                                static PATTERNS: ::std::sync::LazyLock<::ploidy_util::regex::RegexSet> =
+                                    ::std::sync::LazyLock::new(|| {
+                                        ::ploidy_util::regex::RegexSet::new([#(#patterns),*]).unwrap()
+                                    });
+                                if self.#field_name.keys().any(|key| !PATTERNS.is_match(key)) {
+                                    return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                        "patternProperties",
+                                        ::ploidy_util::error::ValidationErrorKind::PropertyName,
+                                    ));
+                                }
+                            }
+                        });
+                    }
+                    _ => (),
+                }
+
+                let default_fn =
+                    default_fn.map(|default_fn| format!("{}::{default_fn}", type_name.display()));
+                let field_attrs = StructFieldAttrs::new(field_name, &field, default_fn);
 
                 quote! {
                     #doc_attrs
+                    #deprecated
                     #field_attrs
                     pub #field_name: #ty,
                 }
@@ -53,22 +405,110 @@ impl ToTokens for CodegenStruct<'_> {
             extra_derives.push(ExtraDerive::Hash);
         }
 
-        // Derive `Default` if all fields are transitively defaultable.
-        if self.ty.defaultable() {
+        // Implement `Default` if all fields are transitively defaultable:
+        // by hand if any field has a `default`, or derived otherwise.
+        let default_impl = if !self.ty.defaultable() {
+            None
+        } else if default_fns.is_empty() {
             extra_derives.push(ExtraDerive::Default);
-        }
+            None
+        } else {
+            Some(quote! {
+                impl ::std::default::Default for #type_name {
+                    fn default() -> Self {
+                        Self {
+                            #(#default_inits,)*
+                        }
+                    }
+                }
+            })
+        };
+
+        let default_fns = (!default_fns.is_empty()).then(|| {
+            quote! {
+                impl #type_name {
+                    #(#default_fns)*
+                }
+            }
+        });
+
+        // Reject unknown fields if the schema is closed. Serde doesn't support
+        // `deny_unknown_fields` with flattened fields; and variant structs
+        // must accept the tag of their tagged union.
+        let deny_unknown_fields = (self.ty.closed()
+            && !self
+                .ty
+                .fields()
+                .any(|field| field.flattened() || field.tag()))
+        .then(|| quote!(#[serde(deny_unknown_fields)]));
 
-        let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
         let doc_attrs = self.ty.description().map(doc_attrs);
+        let examples = self.graph.examples(self.ty.id());
+        let vis = self.graph.visibility();
+
+        let validate = (!validations.is_empty()).then(|| {
+            quote! {
+                impl #type_name {
+                    #[doc = " Checks this value against the constraints in the schema."]
+                    pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                        #(#validations)*
+                        ::std::result::Result::Ok(())
+                    }
+                }
+            }
+        });
+
+        let builder = self.graph.builders().then(|| {
+            let builder_name = format_ident!("{}Builder", type_name);
+            let doc = format!(" A builder for [`{}`].", type_name.display());
+            // A builder without required fields is also `Default`.
+            let default_impl = builder_params.is_empty().then(|| {
+                quote! {
+                    impl ::std::default::Default for #builder_name {
+                        fn default() -> Self {
+                            Self::new()
+                        }
+                    }
+                }
+            });
+            quote! {
+                #[doc = #doc]
+                #[derive(Debug, Clone)]
+                #vis struct #builder_name(#type_name);
+
+                impl #builder_name {
+                    pub fn new(#(#builder_params),*) -> Self {
+                        Self(#type_name {
+                            #(#builder_inits,)*
+                        })
+                    }
+
+                    #(#builder_setters)*
+
+                    pub fn build(self) -> #type_name {
+                        self.0
+                    }
+                }
+
+                #default_impl
+            }
+        });
 
         tokens.append_all(quote! {
             #doc_attrs
+            #examples
             #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde")]
+            #deny_unknown_fields
             #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-            pub struct #type_name {
+            #vis struct #type_name {
                 #(#fields)*
             }
+
+            #default_fns
+            #default_impl
+            #validate
+            #builder
         });
     }
 }
@@ -110,11 +550,20 @@ impl ToTokens for CodegenField<'_, '_> {
 struct StructFieldAttrs<'view, 'a> {
     field_name: CodegenIdentUsage<'a>,
     field: &'a StructFieldView<'view, 'a, 'a>,
+    default_fn: Option<String>,
 }
 
 impl<'view, 'a> StructFieldAttrs<'view, 'a> {
-    fn new(field_name: CodegenIdentUsage<'a>, field: &'a StructFieldView<'view, 'a, 'a>) -> Self {
-        Self { field_name, field }
+    fn new(
+        field_name: CodegenIdentUsage<'a>,
+        field: &'a StructFieldView<'view, 'a, 'a>,
+        default_fn: Option<String>,
+    ) -> Self {
+        Self {
+            field_name,
+            field,
+            default_fn,
+        }
     }
 }
 
@@ -135,12 +584,23 @@ impl ToTokens for StructFieldAttrs<'_, '_> {
                 }
             }
 
-            if matches!(self.field.required(), Required::Optional) {
+            if let Some(default_fn) = &self.default_fn {
+                meta.push(quote! { default = #default_fn });
+            } else if matches!(self.field.required(), Required::Optional) {
                 meta.push(quote! { default });
+            }
+            // `readOnly` fields are never sent in requests,
+            // and `writeOnly` fields are ignored in responses.
+            if self.field.read_only() {
+                meta.push(quote! { skip_serializing });
+            } else if matches!(self.field.required(), Required::Optional) {
                 meta.push(
                     quote! { skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent" },
                 );
             }
+            if self.field.write_only() {
+                meta.push(quote! { skip_deserializing });
+            }
 
             if meta.is_empty() {
                 quote! {}
@@ -178,13 +638,13 @@ mod tests {
 
     use ploidy_core::{
         arena::Arena,
-        ir::{RawGraph, SchemaTypeView, Spec},
+        ir::{RawGraph, SchemaTypeView, Spec, SpecConfig},
         parse::Document,
     };
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::CodegenGraph;
+    use crate::{CodegenConfig, CodegenGraph, Visibility};
 
     #[test]
     fn test_struct() {
@@ -293,6 +753,62 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_struct_excludes_const_tag_fields() {
+        // `Cat.kind` is a `const` that repeats the discriminator value.
+        // The tagged union's `#[serde(tag = "kind")]` reads and writes it,
+        // so the variant struct omits it.
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Cat:
+                  type: object
+                  properties:
+                    kind:
+                      const: cat
+                    meow:
+                      type: string
+                  required:
+                    - kind
+                    - meow
+                Pet:
+                  oneOf:
+                    - $ref: '#/components/schemas/Cat'
+                  discriminator:
+                    propertyName: kind
+                    mapping:
+                      cat: '#/components/schemas/Cat'
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Cat").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Cat`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Cat {
+                pub meow: ::std::string::String,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_struct_required_nullable_field_uses_option() {
         let doc = Document::from_yaml(indoc::indoc! {"
@@ -500,6 +1016,62 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_struct_nullable_all_of_ref_fields() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Owner:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                Pet:
+                  type: object
+                  properties:
+                    owner:
+                      nullable: true
+                      allOf:
+                        - $ref: '#/components/schemas/Owner'
+                    previous:
+                      nullable: true
+                      allOf:
+                        - $ref: '#/components/schemas/Owner'
+                  required:
+                    - owner
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Pet {
+                pub owner: ::std::option::Option<crate::types::Owner>,
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub previous: ::ploidy_util::absent::AbsentOr<crate::types::Owner>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
     // MARK: `Hash` and `Eq`
 
     #[test]
@@ -2165,10 +2737,10 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
-    // MARK: Boxing
+    // MARK: Deprecation
 
     #[test]
-    fn test_struct_boxes_recursive_required_field() {
+    fn test_struct_deprecated_field_emits_deprecated_attr() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
@@ -2177,16 +2749,17 @@ mod tests {
             paths: {}
             components:
               schemas:
-                Node:
+                Pet:
                   type: object
                   properties:
-                    value:
+                    name:
                       type: string
-                    next:
-                      $ref: '#/components/schemas/Node'
+                    nickname:
+                      type: string
+                      description: Use `name` instead.
+                      deprecated: true
                   required:
-                    - value
-                    - next
+                    - name
         "})
         .unwrap();
 
@@ -2194,31 +2767,31 @@ mod tests {
         let spec = Spec::from_doc(&arena, &doc).unwrap();
         let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
 
-        let schema = graph.schema("Node").unwrap();
+        let schema = graph.schema("Pet").unwrap();
         let SchemaTypeView::Struct(_, struct_view) = &schema else {
-            panic!("expected struct `Node`; got `{schema:?}`");
+            panic!("expected struct `Pet`; got `{schema:?}`");
         };
 
         let codegen = CodegenStruct::new(&graph, struct_view);
 
         let actual: syn::ItemStruct = parse_quote!(#codegen);
-        // `next` is required and recursive, so it should be boxed.
-        // `value` is a string which implements `Default`, so the struct can
-        // derive `Default`.
         let expected: syn::ItemStruct = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde")]
             #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-            pub struct Node {
-                pub value: ::std::string::String,
-                pub next: ::std::boxed::Box<crate::types::Node>,
+            pub struct Pet {
+                pub name: ::std::string::String,
+                #[doc = " Use `name` instead."]
+                #[deprecated]
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub nickname: ::ploidy_util::absent::AbsentOr<::std::string::String>,
             }
         };
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn test_struct_boxes_recursive_optional_field() {
+    fn test_struct_read_only_and_write_only_fields_skip_one_direction() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
@@ -2227,15 +2800,21 @@ mod tests {
             paths: {}
             components:
               schemas:
-                Node:
+                User:
                   type: object
                   properties:
-                    value:
+                    id:
                       type: string
-                    next:
-                      $ref: '#/components/schemas/Node'
+                      readOnly: true
+                    name:
+                      type: string
+                    password:
+                      type: string
+                      writeOnly: true
                   required:
-                    - value
+                    - id
+                    - name
+                    - password
         "})
         .unwrap();
 
@@ -2243,32 +2822,36 @@ mod tests {
         let spec = Spec::from_doc(&arena, &doc).unwrap();
         let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
 
-        let schema = graph.schema("Node").unwrap();
+        let schema = graph.schema("User").unwrap();
         let SchemaTypeView::Struct(_, struct_view) = &schema else {
-            panic!("expected struct `Node`; got `{schema:?}`");
+            panic!("expected struct `User`; got `{schema:?}`");
         };
 
         let codegen = CodegenStruct::new(&graph, struct_view);
 
+        // `id` is never serialized into requests, and `password` is never
+        // deserialized from responses. Both are optional, so that callers
+        // don't need dummy values for them.
         let actual: syn::ItemStruct = parse_quote!(#codegen);
-        // `next` is optional and recursive. The box should be inside `AbsentOr`,
-        // giving `AbsentOr<Box<Node>>`, not `Box<AbsentOr<Node>>`. `value` is a
-        // string which implements `Default`, so the struct can derive `Default`.
         let expected: syn::ItemStruct = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde")]
             #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-            pub struct Node {
-                pub value: ::std::string::String,
-                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
-                pub next: ::ploidy_util::absent::AbsentOr<::std::boxed::Box<crate::types::Node>>,
+            pub struct User {
+                #[serde(default, skip_serializing)]
+                pub id: ::ploidy_util::absent::AbsentOr<::std::string::String>,
+                pub name: ::std::string::String,
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent", skip_deserializing)]
+                pub password: ::ploidy_util::absent::AbsentOr<::std::string::String>,
             }
         };
         assert_eq!(actual, expected);
     }
 
+    // MARK: `default` values
+
     #[test]
-    fn test_struct_does_not_box_recursive_array_field() {
+    fn test_struct_implements_default_with_field_defaults() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
@@ -2277,18 +2860,27 @@ mod tests {
             paths: {}
             components:
               schemas:
-                Node:
+                Pet:
                   type: object
                   properties:
-                    value:
+                    name:
                       type: string
-                    children:
-                      type: array
-                      items:
-                        $ref: '#/components/schemas/Node'
+                      default: Rex
+                    age:
+                      type: integer
+                      format: int32
+                      default: -1
+                    friendly:
+                      type: boolean
+                      nullable: true
+                      default: true
+                    kind:
+                      type: string
+                      enum: [cat, dog]
+                      default: dog
                   required:
-                    - value
-                    - children
+                    - name
+                    - friendly
         "})
         .unwrap();
 
@@ -2296,31 +2888,63 @@ mod tests {
         let spec = Spec::from_doc(&arena, &doc).unwrap();
         let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
 
-        let schema = graph.schema("Node").unwrap();
+        let schema = graph.schema("Pet").unwrap();
         let SchemaTypeView::Struct(_, struct_view) = &schema else {
-            panic!("expected struct `Node`; got `{schema:?}`");
+            panic!("expected struct `Pet`; got `{schema:?}`");
         };
 
         let codegen = CodegenStruct::new(&graph, struct_view);
 
-        let actual: syn::ItemStruct = parse_quote!(#codegen);
-        // `children` is an array of recursive elements, but arrays (`Vec`)
-        // provide their own indirection, so no boxing is needed. `value` is a
-        // string which implements `Default`, so the struct can derive `Default`.
-        let expected: syn::ItemStruct = parse_quote! {
-            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde")]
             #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-            pub struct Node {
-                pub value: ::std::string::String,
-                pub children: ::std::vec::Vec<crate::types::Node>,
+            pub struct Pet {
+                #[serde(default = "Pet::default_name")]
+                pub name: ::std::string::String,
+                #[serde(default = "Pet::default_age", skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub age: ::ploidy_util::absent::AbsentOr<i32>,
+                #[serde(default = "Pet::default_friendly")]
+                pub friendly: ::std::option::Option<bool>,
+                #[serde(default = "Pet::default_kind", skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub kind: ::ploidy_util::absent::AbsentOr<crate::types::pet::types::Kind>,
+            }
+
+            impl Pet {
+                fn default_name() -> ::std::string::String {
+                    ::std::string::String::from("Rex")
+                }
+                fn default_age() -> ::ploidy_util::absent::AbsentOr<i32> {
+                    ::ploidy_util::absent::AbsentOr::Present(-1)
+                }
+                fn default_friendly() -> ::std::option::Option<bool> {
+                    ::std::option::Option::Some(true)
+                }
+                fn default_kind() -> ::ploidy_util::absent::AbsentOr<crate::types::pet::types::Kind> {
+                    ::ploidy_util::absent::AbsentOr::Present({
+                        let ::std::result::Result::Ok(v) = ::std::str::FromStr::from_str("dog");
+                        v
+                    })
+                }
+            }
+
+            impl ::std::default::Default for Pet {
+                fn default() -> Self {
+                    Self {
+                        name: Self::default_name(),
+                        age: Self::default_age(),
+                        friendly: Self::default_friendly(),
+                        kind: Self::default_kind(),
+                    }
+                }
             }
         };
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn test_struct_does_not_box_optional_recursive_array_field() {
+    fn test_struct_ignores_default_of_wrong_type() {
         let doc = Document::from_yaml(indoc::indoc! {"
             openapi: 3.0.0
             info:
@@ -2329,17 +2953,16 @@ mod tests {
             paths: {}
             components:
               schemas:
-                Node:
+                Pet:
                   type: object
                   properties:
-                    value:
+                    age:
+                      type: integer
+                      format: uint8
+                      default: 300
+                    name:
                       type: string
-                    children:
-                      type: array
-                      items:
-                        $ref: '#/components/schemas/Node'
-                  required:
-                    - value
+                      default: 5
         "})
         .unwrap();
 
@@ -2347,23 +2970,822 @@ mod tests {
         let spec = Spec::from_doc(&arena, &doc).unwrap();
         let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
 
-        let schema = graph.schema("Node").unwrap();
+        let schema = graph.schema("Pet").unwrap();
         let SchemaTypeView::Struct(_, struct_view) = &schema else {
-            panic!("expected struct `Node`; got `{schema:?}`");
+            panic!("expected struct `Pet`; got `{schema:?}`");
         };
 
         let codegen = CodegenStruct::new(&graph, struct_view);
 
+        // Neither default fits its field's type, so both are ignored.
         let actual: syn::ItemStruct = parse_quote!(#codegen);
-        // `children` is an optional array of recursive elements. Arrays provide
-        // their own indirection, so no boxing is needed, despite the field
-        // being optional (`AbsentOr`). `value` is a string which implements
-        // `Default`, so the struct can derive `Default`.
         let expected: syn::ItemStruct = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde")]
             #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
-            pub struct Node {
+            pub struct Pet {
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub age: ::ploidy_util::absent::AbsentOr<u8>,
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub name: ::ploidy_util::absent::AbsentOr<::std::string::String>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Validation
+
+    #[test]
+    fn test_struct_validates_constraints() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                      minLength: 1
+                      maxLength: 3
+                    age:
+                      type: integer
+                      format: uint8
+                      minimum: -1
+                      maximum: 20.5
+                    weight:
+                      type: number
+                      nullable: true
+                      minimum: 0.5
+                    tags:
+                      type: array
+                      items:
+                        type: string
+                      minItems: 1
+                      maxItems: 2
+                  required:
+                    - name
+                    - weight
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // `age` can't be negative, so its `minimum` is skipped; and
+        // its `maximum` rounds down to the nearest integer.
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemImpl = parse_quote! {
+            impl Pet {
+                #[doc = " Checks this value against the constraints in the schema."]
+                pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                    {
+                        let value = &self.name;
+                        if value.chars().count() < 1 {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "name",
+                                ::ploidy_util::error::ValidationErrorKind::MinLength(1),
+                            ));
+                        }
+                        if value.chars().count() > 3 {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "name",
+                                ::ploidy_util::error::ValidationErrorKind::MaxLength(3),
+                            ));
+                        }
+                    }
+                    if ::std::matches!(
+                        &self.age,
+                        ::ploidy_util::absent::AbsentOr::Present(value) if *value > 20
+                    ) {
+                        return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                            "age",
+                            ::ploidy_util::error::ValidationErrorKind::Maximum(20.5),
+                        ));
+                    }
+                    if ::std::matches!(
+                        &self.weight,
+                        ::std::option::Option::Some(value) if *value < 0.5
+                    ) {
+                        return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                            "weight",
+                            ::ploidy_util::error::ValidationErrorKind::Minimum(0.5),
+                        ));
+                    }
+                    if ::std::matches!(
+                        &self.tags,
+                        ::ploidy_util::absent::AbsentOr::Present(value) if value.is_empty()
+                    ) {
+                        return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                            "tags",
+                            ::ploidy_util::error::ValidationErrorKind::MinItems(1),
+                        ));
+                    }
+                    if ::std::matches!(
+                        &self.tags,
+                        ::ploidy_util::absent::AbsentOr::Present(value) if value.len() > 2
+                    ) {
+                        return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                            "tags",
+                            ::ploidy_util::error::ValidationErrorKind::MaxItems(2),
+                        ));
+                    }
+                    ::std::result::Result::Ok(())
+                }
+            }
+        };
+        let [syn::Item::Struct(_), syn::Item::Impl(actual)] = actual.items.as_slice() else {
+            panic!("expected struct and `impl`; got `{actual:?}`");
+        };
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_struct_validates_unique_items_of_unordered_type() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Point:
+                  type: object
+                  properties:
+                    tags:
+                      type: array
+                      items:
+                        type: string
+                      uniqueItems: true
+                    weights:
+                      type: array
+                      items:
+                        type: number
+                      uniqueItems: true
+                  required:
+                    - tags
+                    - weights
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Point").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Point`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // `tags` is a `BTreeSet`, which is always unique;
+        // `weights` is a `Vec`, so it's checked at runtime.
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemImpl = parse_quote! {
+            impl Point {
+                #[doc = " Checks this value against the constraints in the schema."]
+                pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                    {
+                        let value = &self.weights;
+                        if value.iter().enumerate().any(|(i, item)| value[..i].contains(item)) {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "weights",
+                                ::ploidy_util::error::ValidationErrorKind::UniqueItems,
+                            ));
+                        }
+                    }
+                    ::std::result::Result::Ok(())
+                }
+            }
+        };
+        let [syn::Item::Struct(_), syn::Item::Impl(actual)] = actual.items.as_slice() else {
+            panic!("expected struct and `impl`; got `{actual:?}`");
+        };
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_struct_validates_map_property_counts() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    labels:
+                      type: object
+                      additionalProperties:
+                        type: string
+                      minProperties: 1
+                      maxProperties: 5
+                  required:
+                    - labels
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemImpl = parse_quote! {
+            impl Pet {
+                #[doc = " Checks this value against the constraints in the schema."]
+                pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                    {
+                        let value = &self.labels;
+                        if value.is_empty() {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "labels",
+                                ::ploidy_util::error::ValidationErrorKind::MinProperties(1),
+                            ));
+                        }
+                        if value.len() > 5 {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "labels",
+                                ::ploidy_util::error::ValidationErrorKind::MaxProperties(5),
+                            ));
+                        }
+                    }
+                    ::std::result::Result::Ok(())
+                }
+            }
+        };
+        let [syn::Item::Struct(_), syn::Item::Impl(actual)] = actual.items.as_slice() else {
+            panic!("expected struct and `impl`; got `{actual:?}`");
+        };
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_struct_skips_bounds_at_integer_range_edges() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Color:
+                  type: object
+                  properties:
+                    red:
+                      type: integer
+                      minimum: 0
+                      maximum: 255
+                    alpha:
+                      type: integer
+                      minimum: 0
+                      maximum: 100
+                  required:
+                    - red
+                    - alpha
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc_with_config(
+            &arena,
+            &doc,
+            SpecConfig {
+                narrow_integers: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Color").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Color`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // Both fields narrow to `u8`, so every bound except
+        // `alpha`'s `maximum` holds for every value.
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::ItemImpl = parse_quote! {
+            impl Color {
+                #[doc = " Checks this value against the constraints in the schema."]
+                pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                    {
+                        let value = &self.alpha;
+                        if *value > 100 {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "alpha",
+                                ::ploidy_util::error::ValidationErrorKind::Maximum(100.0),
+                            ));
+                        }
+                    }
+                    ::std::result::Result::Ok(())
+                }
+            }
+        };
+        let [syn::Item::Struct(_), syn::Item::Impl(actual)] = actual.items.as_slice() else {
+            panic!("expected struct and `impl`; got `{actual:?}`");
+        };
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_struct_without_constraints_omits_validate() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                    status:
+                      $ref: '#/components/schemas/Status'
+                Status:
+                  type: string
+                  maxLength: 10
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // Constraints on a referenced schema aren't checked.
+        let actual: syn::File = parse_quote!(#codegen);
+        assert_eq!(actual.items.len(), 1);
+    }
+
+    // MARK: Builders
+
+    #[test]
+    fn test_struct_with_builder() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                    owner:
+                      type: string
+                      nullable: true
+                    age:
+                      type: integer
+                      format: int32
+                    species:
+                      type: string
+                      default: dog
+                  required:
+                    - name
+                    - owner
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                builders: true,
+                ..Default::default()
+            },
+        );
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Pet {
+                pub name: ::std::string::String,
+                pub owner: ::std::option::Option<::std::string::String>,
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub age: ::ploidy_util::absent::AbsentOr<i32>,
+                #[serde(default = "Pet::default_species", skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub species: ::ploidy_util::absent::AbsentOr<::std::string::String>,
+            }
+
+            impl Pet {
+                fn default_species() -> ::ploidy_util::absent::AbsentOr<::std::string::String> {
+                    ::ploidy_util::absent::AbsentOr::Present(::std::string::String::from("dog"))
+                }
+            }
+
+            impl ::std::default::Default for Pet {
+                fn default() -> Self {
+                    Self {
+                        name: ::std::default::Default::default(),
+                        owner: ::std::default::Default::default(),
+                        age: ::std::default::Default::default(),
+                        species: Self::default_species(),
+                    }
+                }
+            }
+
+            #[doc = " A builder for [`Pet`]."]
+            #[derive(Debug, Clone)]
+            pub struct PetBuilder(Pet);
+
+            impl PetBuilder {
+                pub fn new(
+                    name: ::std::string::String,
+                    owner: ::std::option::Option<::std::string::String>
+                ) -> Self {
+                    Self(Pet {
+                        name,
+                        owner,
+                        age: ::std::default::Default::default(),
+                        species: Pet::default_species(),
+                    })
+                }
+
+                pub fn with_name(mut self, value: ::std::string::String) -> Self {
+                    self.0.name = value;
+                    self
+                }
+
+                pub fn with_owner(mut self, value: ::std::string::String) -> Self {
+                    self.0.owner = ::std::option::Option::Some(value);
+                    self
+                }
+
+                pub fn with_age(mut self, value: i32) -> Self {
+                    self.0.age = ::ploidy_util::absent::AbsentOr::Present(value);
+                    self
+                }
+
+                pub fn with_species(mut self, value: ::std::string::String) -> Self {
+                    self.0.species = ::ploidy_util::absent::AbsentOr::Present(value);
+                    self
+                }
+
+                pub fn build(self) -> Pet {
+                    self.0
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_with_builder_without_required_fields() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Tag:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                builders: true,
+                ..Default::default()
+            },
+        );
+
+        let schema = graph.schema("Tag").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Tag`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Tag {
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub name: ::ploidy_util::absent::AbsentOr<::std::string::String>,
+            }
+
+            #[doc = " A builder for [`Tag`]."]
+            #[derive(Debug, Clone)]
+            pub struct TagBuilder(Tag);
+
+            impl TagBuilder {
+                pub fn new() -> Self {
+                    Self(Tag {
+                        name: ::std::default::Default::default(),
+                    })
+                }
+
+                pub fn with_name(mut self, value: ::std::string::String) -> Self {
+                    self.0.name = ::ploidy_util::absent::AbsentOr::Present(value);
+                    self
+                }
+
+                pub fn build(self) -> Tag {
+                    self.0
+                }
+            }
+
+            impl ::std::default::Default for TagBuilder {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_without_builder_by_default() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        assert_eq!(actual.items.len(), 1);
+    }
+
+    // MARK: Boxing
+
+    #[test]
+    fn test_struct_boxes_recursive_required_field() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Node:
+                  type: object
+                  properties:
+                    value:
+                      type: string
+                    next:
+                      $ref: '#/components/schemas/Node'
+                  required:
+                    - value
+                    - next
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Node").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Node`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        // `next` is required and recursive, so it should be boxed.
+        // `value` is a string which implements `Default`, so the struct can
+        // derive `Default`.
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Node {
+                pub value: ::std::string::String,
+                pub next: ::std::boxed::Box<crate::types::Node>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_boxes_recursive_optional_field() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Node:
+                  type: object
+                  properties:
+                    value:
+                      type: string
+                    next:
+                      $ref: '#/components/schemas/Node'
+                  required:
+                    - value
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Node").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Node`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        // `next` is optional and recursive. The box should be inside `AbsentOr`,
+        // giving `AbsentOr<Box<Node>>`, not `Box<AbsentOr<Node>>`. `value` is a
+        // string which implements `Default`, so the struct can derive `Default`.
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Node {
+                pub value: ::std::string::String,
+                #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
+                pub next: ::ploidy_util::absent::AbsentOr<::std::boxed::Box<crate::types::Node>>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_does_not_box_recursive_array_field() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Node:
+                  type: object
+                  properties:
+                    value:
+                      type: string
+                    children:
+                      type: array
+                      items:
+                        $ref: '#/components/schemas/Node'
+                  required:
+                    - value
+                    - children
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Node").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Node`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        // `children` is an array of recursive elements, but arrays (`Vec`)
+        // provide their own indirection, so no boxing is needed. `value` is a
+        // string which implements `Default`, so the struct can derive `Default`.
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Node {
+                pub value: ::std::string::String,
+                pub children: ::std::vec::Vec<crate::types::Node>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_does_not_box_optional_recursive_array_field() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Node:
+                  type: object
+                  properties:
+                    value:
+                      type: string
+                    children:
+                      type: array
+                      items:
+                        $ref: '#/components/schemas/Node'
+                  required:
+                    - value
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Node").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Node`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        // `children` is an optional array of recursive elements. Arrays provide
+        // their own indirection, so no boxing is needed, despite the field
+        // being optional (`AbsentOr`). `value` is a string which implements
+        // `Default`, so the struct can derive `Default`.
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Node {
                 pub value: ::std::string::String,
                 #[serde(default, skip_serializing_if = "::ploidy_util::absent::AbsentOr::is_absent")]
                 pub children: ::ploidy_util::absent::AbsentOr<::std::vec::Vec<crate::types::Node>>,
@@ -2484,6 +3906,185 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_struct_with_pattern_properties() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Labels:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                  required:
+                    - name
+                  patternProperties:
+                    '^[a-z]+$':
+                      type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Labels").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Labels`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Labels {
+                pub name: ::std::string::String,
+                #[serde(flatten)]
+                #[ploidy(pointer(flatten))]
+                pub pattern_properties: ::std::collections::BTreeMap<::std::string::String, ::std::string::String>,
+            }
+
+            impl Labels {
+                #[doc = " Checks this value against the constraints in the schema."]
+                pub fn validate(&self) -> ::std::result::Result<(), ::ploidy_util::error::ValidationError> {
+                    {
+                        static PATTERNS: ::std::sync::LazyLock<::ploidy_util::regex::RegexSet> =
+                            ::std::sync::LazyLock::new(|| {
+                                ::ploidy_util::regex::RegexSet::new(["^[a-z]+$"]).unwrap()
+                            });
+                        if self.pattern_properties.keys().any(|key| !PATTERNS.is_match(key)) {
+                            return ::std::result::Result::Err(::ploidy_util::error::ValidationError::new(
+                                "patternProperties",
+                                ::ploidy_util::error::ValidationErrorKind::PropertyName,
+                            ));
+                        }
+                    }
+                    ::std::result::Result::Ok(())
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_closed_denies_unknown_fields() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Base:
+                  type: object
+                  properties:
+                    id:
+                      type: string
+                  required:
+                    - id
+                Config:
+                  allOf:
+                    - $ref: '#/components/schemas/Base'
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                  required:
+                    - name
+                  additionalProperties: false
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Config").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Config`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // Inherited fields are declared on the struct,
+        // so they aren't unknown.
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[serde(deny_unknown_fields)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Config {
+                pub id: ::std::string::String,
+                pub name: ::std::string::String,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_struct_closed_with_flattened_fields_allows_unknown_fields() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Base:
+                  type: object
+                  properties:
+                    id:
+                      type: string
+                  required:
+                    - id
+                  additionalProperties:
+                    type: string
+                Config:
+                  allOf:
+                    - $ref: '#/components/schemas/Base'
+                  type: object
+                  additionalProperties: false
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Config").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Config`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        // Serde doesn't support `deny_unknown_fields` alongside `flatten`.
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Config {
+                pub id: ::std::string::String,
+                #[serde(flatten)]
+                #[ploidy(pointer(flatten))]
+                pub additional_properties: ::std::collections::BTreeMap<::std::string::String, ::std::string::String>,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
     // MARK: Inlined struct variants of tagged unions
 
     #[test]
@@ -2872,4 +4473,55 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    // MARK: Visibility
+
+    #[test]
+    fn test_struct_uses_configured_visibility() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+                  properties:
+                    name:
+                      type: string
+                  required:
+                    - name
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                visibility: Visibility::PubCrate,
+                ..Default::default()
+            },
+        );
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Struct(_, struct_view) = &schema else {
+            panic!("expected struct `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenStruct::new(&graph, struct_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#codegen);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub(crate) struct Pet {
+                pub name: ::std::string::String,
+            }
+        };
+        assert_eq!(actual, expected);
+    }
 }
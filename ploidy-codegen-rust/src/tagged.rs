@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use ploidy_core::ir::{HasTypeId, TaggedView, View};
 use proc_macro2::TokenStream;
-use quote::{ToTokens, TokenStreamExt, quote};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
 
 use super::{
     derives::ExtraDerive, doc_attrs, graph::CodegenGraph, graph::IdentMapping,
@@ -89,17 +89,38 @@ impl ToTokens for CodegenTagged<'_> {
         let discriminator_field_literal = self.ty.tag();
 
         let doc_attrs = self.ty.description().map(doc_attrs);
+        let examples = self.graph.examples(self.ty.id());
 
         let vs = variants.iter().map(|(variant, _)| variant);
         let fs = variants.iter().map(|(_, from_impl)| from_impl);
         let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+
+        // The catch-all variant comes last. It can't hold the variant's
+        // data, so it can't be serialized back.
+        let other = self.graph.unknown_variants().then(|| {
+            let other_name = format_ident!("Other{}", type_name);
+            quote! {
+                #[serde(other, skip_serializing)]
+                #[ploidy(pointer(skip))]
+                #other_name,
+            }
+        });
+
+        let non_exhaustive = self
+            .graph
+            .non_exhaustive()
+            .then(|| quote!(#[non_exhaustive]));
+        let vis = self.graph.visibility();
         let main = quote! {
             #doc_attrs
+            #examples
             #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", tag = #discriminator_field_literal)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", tag = #discriminator_field_literal))]
-            pub enum #type_name {
+            #non_exhaustive
+            #vis enum #type_name {
                 #(#vs)*
+                #other
             }
 
             #(#fs)*
@@ -121,7 +142,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use syn::parse_quote;
 
-    use crate::CodegenGraph;
+    use crate::{CodegenConfig, CodegenGraph};
 
     #[test]
     fn test_tagged_union_serde_tag_attr() {
@@ -664,4 +685,125 @@ mod tests {
         };
         assert_eq!(actual, expected);
     }
+
+    // MARK: Config
+
+    #[test]
+    fn test_tagged_union_non_exhaustive_with_unknown_variant() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            components:
+              schemas:
+                Dog:
+                  type: object
+                  properties:
+                    bark:
+                      type: string
+                Pet:
+                  oneOf:
+                    - $ref: '#/components/schemas/Dog'
+                  discriminator:
+                    propertyName: petType
+                    mapping:
+                      dog: '#/components/schemas/Dog'
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::with_config(
+            RawGraph::new(&arena, &spec).cook(),
+            &CodegenConfig {
+                non_exhaustive: true,
+                unknown_variants: true,
+                ..Default::default()
+            },
+        );
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Tagged(_, tagged) = schema else {
+            panic!("expected tagged union `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenTagged::new(&graph, &tagged);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde", tag = "petType")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer", tag = "petType"))]
+            #[non_exhaustive]
+            pub enum Pet {
+                #[serde(rename = "dog")]
+                #[ploidy(pointer(rename = "dog"))]
+                Dog(crate::types::Dog),
+                #[serde(other, skip_serializing)]
+                #[ploidy(pointer(skip))]
+                OtherPet,
+            }
+            impl ::std::convert::From<crate::types::Dog> for Pet {
+                fn from(value: crate::types::Dog) -> Self {
+                    Self::Dog(value)
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tagged_union_variant_avoids_colliding_with_unknown_variant() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            components:
+              schemas:
+                OtherPet:
+                  type: object
+                  properties:
+                    bark:
+                      type: string
+                Pet:
+                  oneOf:
+                    - $ref: '#/components/schemas/OtherPet'
+                  discriminator:
+                    propertyName: petType
+                    mapping:
+                      other: '#/components/schemas/OtherPet'
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Pet").unwrap();
+        let SchemaTypeView::Tagged(_, tagged) = schema else {
+            panic!("expected tagged union `Pet`; got `{schema:?}`");
+        };
+
+        let codegen = CodegenTagged::new(&graph, &tagged);
+
+        let actual: syn::File = parse_quote!(#codegen);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde", tag = "petType")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer", tag = "petType"))]
+            pub enum Pet {
+                #[serde(rename = "other")]
+                #[ploidy(pointer(rename = "other"))]
+                OtherPet2(crate::types::OtherPet),
+            }
+            impl ::std::convert::From<crate::types::OtherPet> for Pet {
+                fn from(value: crate::types::OtherPet) -> Self {
+                    Self::OtherPet2(value)
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,322 @@
+use itertools::Itertools;
+use ploidy_core::ir::{HasTypeId, TupleView, View};
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+
+use super::{
+    derives::ExtraDerive, doc_attrs, graph::CodegenGraph, naming::CodegenIdentUsage,
+    ref_::CodegenRef,
+};
+
+/// Generates a tuple struct for a schema with `prefixItems`.
+#[derive(Clone, Debug)]
+pub struct CodegenTuple<'a> {
+    graph: &'a CodegenGraph<'a>,
+    ty: &'a TupleView<'a, 'a>,
+}
+
+impl<'a> CodegenTuple<'a> {
+    pub fn new(graph: &'a CodegenGraph<'a>, ty: &'a TupleView<'a, 'a>) -> Self {
+        Self { graph, ty }
+    }
+}
+
+impl ToTokens for CodegenTuple<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let type_name = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+        let doc_attrs = self.ty.description().map(doc_attrs);
+        let examples = self.graph.examples(self.ty.id());
+        let vis = self.graph.visibility();
+
+        let elements = self
+            .ty
+            .elements()
+            .map(|element| {
+                let ty = element.ty();
+                let ref_ = CodegenRef::new(self.graph, &ty);
+                if element.needs_box() {
+                    quote! { ::std::boxed::Box<#ref_> }
+                } else {
+                    quote! { #ref_ }
+                }
+            })
+            .collect_vec();
+        let rest = self.ty.rest().map(|rest| {
+            let ref_ = CodegenRef::new(self.graph, &rest);
+            quote! { ::std::vec::Vec<#ref_> }
+        });
+
+        let mut extra_derives = vec![];
+
+        // Derive `Eq` and `Hash` if all elements are transitively hashable.
+        if self.ty.hashable() {
+            extra_derives.push(ExtraDerive::Eq);
+            extra_derives.push(ExtraDerive::Hash);
+        }
+
+        // Derive `Default` if all elements are transitively defaultable.
+        if self.ty.defaultable() {
+            extra_derives.push(ExtraDerive::Default);
+        }
+
+        // Serde treats single-element tuple structs as newtypes,
+        // and doesn't support rest elements, so these tuples get
+        // hand-written impls.
+        if elements.len() > 1 && rest.is_none() {
+            tokens.append_all(quote! {
+                #doc_attrs
+                #examples
+                #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+                #[serde(crate = "::ploidy_util::serde")]
+                #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+                #vis struct #type_name(#(pub #elements),*);
+            });
+            return;
+        }
+
+        let count = elements.len();
+        let noun = if count == 1 { "element" } else { "elements" };
+        let indices = (0..count).map(syn::Index::from).collect_vec();
+        let bindings = (0..count)
+            .map(|index| format_ident!("e{index}"))
+            .collect_vec();
+        let positions = (0..count).map(Literal::usize_unsuffixed).collect_vec();
+        let len = Literal::usize_unsuffixed(count);
+
+        let (rest_field, serialize_len, serialize_rest, deserialize_rest, expecting) = match &rest {
+            Some(rest) => {
+                let index = syn::Index::from(count);
+                (
+                    // Pointers can't address rest elements by index,
+                    // because they're stored in a nested `Vec`.
+                    Some(quote! { #[ploidy(pointer(skip))] pub #rest }),
+                    quote! { #len + self.#index.len() },
+                    quote! {
+                        for item in &self.#index {
+                            seq.serialize_element(item)?;
+                        }
+                    },
+                    quote! {
+                        let mut rest = ::std::vec::Vec::new();
+                        while let ::std::option::Option::Some(item) = seq.next_element()? {
+                            rest.push(item);
+                        }
+                    },
+                    format!("an array with at least {count} {noun}"),
+                )
+            }
+            None => (
+                None,
+                quote! { #len },
+                quote! {},
+                quote! {
+                    if seq
+                        .next_element::<::ploidy_util::serde::de::IgnoredAny>()?
+                        .is_some()
+                    {
+                        return ::std::result::Result::Err(
+                            ::ploidy_util::serde::de::Error::invalid_length(#len + 1, &self),
+                        );
+                    }
+                },
+                format!("an array with {count} {noun}"),
+            ),
+        };
+        let rest_binding = rest.as_ref().map(|_| quote! { rest });
+
+        tokens.append_all(quote! {
+            #doc_attrs
+            #examples
+            #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            #vis struct #type_name(#(pub #elements,)* #rest_field);
+
+            impl ::ploidy_util::serde::Serialize for #type_name {
+                fn serialize<S: ::ploidy_util::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::std::result::Result<S::Ok, S::Error> {
+                    use ::ploidy_util::serde::ser::SerializeSeq;
+                    let mut seq = serializer.serialize_seq(::std::option::Option::Some(#serialize_len))?;
+                    #(seq.serialize_element(&self.#indices)?;)*
+                    #serialize_rest
+                    seq.end()
+                }
+            }
+
+            impl<'de> ::ploidy_util::serde::Deserialize<'de> for #type_name {
+                fn deserialize<D: ::ploidy_util::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::std::result::Result<Self, D::Error> {
+                    struct Visitor;
+
+                    impl<'de> ::ploidy_util::serde::de::Visitor<'de> for Visitor {
+                        type Value = #type_name;
+
+                        fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.write_str(#expecting)
+                        }
+
+                        fn visit_seq<A: ::ploidy_util::serde::de::SeqAccess<'de>>(
+                            self,
+                            mut seq: A,
+                        ) -> ::std::result::Result<Self::Value, A::Error> {
+                            #(
+                                let #bindings = seq.next_element()?.ok_or_else(|| {
+                                    ::ploidy_util::serde::de::Error::invalid_length(#positions, &self)
+                                })?;
+                            )*
+                            #deserialize_rest
+                            ::std::result::Result::Ok(#type_name(#(#bindings,)* #rest_binding))
+                        }
+                    }
+
+                    deserializer.deserialize_seq(Visitor)
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::{
+        arena::Arena,
+        ir::{RawGraph, SchemaTypeView, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use crate::CodegenGraph;
+
+    #[test]
+    fn test_tuple_closed() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.1.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Coordinate:
+                  description: A longitude and latitude.
+                  type: array
+                  prefixItems:
+                    - type: number
+                    - type: number
+                  items: false
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Coordinate").unwrap();
+        let SchemaTypeView::Tuple(_, tuple_view) = &schema else {
+            panic!("expected tuple `Coordinate`; got `{schema:?}`");
+        };
+
+        let tuple = CodegenTuple::new(&graph, tuple_view);
+
+        let actual: syn::ItemStruct = parse_quote!(#tuple);
+        let expected: syn::ItemStruct = parse_quote! {
+            #[doc = " A longitude and latitude."]
+            #[derive(Debug, Clone, PartialEq, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde")]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Coordinate(pub f64, pub f64);
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tuple_with_rest() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.1.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Row:
+                  type: array
+                  prefixItems:
+                    - type: string
+                  items:
+                    type: boolean
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Row").unwrap();
+        let SchemaTypeView::Tuple(_, tuple_view) = &schema else {
+            panic!("expected tuple `Row`; got `{schema:?}`");
+        };
+
+        let tuple = CodegenTuple::new(&graph, tuple_view);
+
+        let actual: syn::File = parse_quote!(#tuple);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer"))]
+            pub struct Row(pub ::std::string::String, #[ploidy(pointer(skip))] pub ::std::vec::Vec<bool>);
+
+            impl ::ploidy_util::serde::Serialize for Row {
+                fn serialize<S: ::ploidy_util::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::std::result::Result<S::Ok, S::Error> {
+                    use ::ploidy_util::serde::ser::SerializeSeq;
+                    let mut seq = serializer.serialize_seq(::std::option::Option::Some(1 + self.1.len()))?;
+                    seq.serialize_element(&self.0)?;
+                    for item in &self.1 {
+                        seq.serialize_element(item)?;
+                    }
+                    seq.end()
+                }
+            }
+
+            impl<'de> ::ploidy_util::serde::Deserialize<'de> for Row {
+                fn deserialize<D: ::ploidy_util::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::std::result::Result<Self, D::Error> {
+                    struct Visitor;
+
+                    impl<'de> ::ploidy_util::serde::de::Visitor<'de> for Visitor {
+                        type Value = Row;
+
+                        fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.write_str("an array with at least 1 element")
+                        }
+
+                        fn visit_seq<A: ::ploidy_util::serde::de::SeqAccess<'de>>(
+                            self,
+                            mut seq: A,
+                        ) -> ::std::result::Result<Self::Value, A::Error> {
+                            let e0 = seq.next_element()?.ok_or_else(|| {
+                                ::ploidy_util::serde::de::Error::invalid_length(0, &self)
+                            })?;
+                            let mut rest = ::std::vec::Vec::new();
+                            while let ::std::option::Option::Some(item) = seq.next_element()? {
+                                rest.push(item);
+                            }
+                            ::std::result::Result::Ok(Row(e0, rest))
+                        }
+                    }
+
+                    deserializer.deserialize_seq(Visitor)
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+}
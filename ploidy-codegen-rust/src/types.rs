@@ -34,9 +34,10 @@ impl ToTokens for CodegenTypesModule<'_> {
             let ident = self.graph.ident(schema.id());
             let ty_name = CodegenIdentUsage::Type(ident);
             let mod_name = CodegenIdentUsage::Module(ident);
+            let vis = self.graph.visibility();
             quote! {
                 #cfg
-                pub use #mod_name::#ty_name;
+                #vis use #mod_name::#ty_name;
             }
         });
 
@@ -54,3 +55,121 @@ impl IntoCode for CodegenTypesModule<'_> {
         ("src/types/mod.rs", self.into_token_stream())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::read_to_string;
+
+    use indoc::formatdoc;
+    use ploidy_core::{
+        arena::Arena,
+        codegen::write_to_disk,
+        ir::{RawGraph, Spec},
+        parse::Document,
+    };
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    use crate::{CodegenSchemaType, write_types_to_disk};
+
+    #[test]
+    fn test_types_module_for_spec_without_components_is_empty() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            type: object
+                            properties:
+                              id:
+                                type: string
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let module = CodegenTypesModule::new(&graph);
+
+        let actual: syn::File = parse_quote!(#module);
+        let expected: syn::File = parse_quote! {};
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_write_types_to_disk_matches_serial_output() {
+        let mut yaml = indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+        "}
+        .to_owned();
+        for i in 0..200 {
+            let schemas = formatdoc! {"
+                Pet{i}:
+                  type: object
+                  required: [id]
+                  properties:
+                    id:
+                      type: string
+                    next:
+                      $ref: '#/components/schemas/Pet{next}'
+                    status:
+                      type: string
+                      enum: [available, sold]
+                    tags:
+                      type: array
+                      items:
+                        type: string
+                Owner{i}:
+                  oneOf:
+                    - $ref: '#/components/schemas/Pet{i}'
+                    - type: string
+            ", next = (i + 1) % 200};
+            yaml.push_str(&textwrap::indent(&schemas, "    "));
+        }
+        let doc = Document::from_yaml(&yaml).unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let parallel = tempfile::tempdir().unwrap();
+        let written = write_types_to_disk(parallel.path(), &graph).unwrap();
+
+        let serial = tempfile::tempdir().unwrap();
+        let mut expected = graph
+            .schemas()
+            .map(|schema| {
+                let code = CodegenSchemaType::new(&graph, &schema).into_code();
+                write_to_disk(serial.path(), code).unwrap()
+            })
+            .collect_vec();
+        expected.push(write_to_disk(serial.path(), CodegenTypesModule::new(&graph)).unwrap());
+
+        assert_eq!(written, expected);
+        for file in &written {
+            assert_eq!(
+                read_to_string(parallel.path().join(&file.path)).unwrap(),
+                read_to_string(serial.path().join(&file.path)).unwrap(),
+            );
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use ploidy_core::ir::{HasTypeId, UntaggedView, View};
+use itertools::Itertools;
+use ploidy_core::ir::{
+    ContainerView, HasTypeId, InlineTypeView, SchemaTypeView, TypeView, UntaggedView, View,
+};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
 
 use super::{
     derives::ExtraDerive,
     doc_attrs,
+    ext::EnumViewExt,
     graph::{CodegenGraph, IdentMapping},
     naming::CodegenIdentUsage,
     ref_::CodegenRef,
@@ -38,7 +42,79 @@ impl ToTokens for CodegenUntagged<'_> {
         });
 
         let type_name_ident = CodegenIdentUsage::Type(self.graph.ident(self.ty.id()));
+
+        // Convert from each variant's payload, if no other variant
+        // has the same payload type.
+        let payloads = self
+            .ty
+            .variants()
+            .filter_map(|variant| {
+                let ty = variant.ty()?;
+                let ordinal = variant.ordinal();
+                // Only distinct Rust types get `From` impls, because an alias
+                // might name the same type as another variant's payload.
+                // Inline containers are distinct if their items are.
+                let mut inner = variant.ty()?;
+                while let TypeView::Inline(InlineTypeView::Container(
+                    _,
+                    ContainerView::Array(container)
+                    | ContainerView::Set(container)
+                    | ContainerView::Map(container)
+                    | ContainerView::Optional(container),
+                )) = &inner
+                {
+                    inner = container.ty();
+                }
+                let distinct = match &inner {
+                    TypeView::Schema(view) if view.info().replaced_by.is_some() => false,
+                    TypeView::Schema(SchemaTypeView::Enum(_, view))
+                    | TypeView::Inline(InlineTypeView::Enum(_, view)) => view.representable(),
+                    TypeView::Schema(
+                        SchemaTypeView::Struct(..)
+                        | SchemaTypeView::Tagged(..)
+                        | SchemaTypeView::Untagged(..)
+                        | SchemaTypeView::Tuple(..),
+                    ) => true,
+                    TypeView::Schema(
+                        SchemaTypeView::Container(..)
+                        | SchemaTypeView::Primitive(..)
+                        | SchemaTypeView::Any(..),
+                    ) => false,
+                    TypeView::Inline(_) => true,
+                };
+                distinct.then(|| {
+                    let rust_type = CodegenRef::new(self.graph, &ty).into_token_stream();
+                    (ordinal, rust_type)
+                })
+            })
+            .collect_vec();
+        let from_impls = payloads
+            .iter()
+            .filter(|(_, rust_type)| {
+                let key = rust_type.to_string();
+                payloads
+                    .iter()
+                    .filter(|(_, other)| other.to_string() == key)
+                    .count()
+                    == 1
+            })
+            .map(|(ordinal, rust_type)| {
+                let variant_name = CodegenIdentUsage::Variant(
+                    self.graph
+                        .ident(IdentMapping::UntaggedVariant(self.ty.id(), *ordinal)),
+                );
+                quote! {
+                    impl ::std::convert::From<#rust_type> for #type_name_ident {
+                        fn from(value: #rust_type) -> Self {
+                            Self::#variant_name(value)
+                        }
+                    }
+                }
+            });
+
         let doc_attrs = self.ty.description().map(doc_attrs);
+        let examples = self.graph.examples(self.ty.id());
+        let vis = self.graph.visibility();
 
         let mut extra_derives = vec![];
 
@@ -50,12 +126,15 @@ impl ToTokens for CodegenUntagged<'_> {
 
         tokens.append_all(quote! {
             #doc_attrs
+            #examples
             #[derive(Debug, Clone, PartialEq, #(#extra_derives,)* ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
-            pub enum #type_name_ident {
+            #vis enum #type_name_ident {
                 #(#variants),*
             }
+
+            #(#from_impls)*
         })
     }
 }
@@ -103,8 +182,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -112,6 +191,16 @@ mod tests {
                 String(::std::string::String),
                 I32(i32)
             }
+            impl ::std::convert::From<::std::string::String> for StringOrInt {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+            impl ::std::convert::From<i32> for StringOrInt {
+                fn from(value: i32) -> Self {
+                    Self::I32(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -143,8 +232,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -152,6 +241,16 @@ mod tests {
                 DateTime(::ploidy_util::chrono::DateTime<::ploidy_util::chrono::Utc>),
                 I32(i32)
             }
+            impl ::std::convert::From<::ploidy_util::chrono::DateTime<::ploidy_util::chrono::Utc>> for DateOrUnix {
+                fn from(value: ::ploidy_util::chrono::DateTime<::ploidy_util::chrono::Utc>) -> Self {
+                    Self::DateTime(value)
+                }
+            }
+            impl ::std::convert::From<i32> for DateOrUnix {
+                fn from(value: i32) -> Self {
+                    Self::I32(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -194,8 +293,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -203,6 +302,16 @@ mod tests {
                 Dog(crate::types::Dog),
                 Cat(crate::types::Cat)
             }
+            impl ::std::convert::From<crate::types::Dog> for Animal {
+                fn from(value: crate::types::Dog) -> Self {
+                    Self::Dog(value)
+                }
+            }
+            impl ::std::convert::From<crate::types::Cat> for Animal {
+                fn from(value: crate::types::Cat) -> Self {
+                    Self::Cat(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -265,6 +374,16 @@ mod tests {
                 Animal1(crate::types::animal::types::Animal1),
                 Animal2(crate::types::animal::types::Animal2)
             }
+            impl ::std::convert::From<crate::types::animal::types::Animal1> for Animal {
+                fn from(value: crate::types::animal::types::Animal1) -> Self {
+                    Self::Animal1(value)
+                }
+            }
+            impl ::std::convert::From<crate::types::animal::types::Animal2> for Animal {
+                fn from(value: crate::types::animal::types::Animal2) -> Self {
+                    Self::Animal2(value)
+                }
+            }
             pub mod types {
                 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
                 #[serde(crate = "::ploidy_util::serde")]
@@ -315,8 +434,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[doc = " A union that can be either a string or an integer."]
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
@@ -325,6 +444,16 @@ mod tests {
                 String(::std::string::String),
                 I32(i32)
             }
+            impl ::std::convert::From<::std::string::String> for StringOrInt {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+            impl ::std::convert::From<i32> for StringOrInt {
+                fn from(value: i32) -> Self {
+                    Self::I32(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -358,8 +487,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -367,6 +496,16 @@ mod tests {
                 String(::std::string::String),
                 F32(f32)
             }
+            impl ::std::convert::From<::std::string::String> for StringOrFloat {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+            impl ::std::convert::From<f32> for StringOrFloat {
+                fn from(value: f32) -> Self {
+                    Self::F32(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -400,8 +539,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -409,6 +548,16 @@ mod tests {
                 String(::std::string::String),
                 F64(f64)
             }
+            impl ::std::convert::From<::std::string::String> for StringOrDouble {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+            impl ::std::convert::From<f64> for StringOrDouble {
+                fn from(value: f64) -> Self {
+                    Self::F64(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -451,8 +600,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -461,6 +610,21 @@ mod tests {
                 Array(::std::vec::Vec<::std::string::String>),
                 Array2(::std::vec::Vec<crate::types::input::types::Array2Item>)
             }
+            impl ::std::convert::From<::std::string::String> for Input {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+            impl ::std::convert::From<::std::vec::Vec<::std::string::String>> for Input {
+                fn from(value: ::std::vec::Vec<::std::string::String>) -> Self {
+                    Self::Array(value)
+                }
+            }
+            impl ::std::convert::From<::std::vec::Vec<crate::types::input::types::Array2Item>> for Input {
+                fn from(value: ::std::vec::Vec<crate::types::input::types::Array2Item>) -> Self {
+                    Self::Array2(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -494,8 +658,8 @@ mod tests {
 
         let untagged = CodegenUntagged::new(&graph, untagged_view);
 
-        let actual: syn::ItemEnum = parse_quote!(#untagged);
-        let expected: syn::ItemEnum = parse_quote! {
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
             #[serde(crate = "::ploidy_util::serde", untagged)]
             #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
@@ -504,6 +668,75 @@ mod tests {
                 None,
                 None2
             }
+            impl ::std::convert::From<::std::string::String> for Weird {
+                fn from(value: ::std::string::String) -> Self {
+                    Self::String(value)
+                }
+            }
+        };
+        assert_eq!(actual, expected);
+    }
+
+    // MARK: Conversions
+
+    #[test]
+    fn test_untagged_union_skips_from_for_ambiguous_payloads() {
+        let doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0.0
+            paths: {}
+            components:
+              schemas:
+                Name:
+                  type: string
+                Dog:
+                  type: object
+                  properties:
+                    bark:
+                      type: string
+                Ident:
+                  oneOf:
+                    - $ref: '#/components/schemas/Name'
+                    - $ref: '#/components/schemas/Dog'
+                    - type: integer
+                      format: int64
+                    - type: integer
+                      format: int64
+                      minimum: 0
+        "})
+        .unwrap();
+
+        let arena = Arena::new();
+        let spec = Spec::from_doc(&arena, &doc).unwrap();
+        let graph = CodegenGraph::new(RawGraph::new(&arena, &spec).cook());
+
+        let schema = graph.schema("Ident").unwrap();
+        let SchemaTypeView::Untagged(_, untagged_view) = &schema else {
+            panic!("expected untagged union `Ident`; got `{schema:?}`");
+        };
+
+        let untagged = CodegenUntagged::new(&graph, untagged_view);
+
+        // `Name` is an alias for `String`, and the two `i64` variants
+        // have the same payload, so only `Dog` gets a conversion.
+        let actual: syn::File = parse_quote!(#untagged);
+        let expected: syn::File = parse_quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, ::ploidy_util::serde::Serialize, ::ploidy_util::serde::Deserialize, ::ploidy_util::pointer::JsonPointee, ::ploidy_util::pointer::JsonPointerTarget)]
+            #[serde(crate = "::ploidy_util::serde", untagged)]
+            #[ploidy(pointer(crate = "::ploidy_util::pointer", untagged))]
+            pub enum Ident {
+                Name(crate::types::Name),
+                Dog(crate::types::Dog),
+                I64(i64),
+                I65(i64)
+            }
+            impl ::std::convert::From<crate::types::Dog> for Ident {
+                fn from(value: crate::types::Dog) -> Self {
+                    Self::Dog(value)
+                }
+            }
         };
         assert_eq!(actual, expected);
     }
@@ -25,7 +25,10 @@ use miette::{Context, IntoDiagnostic};
 
 pub mod unique;
 
-pub use unique::{AsKebabCase, AsPascalCase, AsSnakeCase, NamePart, UniqueName, UniqueNames};
+pub use unique::{
+    AsCamelCase, AsKebabCase, AsPascalCase, AsSnakeCase, AsVerbatimCase, NamePart, UniqueName,
+    UniqueNames,
+};
 
 /// A record of a file that [`write_to_disk`] wrote.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -217,6 +217,52 @@ impl Display for AsPascalCase<'_> {
     }
 }
 
+/// Formats a [`UniqueName`] as `camelCase`.
+///
+/// The first segment is lowercase; each subsequent segment starts with
+/// an uppercase character and continues in lowercase.
+pub struct AsCamelCase<'a>(pub UniqueName<'a>);
+
+impl Display for AsCamelCase<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut first = true;
+        for segment in self.0.segments() {
+            if let NameSegment::Text(text) = segment {
+                let mut chars = text.chars();
+                if let Some(c) = chars.next() {
+                    if first {
+                        write!(f, "{}", c.to_lowercase())?;
+                    } else {
+                        write!(f, "{}", c.to_uppercase())?;
+                    }
+                    chars.try_for_each(|c| write!(f, "{}", c.to_lowercase()))?;
+                    first = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats a [`UniqueName`] in its source case.
+///
+/// Separator boundaries become `_`; other boundaries don't change
+/// the text, so `getUserByID` stays `getUserByID`.
+pub struct AsVerbatimCase<'a>(pub UniqueName<'a>);
+
+impl Display for AsVerbatimCase<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for segment in self.0.segments() {
+            match segment {
+                NameSegment::Boundary(SegmentBoundary::Separator) => f.write_char('_')?,
+                NameSegment::Boundary(_) => continue,
+                NameSegment::Text(text) => f.write_str(text)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Formats a [`UniqueName`] as `snake_case`.
 ///
 /// Case and separator boundaries become `_`.
@@ -980,6 +1026,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_camel_case() {
+        let arena = Arena::new();
+        let mut names = UniqueNames::new(&arena);
+
+        assert_eq!(
+            AsCamelCase(names.claim([Text("getUser")])).to_string(),
+            "getUser"
+        );
+        assert_eq!(
+            AsCamelCase(names.claim([
+                Text("list"),
+                Boundary,
+                Text("HTTP"),
+                Boundary,
+                Text("logs")
+            ]))
+            .to_string(),
+            "listHttpLogs"
+        );
+        assert_eq!(
+            AsCamelCase(names.claim([Text("GetUser")])).to_string(),
+            "getUser2"
+        );
+    }
+
+    #[test]
+    fn test_verbatim_case() {
+        let arena = Arena::new();
+        let mut names = UniqueNames::new(&arena);
+
+        assert_eq!(
+            AsVerbatimCase(names.claim([Text("getUserByID")])).to_string(),
+            "getUserByID"
+        );
+        assert_eq!(
+            AsVerbatimCase(names.claim([Text("users"), Boundary, Text("getById")])).to_string(),
+            "users_getById"
+        );
+        assert_eq!(
+            AsVerbatimCase(names.claim([Text("get_user_by_id")])).to_string(),
+            "get_user_by_id_2"
+        );
+    }
+
     #[test]
     fn test_deduplication_with_numbers() {
         let arena = Arena::new();
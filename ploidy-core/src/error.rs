@@ -1,7 +1,49 @@
-#[derive(Debug, thiserror::Error)]
+use miette::SourceSpan;
+
+#[derive(Debug, miette::Diagnostic, thiserror::Error)]
 pub enum SerdeError {
     #[error(transparent)]
-    Json(#[from] serde_json::Error),
+    #[diagnostic(transparent)]
+    Json(#[from] JsonError),
     #[error(transparent)]
     Yaml(#[from] serde_saphyr::Error),
 }
+
+/// An error returned when a JSON document can't be parsed,
+/// with the location of the error in the document.
+#[derive(Debug, miette::Diagnostic, thiserror::Error)]
+#[error("{error}")]
+pub struct JsonError {
+    error: serde_json::Error,
+    #[source_code]
+    code: String,
+    #[label]
+    span: Option<SourceSpan>,
+}
+
+impl JsonError {
+    pub(crate) fn new(code: &str, error: serde_json::Error) -> Self {
+        // `serde_json` reports 1-based lines and columns, or
+        // line 0 for errors that don't have a location.
+        let span = (error.line() > 0).then(|| {
+            let line_start: usize = code
+                .split_inclusive('\n')
+                .take(error.line() - 1)
+                .map(str::len)
+                .sum();
+            let offset = (line_start + error.column().saturating_sub(1)).min(code.len());
+            SourceSpan::from(offset..offset)
+        });
+        Self {
+            error,
+            code: code.to_owned(),
+            span,
+        }
+    }
+
+    /// Returns the underlying `serde_json` error.
+    #[inline]
+    pub fn inner(&self) -> &serde_json::Error {
+        &self.error
+    }
+}
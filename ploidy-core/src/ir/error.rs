@@ -1,9 +1,21 @@
+use itertools::Itertools;
+
 use crate::parse::path::BadPath;
 
+use super::{problem::Problem, unsupported::UnsupportedFeature};
+
 #[derive(Debug, thiserror::Error)]
 pub enum IrError {
-    #[error("can't generate code for an operation without an ID")]
-    NoOperationId,
     #[error("operation has invalid path")]
     BadOperationPath(#[from] BadPath),
+    #[error(
+        "document is invalid:\n{}",
+        .0.iter().map(|problem| format!("  {problem}")).join("\n")
+    )]
+    Invalid(Vec<Problem>),
+    #[error(
+        "document uses unsupported features:\n{}",
+        .0.iter().map(|feature| format!("  {feature}")).join("\n")
+    )]
+    Unsupported(Vec<UnsupportedFeature>),
 }
@@ -28,13 +28,16 @@ use crate::{arena::Arena, ir::TypeView, parse::Info};
 use super::{
     spec::{ResolvedSpecType, Spec},
     types::{
-        FieldMeta, GraphContainer, GraphInlineType, GraphOperation, GraphSchemaType, GraphStruct,
-        GraphTagged, GraphType, GraphUntagged, InlineTypeId, InlineTypeIds, InlineTypePathRoot,
-        OperationUsage, PrimitiveType, SpecInlineType, SpecSchemaType, SpecType, StructFieldName,
-        TaggedVariantMeta, UntaggedVariantMeta, VariantMeta,
+        ElementMeta, FieldMeta, GraphContainer, GraphInlineType, GraphOperation, GraphSchemaType,
+        GraphStruct, GraphTagged, GraphType, GraphUntagged, InlineTypeId, InlineTypeIds,
+        InlineTypePathRoot, OperationUsage, PrimitiveType, SpecInlineType, SpecSchemaType,
+        SpecType, StructFieldName, TaggedVariantMeta, UntaggedVariantMeta, VariantMeta,
         shape::{Operation, Parameter, ParameterInfo, Request, Response},
     },
-    views::{TypeId, operation::OperationView, primitive::PrimitiveView, schema::SchemaTypeView},
+    views::{
+        TypeId, operation::OperationView, primitive::PrimitiveView, schema::SchemaTypeView,
+        struct_::StructView,
+    },
 };
 
 /// The mutable, sparse graph used for transformations.
@@ -142,6 +145,28 @@ impl<'a> RawGraph<'a> {
                     description: info.description,
                     style: info.style,
                 }),
+                Parameter::Header(info) => Parameter::Header(ParameterInfo {
+                    name: info.name,
+                    ty: match info.ty {
+                        SpecType::Schema(s) => indices[&ResolvedSpecType::Schema(s)],
+                        SpecType::Inline(i) => indices[&ResolvedSpecType::Inline(i)],
+                        SpecType::Ref(r) => schemas[&*r.name()],
+                    },
+                    required: info.required,
+                    description: info.description,
+                    style: info.style,
+                }),
+                Parameter::Cookie(info) => Parameter::Cookie(ParameterInfo {
+                    name: info.name,
+                    ty: match info.ty {
+                        SpecType::Schema(s) => indices[&ResolvedSpecType::Schema(s)],
+                        SpecType::Inline(i) => indices[&ResolvedSpecType::Inline(i)],
+                        SpecType::Ref(r) => schemas[&*r.name()],
+                    },
+                    required: info.required,
+                    description: info.description,
+                    style: info.style,
+                }),
             }));
 
             let request = op.request.as_ref().map(|r| match r {
@@ -151,6 +176,8 @@ impl<'a> RawGraph<'a> {
                     SpecType::Ref(r) => schemas[&*r.name()],
                 }),
                 Request::Multipart => Request::Multipart,
+                Request::Text => Request::Text,
+                Request::Bytes => Request::Bytes,
             });
 
             let response = op.response.as_ref().map(|r| match r {
@@ -159,17 +186,42 @@ impl<'a> RawGraph<'a> {
                     SpecType::Inline(i) => indices[&ResolvedSpecType::Inline(i)],
                     SpecType::Ref(r) => schemas[&*r.name()],
                 }),
+                Response::Text => Response::Text,
+                Response::Bytes => Response::Bytes,
             });
 
+            let response_headers =
+                arena.alloc_slice_exact(op.response_headers.iter().map(|info| ParameterInfo {
+                    name: info.name,
+                    ty: match info.ty {
+                        SpecType::Schema(s) => indices[&ResolvedSpecType::Schema(s)],
+                        SpecType::Inline(i) => indices[&ResolvedSpecType::Inline(i)],
+                        SpecType::Ref(r) => schemas[&*r.name()],
+                    },
+                    required: info.required,
+                    description: info.description,
+                    style: info.style,
+                }));
+
             &*arena.alloc(Operation {
                 id: op.id,
                 method: op.method,
                 path: op.path,
                 resource: op.resource,
                 description: op.description,
+                timeout: op.timeout,
+                server: op.server,
+                deprecated: op.deprecated,
                 params,
                 request,
+                request_required: op.request_required,
+                request_examples: op.request_examples,
                 response,
+                response_status: op.response_status,
+                response_examples: op.response_examples,
+                response_content_type: op.response_content_type,
+                response_content_types: op.response_content_types,
+                response_headers,
             })
         }));
 
@@ -497,6 +549,12 @@ impl<'a> RawGraph<'a> {
                             Parameter::Query(info) => collapsed_to
                                 .get(&info.ty)
                                 .map(|&ty| Parameter::Query(ParameterInfo { ty, ..info })),
+                            Parameter::Header(info) => collapsed_to
+                                .get(&info.ty)
+                                .map(|&ty| Parameter::Header(ParameterInfo { ty, ..info })),
+                            Parameter::Cookie(info) => collapsed_to
+                                .get(&info.ty)
+                                .map(|&ty| Parameter::Cookie(ParameterInfo { ty, ..info })),
                         };
                         rewrite.unwrap_or(param)
                     })
@@ -509,7 +567,7 @@ impl<'a> RawGraph<'a> {
                             let &ty = collapsed_to.get(&ty)?;
                             Some(Request::Json(ty))
                         }
-                        Request::Multipart => None,
+                        Request::Multipart | Request::Text | Request::Bytes => None,
                     })
                     .or(op.request);
 
@@ -520,16 +578,31 @@ impl<'a> RawGraph<'a> {
                             let &ty = collapsed_to.get(&ty)?;
                             Some(Response::Json(ty))
                         }
+                        Response::Text | Response::Bytes => None,
                     })
                     .or(op.response);
 
-                if params == op.params && request == op.request && response == op.response {
+                let response_headers = op
+                    .response_headers
+                    .iter()
+                    .map(|&info| match collapsed_to.get(&info.ty) {
+                        Some(&ty) => ParameterInfo { ty, ..info },
+                        None => info,
+                    })
+                    .collect_vec();
+
+                if params == op.params
+                    && request == op.request
+                    && response == op.response
+                    && response_headers == op.response_headers
+                {
                     op
                 } else {
                     self.arena.alloc(Operation {
                         params: self.arena.alloc_slice_copy(&params),
                         request,
                         response,
+                        response_headers: self.arena.alloc_slice_copy(&response_headers),
                         ..*op
                     })
                 }
@@ -760,6 +833,7 @@ pub struct CookedGraph<'a> {
     arena: &'a Arena,
     pub(super) graph: CookedDiGraph<'a>,
     info: &'a Info,
+    server: Option<&'a str>,
     schemas: FxHashMap<&'a str, NodeIndex<usize>>,
     ops: &'a [&'a GraphOperation<'a>],
     /// Additional metadata for each node.
@@ -806,6 +880,9 @@ impl<'a> CookedGraph<'a> {
                 path: op.path,
                 resource: op.resource,
                 description: op.description,
+                timeout: op.timeout,
+                server: op.server,
+                deprecated: op.deprecated,
                 params: raw
                     .arena
                     .alloc_slice_exact(op.params.iter().map(|p| match p {
@@ -823,14 +900,47 @@ impl<'a> CookedGraph<'a> {
                             description: info.description,
                             style: info.style,
                         }),
+                        Parameter::Header(info) => Parameter::Header(ParameterInfo {
+                            name: info.name,
+                            ty: indices[&info.ty],
+                            required: info.required,
+                            description: info.description,
+                            style: info.style,
+                        }),
+                        Parameter::Cookie(info) => Parameter::Cookie(ParameterInfo {
+                            name: info.name,
+                            ty: indices[&info.ty],
+                            required: info.required,
+                            description: info.description,
+                            style: info.style,
+                        }),
                     })),
                 request: op.request.as_ref().map(|r| match r {
                     Request::Json(ty) => Request::Json(indices[ty]),
                     Request::Multipart => Request::Multipart,
+                    Request::Text => Request::Text,
+                    Request::Bytes => Request::Bytes,
                 }),
+                request_required: op.request_required,
+                request_examples: op.request_examples,
                 response: op.response.as_ref().map(|r| match r {
                     Response::Json(ty) => Response::Json(indices[ty]),
+                    Response::Text => Response::Text,
+                    Response::Bytes => Response::Bytes,
                 }),
+                response_status: op.response_status,
+                response_examples: op.response_examples,
+                response_content_type: op.response_content_type,
+                response_content_types: op.response_content_types,
+                response_headers: raw.arena.alloc_slice_exact(op.response_headers.iter().map(
+                    |info| ParameterInfo {
+                        name: info.name,
+                        ty: indices[&info.ty],
+                        required: info.required,
+                        description: info.description,
+                        style: info.style,
+                    },
+                )),
             })
         }));
 
@@ -840,6 +950,7 @@ impl<'a> CookedGraph<'a> {
             arena: raw.arena,
             graph,
             info: raw.spec.info,
+            server: raw.spec.server,
             schemas: raw
                 .schemas
                 .iter()
@@ -863,6 +974,13 @@ impl<'a> CookedGraph<'a> {
         self.info
     }
 
+    /// Returns the URL of the document's first server, with its
+    /// variables replaced by their default values.
+    #[inline]
+    pub fn server(&self) -> Option<&'a str> {
+        self.server
+    }
+
     /// Returns an iterator over all the named schemas in this graph.
     #[inline]
     pub fn schemas(&self) -> impl Iterator<Item = SchemaTypeView<'_, 'a>> + use<'_, 'a> {
@@ -905,6 +1023,20 @@ impl<'a> CookedGraph<'a> {
             })
     }
 
+    /// Returns an iterator over all struct type nodes in this graph.
+    #[inline]
+    pub fn structs(&self) -> impl Iterator<Item = StructView<'_, 'a>> + use<'_, 'a> {
+        self.graph
+            .node_indices()
+            .filter_map(|index| match self.graph[index] {
+                GraphType::Schema(GraphSchemaType::Struct(_, ty))
+                | GraphType::Inline(GraphInlineType::Struct(_, ty)) => {
+                    Some(StructView::new(self, index, ty))
+                }
+                _ => None,
+            })
+    }
+
     /// Returns an iterator over all the operations in this graph.
     #[inline]
     pub fn operations(&self) -> impl Iterator<Item = OperationView<'_, 'a>> + use<'_, 'a> {
@@ -956,6 +1088,22 @@ impl<'a> CookedGraph<'a> {
                 _ => None,
             })
     }
+
+    #[inline]
+    pub(super) fn elements(
+        &self,
+        node: NodeIndex<usize>,
+    ) -> impl Iterator<Item = OutgoingEdge<ElementMeta>> {
+        self.graph
+            .edges_directed(node, Direction::Outgoing)
+            .filter_map(|e| match e.weight() {
+                &GraphEdge::Element(meta) => {
+                    let target = e.target();
+                    Some(OutgoingEdge { meta, target })
+                }
+                _ => None,
+            })
+    }
 }
 
 /// A variant that should be inlined into its tagged union.
@@ -1013,7 +1161,9 @@ pub enum GraphEdge<'a> {
     Field { shadow: bool, meta: FieldMeta<'a> },
     /// The source union has the target type as a variant.
     Variant(VariantMeta<'a>),
-    /// The source type is an array, map, or optional that contains
+    /// The source tuple has the target type as an element.
+    Element(ElementMeta),
+    /// The source type is an array, set, map, or optional that contains
     /// the target type.
     Contains,
 }
@@ -1218,6 +1368,8 @@ impl<'graph, 'a> MetadataBuilder<'graph, 'a> {
                 let (usage, info) = match param {
                     Parameter::Path(info) => (OperationUsage::Path(info.name), info),
                     Parameter::Query(info) => (OperationUsage::Query(info.name), info),
+                    Parameter::Header(info) => (OperationUsage::Header(info.name), info),
+                    Parameter::Cookie(info) => (OperationUsage::Cookie(info.name), info),
                 };
                 if matches!(self.graph[info.ty], GraphType::Inline(_)) && bfs.discover(info.ty) {
                     by_node.insert(
@@ -1265,6 +1417,21 @@ impl<'graph, 'a> MetadataBuilder<'graph, 'a> {
                     },
                 );
             }
+            for info in op.response_headers {
+                if matches!(self.graph[info.ty], GraphType::Inline(_)) && bfs.discover(info.ty) {
+                    by_node.insert(
+                        info.ty,
+                        PartialPath {
+                            root: InlineTypePathRoot::Operation {
+                                id: op.id,
+                                resource: op.resource,
+                                usage: OperationUsage::ResponseHeader(info.name),
+                            },
+                            edges: vec![],
+                        },
+                    );
+                }
+            }
             while let Some(edge) = bfs.next() {
                 let parent = &by_node[&edge.source()];
                 let mut child = parent.clone();
@@ -1295,12 +1462,20 @@ impl<'graph, 'a> MetadataBuilder<'graph, 'a> {
             // Inheritance edges don't contribute to cycles;
             // a type can't inherit from itself.
             GraphEdge::Inherits { .. } => false,
+            // Rest elements are stored in a `Vec`, so cycles
+            // through them don't need `Box`.
+            GraphEdge::Element(ElementMeta::Rest) => false,
             GraphEdge::Contains => match self.graph[e.source()] {
                 GraphType::Schema(GraphSchemaType::Container(_, c))
                 | GraphType::Inline(GraphInlineType::Container(_, c)) => {
-                    // Array and map containers are heap-allocated,
+                    // Array, set, and map containers are heap-allocated,
                     // cycles through these edges don't need `Box`.
-                    !matches!(c, GraphContainer::Array { .. } | GraphContainer::Map { .. })
+                    !matches!(
+                        c,
+                        GraphContainer::Array { .. }
+                            | GraphContainer::Set { .. }
+                            | GraphContainer::Map { .. }
+                    )
                 }
                 _ => true,
             },
@@ -1357,7 +1532,7 @@ impl<'graph, 'a> MetadataBuilder<'graph, 'a> {
             for edge in self.graph.edges_directed(node, Direction::Incoming) {
                 let source = edge.source();
                 match edge.weight() {
-                    GraphEdge::Contains | GraphEdge::Variant(_) => {
+                    GraphEdge::Contains | GraphEdge::Variant(_) | GraphEdge::Element(_) => {
                         if !unhashable.put(source.index()) {
                             queue.push_back(source);
                         }
@@ -1388,12 +1563,16 @@ impl<'graph, 'a> MetadataBuilder<'graph, 'a> {
         let mut queue: VecDeque<_> = undefaultable.ones().map(NodeIndex::new).collect();
         while let Some(node) = queue.pop_front() {
             for edge in self.graph.edges_directed(node, Direction::Incoming) {
-                if !matches!(
-                    edge.weight(),
-                    GraphEdge::Field { meta, .. } if meta.required
-                ) {
+                let propagates = match edge.weight() {
                     // Optional fields become `AbsentOr<T>`,
                     // which is always `Default`.
+                    GraphEdge::Field { meta, .. } => meta.required,
+                    // Rest elements become `Vec<T>`, which is
+                    // also always `Default`.
+                    GraphEdge::Element(ElementMeta::Positional(_)) => true,
+                    _ => false,
+                };
+                if !propagates {
                     continue;
                 }
                 let source = edge.source();
@@ -1426,14 +1605,14 @@ fn invert(mut bits: FixedBitSet) -> FixedBitSet {
 
 /// Visits all the types and references contained within a [`SpecType`].
 #[derive(Debug)]
-struct SpecTypeVisitor<'a> {
+pub(super) struct SpecTypeVisitor<'a> {
     stack: Vec<(Option<(&'a SpecType<'a>, GraphEdge<'a>)>, &'a SpecType<'a>)>,
 }
 
 impl<'a> SpecTypeVisitor<'a> {
     /// Creates a visitor with `roots` on the stack of types to visit.
     #[inline]
-    fn new(roots: impl Iterator<Item = &'a SpecType<'a>>) -> Self {
+    pub(super) fn new(roots: impl Iterator<Item = &'a SpecType<'a>>) -> Self {
         let mut stack = roots.map(|root| (None, root)).collect_vec();
         stack.reverse();
         Self { stack }
@@ -1463,6 +1642,11 @@ impl<'a> Iterator for SpecTypeVisitor<'a> {
                                     required: field.required,
                                     description: field.description,
                                     flattened: field.flattened,
+                                    default: field.default,
+                                    deprecated: field.deprecated,
+                                    read_only: field.read_only,
+                                    write_only: field.write_only,
+                                    constraints: field.constraints,
                                 },
                             },
                             field.ty
@@ -1487,6 +1671,11 @@ impl<'a> Iterator for SpecTypeVisitor<'a> {
                                     required: field.required,
                                     description: field.description,
                                     flattened: field.flattened,
+                                    default: field.default,
+                                    deprecated: field.deprecated,
+                                    read_only: field.read_only,
+                                    write_only: field.write_only,
+                                    constraints: field.constraints,
                                 },
                             },
                             field.ty
@@ -1538,6 +1727,11 @@ impl<'a> Iterator for SpecTypeVisitor<'a> {
                                     required: field.required,
                                     description: field.description,
                                     flattened: field.flattened,
+                                    default: field.default,
+                                    deprecated: field.deprecated,
+                                    read_only: field.read_only,
+                                    write_only: field.write_only,
+                                    constraints: field.constraints,
                                 },
                             },
                             field.ty
@@ -1560,6 +1754,21 @@ impl<'a> Iterator for SpecTypeVisitor<'a> {
                     .rev(),
                 );
             }
+            SpecType::Schema(SpecSchemaType::Tuple(_, ty))
+            | SpecType::Inline(SpecInlineType::Tuple(_, ty)) => {
+                self.stack.extend(
+                    itertools::chain!(
+                        ty.elements.iter().enumerate().map(|(index, &ty)| {
+                            let ordinal = NonZeroUsize::new(index + 1).unwrap();
+                            (GraphEdge::Element(ElementMeta::Positional(ordinal)), ty)
+                        }),
+                        ty.rest
+                            .map(|ty| (GraphEdge::Element(ElementMeta::Rest), ty)),
+                    )
+                    .map(|(edge, ty)| (Some((top, edge)), ty))
+                    .rev(),
+                );
+            }
             SpecType::Schema(SpecSchemaType::Container(_, container))
             | SpecType::Inline(SpecInlineType::Container(_, container)) => {
                 self.stack
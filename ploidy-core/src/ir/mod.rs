@@ -22,20 +22,25 @@
 
 mod error;
 mod graph;
+mod problem;
 mod spec;
 mod transform;
 mod types;
+mod unsupported;
 pub mod views;
 
 #[cfg(test)]
 mod tests;
 
+pub use error::IrError;
 pub use graph::{CookedGraph, RawGraph};
-pub use spec::Spec;
+pub use problem::{Problem, ProblemKind};
+pub use spec::{Spec, SpecConfig};
 pub use types::*;
+pub use unsupported::{UnsupportedFeature, UnsupportedFeatureKind};
 
 pub use views::{
     ExtendableView, HasResource, HasTypeId, TypeId, View, any::*, container::*, enum_::*,
     inline::*, ir::*, operation::*, path::*, primitive::*, schema::*, struct_::*, tagged::*,
-    untagged::*,
+    tuple::*, untagged::*,
 };
@@ -0,0 +1,47 @@
+use std::fmt::{self, Display};
+
+use ploidy_pointer::JsonPointerBuf;
+
+/// A construct in the document that makes it invalid.
+///
+/// Unlike an [`UnsupportedFeature`][crate::ir::UnsupportedFeature],
+/// a problem can't be dropped without generating broken or incomplete code,
+/// so lowering fails with [`IrError::Invalid`][crate::ir::IrError::Invalid]
+/// if the document has any.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Problem {
+    /// A pointer to the construct in the document.
+    pub pointer: JsonPointerBuf,
+    /// What's wrong with it.
+    pub kind: ProblemKind,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`#{}`: {}", self.pointer, self.kind)
+    }
+}
+
+/// The kind of a [`Problem`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProblemKind {
+    /// A `$ref` to a component that doesn't exist, or isn't
+    /// the expected kind of component. The pointer is the
+    /// reference's target.
+    UnresolvedRef,
+    /// A discriminator `mapping` value that references
+    /// a schema that doesn't exist.
+    UnresolvedMapping,
+    /// An `operationId` that another operation already uses.
+    DuplicateOperationId,
+}
+
+impl Display for ProblemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnresolvedRef => "referenced component doesn't exist",
+            Self::UnresolvedMapping => "discriminator mapping references a missing schema",
+            Self::DuplicateOperationId => "duplicate `operationId`",
+        })
+    }
+}
@@ -1,43 +1,59 @@
+use std::time::Duration;
+
 use indexmap::IndexMap;
-use itertools::Itertools;
+use itertools::{Either, Itertools, intersperse};
+use ploidy_pointer::JsonPointerBuf;
 use rustc_hash::FxHashSet;
+use serde_json::Value as JsonValue;
 
 use crate::{
     arena::Arena,
+    codegen::{AsSnakeCase, NamePart, UniqueNames},
     ir::OperationId,
     parse::{
-        self, Document, Info, Method, Operation, Parameter, ParameterLocation,
-        ParameterStyle as ParsedParameterStyle, RefOrParameter, RefOrRequestBody, RefOrResponse,
-        RefOrSchema, RequestBody, Response,
+        self, Document, Example, Header, Info, MediaType, Method, Operation, Parameter,
+        ParameterLocation, ParameterStyle as ParsedParameterStyle, RefOrExample, RefOrHeader,
+        RefOrParameter, RefOrRequestBody, RefOrResponse, RefOrSchema, RequestBody, Response,
+        Server,
         path::{ParsedPath, PathFragment, PathSegment},
     },
 };
 
 use super::{
     error::IrError,
+    graph::SpecTypeVisitor,
+    problem::ProblemKind,
     transform::{TransformContext, TypeInfo, transform_with_context},
     types::{
-        InlineTypeIds, ParameterStyle as IrParameterStyle, SchemaTypeInfo, SpecInlineType,
-        SpecOperation, SpecParameter, SpecParameterInfo, SpecRequest, SpecResponse, SpecSchemaType,
-        SpecType,
+        ExampleValue, InlineTypeIds, ParameterStyle as IrParameterStyle, SchemaTypeInfo,
+        SpecInlineType, SpecOperation, SpecParameter, SpecParameterInfo, SpecRequest, SpecResponse,
+        SpecSchemaType, SpecType,
     },
+    unsupported::{UnsupportedFeature, UnsupportedFeatureKind},
 };
 
 /// The intermediate representation of an OpenAPI document.
 ///
 /// A [`Spec`] is a type tree lowered from a parsed document, with references
-/// still unresolved. Construct one with [`Spec::from_doc()`], then pass it to
-/// [`RawGraph::new()`] to build the type graph.
+/// still unresolved. Construct one with [`Spec::from_doc()`] or
+/// [`Spec::from_doc_strict()`], then pass it to [`RawGraph::new()`] to
+/// build the type graph.
 ///
 /// [`RawGraph::new()`]: crate::ir::RawGraph::new
 #[derive(Debug)]
 pub struct Spec<'a> {
     /// The document's `info` section: title, OpenAPI version, etc.
     pub info: &'a Info,
+    /// The URL of the document's first server, with its variables
+    /// replaced by their default values.
+    pub server: Option<&'a str>,
     /// All operations extracted from the document's `paths` section.
     pub operations: Vec<SpecOperation<'a>>,
     /// Named schemas from `components/schemas`, keyed by name.
     pub schemas: IndexMap<&'a str, SpecType<'a>>,
+    /// Constructs in the document that were dropped, or replaced
+    /// with a fallback, during lowering.
+    pub unsupported: Vec<UnsupportedFeature>,
     /// Allocates inline type IDs.
     pub(crate) ids: InlineTypeIds<'a>,
 }
@@ -47,10 +63,22 @@ impl<'a> Spec<'a> {
     ///
     /// Lowers each schema and operation to IR types, allocating all
     /// long-lived data in the `arena`. Returns an error if the document is
-    /// malformed.
+    /// malformed. Unsupported constructs are recorded in
+    /// [`Spec::unsupported`].
     pub fn from_doc(arena: &'a Arena, doc: &'a Document) -> Result<Self, IrError> {
+        Self::from_doc_with_config(arena, doc, SpecConfig::default())
+    }
+
+    /// Builds a [`Spec`] from a parsed OpenAPI [`Document`], like
+    /// [`Spec::from_doc()`], with the given lowering options.
+    pub fn from_doc_with_config(
+        arena: &'a Arena,
+        doc: &'a Document,
+        config: SpecConfig,
+    ) -> Result<Self, IrError> {
+        let strict = config.strict;
         let ids = InlineTypeIds::new(arena);
-        let context = TransformContext::new(arena, doc, ids);
+        let context = TransformContext::new(arena, doc, ids, config);
 
         let schemas = match &doc.components {
             Some(components) => components
@@ -62,7 +90,31 @@ impl<'a> Spec<'a> {
                         TypeInfo::Schema(SchemaTypeInfo {
                             name,
                             resource: schema.extension("x-resourceId"),
+                            deprecated: schema.deprecated,
+                            replaced_by: schema.extension("x-replaced-by"),
+                            // A schema's `example` comes before its `examples`.
+                            examples: arena.alloc_slice(
+                                schema
+                                    .example
+                                    .iter()
+                                    .chain(
+                                        schema
+                                            .examples
+                                            .as_ref()
+                                            .and_then(JsonValue::as_array)
+                                            .into_iter()
+                                            .flatten(),
+                                    )
+                                    .map(|value| ExampleValue {
+                                        summary: None,
+                                        // Serializing a `JsonValue` can't fail.
+                                        json: arena.alloc_str(
+                                            &serde_json::to_string_pretty(value).unwrap(),
+                                        ),
+                                    }),
+                            ),
                         }),
+                        JsonPointerBuf::from_segments(["components", "schemas", name]),
                         schema,
                     );
                     (name.as_str(), ty)
@@ -71,48 +123,181 @@ impl<'a> Spec<'a> {
             None => IndexMap::new(),
         };
 
-        let operations = doc
+        // Replace a server's `{variables}` in its URL with their default
+        // values. Undeclared variables are kept as-is.
+        let server_url = |server: &'a Server| -> &'a str {
+            if server.variables.is_empty() {
+                return &server.url;
+            }
+            // Substitute in one pass, so that defaults that look like
+            // `{variables}` aren't substituted again.
+            let mut url = String::with_capacity(server.url.len());
+            let mut rest = server.url.as_str();
+            while let Some((before, after)) = rest.split_once('{') {
+                url.push_str(before);
+                match after
+                    .split_once('}')
+                    .and_then(|(name, after)| Some((server.variables.get(name)?, after)))
+                {
+                    Some((variable, after)) => {
+                        url.push_str(&variable.default);
+                        rest = after;
+                    }
+                    None => {
+                        url.push('{');
+                        rest = after;
+                    }
+                }
+            }
+            url.push_str(rest);
+            arena.alloc_str(&url)
+        };
+
+        // Collect a media type's `example`, followed by its named `examples`.
+        // Named examples with an `externalValue` instead of a `value`
+        // are skipped.
+        let media_type_examples = |content: &'a MediaType| -> &'a [ExampleValue<'a>] {
+            let examples = content.examples.values().filter_map(|example_or_ref| {
+                let example = match example_or_ref {
+                    RefOrExample::Other(example) => example,
+                    RefOrExample::Ref(r) => {
+                        let pointer = r.ref_.pointer();
+                        let Ok(example) = pointer.follow::<&Example>(doc) else {
+                            context.problem(pointer.to_owned(), ProblemKind::UnresolvedRef);
+                            return None;
+                        };
+                        example
+                    }
+                };
+                Some(ExampleValue {
+                    summary: example.summary.as_deref(),
+                    // Serializing a `JsonValue` can't fail.
+                    json: arena
+                        .alloc_str(&serde_json::to_string_pretty(example.value.as_ref()?).unwrap()),
+                })
+            });
+            arena.alloc_slice(
+                content
+                    .example
+                    .iter()
+                    .map(|value| ExampleValue {
+                        summary: None,
+                        json: arena.alloc_str(&serde_json::to_string_pretty(value).unwrap()),
+                    })
+                    .chain(examples),
+            )
+        };
+
+        let mut seen_ids = FxHashSet::default();
+        for (path, item) in &doc.paths {
+            for (method, op) in item.operations() {
+                if let Some(id) = op.operation_id.as_deref()
+                    && !seen_ids.insert(id)
+                {
+                    context.problem(
+                        JsonPointerBuf::from_segments([
+                            "paths",
+                            path.as_str(),
+                            &method.as_str().to_ascii_lowercase(),
+                        ]),
+                        ProblemKind::DuplicateOperationId,
+                    );
+                }
+            }
+        }
+
+        // Reserve explicit operation IDs, so that synthesized IDs
+        // don't collide with them.
+        let mut operation_ids = UniqueNames::with_reserved(
+            arena,
+            doc.paths
+                .values()
+                .flat_map(|item| item.operations())
+                .filter_map(|(_, op)| op.operation_id.as_deref())
+                .map(|id| [NamePart::Text(id)]),
+        );
+
+        let operations: Vec<_> = doc
             .paths
             .iter()
             .map(|(path, item)| {
+                let pointer = JsonPointerBuf::from_segments(["paths", path.as_str()]);
                 let path = parse::path::parse(arena, path.as_str())?;
                 Ok(item.operations().map(move |(method, op)| PathOperation {
+                    pointer: pointer.clone(),
                     path,
                     method,
                     params: &item.parameters,
+                    servers: &item.servers,
                     op,
                 }))
             })
             .flatten_ok()
             .map_ok(|item| -> Result<_, IrError> {
-                let resource = item.op.extension("x-resource-name");
-                let id = item
+                // `x-resource-name` overrides the first tag.
+                let resource = item
                     .op
-                    .operation_id
-                    .as_deref()
-                    .ok_or(IrError::NoOperationId)?;
+                    .extension("x-resource-name")
+                    .or_else(|| item.op.tags.first().map(|tag| tag.as_str()));
+                let id = match item.op.operation_id.as_deref() {
+                    Some(id) => id,
+                    None => {
+                        // Synthesize an ID from the method, followed by
+                        // the literal text and parameter names in the path,
+                        // like `get_users_id` for `GET /users/{id}`.
+                        let words = std::iter::once(item.method.as_str()).chain(
+                            item.path.segments.iter().flat_map(|segment| match segment {
+                                PathSegment::Literal(text) => Either::Left(std::iter::once(*text)),
+                                PathSegment::Templated(fragments) => {
+                                    Either::Right(fragments.iter().map(|fragment| match fragment {
+                                        PathFragment::Literal(text) => *text,
+                                        PathFragment::Param(name) => *name,
+                                    }))
+                                }
+                            }),
+                        );
+                        let name = operation_ids.claim(intersperse(
+                            words.filter(|word| !word.is_empty()).map(NamePart::Text),
+                            NamePart::Boundary,
+                        ));
+                        &*arena.alloc_str(&AsSnakeCase(name).to_string())
+                    }
+                };
+
+                let op_pointer = item.pointer.join(item.method.as_str().to_ascii_lowercase());
 
                 let params = {
                     enum Source<'a> {
-                        Declared(&'a Parameter),
+                        Declared(&'a Parameter, JsonPointerBuf),
                         Synthesized(&'a str),
                     }
 
                     // Merge path item and operation parameters.
                     // Operation parameters override path item ones.
                     let mut declared = IndexMap::new();
-                    for param in item
+                    for (param, pointer) in item
                         .params
                         .iter()
-                        .chain(item.op.parameters.iter())
-                        .filter_map(|p| match p {
-                            RefOrParameter::Other(p) => Some(p),
+                        .enumerate()
+                        .map(|(index, p)| {
+                            (p, item.pointer.join("parameters").join(index.to_string()))
+                        })
+                        .chain(item.op.parameters.iter().enumerate().map(|(index, p)| {
+                            (p, op_pointer.join("parameters").join(index.to_string()))
+                        }))
+                        .filter_map(|(p, pointer)| match p {
+                            RefOrParameter::Other(p) => Some((p, pointer)),
                             RefOrParameter::Ref(r) => {
-                                r.ref_.pointer().follow::<&Parameter>(doc).ok()
+                                let pointer = r.ref_.pointer();
+                                let Ok(p) = pointer.follow::<&Parameter>(doc) else {
+                                    context.problem(pointer.to_owned(), ProblemKind::UnresolvedRef);
+                                    return None;
+                                };
+                                Some((p, pointer.to_owned()))
                             }
                         })
                     {
-                        declared.insert((param.name.as_str(), param.location), param);
+                        declared.insert((param.name.as_str(), param.location), (param, pointer));
                     }
 
                     // Walk the path template to produce path parameters in
@@ -135,7 +320,7 @@ impl<'a> Spec<'a> {
                             .filter(|&name| seen.insert(name))
                             .map(|name| {
                                 match declared.shift_remove(&(name, ParameterLocation::Path)) {
-                                    Some(param) => Source::Declared(param),
+                                    Some((param, pointer)) => Source::Declared(param, pointer),
                                     None => Source::Synthesized(name),
                                 }
                             })
@@ -143,42 +328,79 @@ impl<'a> Spec<'a> {
                     };
 
                     // Append remaining parameters in declaration order.
-                    sources.extend(declared.into_iter().filter_map(|((_, location), param)| {
-                        match location {
+                    sources.extend(declared.into_iter().filter_map(
+                        |((_, location), (param, pointer))| match location {
                             // Drop declared path parameters that are
                             // absent from the template.
                             ParameterLocation::Path => None,
-                            _ => Some(Source::Declared(param)),
-                        }
-                    }));
+                            _ => Some(Source::Declared(param, pointer)),
+                        },
+                    ));
 
                     // Lower all sources to spec parameters.
-                    let params = sources.into_iter().filter_map(|source| match source {
-                        Source::Declared(param) => {
+                    let params = sources.into_iter().map(|source| match source {
+                        Source::Declared(param, pointer) => {
                             let ty: &_ = match &param.schema {
                                 Some(RefOrSchema::Ref(r)) => arena.alloc(SpecType::Ref(r)),
-                                Some(RefOrSchema::Inline(schema)) => arena
-                                    .alloc(transform_with_context(&context, ids.next(), schema)),
+                                Some(RefOrSchema::Inline(schema)) => {
+                                    arena.alloc(transform_with_context(
+                                        &context,
+                                        ids.next(),
+                                        pointer.join("schema"),
+                                        schema,
+                                    ))
+                                }
                                 None => arena.alloc(SpecInlineType::Any(ids.next()).into()),
                             };
-                            let style = match (param.style, param.explode) {
-                                (Some(ParsedParameterStyle::DeepObject), Some(true) | None) => {
-                                    Some(IrParameterStyle::DeepObject)
-                                }
+                            let style = match (param.location, param.style, param.explode) {
+                                (ParameterLocation::Path, None, None) => None,
+                                (
+                                    ParameterLocation::Path,
+                                    Some(ParsedParameterStyle::Simple) | None,
+                                    explode,
+                                ) => Some(IrParameterStyle::Simple {
+                                    exploded: explode.unwrap_or(false),
+                                }),
+                                (
+                                    ParameterLocation::Path,
+                                    Some(ParsedParameterStyle::Label),
+                                    explode,
+                                ) => Some(IrParameterStyle::Label {
+                                    exploded: explode.unwrap_or(false),
+                                }),
+                                (
+                                    ParameterLocation::Path,
+                                    Some(ParsedParameterStyle::Matrix),
+                                    explode,
+                                ) => Some(IrParameterStyle::Matrix {
+                                    exploded: explode.unwrap_or(false),
+                                }),
                                 (
+                                    ParameterLocation::Query,
+                                    Some(ParsedParameterStyle::DeepObject),
+                                    Some(true) | None,
+                                ) => Some(IrParameterStyle::DeepObject),
+                                (
+                                    ParameterLocation::Query,
                                     Some(ParsedParameterStyle::SpaceDelimited),
                                     Some(false) | None,
                                 ) => Some(IrParameterStyle::SpaceDelimited),
-                                (Some(ParsedParameterStyle::PipeDelimited), Some(false) | None) => {
-                                    Some(IrParameterStyle::PipeDelimited)
-                                }
-                                (None, None) => None,
-                                (Some(ParsedParameterStyle::Form) | None, Some(true) | None) => {
-                                    Some(IrParameterStyle::Form { exploded: true })
-                                }
-                                (Some(ParsedParameterStyle::Form) | None, Some(false)) => {
-                                    Some(IrParameterStyle::Form { exploded: false })
-                                }
+                                (
+                                    ParameterLocation::Query,
+                                    Some(ParsedParameterStyle::PipeDelimited),
+                                    Some(false) | None,
+                                ) => Some(IrParameterStyle::PipeDelimited),
+                                (ParameterLocation::Query, None, None) => None,
+                                (
+                                    ParameterLocation::Query,
+                                    Some(ParsedParameterStyle::Form) | None,
+                                    Some(true) | None,
+                                ) => Some(IrParameterStyle::Form { exploded: true }),
+                                (
+                                    ParameterLocation::Query,
+                                    Some(ParsedParameterStyle::Form) | None,
+                                    Some(false),
+                                ) => Some(IrParameterStyle::Form { exploded: false }),
                                 _ => None,
                             };
                             let info = SpecParameterInfo {
@@ -188,121 +410,299 @@ impl<'a> Spec<'a> {
                                 description: param.description.as_deref(),
                                 style,
                             };
-                            Some(match param.location {
+                            match param.location {
                                 ParameterLocation::Path => SpecParameter::Path(info),
                                 ParameterLocation::Query => SpecParameter::Query(info),
-                                _ => return None,
-                            })
+                                ParameterLocation::Header => SpecParameter::Header(info),
+                                ParameterLocation::Cookie => SpecParameter::Cookie(info),
+                            }
                         }
                         Source::Synthesized(name) => {
                             let ty: &_ = arena.alloc(SpecInlineType::Any(ids.next()).into());
-                            Some(SpecParameter::Path(SpecParameterInfo {
+                            SpecParameter::Path(SpecParameterInfo {
                                 name,
                                 ty,
                                 required: true,
                                 description: None,
                                 style: None,
-                            }))
+                            })
                         }
                     });
 
                     arena.alloc_slice(params)
                 };
 
-                let request = item
-                    .op
-                    .request_body
-                    .as_ref()
-                    .and_then(|request_or_ref| {
-                        let request = match request_or_ref {
-                            RefOrRequestBody::Other(rb) => rb,
+                let request_body =
+                    item.op
+                        .request_body
+                        .as_ref()
+                        .and_then(|request_or_ref| match request_or_ref {
+                            RefOrRequestBody::Other(rb) => {
+                                Some((rb, op_pointer.join("requestBody")))
+                            }
                             RefOrRequestBody::Ref(r) => {
-                                r.ref_.pointer().follow::<&RequestBody>(doc).ok()?
+                                let pointer = r.ref_.pointer();
+                                let Ok(rb) = pointer.follow::<&RequestBody>(doc) else {
+                                    context.problem(pointer.to_owned(), ProblemKind::UnresolvedRef);
+                                    return None;
+                                };
+                                Some((rb, pointer.to_owned()))
                             }
-                        };
+                        });
 
-                        Some(if request.content.contains_key("multipart/form-data") {
+                let request = request_body
+                    .as_ref()
+                    .map(|(request, pointer)| {
+                        let pointer = pointer.join("content");
+                        if request.content.contains_key("multipart/form-data") {
                             RequestContent::Multipart
                         } else if let Some(content) = request.content.get("application/json")
                             && let Some(schema) = &content.schema
                         {
-                            RequestContent::Json(schema)
+                            RequestContent::Json(
+                                schema,
+                                pointer.join("application/json").join("schema"),
+                            )
                         } else if let Some(content) = request.content.get("*/*")
                             && let Some(schema) = &content.schema
                         {
-                            RequestContent::Json(schema)
+                            RequestContent::Json(schema, pointer.join("*/*").join("schema"))
+                        } else if request.content.keys().any(|media_type| {
+                            // Ignore parameters like `charset`.
+                            let (essence, _) =
+                                media_type.split_once(';').unwrap_or((media_type, ""));
+                            essence.trim().eq_ignore_ascii_case("text/plain")
+                        }) {
+                            RequestContent::Text
+                        } else if request.content.keys().any(|media_type| {
+                            let (essence, _) =
+                                media_type.split_once(';').unwrap_or((media_type, ""));
+                            essence
+                                .trim()
+                                .eq_ignore_ascii_case("application/octet-stream")
+                        }) {
+                            RequestContent::Bytes
                         } else {
                             RequestContent::Any
-                        })
+                        }
                     })
                     .map(|content| match content {
                         RequestContent::Multipart => SpecRequest::Multipart,
-                        RequestContent::Json(RefOrSchema::Ref(r)) => {
+                        RequestContent::Text => SpecRequest::Text,
+                        RequestContent::Bytes => SpecRequest::Bytes,
+                        RequestContent::Json(RefOrSchema::Ref(r), _) => {
                             SpecRequest::Json(arena.alloc(SpecType::Ref(r)))
                         }
-                        RequestContent::Json(RefOrSchema::Inline(schema)) => SpecRequest::Json(
-                            arena.alloc(transform_with_context(&context, ids.next(), schema)),
-                        ),
+                        RequestContent::Json(RefOrSchema::Inline(schema), pointer) => {
+                            SpecRequest::Json(arena.alloc(transform_with_context(
+                                &context,
+                                ids.next(),
+                                pointer,
+                                schema,
+                            )))
+                        }
                         RequestContent::Any => {
                             SpecRequest::Json(arena.alloc(SpecInlineType::Any(ids.next()).into()))
                         }
                     });
 
-                let response = {
-                    let mut statuses = item
-                        .op
-                        .responses
-                        .keys()
-                        .filter_map(|status| Some((status.as_str(), status.parse::<u16>().ok()?)))
-                        .collect_vec();
-                    statuses.sort_unstable_by_key(|&(_, code)| code);
-                    let key = statuses
-                        .iter()
-                        .find(|&(_, code)| matches!(code, 200..300))
-                        .map(|&(key, _)| key)
+                // Like `request`, prefer multipart bodies,
+                // which don't have examples worth showing.
+                let request_examples = request_body
+                    .as_ref()
+                    .filter(|(request, _)| !request.content.contains_key("multipart/form-data"))
+                    .and_then(|(request, _)| {
+                        request
+                            .content
+                            .get("application/json")
+                            .or_else(|| request.content.get("*/*"))
+                    })
+                    .map(media_type_examples)
+                    .unwrap_or_default();
+
+                let mut statuses = item
+                    .op
+                    .responses
+                    .keys()
+                    .filter_map(|status| Some((status.as_str(), status.parse::<u16>().ok()?)))
+                    .collect_vec();
+                statuses.sort_unstable_by_key(|&(_, code)| code);
+                let response_status = statuses
+                    .iter()
+                    .copied()
+                    .find(|&(_, code)| matches!(code, 200..300));
+
+                let response_object = {
+                    // Prefer the lowest explicit 2xx status, then
+                    // the `2XX` range, then the `default` response.
+                    let key = response_status
+                        .map(|(key, _)| key)
+                        .or_else(|| {
+                            item.op
+                                .responses
+                                .keys()
+                                .map(|status| status.as_str())
+                                .find(|status| status.eq_ignore_ascii_case("2XX"))
+                        })
                         .unwrap_or("default");
 
                     item.op
                         .responses
                         .get(key)
-                        .and_then(|response_or_ref| {
-                            let response = match response_or_ref {
-                                RefOrResponse::Other(r) => r,
-                                RefOrResponse::Ref(r) => {
-                                    r.ref_.pointer().follow::<&Response>(doc).ok()?
-                                }
-                            };
-                            response.content.as_ref()
+                        .and_then(|response_or_ref| match response_or_ref {
+                            RefOrResponse::Other(r) => {
+                                Some((r, op_pointer.join("responses").join(key)))
+                            }
+                            RefOrResponse::Ref(r) => {
+                                let pointer = r.ref_.pointer();
+                                let Ok(response) = pointer.follow::<&Response>(doc) else {
+                                    context.problem(pointer.to_owned(), ProblemKind::UnresolvedRef);
+                                    return None;
+                                };
+                                Some((response, pointer.to_owned()))
+                            }
                         })
-                        .map(|content| {
-                            if let Some(content) = content.get("application/json")
-                                && let Some(schema) = &content.schema
-                            {
-                                ResponseContent::Json(schema)
-                            } else if let Some(content) = content.get("*/*")
-                                && let Some(schema) = &content.schema
-                            {
-                                ResponseContent::Json(schema)
-                            } else {
-                                ResponseContent::Any
+                };
+
+                let response_content = response_object.as_ref().and_then(|(response, pointer)| {
+                    Some((response.content.as_ref()?, pointer.join("content")))
+                });
+
+                // The client asks for the preferred supported
+                // media type, and ignores the others.
+                let response_content_types: &[&str] = match &response_content {
+                    Some((content, pointer)) => {
+                        for name in content.keys() {
+                            if ResponseMediaType::parse(name).is_none() {
+                                context.unsupported(
+                                    pointer.join(name),
+                                    UnsupportedFeatureKind::ResponseMediaType,
+                                );
                             }
+                        }
+                        arena.alloc_slice_exact(content.keys().map(|name| name.as_str()))
+                    }
+                    None => &[],
+                };
+
+                let response_content = response_content.map(|(content, pointer)| {
+                    content
+                        .iter()
+                        .filter_map(|(name, content)| {
+                            let ty = ResponseMediaType::parse(name)?;
+                            let content = match ty {
+                                ResponseMediaType::Json
+                                | ResponseMediaType::JsonBased
+                                | ResponseMediaType::Any => ResponseContent::Json(
+                                    name,
+                                    content.schema.as_ref()?,
+                                    pointer.join(name).join("schema"),
+                                ),
+                                ResponseMediaType::Text => ResponseContent::Text(name),
+                                ResponseMediaType::Bytes => ResponseContent::Bytes(name),
+                            };
+                            Some((ty, content))
                         })
-                        .map(|content| match content {
-                            ResponseContent::Json(RefOrSchema::Ref(r)) => {
-                                SpecResponse::Json(arena.alloc(SpecType::Ref(r)))
+                        .min_by_key(|(ty, _)| *ty)
+                        .map(|(_, content)| content)
+                        .unwrap_or(ResponseContent::Any)
+                });
+
+                let response_content_type = match &response_content {
+                    Some(
+                        ResponseContent::Json(name, ..)
+                        | ResponseContent::Text(name)
+                        | ResponseContent::Bytes(name),
+                    ) => Some(*name),
+                    _ => None,
+                };
+
+                let response_examples = response_object
+                    .as_ref()
+                    .zip(response_content_type)
+                    .and_then(|((response, _), name)| response.content.as_ref()?.get(name))
+                    .map(media_type_examples)
+                    .unwrap_or_default();
+
+                let response = response_content.map(|content| match content {
+                    ResponseContent::Json(_, RefOrSchema::Ref(r), _) => {
+                        SpecResponse::Json(arena.alloc(SpecType::Ref(r)))
+                    }
+                    ResponseContent::Json(_, RefOrSchema::Inline(schema), pointer) => {
+                        SpecResponse::Json(arena.alloc(transform_with_context(
+                            &context,
+                            ids.next(),
+                            pointer,
+                            schema,
+                        )))
+                    }
+                    ResponseContent::Text(_) => SpecResponse::Text,
+                    ResponseContent::Bytes(_) => SpecResponse::Bytes,
+                    ResponseContent::Any => {
+                        SpecResponse::Json(arena.alloc(SpecInlineType::Any(ids.next()).into()))
+                    }
+                });
+
+                let mut response_headers = vec![];
+                if let Some((response, pointer)) = &response_object {
+                    for (name, header_or_ref) in &response.headers {
+                        // OpenAPI says that a `Content-Type` response header
+                        // is ignored.
+                        if name.eq_ignore_ascii_case("content-type") {
+                            continue;
+                        }
+                        let (header, pointer) = match header_or_ref {
+                            RefOrHeader::Other(header) => {
+                                (header, pointer.join("headers").join(name))
+                            }
+                            RefOrHeader::Ref(r) => {
+                                let pointer = r.ref_.pointer();
+                                let Ok(header) = pointer.follow::<&Header>(doc) else {
+                                    context.problem(pointer.to_owned(), ProblemKind::UnresolvedRef);
+                                    continue;
+                                };
+                                (header, pointer.to_owned())
                             }
-                            ResponseContent::Json(RefOrSchema::Inline(schema)) => {
-                                SpecResponse::Json(arena.alloc(transform_with_context(
+                        };
+                        let ty: &_ = match &header.schema {
+                            Some(RefOrSchema::Ref(r)) => arena.alloc(SpecType::Ref(r)),
+                            Some(RefOrSchema::Inline(schema)) => {
+                                arena.alloc(transform_with_context(
                                     &context,
                                     ids.next(),
+                                    pointer.join("schema"),
                                     schema,
-                                )))
+                                ))
                             }
-                            ResponseContent::Any => SpecResponse::Json(
-                                arena.alloc(SpecInlineType::Any(ids.next()).into()),
-                            ),
-                        })
-                };
+                            None => arena.alloc(SpecInlineType::Any(ids.next()).into()),
+                        };
+                        response_headers.push(SpecParameterInfo {
+                            name: name.as_str(),
+                            ty,
+                            required: header.required,
+                            description: header.description.as_deref(),
+                            style: None,
+                        });
+                    }
+                }
+
+                // Timeouts that aren't non-negative numbers are ignored.
+                let timeout = item
+                    .op
+                    .extensions
+                    .get("x-timeout-seconds")
+                    .and_then(|value| {
+                        let timeout = value
+                            .as_f64()
+                            .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+                        if timeout.is_none() {
+                            context.unsupported(
+                                op_pointer.join("x-timeout-seconds"),
+                                UnsupportedFeatureKind::InvalidTimeout,
+                            );
+                        }
+                        timeout
+                    });
 
                 Ok(SpecOperation {
                     resource,
@@ -310,22 +710,77 @@ impl<'a> Spec<'a> {
                     method: item.method,
                     path: item.path,
                     description: item.op.description.as_deref(),
+                    timeout,
+                    // Operation servers override path item ones.
+                    server: item
+                        .op
+                        .servers
+                        .first()
+                        .or_else(|| item.servers.first())
+                        .map(server_url),
+                    deprecated: item.op.deprecated,
                     params,
                     request,
+                    request_required: request_body.is_some_and(|(request, _)| request.required),
+                    request_examples,
                     response,
+                    response_status: response_status.map(|(_, code)| code),
+                    response_examples,
+                    response_content_type,
+                    response_content_types,
+                    response_headers: arena.alloc_slice_copy(&response_headers),
                 })
             })
             .flatten_ok()
             .collect::<Result<_, IrError>>()?;
 
+        // Check schema references once all schemas are lowered,
+        // since they can reference each other in any order.
+        let tys = SpecTypeVisitor::new(
+            schemas
+                .values()
+                .chain(operations.iter().flat_map(|op| op.types().copied())),
+        );
+        for (_, ty) in tys {
+            if let SpecType::Ref(r) = ty
+                && !schemas.contains_key(&*r.name())
+            {
+                context.problem(r.pointer().to_owned(), ProblemKind::UnresolvedRef);
+            }
+        }
+
+        let (unsupported, problems) = context.into_diagnostics();
+        if !problems.is_empty() {
+            return Err(IrError::Invalid(problems.into_iter().unique().collect()));
+        }
+        if strict && !unsupported.is_empty() {
+            return Err(IrError::Unsupported(unsupported));
+        }
+
         Ok(Spec {
             info: &doc.info,
+            server: doc.servers.first().map(server_url),
             operations,
             schemas,
+            unsupported,
             ids,
         })
     }
 
+    /// Builds a [`Spec`] from a parsed OpenAPI [`Document`], like
+    /// [`Spec::from_doc()`], but returns an error if the document uses
+    /// any unsupported constructs.
+    pub fn from_doc_strict(arena: &'a Arena, doc: &'a Document) -> Result<Self, IrError> {
+        Self::from_doc_with_config(
+            arena,
+            doc,
+            SpecConfig {
+                strict: true,
+                ..Default::default()
+            },
+        )
+    }
+
     /// Resolves a [`SpecType`], following type references through the spec.
     #[inline]
     pub(super) fn resolve(&'a self, mut ty: &'a SpecType<'a>) -> ResolvedSpecType<'a> {
@@ -339,6 +794,23 @@ impl<'a> Spec<'a> {
     }
 }
 
+/// Options for lowering a [`Document`] to a [`Spec`].
+#[derive(Clone, Debug, Default)]
+pub struct SpecConfig {
+    /// Whether an `integer` without a `format` lowers to the narrowest
+    /// of `u8`, `i8`, `u16`, and `i16` that holds every value between
+    /// its `minimum` and `maximum`, instead of `i32`.
+    pub narrow_integers: bool,
+    /// Formats that lower strings to [`PrimitiveType::Custom`], even
+    /// if Ploidy recognizes them. These formats aren't unsupported.
+    ///
+    /// [`PrimitiveType::Custom`]: crate::ir::PrimitiveType::Custom
+    pub custom_formats: FxHashSet<String>,
+    /// Whether lowering fails if the document uses any
+    /// unsupported constructs.
+    pub strict: bool,
+}
+
 /// A dereferenced type in the spec.
 ///
 /// The derived [`Eq`] and [`Hash`][std::hash::Hash] implementations
@@ -351,23 +823,60 @@ pub(super) enum ResolvedSpecType<'a> {
     Inline(&'a SpecInlineType<'a>),
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 enum RequestContent<'a> {
     Multipart,
-    Json(&'a RefOrSchema),
+    Json(&'a RefOrSchema, JsonPointerBuf),
+    Text,
+    Bytes,
     Any,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 enum ResponseContent<'a> {
-    Json(&'a RefOrSchema),
+    Json(&'a str, &'a RefOrSchema, JsonPointerBuf),
+    Text(&'a str),
+    Bytes(&'a str),
     Any,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A supported response media type, in order of preference.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum ResponseMediaType {
+    /// `application/json`.
+    Json,
+    /// A JSON-based media type, like `application/problem+json`.
+    JsonBased,
+    /// `*/*`, which is treated as JSON.
+    Any,
+    /// `text/plain`.
+    Text,
+    /// `application/octet-stream`.
+    Bytes,
+}
+
+impl ResponseMediaType {
+    /// Parses a media type, ignoring parameters like `charset`.
+    /// Returns `None` if the media type isn't supported.
+    fn parse(media_type: &str) -> Option<Self> {
+        let (essence, _) = media_type.split_once(';').unwrap_or((media_type, ""));
+        Some(match essence.trim().to_ascii_lowercase().as_str() {
+            "application/json" => Self::Json,
+            essence if essence.ends_with("+json") => Self::JsonBased,
+            "*/*" => Self::Any,
+            "text/plain" => Self::Text,
+            "application/octet-stream" => Self::Bytes,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
 struct PathOperation<'a> {
+    pointer: JsonPointerBuf,
     path: ParsedPath<'a>,
     method: Method,
     params: &'a [RefOrParameter],
+    servers: &'a [Server],
     op: &'a Operation,
 }
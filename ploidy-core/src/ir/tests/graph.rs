@@ -219,6 +219,53 @@ fn test_graph_array_edge() {
     assert_matches!(&*schema_names, ["Item", "Items"]);
 }
 
+#[test]
+fn test_graph_set_edge() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Item:
+              type: object
+              properties:
+                value:
+                  type: string
+            Items:
+              type: object
+              properties:
+                set:
+                  type: array
+                  items:
+                    $ref: '#/components/schemas/Item'
+                  uniqueItems: true
+                inline:
+                  type: array
+                  items:
+                    type: object
+                    properties:
+                      value:
+                        type: string
+                  uniqueItems: true
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    // Sets should depend on and inline their items, like arrays.
+    let item = graph.schema("Item").unwrap();
+    let items = graph.schema("Items").unwrap();
+    assert!(items.depends_on(&item));
+    let has_inline_struct = items
+        .inlines()
+        .any(|i| matches!(i, InlineTypeView::Struct(..)));
+    assert!(has_inline_struct);
+}
+
 #[test]
 fn test_graph_map_edge() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -1655,6 +1702,60 @@ fn test_operation_with_no_types() {
     }));
 }
 
+// MARK: Specs without components
+
+#[test]
+fn test_paths_only_spec_has_only_inline_types() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        paths:
+          /pets:
+            post:
+              operationId: createPet
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      type: object
+                      properties:
+                        name:
+                          type: string
+              responses:
+                '200':
+                  description: OK
+                  content:
+                    application/json:
+                      schema:
+                        type: array
+                        items:
+                          type: object
+                          properties:
+                            id:
+                              type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let mut raw = RawGraph::new(&arena, &spec);
+    raw.collapse_trivial_inlines();
+    raw.inline_tagged_variants();
+    raw.inline_untagged_variants();
+    let graph = raw.cook();
+
+    assert_eq!(graph.schemas().count(), 0);
+
+    let op = graph.operations().next().unwrap();
+    let structs = op
+        .inlines()
+        .filter(|inline| matches!(inline, InlineTypeView::Struct(..)))
+        .count();
+    assert_eq!(structs, 2);
+}
+
 // MARK: Inheritance
 
 #[test]
@@ -1792,6 +1893,54 @@ fn test_all_of_inheritance_with_fields() {
     assert!(!child_field.inherited());
 }
 
+#[test]
+fn test_all_of_inheritance_with_additional_properties() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Parent:
+              type: object
+              properties:
+                parent_field:
+                  type: string
+              additionalProperties:
+                type: string
+            Child:
+              allOf:
+                - $ref: '#/components/schemas/Parent'
+              properties:
+                child_field:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let child = graph.schema("Child").unwrap();
+    let child_struct = match child {
+        SchemaTypeView::Struct(_, view) => view,
+        other => panic!("expected struct `Child`; got {other:?}"),
+    };
+
+    // The inherited `additionalProperties` map should come after
+    // the child's own fields.
+    let all_field_names = child_struct.fields().map(|f| f.name()).collect_vec();
+    assert_matches!(
+        &*all_field_names,
+        [
+            StructFieldName::Name("parent_field"),
+            StructFieldName::Name("child_field"),
+            StructFieldName::AdditionalProperties,
+        ]
+    );
+}
+
 #[test]
 fn test_circular_refs_excludes_inherits_edges() {
     // This test constructs a graph like:
@@ -1,14 +1,18 @@
 //! Tests for [`Spec`].
 
+use std::time::Duration;
+
 use itertools::Itertools;
 
 use crate::{
     arena::Arena,
     ir::{
-        spec::Spec,
+        IrError, ProblemKind, UnsupportedFeature, UnsupportedFeatureKind,
+        spec::{Spec, SpecConfig},
         types::{
-            ParameterStyle, PrimitiveType, SpecInlineType, SpecOperation, SpecParameter,
-            SpecParameterInfo, SpecRequest, SpecResponse, SpecType,
+            ExampleValue, ParameterStyle, PrimitiveType, SchemaTypeInfo, SpecInlineType,
+            SpecOperation, SpecParameter, SpecParameterInfo, SpecRequest, SpecResponse,
+            SpecSchemaType, SpecType,
         },
     },
     parse::{Document, Method, path::ParsedPath},
@@ -161,6 +165,49 @@ fn test_parses_path_with_parameter_segments() {
     );
 }
 
+#[test]
+fn test_deprecated_operation() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              deprecated: true
+              responses:
+                '200':
+                  description: Success
+            post:
+              operationId: createUser
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                id: "listUsers",
+                deprecated: true,
+                ..
+            },
+            SpecOperation {
+                id: "createUser",
+                deprecated: false,
+                ..
+            },
+        ],
+    );
+}
+
 // MARK: Path parameters
 
 #[test]
@@ -639,6 +686,60 @@ fn test_parses_query_parameter_with_description() {
     );
 }
 
+// MARK: Header and cookie parameters
+
+#[test]
+fn test_parses_header_and_cookie_parameters() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              parameters:
+                - name: X-Request-Id
+                  in: header
+                  required: true
+                  schema:
+                    type: string
+                - name: session
+                  in: cookie
+                  description: The session ID.
+                  schema:
+                    type: string
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            params: [
+                SpecParameter::Header(SpecParameterInfo {
+                    name: "X-Request-Id",
+                    required: true,
+                    ..
+                }),
+                SpecParameter::Cookie(SpecParameterInfo {
+                    name: "session",
+                    required: false,
+                    description: Some("The session ID."),
+                    ..
+                }),
+            ],
+            ..
+        }],
+    );
+}
+
 // MARK: Request bodies
 
 #[test]
@@ -757,6 +858,42 @@ fn test_parses_request_body_multipart() {
     );
 }
 
+#[test]
+fn test_parses_request_body_text() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /notes:
+            put:
+              operationId: putNote
+              requestBody:
+                required: true
+                content:
+                  text/plain; charset=utf-8:
+                    schema:
+                      type: string
+              responses:
+                '204':
+                  description: Saved
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            request: Some(SpecRequest::Text),
+            request_required: true,
+            ..
+        }],
+    );
+}
+
 #[test]
 fn test_parses_request_body_wildcard_content_type() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -814,6 +951,64 @@ fn test_operation_without_request_body() {
     assert_matches!(&*ir.operations, [SpecOperation { request: None, .. }]);
 }
 
+#[test]
+fn test_parses_request_body_required() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            post:
+              operationId: createUser
+              requestBody:
+                $ref: '#/components/requestBodies/User'
+              responses:
+                '201':
+                  description: Created
+            put:
+              operationId: updateUser
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      type: object
+              responses:
+                '200':
+                  description: Success
+        components:
+          requestBodies:
+            User:
+              required: true
+              content:
+                application/json:
+                  schema:
+                    type: object
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // `required` is resolved through a `$ref`, and defaults to `false`.
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                request: Some(SpecRequest::Json(_)),
+                request_required: true,
+                ..
+            },
+            SpecOperation {
+                request: Some(SpecRequest::Json(_)),
+                request_required: false,
+                ..
+            },
+        ],
+    );
+}
+
 // MARK: Response parsing
 
 #[test]
@@ -971,33 +1166,1306 @@ fn test_falls_back_to_default_response_when_no_2xx_status() {
     let arena = Arena::new();
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
-    assert_matches!(
-        &*ir.operations,
-        [SpecOperation {
-            response: Some(_),
-            ..
-        }],
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            response: Some(_),
+            ..
+        }],
+    );
+}
+
+#[test]
+fn test_prioritizes_2xx_range_over_default_response() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                'default':
+                  description: Error
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/Error'
+                '2XX':
+                  description: Success
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/User'
+        components:
+          schemas:
+            User:
+              type: object
+              properties:
+                name:
+                  type: string
+            Error:
+              type: object
+              properties:
+                message:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // The response should be from the `2XX` range, not the default.
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            response: Some(SpecResponse::Json(SpecType::Ref(component_ref))),
+            ..
+        }] if component_ref.name() == "User",
+    );
+}
+
+#[test]
+fn test_prioritizes_2xx_status_over_2xx_range() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            post:
+              operationId: createUser
+              responses:
+                '2XX':
+                  description: Accepted
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/Job'
+                '201':
+                  description: Created
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/User'
+        components:
+          schemas:
+            User:
+              type: object
+              properties:
+                name:
+                  type: string
+            Job:
+              type: object
+              properties:
+                id:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // An explicit status is more specific than a range.
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            response: Some(SpecResponse::Json(SpecType::Ref(component_ref))),
+            ..
+        }] if component_ref.name() == "User",
+    );
+}
+
+#[test]
+fn test_parses_response_with_wildcard_content_type() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /data:
+            get:
+              operationId: getData
+              responses:
+                '200':
+                  description: Success
+                  content:
+                    '*/*':
+                      schema:
+                        type: object
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            response: Some(SpecResponse::Json(_)),
+            ..
+        }],
+    );
+}
+
+#[test]
+fn test_selects_first_2xx_status_when_multiple_exist() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success 200
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/UserList'
+                '202':
+                  description: Success 202
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/AcceptedResponse'
+        components:
+          schemas:
+            UserList:
+              type: object
+              properties:
+                users:
+                  type: array
+                  items:
+                    type: string
+            AcceptedResponse:
+              type: object
+              properties:
+                accepted:
+                  type: boolean
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // The response should be from the first 2xx status (200), not 202.
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            response: Some(SpecResponse::Json(SpecType::Ref(component_ref))),
+            ..
+        }] if component_ref.name() == "UserList",
+    );
+}
+
+#[test]
+fn test_operation_without_response() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            delete:
+              operationId: deleteUser
+              responses: {}
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(&*ir.operations, [SpecOperation { response: None, .. }]);
+}
+
+#[test]
+fn test_parses_response_headers() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success
+                  headers:
+                    X-Next-Cursor:
+                      description: The cursor for the next page.
+                      schema:
+                        type: string
+                    X-RateLimit-Remaining:
+                      $ref: '#/components/headers/RateLimitRemaining'
+                    Content-Type:
+                      schema:
+                        type: string
+                '404':
+                  description: Not found
+                  headers:
+                    X-Request-Id:
+                      schema:
+                        type: string
+        components:
+          headers:
+            RateLimitRemaining:
+              required: true
+              schema:
+                type: integer
+                format: int32
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // Headers come from the selected response, `$ref`s are resolved,
+    // and `Content-Type` is ignored.
+    let [op] = &*ir.operations else {
+        panic!("expected one operation; got {:?}", ir.operations);
+    };
+    assert_matches!(
+        op.response_headers,
+        [
+            SpecParameterInfo {
+                name: "X-Next-Cursor",
+                ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
+                required: false,
+                description: Some("The cursor for the next page."),
+                ..
+            },
+            SpecParameterInfo {
+                name: "X-RateLimit-Remaining",
+                ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::I32)),
+                required: true,
+                description: None,
+                ..
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_prefers_json_response_over_other_content_types() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /reports:
+            get:
+              operationId: getReport
+              responses:
+                '200':
+                  description: Success
+                  content:
+                    text/csv:
+                      schema:
+                        type: string
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/Report'
+        components:
+          schemas:
+            Report:
+              type: object
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    let [op] = &*ir.operations else {
+        panic!("expected one operation; got {:?}", ir.operations);
+    };
+    assert_matches!(op.response, Some(SpecResponse::Json(SpecType::Ref(_))));
+    assert_eq!(op.response_content_type, Some("application/json"));
+    assert_eq!(op.response_content_types, ["text/csv", "application/json"]);
+
+    // The CSV body is ignored.
+    let pointers = ir
+        .unsupported
+        .iter()
+        .map(|feature| (feature.pointer.to_string(), feature.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [(
+            "/paths/~1reports/get/responses/200/content/text~1csv".to_owned(),
+            UnsupportedFeatureKind::ResponseMediaType
+        )]
+    );
+}
+
+#[test]
+fn test_parses_response_bytes() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /files/{id}:
+            get:
+              operationId: downloadFile
+              parameters:
+                - name: id
+                  in: path
+                  required: true
+                  schema:
+                    type: string
+              responses:
+                '200':
+                  description: Success
+                  content:
+                    application/octet-stream:
+                      schema:
+                        type: string
+                        format: binary
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    let [op] = &*ir.operations else {
+        panic!("expected one operation; got {:?}", ir.operations);
+    };
+    assert_matches!(op.response, Some(SpecResponse::Bytes));
+    assert_eq!(op.response_content_type, Some("application/octet-stream"));
+    assert!(ir.unsupported.is_empty());
+}
+
+#[test]
+fn test_parses_response_with_json_based_content_type() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success
+                  content:
+                    '*/*':
+                      schema:
+                        type: string
+                    application/vnd.api+json; charset=utf-8:
+                      schema:
+                        type: object
+                        properties:
+                          name:
+                            type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // `+json` media types are preferred over `*/*`.
+    let [op] = &*ir.operations else {
+        panic!("expected one operation; got {:?}", ir.operations);
+    };
+    assert_matches!(
+        op.response,
+        Some(SpecResponse::Json(SpecType::Inline(
+            SpecInlineType::Struct(..)
+        )))
+    );
+    assert_eq!(
+        op.response_content_type,
+        Some("application/vnd.api+json; charset=utf-8")
+    );
+    assert!(ir.unsupported.is_empty());
+}
+
+// MARK: `x-resource-name` extension
+
+#[test]
+fn test_parses_custom_resource_name_from_extension() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              x-resource-name: user_management
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            resource: Some("user_management"),
+            ..
+        }],
+    );
+}
+
+#[test]
+fn test_different_operations_can_have_different_resources() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              x-resource-name: user_management
+              responses:
+                '200':
+                  description: Success
+          /posts:
+            get:
+              operationId: listPosts
+              x-resource-name: content
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                resource: Some("user_management"),
+                ..
+            },
+            SpecOperation {
+                resource: Some("content"),
+                ..
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_falls_back_to_first_tag_for_resource_name() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              tags: [Users, Admin]
+              responses:
+                '200':
+                  description: Success
+          /posts:
+            get:
+              operationId: listPosts
+              tags: [Posts]
+              x-resource-name: content
+              responses:
+                '200':
+                  description: Success
+          /health:
+            get:
+              operationId: getHealth
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // `x-resource-name` overrides tags, and operations with neither
+    // don't have a resource.
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                resource: Some("Users"),
+                ..
+            },
+            SpecOperation {
+                resource: Some("content"),
+                ..
+            },
+            SpecOperation { resource: None, .. },
+        ],
+    );
+}
+
+// MARK: `x-timeout-seconds` extension
+
+#[test]
+fn test_parses_timeout_from_extension() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /reports:
+            post:
+              operationId: createReport
+              x-timeout-seconds: 120
+              responses:
+                '200':
+                  description: Success
+          /exports:
+            post:
+              operationId: createExport
+              x-timeout-seconds: 2.5
+              responses:
+                '200':
+                  description: Success
+          /imports:
+            post:
+              operationId: createImport
+              x-timeout-seconds: -1
+              responses:
+                '200':
+                  description: Success
+          /uploads:
+            post:
+              operationId: createUpload
+              x-timeout-seconds: soon
+              responses:
+                '200':
+                  description: Success
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // Negative and non-numeric timeouts are ignored.
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                timeout: Some(report),
+                ..
+            },
+            SpecOperation {
+                timeout: Some(export),
+                ..
+            },
+            SpecOperation { timeout: None, .. },
+            SpecOperation { timeout: None, .. },
+            SpecOperation { timeout: None, .. },
+        ] if *report == Duration::from_secs(120) && *export == Duration::from_millis(2500),
+    );
+
+    // ...With a warning for each.
+    assert_matches!(
+        &*ir.unsupported,
+        [
+            UnsupportedFeature {
+                kind: UnsupportedFeatureKind::InvalidTimeout,
+                ..
+            },
+            UnsupportedFeature {
+                kind: UnsupportedFeatureKind::InvalidTimeout,
+                ..
+            },
+        ],
+    );
+    let pointers = ir
+        .unsupported
+        .iter()
+        .map(|unsupported| unsupported.pointer.to_string())
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [
+            "/paths/~1imports/post/x-timeout-seconds",
+            "/paths/~1uploads/post/x-timeout-seconds",
+        ]
+    );
+}
+
+// MARK: Servers
+
+#[test]
+fn test_operation_server_overrides_path_item_server() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /uploads:
+            servers:
+              - url: https://uploads.example.com
+            get:
+              operationId: listUploads
+              responses:
+                '200':
+                  description: Success
+            post:
+              operationId: createUpload
+              servers:
+                - url: https://bulk.example.com/v2
+              responses:
+                '200':
+                  description: Success
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                server: Some("https://uploads.example.com"),
+                ..
+            },
+            SpecOperation {
+                server: Some("https://bulk.example.com/v2"),
+                ..
+            },
+            SpecOperation { server: None, .. },
+        ],
+    );
+}
+
+#[test]
+fn test_substitutes_server_variable_defaults() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        servers:
+          - url: https://{region}.example.com/{version}
+            variables:
+              region:
+                default: us-east
+              version:
+                default: v1
+          - url: https://eu.example.com/v1
+        paths:
+          /downloads:
+            servers:
+              - url: https://{host}/{version}/{unknown}
+                variables:
+                  host:
+                    default: '{version}.example.com'
+                  version:
+                    default: v3
+            get:
+              operationId: listDownloads
+              responses:
+                '200':
+                  description: Success
+          /uploads:
+            post:
+              operationId: createUpload
+              servers:
+                - url: https://{host}/v2
+                  variables:
+                    host:
+                      default: uploads.example.com
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_eq!(ir.server, Some("https://us-east.example.com/v1"));
+    assert_matches!(
+        &*ir.operations,
+        [
+            SpecOperation {
+                server: Some("https://{version}.example.com/v3/{unknown}"),
+                ..
+            },
+            SpecOperation {
+                server: Some("https://uploads.example.com/v2"),
+                ..
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_spec_without_servers_has_no_server() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_eq!(ir.server, None);
+}
+
+// MARK: `x-resourceId` extension
+
+#[test]
+fn test_schema_stores_x_resource_id() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+        components:
+          schemas:
+            User:
+              type: object
+              x-resourceId: users
+              properties:
+                name:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let schema = spec.schemas.get("User").unwrap();
+
+    assert_matches!(
+        schema,
+        SpecType::Schema(schema_ty) if schema_ty.resource() == Some("users"),
+    );
+}
+
+#[test]
+fn test_schema_without_x_resource_id_has_none() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+        components:
+          schemas:
+            User:
+              type: object
+              properties:
+                name:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let schema = spec.schemas.get("User").unwrap();
+
+    assert_matches!(
+        schema,
+        SpecType::Schema(schema_ty) if schema_ty.resource().is_none(),
+    );
+}
+
+// MARK: Examples
+
+#[test]
+fn test_schema_stores_example_and_examples() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.1.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+        components:
+          schemas:
+            Id:
+              type: string
+              example: abc
+              examples:
+                - def
+                - 123
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let schema = spec.schemas.get("Id").unwrap();
+
+    assert_matches!(
+        schema,
+        SpecType::Schema(SpecSchemaType::Primitive(
+            SchemaTypeInfo {
+                examples: [
+                    ExampleValue {
+                        summary: None,
+                        json: r#""abc""#,
+                    },
+                    ExampleValue {
+                        summary: None,
+                        json: r#""def""#,
+                    },
+                    ExampleValue {
+                        summary: None,
+                        json: "123",
+                    },
+                ],
+                ..
+            },
+            PrimitiveType::String,
+        )),
+    );
+}
+
+#[test]
+fn test_operation_stores_request_and_response_examples() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            post:
+              operationId: createUser
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      type: string
+                    examples:
+                      alice:
+                        summary: A user named Alice
+                        value: alice
+                      bob:
+                        $ref: '#/components/examples/Bob'
+                      external:
+                        externalValue: https://example.com/user.json
+              responses:
+                '201':
+                  description: Created
+                  content:
+                    application/json:
+                      schema:
+                        type: integer
+                      example: 1
+        components:
+          examples:
+            Bob:
+              value: bob
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // Named examples with only an `externalValue` are skipped.
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            request_examples: [
+                ExampleValue {
+                    summary: Some("A user named Alice"),
+                    json: r#""alice""#,
+                },
+                ExampleValue {
+                    summary: None,
+                    json: r#""bob""#,
+                },
+            ],
+            response_examples: [ExampleValue {
+                summary: None,
+                json: "1",
+            }],
+            ..
+        }],
+    );
+}
+
+#[test]
+fn test_operation_with_unresolved_example_ref_is_problem() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            post:
+              operationId: createUser
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      type: string
+                    examples:
+                      missing:
+                        $ref: '#/components/examples/Missing'
+              responses:
+                '204':
+                  description: Created
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = Spec::from_doc(&arena, &doc);
+
+    let Err(IrError::Invalid(problems)) = result else {
+        panic!("expected `IrError::Invalid`; got `{result:?}`");
+    };
+    let pointers = problems
+        .iter()
+        .map(|problem| (problem.pointer.to_string(), problem.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [(
+            "/components/examples/Missing".to_owned(),
+            ProblemKind::UnresolvedRef
+        )],
+    );
+}
+
+// MARK: Synthesized operation IDs
+
+#[test]
+fn test_synthesizes_ids_for_operations_without_ids() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: Success
+          /users/{id}:
+            get:
+              responses:
+                '200':
+                  description: Success
+            delete:
+              responses:
+                '204':
+                  description: Deleted
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    let ids = ir.operations.iter().map(|op| op.id).collect_vec();
+    assert_eq!(ids, ["listUsers", "get_users_id", "delete_users_id"]);
+}
+
+#[test]
+fn test_synthesized_ids_do_not_collide_with_explicit_ids() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users/{id}:
+            get:
+              responses:
+                '200':
+                  description: Success
+          /users-{id}:
+            get:
+              responses:
+                '200':
+                  description: Success
+          /accounts:
+            get:
+              operationId: getUsersId
+              responses:
+                '200':
+                  description: Success
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    // `getUsersId` is claimed first, even though it comes last.
+    let ids = ir.operations.iter().map(|op| op.id).collect_vec();
+    assert_eq!(ids, ["get_users_id_2", "get_users_id_3", "getUsersId"]);
+}
+
+// MARK: Problems
+
+#[test]
+fn test_rejects_unresolved_refs() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              parameters:
+                - $ref: '#/components/parameters/Missing'
+              responses:
+                '200':
+                  $ref: '#/components/responses/Missing'
+            post:
+              operationId: createUser
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      $ref: '#/components/schemas/Missing'
+              responses:
+                '204':
+                  description: Created
+        components:
+          schemas:
+            User:
+              type: object
+              properties:
+                team:
+                  $ref: '#/components/schemas/Missing'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = Spec::from_doc(&arena, &doc);
+
+    let Err(IrError::Invalid(problems)) = result else {
+        panic!("expected `IrError::Invalid`; got `{result:?}`");
+    };
+    // Each missing component is reported once.
+    let pointers = problems
+        .iter()
+        .map(|problem| (problem.pointer.to_string(), problem.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [
+            (
+                "/components/parameters/Missing".to_owned(),
+                ProblemKind::UnresolvedRef
+            ),
+            (
+                "/components/responses/Missing".to_owned(),
+                ProblemKind::UnresolvedRef
+            ),
+            (
+                "/components/schemas/Missing".to_owned(),
+                ProblemKind::UnresolvedRef
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_rejects_unresolved_discriminator_mapping() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+        components:
+          schemas:
+            Pet:
+              oneOf:
+                - $ref: '#/components/schemas/Dog'
+              discriminator:
+                propertyName: kind
+                mapping:
+                  dog: '#/components/schemas/Dog'
+                  cat: '#/components/schemas/Cat'
+            Dog:
+              type: object
+              properties:
+                bark:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = Spec::from_doc(&arena, &doc);
+
+    let Err(IrError::Invalid(problems)) = result else {
+        panic!("expected `IrError::Invalid`; got `{result:?}`");
+    };
+    let pointers = problems
+        .iter()
+        .map(|problem| (problem.pointer.to_string(), problem.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [(
+            "/components/schemas/Pet/discriminator/mapping/cat".to_owned(),
+            ProblemKind::UnresolvedMapping
+        )]
+    );
+}
+
+#[test]
+fn test_rejects_duplicate_operation_ids() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: getUsers
+              responses:
+                '204':
+                  description: OK
+          /people:
+            get:
+              operationId: getUsers
+              responses:
+                '204':
+                  description: OK
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = Spec::from_doc(&arena, &doc);
+
+    let Err(IrError::Invalid(problems)) = result else {
+        panic!("expected `IrError::Invalid`; got `{result:?}`");
+    };
+    let pointers = problems
+        .iter()
+        .map(|problem| (problem.pointer.to_string(), problem.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [(
+            "/paths/~1people/get".to_owned(),
+            ProblemKind::DuplicateOperationId
+        )]
+    );
+}
+
+// MARK: Unsupported features
+
+#[test]
+fn test_records_unsupported_features() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths:
+          /users/{id}:
+            get:
+              operationId: getUser
+              parameters:
+                - name: id
+                  in: path
+                  required: true
+                  schema:
+                    type: string
+                    format: ulid
+              responses:
+                '200':
+                  description: Success
+        components:
+          schemas:
+            NotString:
+              not:
+                type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    let pointers = ir
+        .unsupported
+        .iter()
+        .map(|feature| (feature.pointer.to_string(), feature.kind))
+        .collect_vec();
+    assert_eq!(
+        pointers,
+        [
+            (
+                "/components/schemas/NotString/not".to_owned(),
+                UnsupportedFeatureKind::Not
+            ),
+            (
+                "/paths/~1users~1{id}/get/parameters/0/schema/format".to_owned(),
+                UnsupportedFeatureKind::UnknownFormat
+            ),
+        ]
     );
 }
 
 #[test]
-fn test_parses_response_with_wildcard_content_type() {
+fn test_records_inline_tagged_variant() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /data:
-            get:
-              operationId: getData
-              responses:
-                '200':
-                  description: Success
-                  content:
-                    '*/*':
-                      schema:
-                        type: object
+        paths: {}
+        components:
+          schemas:
+            Pet:
+              oneOf:
+                - type: object
+                  properties:
+                    kind:
+                      type: string
+              discriminator:
+                propertyName: kind
     "})
     .unwrap();
 
@@ -1005,106 +2473,105 @@ fn test_parses_response_with_wildcard_content_type() {
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
     assert_matches!(
-        &*ir.operations,
-        [SpecOperation {
-            response: Some(SpecResponse::Json(_)),
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::InlineTaggedVariant,
             ..
         }],
     );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Pet/discriminator"
+    );
 }
 
 #[test]
-fn test_selects_first_2xx_status_when_multiple_exist() {
+fn test_records_composite_default() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /users:
-            get:
-              operationId: listUsers
-              responses:
-                '200':
-                  description: Success 200
-                  content:
-                    application/json:
-                      schema:
-                        $ref: '#/components/schemas/UserList'
-                '202':
-                  description: Success 202
-                  content:
-                    application/json:
-                      schema:
-                        $ref: '#/components/schemas/AcceptedResponse'
+        paths: {}
         components:
           schemas:
-            UserList:
+            Pet:
               type: object
               properties:
-                users:
+                tags:
                   type: array
                   items:
                     type: string
-            AcceptedResponse:
-              type: object
-              properties:
-                accepted:
-                  type: boolean
+                  default: [a, b]
     "})
     .unwrap();
 
     let arena = Arena::new();
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
-    // The response should be from the first 2xx status (200), not 202.
     assert_matches!(
-        &*ir.operations,
-        [SpecOperation {
-            response: Some(SpecResponse::Json(SpecType::Ref(component_ref))),
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::CompositeDefault,
             ..
-        }] if component_ref.name() == "UserList",
+        }],
+    );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Pet/properties/tags/default"
     );
 }
 
 #[test]
-fn test_operation_without_response() {
+fn test_records_unknown_enum_default() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /users:
-            delete:
-              operationId: deleteUser
-              responses: {}
+        paths: {}
+        components:
+          schemas:
+            Status:
+              type: string
+              enum: [active, inactive]
+              default: archived
     "})
     .unwrap();
 
     let arena = Arena::new();
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
-    assert_matches!(&*ir.operations, [SpecOperation { response: None, .. }]);
+    assert_matches!(
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::UnknownEnumDefault,
+            ..
+        }],
+    );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Status/default"
+    );
 }
 
-// MARK: `x-resource-name` extension
-
 #[test]
-fn test_parses_custom_resource_name_from_extension() {
+fn test_records_pattern_properties_with_additional_properties() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /users:
-            get:
-              operationId: listUsers
-              x-resource-name: user_management
-              responses:
-                '200':
-                  description: Success
+        paths: {}
+        components:
+          schemas:
+            Labels:
+              type: object
+              patternProperties:
+                '^x-':
+                  type: string
+              additionalProperties:
+                type: integer
     "})
     .unwrap();
 
@@ -1112,36 +2579,33 @@ fn test_parses_custom_resource_name_from_extension() {
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
     assert_matches!(
-        &*ir.operations,
-        [SpecOperation {
-            resource: Some("user_management"),
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::PatternPropertiesWithAdditionalProperties,
             ..
         }],
     );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Labels/patternProperties"
+    );
 }
 
 #[test]
-fn test_different_operations_can_have_different_resources() {
+fn test_records_invalid_pattern() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /users:
-            get:
-              operationId: listUsers
-              x-resource-name: user_management
-              responses:
-                '200':
-                  description: Success
-          /posts:
-            get:
-              operationId: listPosts
-              x-resource-name: content
-              responses:
-                '200':
-                  description: Success
+        paths: {}
+        components:
+          schemas:
+            Labels:
+              type: object
+              patternProperties:
+                '^[a-z':
+                  type: string
     "})
     .unwrap();
 
@@ -1149,24 +2613,20 @@ fn test_different_operations_can_have_different_resources() {
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
     assert_matches!(
-        &*ir.operations,
-        [
-            SpecOperation {
-                resource: Some("user_management"),
-                ..
-            },
-            SpecOperation {
-                resource: Some("content"),
-                ..
-            },
-        ],
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::InvalidPattern,
+            ..
+        }],
+    );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Labels/patternProperties/^[a-z"
     );
 }
 
-// MARK: `x-resourceId` extension
-
 #[test]
-fn test_schema_stores_x_resource_id() {
+fn test_from_doc_strict_fails_on_unsupported_features() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -1175,27 +2635,27 @@ fn test_schema_stores_x_resource_id() {
         paths: {}
         components:
           schemas:
-            User:
-              type: object
-              x-resourceId: users
-              properties:
-                name:
-                  type: string
+            NotString:
+              not:
+                type: string
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let spec = Spec::from_doc(&arena, &doc).unwrap();
-    let schema = spec.schemas.get("User").unwrap();
-
-    assert_matches!(
-        schema,
-        SpecType::Schema(schema_ty) if schema_ty.resource() == Some("users"),
+    let result = Spec::from_doc_strict(&arena, &doc);
+
+    let Err(IrError::Unsupported(features)) = result else {
+        panic!("expected unsupported features; got `{result:?}`");
+    };
+    assert_eq!(features.len(), 1);
+    assert_eq!(
+        features[0].to_string(),
+        "`#/components/schemas/NotString/not`: `not` schemas are unsupported"
     );
 }
 
 #[test]
-fn test_schema_without_x_resource_id_has_none() {
+fn test_from_doc_strict_succeeds_without_unsupported_features() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -1204,57 +2664,59 @@ fn test_schema_without_x_resource_id_has_none() {
         paths: {}
         components:
           schemas:
-            User:
-              type: object
-              properties:
-                name:
-                  type: string
+            Name:
+              type: string
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let spec = Spec::from_doc(&arena, &doc).unwrap();
-    let schema = spec.schemas.get("User").unwrap();
+    let ir = Spec::from_doc_strict(&arena, &doc).unwrap();
 
-    assert_matches!(
-        schema,
-        SpecType::Schema(schema_ty) if schema_ty.resource().is_none(),
-    );
+    assert!(ir.unsupported.is_empty());
 }
 
-// MARK: Error cases
-
 #[test]
-fn test_operation_without_id_is_skipped() {
+fn test_strict_accepts_custom_formats() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test API
           version: 1.0
-        paths:
-          /users:
-            get:
-              operationId: listUsers
-              responses:
-                '200':
-                  description: Success
-            post:
-              responses:
-                '201':
-                  description: Created
+        paths: {}
+        components:
+          schemas:
+            Phone:
+              type: string
+              format: phone
+            Amount:
+              type: string
+              format: money
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let ir = Spec::from_doc(&arena, &doc).unwrap();
+    let ir = Spec::from_doc_with_config(
+        &arena,
+        &doc,
+        SpecConfig {
+            custom_formats: ["phone".to_owned(), "money".to_owned()]
+                .into_iter()
+                .collect(),
+            strict: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
 
-    // Only the GET operation with `operationId` should be present.
+    assert!(ir.unsupported.is_empty());
     assert_matches!(
-        &*ir.operations,
-        [SpecOperation {
-            id: "listUsers",
-            ..
-        }],
+        ir.schemas["Phone"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::Custom("phone"))),
+    );
+    // Custom formats take precedence over recognized formats.
+    assert_matches!(
+        ir.schemas["Amount"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::Custom("money"))),
     );
 }
 
@@ -1578,41 +3040,6 @@ fn test_mixed_path_and_query_parameters() {
     );
 }
 
-#[test]
-fn test_ignores_header_and_cookie_parameters() {
-    let doc = Document::from_yaml(indoc::indoc! {"
-        openapi: 3.0.0
-        info:
-          title: Test API
-          version: 1.0
-        paths:
-          /users:
-            get:
-              operationId: listUsers
-              parameters:
-                - name: X-API-Key
-                  in: header
-                  required: true
-                  schema:
-                    type: string
-                - name: sessionId
-                  in: cookie
-                  required: true
-                  schema:
-                    type: string
-              responses:
-                '200':
-                  description: Success
-    "})
-    .unwrap();
-
-    let arena = Arena::new();
-    let ir = Spec::from_doc(&arena, &doc).unwrap();
-
-    // Header and cookie parameters are ignored for now.
-    assert_matches!(&*ir.operations, [SpecOperation { params: [], .. }]);
-}
-
 // MARK: Path item parameters
 
 #[test]
@@ -1908,7 +3335,7 @@ fn test_path_item_ref_parameter_inherited_by_operation() {
 }
 
 #[test]
-fn test_path_item_ignores_header_and_cookie_parameters() {
+fn test_path_item_header_and_cookie_parameters_inherited_by_operation() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -1938,7 +3365,22 @@ fn test_path_item_ignores_header_and_cookie_parameters() {
     let arena = Arena::new();
     let ir = Spec::from_doc(&arena, &doc).unwrap();
 
-    assert_matches!(&*ir.operations, [SpecOperation { params: [], .. }]);
+    assert_matches!(
+        &*ir.operations,
+        [SpecOperation {
+            params: [
+                SpecParameter::Header(SpecParameterInfo {
+                    name: "X-API-Key",
+                    ..
+                }),
+                SpecParameter::Cookie(SpecParameterInfo {
+                    name: "sessionId",
+                    ..
+                }),
+            ],
+            ..
+        }],
+    );
 }
 
 #[test]
@@ -2262,3 +3704,38 @@ fn test_path_parameters_precede_query_parameters() {
         }],
     );
 }
+
+#[test]
+fn test_records_closed_struct_with_flattened_fields() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0
+        paths: {}
+        components:
+          schemas:
+            Labels:
+              type: object
+              patternProperties:
+                '^x-':
+                  type: string
+              additionalProperties: false
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let ir = Spec::from_doc(&arena, &doc).unwrap();
+
+    assert_matches!(
+        &*ir.unsupported,
+        [UnsupportedFeature {
+            kind: UnsupportedFeatureKind::ClosedWithFlattenedFields,
+            ..
+        }],
+    );
+    assert_eq!(
+        ir.unsupported[0].pointer.to_string(),
+        "/components/schemas/Labels/additionalProperties"
+    );
+}
@@ -1,11 +1,14 @@
 //! IR transformation tests.
 
+use ploidy_pointer::JsonPointerBuf;
+
 use crate::{
     arena::Arena,
     ir::{
-        Enum, EnumVariant, InlineTypeIds, PrimitiveType, SchemaTypeInfo, SpecContainer,
-        SpecInlineType, SpecInner, SpecSchemaType, SpecStruct, SpecStructField, SpecTagged,
-        SpecTaggedVariant, SpecType, SpecUntagged, StructFieldName,
+        Enum, EnumVariant, FieldConstraints, FieldDefault, InlineTypeIds, PrimitiveType,
+        SchemaTypeInfo, Spec, SpecConfig, SpecContainer, SpecInlineType, SpecInner, SpecSchemaType,
+        SpecStruct, SpecStructField, SpecTagged, SpecTaggedVariant, SpecTuple, SpecType,
+        SpecUntagged, StructFieldName,
         transform::{TransformContext, TypeInfo, transform_with_context},
     },
     parse::{Document, Schema},
@@ -18,12 +21,14 @@ fn transform<'a>(
     name: &'a str,
     schema: &'a Schema,
 ) -> SpecType<'a> {
-    let context = TransformContext::new(arena, doc, InlineTypeIds::new(arena));
+    let context =
+        TransformContext::new(arena, doc, InlineTypeIds::new(arena), SpecConfig::default());
     let info = TypeInfo::Schema(SchemaTypeInfo {
         name,
-        resource: None,
+        ..Default::default()
     });
-    transform_with_context(&context, info, schema)
+    let pointer = JsonPointerBuf::from_segments(["components", "schemas", name]);
+    transform_with_context(&context, info, pointer, schema)
 }
 
 // MARK: Enums
@@ -62,6 +67,44 @@ fn test_enum_string_variants() {
     );
 }
 
+#[test]
+fn test_enum_variant_names() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: string
+        enum: ['01', '02', '01', '03']
+        x-enum-varnames: [Created, Shipped, Duplicate]
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Status", &schema);
+
+    // Names pair with values by position, and a duplicate value
+    // keeps the first name. `03` has no name.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Enum(
+            SchemaTypeInfo { name: "Status", .. },
+            Enum {
+                variants: [
+                    EnumVariant::String("01"),
+                    EnumVariant::String("02"),
+                    EnumVariant::String("03"),
+                ],
+                names: [Some("Created"), Some("Shipped"), None],
+                ..
+            },
+        )),
+    );
+}
+
 #[test]
 fn test_enum_number_variants() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -163,6 +206,39 @@ fn test_enum_mixed_types() {
     );
 }
 
+#[test]
+fn test_const_is_single_variant_enum() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.1.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: string
+        const: v2
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Version", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Enum(
+            SchemaTypeInfo {
+                name: "Version",
+                ..
+            },
+            Enum {
+                variants: [EnumVariant::String("v2")],
+                ..
+            },
+        )),
+    );
+}
+
 #[test]
 fn test_enum_deduplicates_variants() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -197,6 +273,37 @@ fn test_enum_deduplicates_variants() {
     );
 }
 
+#[test]
+fn test_enum_default_variant() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: string
+        enum: [active, inactive, pending]
+        default: pending
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Status", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Enum(
+            SchemaTypeInfo { name: "Status", .. },
+            Enum {
+                default: Some(EnumVariant::String("pending")),
+                ..
+            },
+        )),
+    );
+}
+
 // MARK: Primitives
 
 #[test]
@@ -341,6 +448,18 @@ fn test_primitive_integer_formats() {
         SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::UnixTime)),
     );
 
+    // `integer` with an unknown format defaults to `int32`.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: integer
+        format: cents
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Cents", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I32)),
+    );
+
     // `integer` without format defaults to `int32`.
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
         type: integer
@@ -351,6 +470,161 @@ fn test_primitive_integer_formats() {
         result,
         SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I32)),
     );
+
+    // `integer` with `int8` format.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: integer
+        format: int8
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Tiny", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I8)),
+    );
+
+    // `integer` with `uint16` format.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: integer
+        format: uint16
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Port", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::U16)),
+    );
+}
+
+#[test]
+fn test_primitive_integer_bounds_narrow_type() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        paths: {}
+        components:
+          schemas:
+            Byte:
+              type: integer
+              minimum: 0
+              maximum: 255
+            Offset:
+              type: integer
+              minimum: -10
+              maximum: 10
+            Percent:
+              type: integer
+              minimum: -0.5
+              maximum: 100.5
+            Port:
+              type: integer
+              minimum: 1
+              maximum: 65535
+            Delta:
+              type: integer
+              minimum: -1000
+              maximum: 1000
+            Large:
+              type: integer
+              minimum: 0
+              maximum: 100000
+            Positive:
+              type: integer
+              minimum: 0
+            Explicit:
+              type: integer
+              format: int64
+              minimum: 0
+              maximum: 10
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc_with_config(
+        &arena,
+        &doc,
+        SpecConfig {
+            narrow_integers: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    // Bounds that fit in a `u8` narrow to `u8`.
+    assert_matches!(
+        spec.schemas["Byte"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::U8)),
+    );
+
+    // Negative bounds that fit in an `i8` narrow to `i8`.
+    assert_matches!(
+        spec.schemas["Offset"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I8)),
+    );
+
+    // Fractional bounds round inward.
+    assert_matches!(
+        spec.schemas["Percent"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::U8)),
+    );
+
+    // Bounds that need 16 bits narrow to `u16`.
+    assert_matches!(
+        spec.schemas["Port"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::U16)),
+    );
+
+    // Negative bounds that need 16 bits narrow to `i16`.
+    assert_matches!(
+        spec.schemas["Delta"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I16)),
+    );
+
+    // Bounds wider than 16 bits stay `i32`.
+    assert_matches!(
+        spec.schemas["Large"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I32)),
+    );
+
+    // Only one bound stays `i32`.
+    assert_matches!(
+        spec.schemas["Positive"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I32)),
+    );
+
+    // An explicit format wins over the bounds.
+    assert_matches!(
+        spec.schemas["Explicit"],
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I64)),
+    );
+}
+
+#[test]
+fn test_primitive_integer_bounds_stay_i32_by_default() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: integer
+        minimum: 0
+        maximum: 255
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Byte", &schema);
+
+    // Without `narrow_integers`, bounds don't narrow the type.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::I32)),
+    );
 }
 
 #[test]
@@ -400,6 +674,30 @@ fn test_primitive_number_formats() {
         SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::UnixTime)),
     );
 
+    // `number` with `money` format.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: number
+        format: money
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Amount", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::Decimal)),
+    );
+
+    // `string` with `decimal` format.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: string
+        format: decimal
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Balance", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::Decimal)),
+    );
+
     // `number` without format defaults to `double`.
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
         type: number
@@ -483,10 +781,8 @@ fn test_array_with_inline_items() {
     );
 }
 
-// MARK: `try_struct()`
-
 #[test]
-fn test_struct_with_own_properties() {
+fn test_array_with_unique_items() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -494,78 +790,525 @@ fn test_struct_with_own_properties() {
           version: 1.0.0
     "})
     .unwrap();
-    let arena = Arena::new();
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
-        type: object
-        required: [name, age]
-        properties:
-          name:
-            type: string
-          age:
-            type: integer
+        type: array
+        items:
+          type: string
+        uniqueItems: true
     "})
     .unwrap();
 
-    let result = transform(&arena, &doc, "Person", &schema);
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Tags", &schema);
 
     assert_matches!(
         result,
-        SpecType::Schema(SpecSchemaType::Struct(
-            SchemaTypeInfo { name: "Person", .. },
-            SpecStruct {
-                fields: [
-                    SpecStructField {
-                        name: StructFieldName::Name("name"),
-                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
-                        ..
-                    },
-                    SpecStructField {
-                        name: StructFieldName::Name("age"),
-                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::I32)),
-                        ..
-                    },
-                ],
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo { name: "Tags", .. },
+            SpecContainer::Set(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
                 ..
-            },
+            }),
         )),
     );
 }
 
 #[test]
-fn test_struct_with_additional_properties_ref() {
+fn test_array_with_prefix_items() {
     let doc = Document::from_yaml(indoc::indoc! {"
-        openapi: 3.0.0
+        openapi: 3.1.0
         info:
           title: Test
           version: 1.0.0
-        components:
-          schemas:
-            Value:
-              type: string
     "})
     .unwrap();
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
-        type: object
-        properties:
-          name:
-            type: string
-        additionalProperties:
-          $ref: '#/components/schemas/Value'
+        type: array
+        prefixItems:
+          - type: number
+          - type: number
+        items: false
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let result = transform(&arena, &doc, "Config", &schema);
+    let result = transform(&arena, &doc, "Coordinate", &schema);
 
     assert_matches!(
         result,
-        SpecType::Schema(SpecSchemaType::Struct(
-            SchemaTypeInfo { name: "Config", .. },
-            SpecStruct {
-                fields: [
-                    _,
-                    SpecStructField {
-                        name: StructFieldName::AdditionalProperties,
+        SpecType::Schema(SpecSchemaType::Tuple(
+            SchemaTypeInfo {
+                name: "Coordinate",
+                ..
+            },
+            SpecTuple {
+                elements: [
+                    SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::F64)),
+                    SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::F64)),
+                ],
+                rest: None,
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_array_with_prefix_items_and_rest() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.1.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: array
+        prefixItems:
+          - type: string
+        items:
+          type: integer
+          format: int32
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Row", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Tuple(
+            SchemaTypeInfo { name: "Row", .. },
+            SpecTuple {
+                elements: [SpecType::Inline(SpecInlineType::Primitive(
+                    _,
+                    PrimitiveType::String
+                ))],
+                rest: Some(SpecType::Inline(SpecInlineType::Primitive(
+                    _,
+                    PrimitiveType::I32
+                ))),
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_array_with_nullable_items() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: array
+        items:
+          type: string
+          nullable: true
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Strings", &schema);
+
+    // Nullable items are optional, but the array itself isn't.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo {
+                name: "Strings",
+                ..
+            },
+            SpecContainer::Array(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Container(
+                    _,
+                    SpecContainer::Optional(SpecInner {
+                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
+                        ..
+                    }),
+                )),
+                ..
+            }),
+        )),
+    );
+}
+
+#[test]
+fn test_array_with_nullable_ref_items() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Item:
+              type: string
+              nullable: true
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: array
+        items:
+          $ref: '#/components/schemas/Item'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Items", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo { name: "Items", .. },
+            SpecContainer::Array(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Container(
+                    _,
+                    SpecContainer::Optional(SpecInner {
+                        ty: SpecType::Ref(r),
+                        ..
+                    }),
+                )),
+                ..
+            }),
+        )) if r.name() == "Item",
+    );
+}
+
+#[test]
+fn test_map_with_nullable_values() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        additionalProperties:
+          type: string
+          nullable: true
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Labels", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo { name: "Labels", .. },
+            SpecContainer::Map(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Container(
+                    _,
+                    SpecContainer::Optional(SpecInner {
+                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
+                        ..
+                    }),
+                )),
+                ..
+            }),
+        )),
+    );
+}
+
+#[test]
+fn test_array_with_nullable_all_of_ref_items() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Kind:
+              type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: array
+        items:
+          nullable: true
+          allOf:
+            - $ref: '#/components/schemas/Kind'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Kinds", &schema);
+
+    // The item is already an optional reference, so it isn't wrapped again.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo { name: "Kinds", .. },
+            SpecContainer::Array(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Container(
+                    _,
+                    SpecContainer::Optional(SpecInner {
+                        ty: SpecType::Ref(r),
+                        ..
+                    }),
+                )),
+                ..
+            }),
+        )) if r.name() == "Kind",
+    );
+}
+
+#[test]
+fn test_map_with_nullable_all_of_ref_values() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Kind:
+              type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        additionalProperties:
+          nullable: true
+          allOf:
+            - $ref: '#/components/schemas/Kind'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Labels", &schema);
+
+    // Like array items, the value isn't wrapped in a second `Optional`.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo { name: "Labels", .. },
+            SpecContainer::Map(SpecInner {
+                ty: SpecType::Inline(SpecInlineType::Container(
+                    _,
+                    SpecContainer::Optional(SpecInner {
+                        ty: SpecType::Ref(r),
+                        ..
+                    }),
+                )),
+                ..
+            }),
+        )) if r.name() == "Kind",
+    );
+}
+
+#[test]
+fn test_nullable_containers_with_and_without_nullable_items() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        required: [tags, aliases, labels]
+        properties:
+          tags:
+            type: array
+            nullable: true
+            items:
+              type: string
+          aliases:
+            type: array
+            nullable: true
+            items:
+              type: string
+              nullable: true
+          labels:
+            type: object
+            nullable: true
+            additionalProperties:
+              type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Record", &schema);
+
+    // Container nullability wraps the container; item nullability
+    // wraps the items.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Record", .. },
+            SpecStruct {
+                fields: [
+                    // `Option<Vec<T>>`.
+                    SpecStructField {
+                        name: StructFieldName::Name("tags"),
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Optional(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Container(
+                                    _,
+                                    SpecContainer::Array(SpecInner {
+                                        ty: SpecType::Inline(SpecInlineType::Primitive(
+                                            _,
+                                            PrimitiveType::String,
+                                        )),
+                                        ..
+                                    }),
+                                )),
+                                ..
+                            }),
+                        )),
+                        required: true,
+                        ..
+                    },
+                    // `Option<Vec<Option<T>>>`.
+                    SpecStructField {
+                        name: StructFieldName::Name("aliases"),
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Optional(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Container(
+                                    _,
+                                    SpecContainer::Array(SpecInner {
+                                        ty: SpecType::Inline(SpecInlineType::Container(
+                                            _,
+                                            SpecContainer::Optional(SpecInner {
+                                                ty: SpecType::Inline(SpecInlineType::Primitive(
+                                                    _,
+                                                    PrimitiveType::String,
+                                                )),
+                                                ..
+                                            }),
+                                        )),
+                                        ..
+                                    }),
+                                )),
+                                ..
+                            }),
+                        )),
+                        required: true,
+                        ..
+                    },
+                    // `Option<BTreeMap<String, T>>`.
+                    SpecStructField {
+                        name: StructFieldName::Name("labels"),
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Optional(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Container(
+                                    _,
+                                    SpecContainer::Map(SpecInner {
+                                        ty: SpecType::Inline(SpecInlineType::Primitive(
+                                            _,
+                                            PrimitiveType::String,
+                                        )),
+                                        ..
+                                    }),
+                                )),
+                                ..
+                            }),
+                        )),
+                        required: true,
+                        ..
+                    },
+                ],
+                ..
+            },
+        )),
+    );
+}
+
+// MARK: `try_struct()`
+
+#[test]
+fn test_struct_with_own_properties() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let arena = Arena::new();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        required: [name, age]
+        properties:
+          name:
+            type: string
+          age:
+            type: integer
+    "})
+    .unwrap();
+
+    let result = transform(&arena, &doc, "Person", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Person", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("name"),
+                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::String)),
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::Name("age"),
+                        ty: SpecType::Inline(SpecInlineType::Primitive(_, PrimitiveType::I32)),
+                        ..
+                    },
+                ],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_additional_properties_ref() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Value:
+              type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          name:
+            type: string
+        additionalProperties:
+          $ref: '#/components/schemas/Value'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Config", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Config", .. },
+            SpecStruct {
+                fields: [
+                    _,
+                    SpecStructField {
+                        name: StructFieldName::AdditionalProperties,
                         flattened: true,
                         required: true,
                         ty: SpecType::Inline(SpecInlineType::Container(
@@ -577,7 +1320,213 @@ fn test_struct_with_additional_properties_ref() {
                         )),
                         ..
                     },
-                ],
+                ],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_additional_properties_inline() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          name:
+            type: string
+        additionalProperties:
+          type: object
+          properties:
+            inner:
+              type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Config", &schema);
+
+    // When `additionalProperties` is present alongside `properties`,
+    // the result should be a struct with a flattened, inline map field.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Config", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("name"),
+                        flattened: false,
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::AdditionalProperties,
+                        flattened: true,
+                        required: true,
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Map(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Struct(_, _)),
+                                ..
+                            }),
+                        )),
+                        ..
+                    },
+                ],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_additional_properties_true() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties: {}
+        additionalProperties: true
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "DynamicMap", &schema);
+
+    // Empty `properties` with `additionalProperties: true` produces a
+    // struct with a single flattened map field of type `Any`.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo {
+                name: "DynamicMap",
+                ..
+            },
+            SpecStruct {
+                fields: [SpecStructField {
+                    name: StructFieldName::AdditionalProperties,
+                    flattened: true,
+                    required: true,
+                    ty: SpecType::Inline(SpecInlineType::Container(
+                        _,
+                        SpecContainer::Map(SpecInner {
+                            ty: SpecType::Inline(SpecInlineType::Any(_)),
+                            ..
+                        }),
+                    )),
+                    ..
+                }],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_additional_properties_false() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          name:
+            type: string
+        additionalProperties: false
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Closed", &schema);
+
+    // `additionalProperties: false` closes the struct,
+    // without adding a field.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Closed", .. },
+            SpecStruct {
+                fields: [SpecStructField {
+                    name: StructFieldName::Name("name"),
+                    ..
+                }],
+                closed: true,
+                ..
+            },
+        )),
+    );
+
+    // Other schemas are open.
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          name:
+            type: string
+    "})
+    .unwrap();
+    let result = transform(&arena, &doc, "Open", &schema);
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(_, SpecStruct { closed: false, .. },)),
+    );
+}
+
+#[test]
+fn test_struct_with_map_field_property_counts() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          labels:
+            type: object
+            additionalProperties:
+              type: string
+            minProperties: 1
+            maxProperties: 5
+        required:
+          - labels
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Pet", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            _,
+            SpecStruct {
+                fields: [SpecStructField {
+                    name: StructFieldName::Name("labels"),
+                    ty: SpecType::Inline(SpecInlineType::Container(_, SpecContainer::Map(_))),
+                    constraints: FieldConstraints {
+                        min_properties: Some(1),
+                        max_properties: Some(5),
+                        ..
+                    },
+                    ..
+                }],
                 ..
             },
         )),
@@ -585,7 +1534,40 @@ fn test_struct_with_additional_properties_ref() {
 }
 
 #[test]
-fn test_struct_with_additional_properties_inline() {
+fn test_struct_without_properties_falls_through() {
+    // A schema with only `additionalProperties` and no `properties`
+    // falls through to `other()`, producing a map.
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        additionalProperties:
+          type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "DynamicMap", &schema);
+
+    assert_matches!(
+        &result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo {
+                name: "DynamicMap",
+                ..
+            },
+            SpecContainer::Map(_),
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_required_fields() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -598,41 +1580,30 @@ fn test_struct_with_additional_properties_inline() {
         properties:
           name:
             type: string
-        additionalProperties:
-          type: object
-          properties:
-            inner:
-              type: string
+          email:
+            type: string
+        required:
+          - name
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let result = transform(&arena, &doc, "Config", &schema);
+    let result = transform(&arena, &doc, "User", &schema);
 
-    // When `additionalProperties` is present alongside `properties`,
-    // the result should be a struct with a flattened, inline map field.
     assert_matches!(
         result,
         SpecType::Schema(SpecSchemaType::Struct(
-            SchemaTypeInfo { name: "Config", .. },
+            SchemaTypeInfo { name: "User", .. },
             SpecStruct {
                 fields: [
                     SpecStructField {
                         name: StructFieldName::Name("name"),
-                        flattened: false,
+                        required: true,
                         ..
                     },
                     SpecStructField {
-                        name: StructFieldName::AdditionalProperties,
-                        flattened: true,
-                        required: true,
-                        ty: SpecType::Inline(SpecInlineType::Container(
-                            _,
-                            SpecContainer::Map(SpecInner {
-                                ty: SpecType::Inline(SpecInlineType::Struct(_, _)),
-                                ..
-                            }),
-                        )),
+                        name: StructFieldName::Name("email"),
+                        required: false,
                         ..
                     },
                 ],
@@ -643,7 +1614,7 @@ fn test_struct_with_additional_properties_inline() {
 }
 
 #[test]
-fn test_struct_with_additional_properties_true() {
+fn test_struct_with_field_defaults() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -653,47 +1624,59 @@ fn test_struct_with_additional_properties_true() {
     .unwrap();
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
         type: object
-        properties: {}
-        additionalProperties: true
+        properties:
+          name:
+            type: string
+            default: anonymous
+          retries:
+            type: integer
+            default: -3
+          ratio:
+            type: number
+            default: 0.5
+          active:
+            type: boolean
+            default: false
+          email:
+            type: string
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let result = transform(&arena, &doc, "DynamicMap", &schema);
+    let result = transform(&arena, &doc, "User", &schema);
 
-    // Empty `properties` with `additionalProperties: true` produces a
-    // struct with a single flattened map field of type `Any`.
     assert_matches!(
         result,
         SpecType::Schema(SpecSchemaType::Struct(
-            SchemaTypeInfo {
-                name: "DynamicMap",
-                ..
-            },
+            SchemaTypeInfo { name: "User", .. },
             SpecStruct {
-                fields: [SpecStructField {
-                    name: StructFieldName::AdditionalProperties,
-                    flattened: true,
-                    required: true,
-                    ty: SpecType::Inline(SpecInlineType::Container(
-                        _,
-                        SpecContainer::Map(SpecInner {
-                            ty: SpecType::Inline(SpecInlineType::Any(_)),
-                            ..
-                        }),
-                    )),
-                    ..
-                }],
+                fields: [
+                    SpecStructField {
+                        default: Some(FieldDefault::String("anonymous")),
+                        ..
+                    },
+                    SpecStructField {
+                        default: Some(FieldDefault::I64(-3)),
+                        ..
+                    },
+                    SpecStructField {
+                        default: Some(FieldDefault::F64(ratio)),
+                        ..
+                    },
+                    SpecStructField {
+                        default: Some(FieldDefault::Bool(false)),
+                        ..
+                    },
+                    SpecStructField { default: None, .. },
+                ],
                 ..
             },
-        )),
+        )) if ratio.to_f64() == 0.5,
     );
 }
 
 #[test]
-fn test_struct_without_properties_falls_through() {
-    // A schema with only `additionalProperties` and no `properties`
-    // falls through to `other()`, producing a map.
+fn test_struct_with_deprecated_field() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -703,64 +1686,99 @@ fn test_struct_without_properties_falls_through() {
     .unwrap();
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
         type: object
-        additionalProperties:
-          type: string
+        properties:
+          name:
+            type: string
+          nickname:
+            type: string
+            deprecated: true
     "})
     .unwrap();
 
     let arena = Arena::new();
-    let result = transform(&arena, &doc, "DynamicMap", &schema);
+    let result = transform(&arena, &doc, "User", &schema);
 
     assert_matches!(
-        &result,
-        SpecType::Schema(SpecSchemaType::Container(
-            SchemaTypeInfo {
-                name: "DynamicMap",
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "User", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("name"),
+                        deprecated: false,
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::Name("nickname"),
+                        deprecated: true,
+                        ..
+                    },
+                ],
                 ..
             },
-            SpecContainer::Map(_),
         )),
     );
 }
 
 #[test]
-fn test_struct_with_required_fields() {
+fn test_struct_with_read_only_and_write_only_fields() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
           title: Test
           version: 1.0.0
+        components:
+          schemas:
+            Id:
+              type: string
+              readOnly: true
     "})
     .unwrap();
     let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
         type: object
         properties:
+          id:
+            $ref: '#/components/schemas/Id'
           name:
             type: string
-          email:
+          password:
             type: string
-        required:
-          - name
+            writeOnly: true
+        required: [id, name, password]
     "})
     .unwrap();
 
     let arena = Arena::new();
     let result = transform(&arena, &doc, "User", &schema);
 
+    // Required `readOnly` and `writeOnly` fields are only required
+    // in one direction, so they're lowered as optional.
     assert_matches!(
         result,
         SpecType::Schema(SpecSchemaType::Struct(
             SchemaTypeInfo { name: "User", .. },
             SpecStruct {
                 fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("id"),
+                        required: false,
+                        read_only: true,
+                        write_only: false,
+                        ..
+                    },
                     SpecStructField {
                         name: StructFieldName::Name("name"),
                         required: true,
+                        read_only: false,
+                        write_only: false,
                         ..
                     },
                     SpecStructField {
-                        name: StructFieldName::Name("email"),
+                        name: StructFieldName::Name("password"),
                         required: false,
+                        read_only: false,
+                        write_only: true,
                         ..
                     },
                 ],
@@ -816,7 +1834,87 @@ fn test_struct_with_nullable_field_ref() {
                 }],
                 ..
             },
-        )),
+        )),
+    );
+}
+
+#[test]
+fn test_struct_with_nullable_all_of_ref_field() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test API
+          version: 1.0.0
+        components:
+          schemas:
+            Owner:
+              type: object
+              properties:
+                name:
+                  type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          owner:
+            nullable: true
+            allOf:
+              - $ref: '#/components/schemas/Owner'
+          previous:
+            nullable: true
+            allOf:
+              - $ref: '#/components/schemas/Owner'
+        required:
+          - owner
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Pet", &schema);
+
+    // A required field is `Optional(Ref)`, like a field that references
+    // a nullable schema; an optional field wraps that in another `Optional`.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Pet", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("owner"),
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Optional(SpecInner {
+                                ty: SpecType::Ref(owner),
+                                ..
+                            }),
+                        )),
+                        required: true,
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::Name("previous"),
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Optional(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Container(
+                                    _,
+                                    SpecContainer::Optional(SpecInner {
+                                        ty: SpecType::Ref(previous),
+                                        ..
+                                    }),
+                                )),
+                                ..
+                            }),
+                        )),
+                        required: false,
+                        ..
+                    },
+                ],
+                ..
+            },
+        )) if owner.name() == "Owner" && previous.name() == "Owner",
     );
 }
 
@@ -1124,6 +2222,73 @@ fn test_struct_mixed_all_of_ref_and_inline() {
     );
 }
 
+#[test]
+fn test_struct_all_of_with_additional_properties() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Base:
+              type: object
+              properties:
+                id:
+                  type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        allOf:
+          - $ref: '#/components/schemas/Base'
+        properties:
+          name:
+            type: string
+        additionalProperties:
+          type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Child", &schema);
+
+    // The struct keeps its `allOf` parent, and adds the flattened map
+    // after its own fields.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Child", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("name"),
+                        flattened: false,
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::AdditionalProperties,
+                        flattened: true,
+                        required: true,
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Map(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Primitive(
+                                    _,
+                                    PrimitiveType::String,
+                                )),
+                                ..
+                            }),
+                        )),
+                        ..
+                    },
+                ],
+                parents: [SpecType::Ref(r)],
+                ..
+            },
+        )) if r.name() == "Base",
+    );
+}
+
 // MARK: `try_tagged()`
 
 #[test]
@@ -2154,7 +3319,7 @@ fn test_boolean_primitive_transformation() {
 }
 
 #[test]
-fn test_unhandled_string_format_falls_back_to_string() {
+fn test_unhandled_string_format_keeps_format_name() {
     let doc = Document::from_yaml(indoc::indoc! {"
         openapi: 3.0.0
         info:
@@ -2172,9 +3337,13 @@ fn test_unhandled_string_format_falls_back_to_string() {
     let arena = Arena::new();
     let result = transform(&arena, &doc, "CustomType", &schema);
 
+    // Codegen decides how to represent custom formats.
     assert_matches!(
         result,
-        SpecType::Schema(SpecSchemaType::Primitive(_, PrimitiveType::String)),
+        SpecType::Schema(SpecSchemaType::Primitive(
+            _,
+            PrimitiveType::Custom("currency")
+        )),
     );
 }
 
@@ -2614,6 +3783,168 @@ fn test_additional_properties_false_creates_struct() {
     );
 }
 
+#[test]
+fn test_struct_with_pattern_properties() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        properties:
+          name:
+            type: string
+        patternProperties:
+          '^[a-z]+$':
+            type: integer
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Labels", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo { name: "Labels", .. },
+            SpecStruct {
+                fields: [
+                    SpecStructField {
+                        name: StructFieldName::Name("name"),
+                        ..
+                    },
+                    SpecStructField {
+                        name: StructFieldName::PatternProperties(["^[a-z]+$"]),
+                        flattened: true,
+                        required: true,
+                        ty: SpecType::Inline(SpecInlineType::Container(
+                            _,
+                            SpecContainer::Map(SpecInner {
+                                ty: SpecType::Inline(SpecInlineType::Primitive(
+                                    _,
+                                    PrimitiveType::I32,
+                                )),
+                                ..
+                            }),
+                        )),
+                        ..
+                    },
+                ],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_pattern_properties_with_same_values_share_type() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        patternProperties:
+          '^x-':
+            type: string
+          '^y-':
+            type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Extensions", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            _,
+            SpecStruct {
+                fields: [SpecStructField {
+                    name: StructFieldName::PatternProperties(["^x-", "^y-"]),
+                    ty: SpecType::Inline(SpecInlineType::Container(
+                        _,
+                        SpecContainer::Map(SpecInner {
+                            ty: SpecType::Inline(SpecInlineType::Primitive(
+                                _,
+                                PrimitiveType::String,
+                            )),
+                            ..
+                        }),
+                    )),
+                    ..
+                }],
+                ..
+            },
+        )),
+    );
+}
+
+#[test]
+fn test_pattern_properties_with_different_values_become_union() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        type: object
+        patternProperties:
+          '^x-':
+            type: string
+          '^n-':
+            type: integer
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Extensions", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            _,
+            SpecStruct {
+                fields: [SpecStructField {
+                    name: StructFieldName::PatternProperties(["^x-", "^n-"]),
+                    ty: SpecType::Inline(SpecInlineType::Container(
+                        _,
+                        SpecContainer::Map(SpecInner {
+                            ty: SpecType::Inline(SpecInlineType::Untagged(
+                                _,
+                                SpecUntagged {
+                                    variants: [
+                                        Some(SpecType::Inline(SpecInlineType::Primitive(
+                                            _,
+                                            PrimitiveType::String,
+                                        ))),
+                                        Some(SpecType::Inline(SpecInlineType::Primitive(
+                                            _,
+                                            PrimitiveType::I32,
+                                        ))),
+                                    ],
+                                    ..
+                                },
+                            )),
+                            ..
+                        }),
+                    )),
+                    ..
+                }],
+                ..
+            },
+        )),
+    );
+}
+
 // MARK: Inline type paths
 
 #[test]
@@ -3130,6 +4461,94 @@ fn test_named_nullable_schema_produces_container() {
     );
 }
 
+#[test]
+fn test_named_nullable_all_of_ref_produces_container() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Owner:
+              type: object
+              properties:
+                name:
+                  type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        description: The owner, if any
+        nullable: true
+        allOf:
+          - $ref: '#/components/schemas/Owner'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "MaybeOwner", &schema);
+
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Container(
+            SchemaTypeInfo {
+                name: "MaybeOwner",
+                ..
+            },
+            SpecContainer::Optional(SpecInner {
+                description: Some("The owner, if any"),
+                ty: SpecType::Ref(r),
+            }),
+        )) if r.name() == "Owner",
+    );
+}
+
+#[test]
+fn test_nullable_all_of_ref_with_properties_stays_struct() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Owner:
+              type: object
+              properties:
+                name:
+                  type: string
+    "})
+    .unwrap();
+    let schema: Schema = serde_saphyr::from_str(indoc::indoc! {"
+        nullable: true
+        allOf:
+          - $ref: '#/components/schemas/Owner'
+        properties:
+          since:
+            type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let result = transform(&arena, &doc, "Ownership", &schema);
+
+    // Only a bare `allOf` is a nullable reference; a schema that
+    // extends the parent with its own properties is still a struct.
+    assert_matches!(
+        result,
+        SpecType::Schema(SpecSchemaType::Struct(
+            SchemaTypeInfo {
+                name: "Ownership",
+                ..
+            },
+            SpecStruct {
+                parents: [SpecType::Ref(r)],
+                ..
+            },
+        )) if r.name() == "Owner",
+    );
+}
+
 #[test]
 fn test_named_container_preserves_description() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -1197,6 +1197,53 @@ fn test_tagged_variant_names_and_aliases() {
     assert_matches!(variant.aliases(), ["cat"]);
 }
 
+#[test]
+fn test_tagged_variant_aliases_without_mapping() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0
+        components:
+          schemas:
+            Cat:
+              type: object
+              properties:
+                meow:
+                  type: string
+            Dog:
+              type: object
+              properties:
+                bark:
+                  type: string
+            Animal:
+              oneOf:
+                - $ref: '#/components/schemas/Cat'
+                - $ref: '#/components/schemas/Dog'
+              discriminator:
+                propertyName: kind
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let animal_schema = graph.schema("Animal").unwrap();
+    let tagged_view = match animal_schema {
+        SchemaTypeView::Tagged(_, view) => view,
+        other => panic!("expected tagged union `Animal`; got {other:?}"),
+    };
+
+    // Without a `mapping`, each variant's discriminator value
+    // should be its schema name.
+    let variants = tagged_view
+        .variants()
+        .map(|v| (v.name(), v.aliases()))
+        .collect_vec();
+    assert_matches!(&*variants, [("Cat", ["Cat"]), ("Dog", ["Dog"])]);
+}
+
 #[test]
 fn test_tagged_variant_type_access() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -1427,6 +1474,117 @@ fn test_nullable_view_provides_access_to_inner_type() {
     );
 }
 
+#[test]
+fn test_type_view_unwrap_optional_peels_nullable_type() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Container:
+              type: object
+              required: [nullable_field, plain_field]
+              properties:
+                nullable_field:
+                  type: string
+                  nullable: true
+                plain_field:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let container_schema = graph.schemas().next().unwrap();
+    let container_struct = match container_schema {
+        SchemaTypeView::Struct(_, view) => view,
+        other => panic!("expected struct `Container`; got {other:?}"),
+    };
+
+    let nullable_field = container_struct
+        .fields()
+        .find(|f| matches!(f.name(), StructFieldName::Name("nullable_field")))
+        .unwrap();
+    assert!(nullable_field.ty().is_nullable());
+    assert_matches!(
+        nullable_field.ty().unwrap_optional(),
+        TypeView::Inline(InlineTypeView::Primitive(_, p)) if p.ty() == PrimitiveType::String,
+    );
+
+    let plain_field = container_struct
+        .fields()
+        .find(|f| matches!(f.name(), StructFieldName::Name("plain_field")))
+        .unwrap();
+    assert!(!plain_field.ty().is_nullable());
+    assert_matches!(
+        plain_field.ty().unwrap_optional(),
+        TypeView::Inline(InlineTypeView::Primitive(_, p)) if p.ty() == PrimitiveType::String,
+    );
+}
+
+// MARK: Tuple views
+
+#[test]
+fn test_tuple_view_elements_and_rest() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.1.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Row:
+              type: array
+              prefixItems:
+                - type: string
+                - type: object
+                  properties:
+                    id:
+                      type: string
+              items:
+                type: boolean
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let row_schema = graph.schemas().next().unwrap();
+    let row_tuple = match row_schema {
+        SchemaTypeView::Tuple(_, view) => view,
+        other => panic!("expected tuple `Row`; got {other:?}"),
+    };
+
+    let elements = row_tuple.elements().collect_vec();
+    assert_eq!(
+        elements.iter().map(|e| e.ordinal().get()).collect_vec(),
+        [1, 2]
+    );
+    assert_matches!(
+        elements[0].ty(),
+        TypeView::Inline(InlineTypeView::Primitive(_, p)) if p.ty() == PrimitiveType::String,
+    );
+
+    // Inline element types are named by their position.
+    let TypeView::Inline(inline) = elements[1].ty() else {
+        panic!("expected inline element; got {:?}", elements[1].ty());
+    };
+    assert_matches!(
+        inline.path().segments().collect_vec().as_slice(),
+        [InlineTypePathSegment::TupleElement(ordinal)] if ordinal.get() == 2,
+    );
+
+    assert_matches!(
+        row_tuple.rest(),
+        Some(TypeView::Inline(InlineTypeView::Primitive(_, p))) if p.ty() == PrimitiveType::Bool,
+    );
+}
+
 // MARK: Inline type views
 
 #[test]
@@ -2452,6 +2610,139 @@ fn test_operation_parameter_style() {
     assert_matches!(no_style.style(), None);
 }
 
+#[test]
+fn test_operation_path_parameter_style() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0
+        paths:
+          /items/{simple}/{simple_exploded}/{label}/{label_exploded}/{matrix}/{matrix_exploded}/{no_style}:
+            get:
+              operationId: getItems
+              parameters:
+                - name: simple
+                  in: path
+                  required: true
+                  schema:
+                    type: string
+                  style: simple
+                - name: simple_exploded
+                  in: path
+                  required: true
+                  schema:
+                    type: array
+                    items:
+                      type: string
+                  explode: true
+                - name: label
+                  in: path
+                  required: true
+                  schema:
+                    type: array
+                    items:
+                      type: string
+                  style: label
+                - name: label_exploded
+                  in: path
+                  required: true
+                  schema:
+                    type: array
+                    items:
+                      type: string
+                  style: label
+                  explode: true
+                - name: matrix
+                  in: path
+                  required: true
+                  schema:
+                    type: string
+                  style: matrix
+                - name: matrix_exploded
+                  in: path
+                  required: true
+                  schema:
+                    type: object
+                    properties:
+                      r:
+                        type: integer
+                  style: matrix
+                  explode: true
+                - name: no_style
+                  in: path
+                  required: true
+                  schema:
+                    type: string
+              responses:
+                '200':
+                  description: OK
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let operation = graph.operations().next().unwrap();
+    let path_params = operation.path().params().collect_vec();
+
+    // Non-exploded `simple` style, explicitly specified.
+    let simple = path_params.iter().find(|p| p.name() == "simple").unwrap();
+    assert_matches!(
+        simple.style(),
+        Some(ParameterStyle::Simple { exploded: false }),
+    );
+
+    // Exploded `simple` style, implied by `explode`.
+    let simple_exploded = path_params
+        .iter()
+        .find(|p| p.name() == "simple_exploded")
+        .unwrap();
+    assert_matches!(
+        simple_exploded.style(),
+        Some(ParameterStyle::Simple { exploded: true }),
+    );
+
+    // Non-exploded `label` style.
+    let label = path_params.iter().find(|p| p.name() == "label").unwrap();
+    assert_matches!(
+        label.style(),
+        Some(ParameterStyle::Label { exploded: false }),
+    );
+
+    // Exploded `label` style.
+    let label_exploded = path_params
+        .iter()
+        .find(|p| p.name() == "label_exploded")
+        .unwrap();
+    assert_matches!(
+        label_exploded.style(),
+        Some(ParameterStyle::Label { exploded: true }),
+    );
+
+    // Non-exploded `matrix` style.
+    let matrix = path_params.iter().find(|p| p.name() == "matrix").unwrap();
+    assert_matches!(
+        matrix.style(),
+        Some(ParameterStyle::Matrix { exploded: false }),
+    );
+
+    // Exploded `matrix` style.
+    let matrix_exploded = path_params
+        .iter()
+        .find(|p| p.name() == "matrix_exploded")
+        .unwrap();
+    assert_matches!(
+        matrix_exploded.style(),
+        Some(ParameterStyle::Matrix { exploded: true }),
+    );
+
+    // No explicit style; `None` defers to the default `simple` style.
+    let no_style = path_params.iter().find(|p| p.name() == "no_style").unwrap();
+    assert_matches!(no_style.style(), None);
+}
+
 #[test]
 fn test_operation_request_json() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -2603,6 +2894,56 @@ fn test_operation_response_without_schema() {
     );
 }
 
+#[test]
+fn test_operation_response_headers() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0
+        paths:
+          /users:
+            get:
+              operationId: listUsers
+              responses:
+                '200':
+                  description: OK
+                  headers:
+                    X-Next-Cursor:
+                      schema:
+                        type: string
+                    X-Sort:
+                      schema:
+                        type: string
+                        enum: [asc, desc]
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let operation = graph.operations().next().unwrap();
+    let headers = operation.response_headers().collect_vec();
+    assert_eq!(
+        headers.iter().map(|header| header.name()).collect_vec(),
+        ["X-Next-Cursor", "X-Sort"]
+    );
+
+    // Inline header types are rooted at the operation.
+    let TypeView::Inline(inline) = headers[1].ty() else {
+        panic!("expected inline header type; got {:?}", headers[1].ty());
+    };
+    assert_matches!(
+        inline.path().root(),
+        InlineTypePathRoot::Operation {
+            usage: OperationUsage::ResponseHeader("X-Sort"),
+            ..
+        },
+    );
+    assert!(operation.inlines().any(|ty| ty.id() == inline.id()));
+}
+
 #[test]
 fn test_operation_query() {
     let doc = Document::from_yaml(indoc::indoc! {"
@@ -2821,6 +3162,72 @@ fn test_operation_request_and_response() {
     assert!(resp_path.segments().next().is_none());
 }
 
+#[test]
+fn test_operation_view_type_closure() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0
+        paths:
+          /pets:
+            post:
+              operationId: createPet
+              requestBody:
+                content:
+                  application/json:
+                    schema:
+                      $ref: '#/components/schemas/NewPet'
+              responses:
+                '200':
+                  description: OK
+                  content:
+                    application/json:
+                      schema:
+                        $ref: '#/components/schemas/Pet'
+        components:
+          schemas:
+            NewPet:
+              type: object
+              properties:
+                tag:
+                  $ref: '#/components/schemas/Tag'
+            Pet:
+              type: object
+              properties:
+                name:
+                  type: string
+            Tag:
+              type: object
+              properties:
+                label:
+                  type: string
+            Store:
+              type: object
+              properties:
+                pets:
+                  type: array
+                  items:
+                    $ref: '#/components/schemas/Pet'
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    let operation = graph.operations().next().unwrap();
+
+    // `NewPet` and `Pet` are direct; `Tag` is transitive through `NewPet`.
+    // `Store` depends on `Pet`, but the operation doesn't use it.
+    let names = operation
+        .type_closure()
+        .map(|schema| schema.name())
+        .sorted()
+        .collect_vec();
+    assert_eq!(names, ["NewPet", "Pet", "Tag"]);
+}
+
 // MARK: Parameter views
 
 #[test]
@@ -3085,6 +3492,57 @@ fn test_variant_field_matching_tagged_union_tag_is_tag() {
     assert!(kind_field.tag());
 }
 
+#[test]
+fn test_const_variant_field_matching_tagged_union_tag_is_tag() {
+    let doc = Document::from_yaml(indoc::indoc! {"
+        openapi: 3.0.0
+        info:
+          title: Test
+          version: 1.0.0
+        components:
+          schemas:
+            Pet:
+              oneOf:
+                - $ref: '#/components/schemas/Cat'
+              discriminator:
+                propertyName: kind
+                mapping:
+                  cat: '#/components/schemas/Cat'
+            Cat:
+              type: object
+              required: [kind, meow]
+              properties:
+                kind:
+                  const: cat
+                meow:
+                  type: string
+    "})
+    .unwrap();
+
+    let arena = Arena::new();
+    let spec = Spec::from_doc(&arena, &doc).unwrap();
+    let graph = RawGraph::new(&arena, &spec).cook();
+
+    // `Cat.kind` repeats the discriminator value as a `const`, but
+    // is still a tag field that the union's tag represents.
+    let cat = graph.schema("Cat").unwrap();
+    let SchemaTypeView::Struct(_, cat_struct) = cat else {
+        panic!("expected struct `Cat`; got `{cat:?}`");
+    };
+    let kind_field = cat_struct
+        .fields()
+        .find(|f| matches!(f.name(), StructFieldName::Name("kind")))
+        .unwrap();
+    assert!(kind_field.tag());
+
+    // The union carries the tag, so it's present on the wire.
+    let pet = graph.schema("Pet").unwrap();
+    let SchemaTypeView::Tagged(_, pet_tagged) = pet else {
+        panic!("expected tagged union `Pet`; got `{pet:?}`");
+    };
+    assert_eq!(pet_tagged.tag(), "kind");
+}
+
 #[test]
 fn test_transitive_dependency_field_matching_tag_is_not_tag() {
     // `Inner` has a `kind` field that matches the `Outer` tagged union's
@@ -1,18 +1,30 @@
-use std::num::NonZeroUsize;
+use std::{cell::RefCell, num::NonZeroUsize};
 
 use itertools::Itertools;
+use ploidy_pointer::JsonPointerBuf;
 use rustc_hash::FxHashMap;
+use serde::{
+    Deserialize,
+    de::{IntoDeserializer, value::Error as DeError},
+};
+use serde_json::Value as JsonValue;
 
 use crate::{
     arena::Arena,
     ir::{JsonF64, SchemaTypeInfo},
-    parse::{AdditionalProperties, Document, Format, RefOrSchema, Schema, Ty},
+    parse::{AdditionalProperties, Document, Format, Items, RefOrSchema, Schema, Ty},
 };
 
-use super::types::{
-    Enum, EnumVariant, InlineTypeId, InlineTypeIds, PrimitiveType, SpecContainer, SpecInlineType,
-    SpecInner, SpecSchemaType, SpecStruct, SpecStructField, SpecTagged, SpecTaggedVariant,
-    SpecType, SpecUntagged, StructFieldName,
+use super::{
+    problem::{Problem, ProblemKind},
+    spec::SpecConfig,
+    types::{
+        Enum, EnumVariant, FieldConstraints, FieldDefault, InlineTypeId, InlineTypeIds,
+        PrimitiveType, SpecContainer, SpecInlineType, SpecInner, SpecSchemaType, SpecStruct,
+        SpecStructField, SpecTagged, SpecTaggedVariant, SpecTuple, SpecType, SpecUntagged,
+        StructFieldName,
+    },
+    unsupported::{UnsupportedFeature, UnsupportedFeatureKind},
 };
 
 /// Metadata about a type in the dependency graph.
@@ -40,27 +52,86 @@ pub struct TransformContext<'a> {
     arena: &'a Arena,
     doc: &'a Document,
     ids: InlineTypeIds<'a>,
+    config: SpecConfig,
+    custom_formats: FxHashMap<Format, &'a str>,
+    unsupported: RefCell<Vec<UnsupportedFeature>>,
+    problems: RefCell<Vec<Problem>>,
 }
 
 impl<'a> TransformContext<'a> {
     /// Creates a new context for the given document.
-    pub fn new(arena: &'a Arena, doc: &'a Document, ids: InlineTypeIds<'a>) -> Self {
-        Self { arena, doc, ids }
+    pub fn new(
+        arena: &'a Arena,
+        doc: &'a Document,
+        ids: InlineTypeIds<'a>,
+        config: SpecConfig,
+    ) -> Self {
+        let custom_formats = config
+            .custom_formats
+            .iter()
+            .map(|name| {
+                // Deserialize each name, so that recognized formats
+                // and their aliases match, too. Unrecognized names
+                // deserialize as `Format::Other`, so this can't fail.
+                let format: Result<Format, DeError> =
+                    Format::deserialize(name.as_str().into_deserializer());
+                (format.unwrap(), &*arena.alloc_str(name))
+            })
+            .collect();
+        Self {
+            arena,
+            doc,
+            ids,
+            config,
+            custom_formats,
+            unsupported: RefCell::default(),
+            problems: RefCell::default(),
+        }
+    }
+
+    /// Records an unsupported construct that the transformer dropped,
+    /// or replaced with a fallback.
+    pub fn unsupported(&self, pointer: JsonPointerBuf, kind: UnsupportedFeatureKind) {
+        self.unsupported
+            .borrow_mut()
+            .push(UnsupportedFeature { pointer, kind });
+    }
+
+    /// Records a construct that makes the document invalid.
+    pub fn problem(&self, pointer: JsonPointerBuf, kind: ProblemKind) {
+        self.problems.borrow_mut().push(Problem { pointer, kind });
+    }
+
+    /// Returns `true` if `components/schemas` has a schema named `name`.
+    pub fn has_schema(&self, name: &str) -> bool {
+        self.doc
+            .components
+            .as_ref()
+            .is_some_and(|components| components.schemas.contains_key(name))
+    }
+
+    /// Consumes this context, returning all the recorded
+    /// unsupported constructs and problems.
+    pub fn into_diagnostics(self) -> (Vec<UnsupportedFeature>, Vec<Problem>) {
+        (self.unsupported.into_inner(), self.problems.into_inner())
     }
 }
 
+/// Lowers a schema at the given location in the document.
 pub(super) fn transform_with_context<'context, 'a>(
     context: &'context TransformContext<'a>,
     name: impl Into<TypeInfo<'a>>,
+    pointer: JsonPointerBuf,
     schema: &'a Schema,
 ) -> SpecType<'a> {
-    IrTransformer::new(context, name.into(), schema).transform()
+    IrTransformer::new(context, name.into(), pointer, schema).transform()
 }
 
 #[derive(Debug)]
 struct IrTransformer<'context, 'a> {
     context: &'context TransformContext<'a>,
     name: TypeInfo<'a>,
+    pointer: JsonPointerBuf,
     schema: &'a Schema,
 }
 
@@ -68,11 +139,13 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
     fn new(
         context: &'context TransformContext<'a>,
         name: TypeInfo<'a>,
+        pointer: JsonPointerBuf,
         schema: &'a Schema,
     ) -> Self {
         Self {
             context,
             name,
+            pointer,
             schema,
         }
     }
@@ -83,8 +156,38 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
         self.context.arena
     }
 
+    /// Returns a pointer to a nested location in this schema.
+    fn pointer_to(&self, segments: &[&str]) -> JsonPointerBuf {
+        segments
+            .iter()
+            .fold(self.pointer.clone(), |pointer, segment| {
+                pointer.join(segment)
+            })
+    }
+
     fn transform(self) -> SpecType<'a> {
-        self.try_tagged()
+        // Record constructs that the lowering below drops.
+        if self.schema.extensions.contains_key("not") {
+            self.context
+                .unsupported(self.pointer_to(&["not"]), UnsupportedFeatureKind::Not);
+        }
+        if let Some(format @ Format::Other(_)) = &self.schema.format
+            && !self.context.custom_formats.contains_key(format)
+        {
+            self.context.unsupported(
+                self.pointer_to(&["format"]),
+                UnsupportedFeatureKind::UnknownFormat,
+            );
+        }
+        if self.schema.one_of.is_some() && self.schema.any_of.is_some() {
+            self.context.unsupported(
+                self.pointer_to(&["anyOf"]),
+                UnsupportedFeatureKind::AnyOfWithOneOf,
+            );
+        }
+
+        self.try_nullable_ref()
+            .or_else(Self::try_tagged)
             .or_else(Self::try_untagged)
             .or_else(Self::try_any_of)
             .or_else(Self::try_enum)
@@ -92,12 +195,52 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             .unwrap_or_else(Self::other)
     }
 
+    /// Lowers a `nullable` schema whose only content is a single-`$ref`
+    /// `allOf`, the OpenAPI 3.0 idiom for a nullable reference,
+    /// to an optional reference.
+    fn try_nullable_ref(self) -> Result<SpecType<'a>, Self> {
+        let Some([RefOrSchema::Ref(r)]) = self.schema.all_of.as_deref() else {
+            return Err(self);
+        };
+        let only = self.schema.nullable
+            && matches!(&*self.schema.ty, [] | [Ty::Object])
+            && self.schema.properties.is_none()
+            && self.schema.additional_properties.is_none()
+            && self.schema.pattern_properties.is_none()
+            && self.schema.items.is_none()
+            && self.schema.prefix_items.is_none()
+            && self.schema.variants.is_none()
+            && self.schema.constant.is_none()
+            && self.schema.one_of.is_none()
+            && self.schema.any_of.is_none();
+        if !only {
+            return Err(self);
+        }
+        let container = SpecContainer::Optional(SpecInner {
+            description: self.schema.description.as_deref(),
+            ty: self.arena().alloc(SpecType::Ref(r)),
+        });
+        Ok(match self.name {
+            TypeInfo::Schema(info) => SpecSchemaType::Container(info, container).into(),
+            TypeInfo::Inline(id) => SpecInlineType::Container(id, container).into(),
+        })
+    }
+
     fn try_tagged(self) -> Result<SpecType<'a>, Self> {
         let (Some(one_of), Some(discriminator)) = (&self.schema.one_of, &self.schema.discriminator)
         else {
             return Err(self);
         };
 
+        for (tag, r) in &discriminator.mapping {
+            if !self.context.has_schema(&r.name()) {
+                self.context.problem(
+                    self.pointer_to(&["discriminator", "mapping", tag]),
+                    ProblemKind::UnresolvedMapping,
+                );
+            }
+        }
+
         let variants = {
             let mut inverted = FxHashMap::<_, Vec<_>>::default();
             for (tag, r) in &discriminator.mapping {
@@ -122,7 +265,13 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                     }
                     // An inline schema variant can't have a discriminator mapping;
                     // fall through to `try_untagged`.
-                    RefOrSchema::Inline(_) => return Err(self),
+                    RefOrSchema::Inline(_) => {
+                        self.context.unsupported(
+                            self.pointer_to(&["discriminator"]),
+                            UnsupportedFeatureKind::InlineTaggedVariant,
+                        );
+                        return Err(self);
+                    }
                 }
             }
             variants
@@ -162,20 +311,25 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                         TypeInfo::Schema(info) => SpecSchemaType::Any(info).into(),
                         TypeInfo::Inline(id) => SpecInlineType::Any(id).into(),
                     },
-                    RefOrSchema::Inline(schema) => {
-                        transform_with_context(self.context, self.name, schema)
-                    }
+                    RefOrSchema::Inline(schema) => transform_with_context(
+                        self.context,
+                        self.name,
+                        self.pointer_to(&["oneOf", "0"]),
+                        schema,
+                    ),
                 });
             }
             variants => variants
                 .iter()
-                .map(|schema| {
+                .enumerate()
+                .map(|(index, schema)| {
                     let ty = match schema {
                         RefOrSchema::Ref(r) => Some(SpecType::Ref(r)),
                         RefOrSchema::Inline(s) if matches!(&*s.ty, [Ty::Null]) => None,
                         RefOrSchema::Inline(schema) => {
                             let id = self.context.ids.next();
-                            Some(transform_with_context(self.context, id, schema))
+                            let pointer = self.pointer_to(&["oneOf", &index.to_string()]);
+                            Some(transform_with_context(self.context, id, pointer, schema))
                         }
                     };
                     ty.map(|ty| &*self.arena().alloc(ty))
@@ -221,9 +375,12 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             // preserves type references that would otherwise become `Any`.
             return Ok(match schema {
                 RefOrSchema::Ref(r) => SpecType::Ref(r),
-                RefOrSchema::Inline(schema) => {
-                    transform_with_context(self.context, self.name, schema)
-                }
+                RefOrSchema::Inline(schema) => transform_with_context(
+                    self.context,
+                    self.name,
+                    self.pointer_to(&["anyOf", "0"]),
+                    schema,
+                ),
             });
         }
 
@@ -246,9 +403,13 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                     RefOrSchema::Inline(schema) => {
                         let name = StructFieldName::Ordinal(ordinal);
                         let id = self.context.ids.next();
-                        let ty: &_ =
-                            self.arena()
-                                .alloc(transform_with_context(self.context, id, schema));
+                        let pointer = self.pointer_to(&["anyOf", &index.to_string()]);
+                        let ty: &_ = self.arena().alloc(transform_with_context(
+                            self.context,
+                            id,
+                            pointer,
+                            schema,
+                        ));
                         let desc = schema.description.as_deref();
                         (name, ty, desc)
                     }
@@ -268,6 +429,11 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                     required: false,
                     description,
                     flattened: true,
+                    default: None,
+                    deprecated: false,
+                    read_only: false,
+                    write_only: false,
+                    constraints: FieldConstraints::default(),
                 }
             })
             .collect_vec();
@@ -282,6 +448,7 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                 itertools::chain!(self.properties(), any_of_fields)
             }),
             parents: self.arena().alloc_slice(self.parents()),
+            closed: self.closed(true),
         };
 
         Ok(match self.name {
@@ -291,34 +458,59 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
     }
 
     fn try_enum(self) -> Result<SpecType<'a>, Self> {
-        let Some(values) = &self.schema.variants else {
-            return Err(self);
+        // A `const` is an enum with a single value.
+        let values = match (&self.schema.variants, &self.schema.constant) {
+            (Some(values), _) => values.as_slice(),
+            (None, Some(value)) => std::slice::from_ref(value),
+            (None, None) => return Err(self),
         };
+        // `x-enum-varnames` and `x-enumNames` name the variants
+        // at the same positions in `enum`.
+        let names: Vec<&str> = self
+            .schema
+            .extension("x-enum-varnames")
+            .or_else(|| self.schema.extension("x-enumNames"))
+            .unwrap_or_default();
         // JSON Schema Validation (draft-bhutton-json-schema-validation-01)
         // recommends unique enum values, but specs in the wild repeat values.
-        let variants = self.arena().alloc_slice(
-            values
-                .iter()
-                .filter_map(|value| {
-                    if let Some(s) = value.as_str() {
-                        Some(EnumVariant::String(s))
-                    } else if let Some(n) = value.as_number() {
-                        if let Some(n) = n.as_i64() {
-                            Some(EnumVariant::I64(n))
-                        } else if let Some(n) = n.as_u64() {
-                            Some(EnumVariant::U64(n))
-                        } else {
-                            n.as_f64().map(|f| EnumVariant::F64(JsonF64::new(f)))
-                        }
+        let mut default = None;
+        let (variants, names): (Vec<_>, Vec<_>) = values
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| {
+                let variant = if let Some(s) = value.as_str() {
+                    EnumVariant::String(s)
+                } else if let Some(n) = value.as_number() {
+                    if let Some(n) = n.as_i64() {
+                        EnumVariant::I64(n)
+                    } else if let Some(n) = n.as_u64() {
+                        EnumVariant::U64(n)
                     } else {
-                        value.as_bool().map(EnumVariant::Bool)
+                        EnumVariant::F64(JsonF64::new(n.as_f64()?))
                     }
-                })
-                .unique(),
-        );
+                } else {
+                    EnumVariant::Bool(value.as_bool()?)
+                };
+                if self.schema.default.as_ref() == Some(value) {
+                    default = Some(variant);
+                }
+                let name = names.get(index).copied().filter(|name| !name.is_empty());
+                Some((variant, name))
+            })
+            .unique_by(|&(variant, _)| variant)
+            .unzip();
+        // A `null` default belongs to a nullable enum, not a variant.
+        if default.is_none() && self.schema.default.as_ref().is_some_and(|d| !d.is_null()) {
+            self.context.unsupported(
+                self.pointer_to(&["default"]),
+                UnsupportedFeatureKind::UnknownEnumDefault,
+            );
+        }
         let ty = Enum {
             description: self.schema.description.as_deref(),
-            variants,
+            variants: self.arena().alloc_slice_copy(&variants),
+            names: self.arena().alloc_slice_copy(&names),
+            default,
         };
         Ok(match self.name {
             TypeInfo::Schema(info) => SpecSchemaType::Enum(info, ty).into(),
@@ -327,7 +519,10 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
     }
 
     fn try_struct(self) -> Result<SpecType<'a>, Self> {
-        if self.schema.properties.is_none() && self.schema.all_of.is_none() {
+        if self.schema.properties.is_none()
+            && self.schema.pattern_properties.is_none()
+            && self.schema.all_of.is_none()
+        {
             return Err(self);
         }
 
@@ -335,9 +530,11 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             description: self.schema.description.as_deref(),
             fields: self.arena().alloc_slice(itertools::chain!(
                 self.properties(),
+                self.pattern_properties(),
                 self.additional_properties()
             )),
             parents: self.arena().alloc_slice(self.parents()),
+            closed: self.closed(self.schema.pattern_properties.is_some()),
         };
         Ok(match self.name {
             TypeInfo::Schema(info) => SpecSchemaType::Struct(info, ty).into(),
@@ -351,8 +548,21 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             nullable: false,
         };
 
+        // Custom formats take precedence over recognized formats.
+        let custom = self
+            .schema
+            .format
+            .as_ref()
+            .and_then(|format| self.context.custom_formats.get(format));
+
         for ty in &self.schema.ty {
-            let variant = match (ty, self.schema.format) {
+            if let (Ty::String, Some(&format)) = (ty, custom) {
+                other
+                    .variants
+                    .push(OtherVariant::Primitive(PrimitiveType::Custom(format)));
+                continue;
+            }
+            let variant = match (ty, &self.schema.format) {
                 (Ty::String, Some(Format::DateTime)) => {
                     OtherVariant::Primitive(PrimitiveType::DateTime)
                 }
@@ -363,6 +573,12 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                 (Ty::String, Some(Format::Binary)) => {
                     OtherVariant::Primitive(PrimitiveType::Binary)
                 }
+                (Ty::String, Some(Format::Decimal | Format::Money)) => {
+                    OtherVariant::Primitive(PrimitiveType::Decimal)
+                }
+                (Ty::String, Some(Format::Other(format))) => {
+                    OtherVariant::Primitive(PrimitiveType::Custom(format))
+                }
                 (Ty::String, _) => OtherVariant::Primitive(PrimitiveType::String),
 
                 (Ty::Integer, Some(Format::Int8)) => OtherVariant::Primitive(PrimitiveType::I8),
@@ -376,10 +592,34 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                 (Ty::Integer, Some(Format::UnixTime)) => {
                     OtherVariant::Primitive(PrimitiveType::UnixTime)
                 }
+                (Ty::Integer, None) if self.context.config.narrow_integers => {
+                    // Use the narrowest integer type narrower than `i32` that
+                    // holds every value between the `minimum` and `maximum`.
+                    let ty = self
+                        .schema
+                        .minimum
+                        .zip(self.schema.maximum)
+                        .and_then(|(min, max)| {
+                            let (min, max) = (min.ceil(), max.floor());
+                            [
+                                (PrimitiveType::U8, f64::from(u8::MIN), f64::from(u8::MAX)),
+                                (PrimitiveType::I8, f64::from(i8::MIN), f64::from(i8::MAX)),
+                                (PrimitiveType::U16, f64::from(u16::MIN), f64::from(u16::MAX)),
+                                (PrimitiveType::I16, f64::from(i16::MIN), f64::from(i16::MAX)),
+                            ]
+                            .into_iter()
+                            .find(|&(_, lo, hi)| lo <= min && max <= hi)
+                        })
+                        .map_or(PrimitiveType::I32, |(ty, ..)| ty);
+                    OtherVariant::Primitive(ty)
+                }
                 (Ty::Integer, _) => OtherVariant::Primitive(PrimitiveType::I32),
 
                 (Ty::Number, Some(Format::Float)) => OtherVariant::Primitive(PrimitiveType::F32),
                 (Ty::Number, Some(Format::Double)) => OtherVariant::Primitive(PrimitiveType::F64),
+                (Ty::Number, Some(Format::Decimal | Format::Money)) => {
+                    OtherVariant::Primitive(PrimitiveType::Decimal)
+                }
                 (Ty::Number, Some(Format::UnixTime)) => {
                     OtherVariant::Primitive(PrimitiveType::UnixTime)
                 }
@@ -387,56 +627,106 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
 
                 (Ty::Boolean, _) => OtherVariant::Primitive(PrimitiveType::Bool),
 
-                (Ty::Array, _) => {
-                    let items = match &self.schema.items {
-                        Some(RefOrSchema::Ref(r)) => SpecType::Ref(r),
-                        Some(RefOrSchema::Inline(schema)) => {
-                            let id = self.context.ids.next();
-                            transform_with_context(self.context, id, schema)
-                        }
-                        None => {
-                            let id = self.context.ids.next();
-                            SpecInlineType::Any(id).into()
+                (Ty::Array, _) => match &self.schema.prefix_items {
+                    Some(prefix_items) => {
+                        let elements = prefix_items
+                            .iter()
+                            .enumerate()
+                            .map(|(index, schema)| {
+                                let pointer = self.pointer_to(&["prefixItems", &index.to_string()]);
+                                &*self.arena().alloc(self.item(Some(schema), pointer))
+                            })
+                            .collect_vec();
+                        // `items` alongside `prefixItems` describes the elements
+                        // after the positional ones. Without `items`, or with
+                        // `items: false`, the tuple doesn't allow extra elements.
+                        let rest = match &self.schema.items {
+                            Some(Items::RefOrSchema(schema)) => {
+                                let pointer = self.pointer_to(&["items"]);
+                                Some(self.item(Some(schema), pointer))
+                            }
+                            Some(Items::Bool(true)) => {
+                                let pointer = self.pointer_to(&["items"]);
+                                Some(self.item(None, pointer))
+                            }
+                            Some(Items::Bool(false)) | None => None,
+                        };
+                        OtherVariant::Tuple(SpecTuple {
+                            description: self.schema.description.as_deref(),
+                            elements: self.arena().alloc_slice_copy(&elements),
+                            rest: rest.map(|ty| &*self.arena().alloc(ty)),
+                        })
+                    }
+                    None => {
+                        let items = match &self.schema.items {
+                            Some(Items::RefOrSchema(schema)) => Some(schema),
+                            Some(Items::Bool(_)) | None => None,
+                        };
+                        let inner = SpecInner {
+                            description: self.schema.description.as_deref(),
+                            ty: self
+                                .arena()
+                                .alloc(self.item(items, self.pointer_to(&["items"]))),
+                        };
+                        if self.schema.unique_items {
+                            OtherVariant::Set(inner)
+                        } else {
+                            OtherVariant::Array(inner)
                         }
-                    };
-                    OtherVariant::Array(SpecInner {
-                        description: self.schema.description.as_deref(),
-                        ty: self.arena().alloc(items),
-                    })
-                }
+                    }
+                },
 
                 (Ty::Object, _) => {
-                    let inner = match &self.schema.additional_properties {
-                        Some(AdditionalProperties::RefOrSchema(RefOrSchema::Ref(r))) => {
-                            Some(SpecInner {
-                                description: self.schema.description.as_deref(),
-                                ty: self.arena().alloc(SpecType::Ref(r)),
-                            })
-                        }
+                    let values = match &self.schema.additional_properties {
+                        Some(AdditionalProperties::RefOrSchema(RefOrSchema::Ref(r))) => Some((
+                            SpecType::Ref(r),
+                            r.pointer()
+                                .follow::<&Schema>(self.context.doc)
+                                .is_ok_and(|schema| schema.nullable),
+                        )),
                         Some(AdditionalProperties::RefOrSchema(RefOrSchema::Inline(schema))) => {
                             let id = self.context.ids.next();
-                            Some(SpecInner {
-                                description: self.schema.description.as_deref(),
-                                ty: self.arena().alloc(transform_with_context(
-                                    self.context,
-                                    id,
-                                    schema,
-                                )),
-                            })
+                            let pointer = self.pointer_to(&["additionalProperties"]);
+                            let ty = transform_with_context(self.context, id, pointer, schema);
+                            // Nullable references already lower to `Optional`.
+                            let nullable = schema.nullable
+                                && !matches!(
+                                    ty,
+                                    SpecType::Inline(SpecInlineType::Container(
+                                        _,
+                                        SpecContainer::Optional(_)
+                                    ))
+                                );
+                            Some((ty, nullable))
                         }
                         Some(AdditionalProperties::Bool(true)) => {
                             let id = self.context.ids.next();
-                            Some(SpecInner {
-                                description: self.schema.description.as_deref(),
-                                ty: self
-                                    .arena()
-                                    .alloc(SpecType::Inline(SpecInlineType::Any(id))),
-                            })
+                            Some((SpecType::Inline(SpecInlineType::Any(id)), false))
                         }
                         _ => None,
                     };
-                    match inner {
-                        Some(inner) => OtherVariant::Map(inner),
+                    match values {
+                        Some((values, nullable)) => {
+                            // Nullable values are wrapped in `Optional`,
+                            // like nullable array items.
+                            let values = if nullable {
+                                let id = self.context.ids.next();
+                                SpecInlineType::Container(
+                                    id,
+                                    SpecContainer::Optional(SpecInner {
+                                        description: None,
+                                        ty: self.arena().alloc(values),
+                                    }),
+                                )
+                                .into()
+                            } else {
+                                values
+                            };
+                            OtherVariant::Map(SpecInner {
+                                description: self.schema.description.as_deref(),
+                                ty: self.arena().alloc(values),
+                            })
+                        }
                         None => OtherVariant::Any,
                     }
                 }
@@ -516,6 +806,50 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
 
     // MARK: Shared lowering
 
+    /// Lowers an array item or tuple element schema, wrapping
+    /// nullable items in [`Container::Optional`]. A missing schema
+    /// lowers to any JSON value.
+    fn item(&self, schema: Option<&'a RefOrSchema>, pointer: JsonPointerBuf) -> SpecType<'a> {
+        let (ty, nullable) = match schema {
+            Some(RefOrSchema::Ref(r)) => (
+                SpecType::Ref(r),
+                r.pointer()
+                    .follow::<&Schema>(self.context.doc)
+                    .is_ok_and(|schema| schema.nullable),
+            ),
+            Some(RefOrSchema::Inline(schema)) => {
+                let id = self.context.ids.next();
+                let ty = transform_with_context(self.context, id, pointer, schema);
+                // Nullable references already lower to `Optional`.
+                let nullable = schema.nullable
+                    && !matches!(
+                        ty,
+                        SpecType::Inline(SpecInlineType::Container(_, SpecContainer::Optional(_)))
+                    );
+                (ty, nullable)
+            }
+            None => {
+                let id = self.context.ids.next();
+                (SpecInlineType::Any(id).into(), false)
+            }
+        };
+        // Like struct fields, nullable items are wrapped in
+        // `Optional`, independently of the array's own nullability.
+        if nullable {
+            let id = self.context.ids.next();
+            SpecInlineType::Container(
+                id,
+                SpecContainer::Optional(SpecInner {
+                    description: None,
+                    ty: self.arena().alloc(ty),
+                }),
+            )
+            .into()
+        } else {
+            ty
+        }
+    }
+
     /// Lowers immediate parents from `allOf` into a list of types.
     fn parents(&self) -> impl Iterator<Item = &'a SpecType<'a>> {
         self.schema
@@ -523,13 +857,13 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             .iter()
             .flatten()
             .enumerate()
-            .map(|(index, parent)| (index + 1, parent))
-            .map(move |(_index, parent)| &*match parent {
+            .map(move |(index, parent)| &*match parent {
                 RefOrSchema::Ref(r) => self.arena().alloc(SpecType::Ref(r)),
                 RefOrSchema::Inline(schema) => {
                     let id = self.context.ids.next();
+                    let pointer = self.pointer_to(&["allOf", &index.to_string()]);
                     self.arena()
-                        .alloc(transform_with_context(self.context, id, schema))
+                        .alloc(transform_with_context(self.context, id, pointer, schema))
                 }
             })
     }
@@ -543,13 +877,29 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             .flatten()
             .map(move |(name, field_schema)| {
                 let field_name = name.as_str();
-                let required = self.schema.required.contains(name);
+                let (read_only, write_only) = match field_schema {
+                    RefOrSchema::Inline(schema) => (schema.read_only, schema.write_only),
+                    RefOrSchema::Ref(r) => r
+                        .pointer()
+                        .follow::<&Schema>(self.context.doc)
+                        .map(|schema| (schema.read_only, schema.write_only))
+                        .unwrap_or_default(),
+                };
+                // A required `readOnly` field is only required in responses,
+                // and a required `writeOnly` field only in requests. Since
+                // requests and responses share a type, the field is optional.
+                let required = self.schema.required.contains(name) && !read_only && !write_only;
                 let ty: &_ = match field_schema {
                     RefOrSchema::Ref(r) => self.arena().alloc(SpecType::Ref(r)),
                     RefOrSchema::Inline(schema) => {
                         let id = self.context.ids.next();
-                        self.arena()
-                            .alloc(transform_with_context(self.context, id, schema))
+                        let pointer = self.pointer_to(&["properties", field_name]);
+                        self.arena().alloc(transform_with_context(
+                            self.context,
+                            id,
+                            pointer,
+                            schema,
+                        ))
                     }
                 };
                 let description = match field_schema {
@@ -561,7 +911,11 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                         .and_then(|schema| schema.description.as_deref()),
                 };
                 let nullable = match field_schema {
-                    RefOrSchema::Inline(schema) if schema.nullable => true,
+                    // Nullable references already lower to `Optional`.
+                    RefOrSchema::Inline(schema) if schema.nullable => !matches!(
+                        ty,
+                        SpecType::Inline(SpecInlineType::Container(_, SpecContainer::Optional(_)))
+                    ),
                     RefOrSchema::Ref(r) => r
                         .pointer()
                         .follow::<&Schema>(self.context.doc)
@@ -580,14 +934,194 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
                 } else {
                     ty
                 };
+                // Only scalar defaults are supported; array and
+                // object defaults are dropped.
+                let default = match field_schema {
+                    RefOrSchema::Inline(schema) => match &schema.default {
+                        Some(JsonValue::String(s)) => Some(FieldDefault::String(s)),
+                        Some(JsonValue::Number(n)) => n
+                            .as_i64()
+                            .map(FieldDefault::I64)
+                            .or_else(|| n.as_u64().map(FieldDefault::U64))
+                            .or_else(|| n.as_f64().map(|f| FieldDefault::F64(JsonF64::new(f)))),
+                        Some(JsonValue::Bool(b)) => Some(FieldDefault::Bool(*b)),
+                        Some(JsonValue::Array(_) | JsonValue::Object(_)) => {
+                            self.context.unsupported(
+                                self.pointer_to(&["properties", field_name, "default"]),
+                                UnsupportedFeatureKind::CompositeDefault,
+                            );
+                            None
+                        }
+                        Some(JsonValue::Null) | None => None,
+                    },
+                    RefOrSchema::Ref(_) => None,
+                };
+                // A deprecated `$ref` marks the referenced type
+                // as deprecated, not the field.
+                let deprecated = match field_schema {
+                    RefOrSchema::Inline(schema) => schema.deprecated,
+                    RefOrSchema::Ref(_) => false,
+                };
+                // Like `default`, constraints on a `$ref` belong to
+                // the referenced schema, not the field.
+                let constraints = match field_schema {
+                    RefOrSchema::Inline(schema) => FieldConstraints {
+                        minimum: schema.minimum.filter(|f| !f.is_nan()).map(JsonF64::new),
+                        maximum: schema.maximum.filter(|f| !f.is_nan()).map(JsonF64::new),
+                        min_length: schema.min_length,
+                        max_length: schema.max_length,
+                        min_items: schema.min_items,
+                        max_items: schema.max_items,
+                        min_properties: schema.min_properties,
+                        max_properties: schema.max_properties,
+                    },
+                    RefOrSchema::Ref(_) => FieldConstraints::default(),
+                };
                 SpecStructField {
                     name: StructFieldName::Name(field_name),
                     ty,
                     required,
                     description,
                     flattened: false,
+                    default,
+                    deprecated,
+                    read_only,
+                    write_only,
+                    constraints,
+                }
+            })
+    }
+
+    /// Lowers `patternProperties` into a struct field definition,
+    /// if the schema specifies them.
+    ///
+    /// Values for all patterns share one map. If the patterns'
+    /// schemas differ, the map's values are an untagged union.
+    fn pattern_properties(&self) -> Option<SpecStructField<'a>> {
+        let pattern_properties = self.schema.pattern_properties.as_ref()?;
+        if matches!(
+            self.schema.additional_properties,
+            Some(AdditionalProperties::Bool(true) | AdditionalProperties::RefOrSchema(_))
+        ) {
+            // Both maps would be flattened into the struct,
+            // and would see each other's keys.
+            self.context.unsupported(
+                self.pointer_to(&["patternProperties"]),
+                UnsupportedFeatureKind::PatternPropertiesWithAdditionalProperties,
+            );
+            return None;
+        }
+
+        let (patterns, values): (Vec<_>, Vec<&SpecType<'_>>) = pattern_properties
+            .iter()
+            .filter(|(pattern, _)| {
+                let valid = regex_syntax::parse(pattern).is_ok();
+                if !valid {
+                    self.context.unsupported(
+                        self.pointer_to(&["patternProperties", pattern]),
+                        UnsupportedFeatureKind::InvalidPattern,
+                    );
                 }
+                valid
+            })
+            .map(|(pattern, value)| {
+                let ty: &_ = match value {
+                    RefOrSchema::Ref(r) => self.arena().alloc(SpecType::Ref(r)),
+                    RefOrSchema::Inline(schema) => {
+                        let id = self.context.ids.next();
+                        let pointer = self.pointer_to(&["patternProperties", pattern]);
+                        self.arena().alloc(transform_with_context(
+                            self.context,
+                            id,
+                            pointer,
+                            schema,
+                        ))
+                    }
+                };
+                (pattern.as_str(), ty)
             })
+            .unzip();
+
+        let variants = values
+            .into_iter()
+            .fold(vec![], |mut variants: Vec<&SpecType<'_>>, ty| {
+                // Compare inline primitives and `any` types without their IDs,
+                // so that patterns with identical value schemas share a type.
+                let seen = variants.iter().any(|&other| match (other, ty) {
+                    (
+                        SpecType::Inline(SpecInlineType::Primitive(_, a)),
+                        SpecType::Inline(SpecInlineType::Primitive(_, b)),
+                    ) => a == b,
+                    (
+                        SpecType::Inline(SpecInlineType::Any(_)),
+                        SpecType::Inline(SpecInlineType::Any(_)),
+                    ) => true,
+                    (a, b) => a == b,
+                });
+                if !seen {
+                    variants.push(ty);
+                }
+                variants
+            });
+        let ty: &_ = match &*variants {
+            [] => return None,
+            &[ty] => ty,
+            variants => {
+                let id = self.context.ids.next();
+                self.arena().alloc(SpecType::from(SpecInlineType::Untagged(
+                    id,
+                    SpecUntagged {
+                        description: None,
+                        variants: self
+                            .arena()
+                            .alloc_slice(variants.iter().map(|&ty| Some(ty))),
+                        fields: &[],
+                        parents: &[],
+                    },
+                )))
+            }
+        };
+
+        let map_id = self.context.ids.next();
+        let ty: &_ = self.arena().alloc(SpecType::from(SpecInlineType::Container(
+            map_id,
+            SpecContainer::Map(SpecInner {
+                description: self.schema.description.as_deref(),
+                ty,
+            }),
+        )));
+
+        Some(SpecStructField {
+            name: StructFieldName::PatternProperties(self.arena().alloc_slice_copy(&patterns)),
+            ty,
+            required: true,
+            description: None,
+            flattened: true,
+            default: None,
+            deprecated: false,
+            read_only: false,
+            write_only: false,
+            constraints: FieldConstraints::default(),
+        })
+    }
+
+    /// Returns `true` if the schema forbids undeclared properties,
+    /// with `additionalProperties: false`.
+    ///
+    /// A struct with `flattened` fields can't reject unknown properties,
+    /// so this also records the combination as unsupported.
+    fn closed(&self, flattened: bool) -> bool {
+        let closed = matches!(
+            self.schema.additional_properties,
+            Some(AdditionalProperties::Bool(false))
+        );
+        if closed && flattened {
+            self.context.unsupported(
+                self.pointer_to(&["additionalProperties"]),
+                UnsupportedFeatureKind::ClosedWithFlattenedFields,
+            );
+        }
+        closed
     }
 
     /// Lowers `additionalProperties` into a struct field definition,
@@ -600,11 +1134,15 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             },
             Some(AdditionalProperties::RefOrSchema(RefOrSchema::Inline(schema))) => {
                 let id = self.context.ids.next();
+                let pointer = self.pointer_to(&["additionalProperties"]);
                 SpecInner {
                     description: self.schema.description.as_deref(),
-                    ty: self
-                        .arena()
-                        .alloc(transform_with_context(self.context, id, schema)),
+                    ty: self.arena().alloc(transform_with_context(
+                        self.context,
+                        id,
+                        pointer,
+                        schema,
+                    )),
                 }
             }
             Some(AdditionalProperties::Bool(true)) => {
@@ -631,6 +1169,11 @@ impl<'context, 'a> IrTransformer<'context, 'a> {
             required: true,
             description: None,
             flattened: true,
+            default: None,
+            deprecated: false,
+            read_only: false,
+            write_only: false,
+            constraints: FieldConstraints::default(),
         })
     }
 }
@@ -645,9 +1188,11 @@ struct Other<'a> {
 /// A variant of an [`Other`] union.
 #[derive(Clone, Copy)]
 enum OtherVariant<'a> {
-    Primitive(PrimitiveType),
+    Primitive(PrimitiveType<'a>),
     Array(SpecInner<'a>),
+    Set(SpecInner<'a>),
     Map(SpecInner<'a>),
+    Tuple(SpecTuple<'a>),
     Any,
 }
 
@@ -657,7 +1202,9 @@ impl<'a> OtherVariant<'a> {
         match self {
             Self::Primitive(p) => SpecSchemaType::Primitive(info, p),
             Self::Array(inner) => SpecSchemaType::Container(info, SpecContainer::Array(inner)),
+            Self::Set(inner) => SpecSchemaType::Container(info, SpecContainer::Set(inner)),
             Self::Map(inner) => SpecSchemaType::Container(info, SpecContainer::Map(inner)),
+            Self::Tuple(tuple) => SpecSchemaType::Tuple(info, tuple),
             Self::Any => SpecSchemaType::Any(info),
         }
     }
@@ -667,7 +1214,9 @@ impl<'a> OtherVariant<'a> {
         match self {
             Self::Primitive(p) => SpecInlineType::Primitive(id, p),
             Self::Array(inner) => SpecInlineType::Container(id, SpecContainer::Array(inner)),
+            Self::Set(inner) => SpecInlineType::Container(id, SpecContainer::Set(inner)),
             Self::Map(inner) => SpecInlineType::Container(id, SpecContainer::Map(inner)),
+            Self::Tuple(tuple) => SpecInlineType::Tuple(id, tuple),
             Self::Any => SpecInlineType::Any(id),
         }
     }
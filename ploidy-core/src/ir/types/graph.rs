@@ -5,7 +5,8 @@ use std::num::NonZeroUsize;
 use petgraph::graph::NodeIndex;
 
 use super::{
-    Enum, InlineTypeId, PrimitiveType, SchemaTypeInfo, StructFieldName,
+    Enum, FieldConstraints, FieldDefault, InlineTypeId, PrimitiveType, SchemaTypeInfo,
+    StructFieldName,
     shape::{Operation, Parameter, ParameterInfo, Request, Response},
     spec::{SpecContainer, SpecInlineType, SpecSchemaType},
 };
@@ -28,10 +29,12 @@ pub enum GraphSchemaType<'a> {
     Tagged(SchemaTypeInfo<'a>, GraphTagged<'a>),
     /// An untagged union.
     Untagged(SchemaTypeInfo<'a>, GraphUntagged<'a>),
+    /// A tuple with positional elements.
+    Tuple(SchemaTypeInfo<'a>, GraphTuple<'a>),
     /// A named container.
     Container(SchemaTypeInfo<'a>, GraphContainer<'a>),
     /// A primitive type.
-    Primitive(SchemaTypeInfo<'a>, PrimitiveType),
+    Primitive(SchemaTypeInfo<'a>, PrimitiveType<'a>),
     /// Any JSON value.
     Any(SchemaTypeInfo<'a>),
 }
@@ -44,6 +47,7 @@ impl<'a> GraphSchemaType<'a> {
         | Self::Struct(info, ..)
         | Self::Tagged(info, ..)
         | Self::Untagged(info, ..)
+        | Self::Tuple(info, ..)
         | Self::Container(info, ..)
         | Self::Primitive(info, ..)
         | Self::Any(info)) = *self;
@@ -59,6 +63,7 @@ impl<'a> From<SpecSchemaType<'a>> for GraphSchemaType<'a> {
                 info,
                 GraphStruct {
                     description: s.description,
+                    closed: s.closed,
                 },
             ),
             SpecSchemaType::Tagged(info, t) => Self::Tagged(
@@ -74,6 +79,12 @@ impl<'a> From<SpecSchemaType<'a>> for GraphSchemaType<'a> {
                     description: u.description,
                 },
             ),
+            SpecSchemaType::Tuple(info, t) => Self::Tuple(
+                info,
+                GraphTuple {
+                    description: t.description,
+                },
+            ),
             SpecSchemaType::Container(info, c) => Self::Container(info, c.into()),
             SpecSchemaType::Primitive(info, p) => Self::Primitive(info, p),
             SpecSchemaType::Any(info) => Self::Any(info),
@@ -88,8 +99,9 @@ pub enum GraphInlineType<'a> {
     Struct(InlineTypeId, GraphStruct<'a>),
     Tagged(InlineTypeId, GraphTagged<'a>),
     Untagged(InlineTypeId, GraphUntagged<'a>),
+    Tuple(InlineTypeId, GraphTuple<'a>),
     Container(InlineTypeId, GraphContainer<'a>),
-    Primitive(InlineTypeId, PrimitiveType),
+    Primitive(InlineTypeId, PrimitiveType<'a>),
     Any(InlineTypeId),
 }
 
@@ -101,6 +113,7 @@ impl<'a> GraphInlineType<'a> {
         | Self::Struct(id, _)
         | Self::Tagged(id, _)
         | Self::Untagged(id, _)
+        | Self::Tuple(id, _)
         | Self::Container(id, _)
         | Self::Primitive(id, _)
         | Self::Any(id)) = *self;
@@ -116,6 +129,7 @@ impl<'a> From<SpecInlineType<'a>> for GraphInlineType<'a> {
                 id,
                 GraphStruct {
                     description: s.description,
+                    closed: s.closed,
                 },
             ),
             SpecInlineType::Tagged(id, t) => Self::Tagged(
@@ -131,6 +145,12 @@ impl<'a> From<SpecInlineType<'a>> for GraphInlineType<'a> {
                     description: u.description,
                 },
             ),
+            SpecInlineType::Tuple(id, t) => Self::Tuple(
+                id,
+                GraphTuple {
+                    description: t.description,
+                },
+            ),
             SpecInlineType::Container(id, c) => Self::Container(id, c.into()),
             SpecInlineType::Primitive(id, p) => Self::Primitive(id, p),
             SpecInlineType::Any(id) => Self::Any(id),
@@ -142,6 +162,7 @@ impl<'a> From<SpecInlineType<'a>> for GraphInlineType<'a> {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct GraphStruct<'a> {
     pub description: Option<&'a str>,
+    pub closed: bool,
 }
 
 /// A tagged union in the graph.
@@ -157,10 +178,17 @@ pub struct GraphUntagged<'a> {
     pub description: Option<&'a str>,
 }
 
+/// A tuple in the graph.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GraphTuple<'a> {
+    pub description: Option<&'a str>,
+}
+
 /// A container in the graph.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum GraphContainer<'a> {
     Array { description: Option<&'a str> },
+    Set { description: Option<&'a str> },
     Map { description: Option<&'a str> },
     Optional { description: Option<&'a str> },
 }
@@ -171,6 +199,9 @@ impl<'a> From<SpecContainer<'a>> for GraphContainer<'a> {
             SpecContainer::Array(inner) => Self::Array {
                 description: inner.description,
             },
+            SpecContainer::Set(inner) => Self::Set {
+                description: inner.description,
+            },
             SpecContainer::Map(inner) => Self::Map {
                 description: inner.description,
             },
@@ -188,6 +219,11 @@ pub struct FieldMeta<'a> {
     pub required: bool,
     pub description: Option<&'a str>,
     pub flattened: bool,
+    pub default: Option<FieldDefault<'a>>,
+    pub deprecated: bool,
+    pub read_only: bool,
+    pub write_only: bool,
+    pub constraints: FieldConstraints,
 }
 
 /// Metadata for a tagged or untagged union variant.
@@ -225,6 +261,15 @@ pub struct UntaggedVariantMeta {
     pub null: bool,
 }
 
+/// Metadata for a tuple element.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ElementMeta {
+    /// The nth positional element, counted from 1 in declaration order.
+    Positional(NonZeroUsize),
+    /// The type of any elements after the positional ones.
+    Rest,
+}
+
 /// An operation with graph node references.
 pub type GraphOperation<'a> = Operation<'a, NodeIndex<usize>>;
 
@@ -26,6 +26,22 @@ pub struct SchemaTypeInfo<'a> {
     pub name: &'a str,
     /// The `x-resourceId` extension value, if present.
     pub resource: Option<&'a str>,
+    /// Whether the schema is marked as `deprecated`.
+    pub deprecated: bool,
+    /// The name of the schema that replaces this one, from the
+    /// `x-replaced-by` extension, if present.
+    pub replaced_by: Option<&'a str>,
+    /// The schema's `example` and `examples`.
+    pub examples: &'a [ExampleValue<'a>],
+}
+
+/// An example value from the spec.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExampleValue<'a> {
+    /// The example's `summary`, if it's a named example.
+    pub summary: Option<&'a str>,
+    /// The value, as pretty-printed JSON.
+    pub json: &'a str,
 }
 
 /// Generates unique opaque identities for inline types.
@@ -48,7 +64,8 @@ impl<'a> InlineTypeIds<'a> {
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct InlineTypeId(usize);
 
-/// An `operationId` from the OpenAPI spec.
+/// An `operationId` from the OpenAPI spec, or an ID synthesized
+/// from the method and path if the operation doesn't have one.
 #[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd, RefCastCustom)]
 #[repr(transparent)]
 pub struct OperationId(str);
@@ -100,10 +117,16 @@ pub enum OperationUsage<'a> {
     Path(&'a str),
     /// A query parameter with the given name.
     Query(&'a str),
+    /// A header parameter with the given name.
+    Header(&'a str),
+    /// A cookie parameter with the given name.
+    Cookie(&'a str),
     /// The request body.
     Request,
     /// The response body.
     Response,
+    /// A response header with the given name.
+    ResponseHeader(&'a str),
 }
 
 /// A segment in an inline type path.
@@ -117,7 +140,11 @@ pub enum InlineTypePathSegment<'a> {
     TaggedVariant(TypeId, &'a str),
     /// Enters the nth untagged union variant, counted from 1 in declaration order.
     UntaggedVariant(TypeId, NonZeroUsize),
-    /// Enters the item type of an array.
+    /// Enters the nth positional tuple element, counted from 1
+    /// in declaration order.
+    TupleElement(NonZeroUsize),
+    /// Enters the item type of an array, or the rest element type
+    /// of a tuple.
     ArrayItem,
     /// Enters the value type of a map.
     MapValue,
@@ -129,7 +156,7 @@ pub enum InlineTypePathSegment<'a> {
 
 /// A primitive type in the dependency graph.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum PrimitiveType {
+pub enum PrimitiveType<'a> {
     String,
     I8,
     U8,
@@ -141,6 +168,7 @@ pub enum PrimitiveType {
     U64,
     F32,
     F64,
+    Decimal,
     Bool,
     DateTime,
     UnixTime,
@@ -149,6 +177,11 @@ pub enum PrimitiveType {
     Uuid,
     Bytes,
     Binary,
+    /// A string with a `format` that Ploidy doesn't recognize, like
+    /// `phone`, or a format in [`SpecConfig::custom_formats`].
+    ///
+    /// [`SpecConfig::custom_formats`]: crate::ir::SpecConfig::custom_formats
+    Custom(&'a str),
 }
 
 /// An enum type in the dependency graph.
@@ -156,6 +189,11 @@ pub enum PrimitiveType {
 pub struct Enum<'a> {
     pub description: Option<&'a str>,
     pub variants: &'a [EnumVariant<'a>],
+    /// Identifier hints for each variant, from `x-enum-varnames`
+    /// or `x-enumNames`.
+    pub names: &'a [Option<&'a str>],
+    /// The variant that matches the schema's `default`, if any.
+    pub default: Option<EnumVariant<'a>>,
 }
 
 /// A variant of an enum.
@@ -168,6 +206,45 @@ pub enum EnumVariant<'a> {
     Bool(bool),
 }
 
+/// A scalar `default` value for a struct field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldDefault<'a> {
+    String(&'a str),
+    I64(i64),
+    U64(u64),
+    F64(JsonF64),
+    Bool(bool),
+}
+
+/// Validation constraints on a struct field's value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FieldConstraints {
+    /// The inclusive lower bound for a number.
+    pub minimum: Option<JsonF64>,
+    /// The inclusive upper bound for a number.
+    pub maximum: Option<JsonF64>,
+    /// The minimum length of a string, in characters.
+    pub min_length: Option<u64>,
+    /// The maximum length of a string, in characters.
+    pub max_length: Option<u64>,
+    /// The minimum length of an array.
+    pub min_items: Option<u64>,
+    /// The maximum length of an array.
+    pub max_items: Option<u64>,
+    /// The minimum number of entries in a map.
+    pub min_properties: Option<u64>,
+    /// The maximum number of entries in a map.
+    pub max_properties: Option<u64>,
+}
+
+impl FieldConstraints {
+    /// Returns `true` if there are no constraints.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// A struct field name.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum StructFieldName<'a> {
@@ -177,15 +254,21 @@ pub enum StructFieldName<'a> {
     Ordinal(NonZeroUsize),
     /// The synthetic field for additional properties.
     AdditionalProperties,
+    /// The synthetic field for properties with names that match
+    /// any of these `patternProperties` patterns.
+    PatternProperties(&'a [&'a str]),
 }
 
-/// The serialization style for query parameters.
+/// The serialization style for path and query parameters.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum ParameterStyle {
     Form { exploded: bool },
     PipeDelimited,
     SpaceDelimited,
     DeepObject,
+    Simple { exploded: bool },
+    Label { exploded: bool },
+    Matrix { exploded: bool },
 }
 
 /// A floating-point number that's representable in JSON.
@@ -1,9 +1,11 @@
 //! Generic operation types, parameterized over the type reference
 //! representation. Used by both spec and graph layers.
 
+use std::time::Duration;
+
 use crate::parse::{Method, path::ParsedPath};
 
-use super::ParameterStyle;
+use super::{ExampleValue, ParameterStyle};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Operation<'a, Ty> {
@@ -12,9 +14,19 @@ pub struct Operation<'a, Ty> {
     pub path: ParsedPath<'a>,
     pub resource: Option<&'a str>,
     pub description: Option<&'a str>,
+    pub timeout: Option<Duration>,
+    pub server: Option<&'a str>,
+    pub deprecated: bool,
     pub params: &'a [Parameter<'a, Ty>],
     pub request: Option<Request<Ty>>,
+    pub request_required: bool,
+    pub request_examples: &'a [ExampleValue<'a>],
     pub response: Option<Response<Ty>>,
+    pub response_status: Option<u16>,
+    pub response_examples: &'a [ExampleValue<'a>],
+    pub response_content_type: Option<&'a str>,
+    pub response_content_types: &'a [&'a str],
+    pub response_headers: &'a [ParameterInfo<'a, Ty>],
 }
 
 impl<'a, Ty> Operation<'a, Ty> {
@@ -25,14 +37,18 @@ impl<'a, Ty> Operation<'a, Ty> {
             self.params.iter().map(|param| match param {
                 Parameter::Path(info) => &info.ty,
                 Parameter::Query(info) => &info.ty,
+                Parameter::Header(info) => &info.ty,
+                Parameter::Cookie(info) => &info.ty,
             }),
             self.request.as_ref().and_then(|request| match request {
                 Request::Json(ty) => Some(ty),
-                Request::Multipart => None,
+                Request::Multipart | Request::Text | Request::Bytes => None,
+            }),
+            self.response.as_ref().and_then(|response| match response {
+                Response::Json(ty) => Some(ty),
+                Response::Text | Response::Bytes => None,
             }),
-            self.response.as_ref().map(|response| match response {
-                Response::Json(ty) => ty,
-            })
+            self.response_headers.iter().map(|info| &info.ty),
         )
     }
 }
@@ -40,18 +56,24 @@ impl<'a, Ty> Operation<'a, Ty> {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Response<Ty> {
     Json(Ty),
+    Text,
+    Bytes,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Request<Ty> {
     Json(Ty),
     Multipart,
+    Text,
+    Bytes,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Parameter<'a, Ty> {
     Path(ParameterInfo<'a, Ty>),
     Query(ParameterInfo<'a, Ty>),
+    Header(ParameterInfo<'a, Ty>),
+    Cookie(ParameterInfo<'a, Ty>),
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -4,7 +4,8 @@
 use crate::parse::SchemaRef;
 
 use super::{
-    Enum, InlineTypeId, PrimitiveType, SchemaTypeInfo, StructFieldName,
+    Enum, FieldConstraints, FieldDefault, InlineTypeId, PrimitiveType, SchemaTypeInfo,
+    StructFieldName,
     shape::{Operation, Parameter, ParameterInfo, Request, Response},
 };
 
@@ -42,10 +43,12 @@ pub enum SpecSchemaType<'a> {
     Tagged(SchemaTypeInfo<'a>, SpecTagged<'a>),
     /// An untagged union.
     Untagged(SchemaTypeInfo<'a>, SpecUntagged<'a>),
+    /// A tuple with positional elements.
+    Tuple(SchemaTypeInfo<'a>, SpecTuple<'a>),
     /// A named container.
     Container(SchemaTypeInfo<'a>, SpecContainer<'a>),
     /// A primitive type.
-    Primitive(SchemaTypeInfo<'a>, PrimitiveType),
+    Primitive(SchemaTypeInfo<'a>, PrimitiveType<'a>),
     /// Any JSON value.
     Any(SchemaTypeInfo<'a>),
 }
@@ -57,6 +60,7 @@ impl<'a> SpecSchemaType<'a> {
         | Self::Struct(info, ..)
         | Self::Tagged(info, ..)
         | Self::Untagged(info, ..)
+        | Self::Tuple(info, ..)
         | Self::Container(info, ..)
         | Self::Primitive(info, ..)
         | Self::Any(info)) = self;
@@ -69,6 +73,7 @@ impl<'a> SpecSchemaType<'a> {
         | Self::Struct(info, ..)
         | Self::Tagged(info, ..)
         | Self::Untagged(info, ..)
+        | Self::Tuple(info, ..)
         | Self::Container(info, ..)
         | Self::Primitive(info, ..)
         | Self::Any(info)) = self;
@@ -83,8 +88,9 @@ pub enum SpecInlineType<'a> {
     Struct(InlineTypeId, SpecStruct<'a>),
     Tagged(InlineTypeId, SpecTagged<'a>),
     Untagged(InlineTypeId, SpecUntagged<'a>),
+    Tuple(InlineTypeId, SpecTuple<'a>),
     Container(InlineTypeId, SpecContainer<'a>),
-    Primitive(InlineTypeId, PrimitiveType),
+    Primitive(InlineTypeId, PrimitiveType<'a>),
     Any(InlineTypeId),
 }
 
@@ -96,6 +102,7 @@ impl<'a> SpecInlineType<'a> {
         | Self::Struct(id, _)
         | Self::Tagged(id, _)
         | Self::Untagged(id, _)
+        | Self::Tuple(id, _)
         | Self::Container(id, _)
         | Self::Primitive(id, _)
         | Self::Any(id)) = *self;
@@ -110,6 +117,9 @@ pub struct SpecStruct<'a> {
     pub fields: &'a [SpecStructField<'a>],
     /// Immediate parent types from `allOf`, in declaration order.
     pub parents: &'a [&'a SpecType<'a>],
+    /// Whether the schema forbids undeclared properties,
+    /// with `additionalProperties: false`.
+    pub closed: bool,
 }
 
 /// A field in a spec struct.
@@ -120,6 +130,13 @@ pub struct SpecStructField<'a> {
     pub required: bool,
     pub description: Option<&'a str>,
     pub flattened: bool,
+    pub default: Option<FieldDefault<'a>>,
+    pub deprecated: bool,
+    /// Whether the field only appears in responses.
+    pub read_only: bool,
+    /// Whether the field only appears in requests.
+    pub write_only: bool,
+    pub constraints: FieldConstraints,
 }
 
 /// A tagged union, created from a `oneOf` schema
@@ -156,11 +173,24 @@ pub struct SpecUntagged<'a> {
     pub parents: &'a [&'a SpecType<'a>],
 }
 
-/// An array, map, or optional type with [`SpecType`] references.
+/// A tuple, created from an array schema with `prefixItems`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SpecTuple<'a> {
+    pub description: Option<&'a str>,
+    /// Positional element types, in declaration order.
+    pub elements: &'a [&'a SpecType<'a>],
+    /// The type of any elements after the positional ones,
+    /// or `None` if the tuple doesn't allow extra elements.
+    pub rest: Option<&'a SpecType<'a>>,
+}
+
+/// An array, set, map, or optional type with [`SpecType`] references.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum SpecContainer<'a> {
     /// An array of items.
     Array(SpecInner<'a>),
+    /// An array of unique items.
+    Set(SpecInner<'a>),
     /// A map with string keys.
     Map(SpecInner<'a>),
     /// A nullable value, or an optional struct field.
@@ -171,7 +201,8 @@ impl<'a> SpecContainer<'a> {
     /// Returns a reference to the inner type of this container.
     #[inline]
     pub fn inner(&self) -> &SpecInner<'a> {
-        let (Self::Array(inner) | Self::Map(inner) | Self::Optional(inner)) = self;
+        let (Self::Array(inner) | Self::Set(inner) | Self::Map(inner) | Self::Optional(inner)) =
+            self;
         inner
     }
 }
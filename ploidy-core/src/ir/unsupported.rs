@@ -0,0 +1,83 @@
+use std::fmt::{self, Display};
+
+use ploidy_pointer::JsonPointerBuf;
+
+/// A construct in the document that the IR can't represent faithfully.
+///
+/// Lowering a document never fails because of an unsupported construct:
+/// the construct is dropped, or replaced with a fallback type, and
+/// recorded in [`Spec::unsupported`][crate::ir::Spec::unsupported].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsupportedFeature {
+    /// A pointer to the construct in the document.
+    pub pointer: JsonPointerBuf,
+    /// What was lost.
+    pub kind: UnsupportedFeatureKind,
+}
+
+impl Display for UnsupportedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`#{}`: {}", self.pointer, self.kind)
+    }
+}
+
+/// The kind of an [`UnsupportedFeature`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnsupportedFeatureKind {
+    /// A `not` schema, which is ignored.
+    Not,
+    /// A `format` that isn't recognized, which is ignored.
+    UnknownFormat,
+    /// An `anyOf` alongside a `oneOf`, which is ignored.
+    AnyOfWithOneOf,
+    /// A `discriminator` with an inline `oneOf` variant. The schema is
+    /// lowered as an untagged union instead.
+    InlineTaggedVariant,
+    /// An array or object `default` value, which is ignored.
+    CompositeDefault,
+    /// A response media type that isn't JSON, plain text, or binary,
+    /// which is ignored.
+    ResponseMediaType,
+    /// `patternProperties` alongside `additionalProperties`, which
+    /// are ignored.
+    PatternPropertiesWithAdditionalProperties,
+    /// A `patternProperties` pattern that isn't a valid regular
+    /// expression, which is ignored.
+    InvalidPattern,
+    /// `additionalProperties: false` alongside `anyOf` or
+    /// `patternProperties`, whose fields are flattened. Unknown
+    /// properties are allowed.
+    ClosedWithFlattenedFields,
+    /// An enum `default` that doesn't match any of its values,
+    /// which is ignored.
+    UnknownEnumDefault,
+    /// An `x-timeout-seconds` extension that isn't a non-negative
+    /// number, which is ignored.
+    InvalidTimeout,
+}
+
+impl Display for UnsupportedFeatureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Not => "`not` schemas are unsupported",
+            Self::UnknownFormat => "unknown `format`",
+            Self::AnyOfWithOneOf => "`anyOf` alongside `oneOf` is unsupported",
+            Self::InlineTaggedVariant => {
+                "discriminators with inline variants are unsupported; \
+                 treating as untagged"
+            }
+            Self::CompositeDefault => "array and object `default` values are unsupported",
+            Self::ResponseMediaType => "unsupported response media type",
+            Self::PatternPropertiesWithAdditionalProperties => {
+                "`patternProperties` alongside `additionalProperties` are unsupported"
+            }
+            Self::InvalidPattern => "invalid `patternProperties` pattern",
+            Self::ClosedWithFlattenedFields => {
+                "`additionalProperties: false` alongside `anyOf` or `patternProperties` \
+                 is unsupported; allowing unknown properties"
+            }
+            Self::UnknownEnumDefault => "enum `default` isn't one of its values",
+            Self::InvalidTimeout => "`x-timeout-seconds` isn't a non-negative number",
+        })
+    }
+}
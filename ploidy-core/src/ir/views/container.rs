@@ -1,11 +1,11 @@
-//! Container types: arrays, maps, and optionals.
+//! Container types: arrays, sets, maps, and optionals.
 //!
 //! In OpenAPI, `type: array` with `items` defines a list,
-//! and `type: object` without `properties` and with
-//! `additionalProperties` defines a map. Schemas with
-//! `nullable: true` (OpenAPI 3.0), `type: [T, "null"]`
-//! (OpenAPI 3.1), or `oneOf` with a `null` branch all
-//! become optionals:
+//! or a set with `uniqueItems: true`; and `type: object`
+//! without `properties` and with `additionalProperties`
+//! defines a map. Schemas with `nullable: true` (OpenAPI 3.0),
+//! `type: [T, "null"]` (OpenAPI 3.1), or `oneOf` with a `null`
+//! branch all become optionals:
 //!
 //! ```yaml
 //! components:
@@ -14,6 +14,11 @@
 //!       type: array
 //!       items:
 //!         type: string
+//!     Labels:
+//!       type: array
+//!       items:
+//!         type: string
+//!       uniqueItems: true
 //!     Metadata:
 //!       type: object
 //!       additionalProperties:
@@ -22,12 +27,13 @@
 //!       type: [string, null]
 //! ```
 //!
-//! Ploidy represents all three as [`ContainerView`] variants—
-//! [`Array`][array], [`Map`][map], and [`Optional`][opt]—
-//! each wrapping an [`InnerView`] that provides access to
-//! the contained type.
+//! Ploidy represents all four as [`ContainerView`] variants—
+//! [`Array`][array], [`Set`][set], [`Map`][map], and
+//! [`Optional`][opt]—each wrapping an [`InnerView`] that
+//! provides access to the contained type.
 //!
 //! [array]: ContainerView::Array
+//! [set]: ContainerView::Set
 //! [map]: ContainerView::Map
 //! [opt]: ContainerView::Optional
 
@@ -45,6 +51,7 @@ use super::{TypeView, ViewNode};
 #[derive(Debug)]
 pub enum ContainerView<'graph, 'a> {
     Array(InnerView<'graph, 'a>),
+    Set(InnerView<'graph, 'a>),
     Map(InnerView<'graph, 'a>),
     Optional(InnerView<'graph, 'a>),
 }
@@ -60,13 +67,13 @@ impl<'graph, 'a> ContainerView<'graph, 'a> {
 impl<'graph, 'a> ViewNode<'graph, 'a> for ContainerView<'graph, 'a> {
     #[inline]
     fn cooked(&self) -> &'graph CookedGraph<'a> {
-        let (Self::Array(c) | Self::Map(c) | Self::Optional(c)) = self;
+        let (Self::Array(c) | Self::Set(c) | Self::Map(c) | Self::Optional(c)) = self;
         c.cooked
     }
 
     #[inline]
     fn index(&self) -> NodeIndex<usize> {
-        let (Self::Array(c) | Self::Map(c) | Self::Optional(c)) = self;
+        let (Self::Array(c) | Self::Set(c) | Self::Map(c) | Self::Optional(c)) = self;
         c.container
     }
 }
@@ -93,12 +100,14 @@ impl<'graph, 'a> InnerView<'graph, 'a> {
             GraphType::Schema(GraphSchemaType::Container(
                 _,
                 GraphContainer::Array { description }
+                | GraphContainer::Set { description }
                 | GraphContainer::Map { description }
                 | GraphContainer::Optional { description },
             ))
             | GraphType::Inline(GraphInlineType::Container(
                 _,
                 GraphContainer::Array { description }
+                | GraphContainer::Set { description }
                 | GraphContainer::Map { description }
                 | GraphContainer::Optional { description },
             )) => description,
@@ -130,6 +139,7 @@ impl<'graph, 'a> ContainerView<'graph, 'a> {
         };
         match container {
             GraphContainer::Array { .. } => Self::Array(inner),
+            GraphContainer::Set { .. } => Self::Set(inner),
             GraphContainer::Map { .. } => Self::Map(inner),
             GraphContainer::Optional { .. } => Self::Optional(inner),
         }
@@ -14,6 +14,9 @@
 //! Ploidy represents this as an [`EnumView`]. Each variant carries a
 //! literal value: string, number, or boolean. See [`EnumVariant`]
 //! for the full set.
+//!
+//! The `x-enum-varnames` and `x-enumNames` extensions give each variant
+//! a name, for languages that can't name variants after their values.
 
 use petgraph::graph::NodeIndex;
 
@@ -53,6 +56,23 @@ impl<'graph, 'a> EnumView<'graph, 'a> {
     pub fn variants(&self) -> &'a [EnumVariant<'a>] {
         self.ty.variants
     }
+
+    /// Returns the variant that matches the schema's `default`, if any.
+    #[inline]
+    pub fn default_variant(&self) -> Option<EnumVariant<'a>> {
+        self.ty.default
+    }
+
+    /// Returns the enum's variants, each with its name from
+    /// `x-enum-varnames` or `x-enumNames`, if present in the schema.
+    #[inline]
+    pub fn named_variants(&self) -> impl Iterator<Item = (EnumVariant<'a>, Option<&'a str>)> {
+        self.ty
+            .variants
+            .iter()
+            .copied()
+            .zip(self.ty.names.iter().copied())
+    }
 }
 
 impl<'graph, 'a> ViewNode<'graph, 'a> for EnumView<'graph, 'a> {
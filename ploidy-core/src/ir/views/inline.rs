@@ -31,7 +31,7 @@ use crate::ir::{
 use super::{
     HasResource, ViewNode, any::AnyView, container::ContainerView, enum_::EnumView,
     path::InlineTypePathView, primitive::PrimitiveView, struct_::StructView, tagged::TaggedView,
-    untagged::UntaggedView,
+    tuple::TupleView, untagged::UntaggedView,
 };
 
 /// A graph-aware view of an [inline type][GraphInlineType].
@@ -41,6 +41,7 @@ pub enum InlineTypeView<'graph, 'a> {
     Struct(InlineTypeId, StructView<'graph, 'a>),
     Tagged(InlineTypeId, TaggedView<'graph, 'a>),
     Untagged(InlineTypeId, UntaggedView<'graph, 'a>),
+    Tuple(InlineTypeId, TupleView<'graph, 'a>),
     Container(InlineTypeId, ContainerView<'graph, 'a>),
     Primitive(InlineTypeId, PrimitiveView<'graph, 'a>),
     Any(InlineTypeId, AnyView<'graph, 'a>),
@@ -60,6 +61,7 @@ impl<'graph, 'a> InlineTypeView<'graph, 'a> {
             GraphInlineType::Untagged(id, ty) => {
                 Self::Untagged(id, UntaggedView::new(cooked, index, ty))
             }
+            GraphInlineType::Tuple(id, ty) => Self::Tuple(id, TupleView::new(cooked, index, ty)),
             GraphInlineType::Container(id, container) => {
                 Self::Container(id, ContainerView::new(cooked, index, container))
             }
@@ -82,6 +84,7 @@ impl<'graph, 'a> InlineTypeView<'graph, 'a> {
         | Self::Struct(id, _)
         | Self::Tagged(id, _)
         | Self::Untagged(id, _)
+        | Self::Tuple(id, _)
         | Self::Container(id, _)
         | Self::Primitive(id, _)
         | Self::Any(id, _)) = self;
@@ -113,6 +116,7 @@ impl<'graph, 'a> ViewNode<'graph, 'a> for InlineTypeView<'graph, 'a> {
             Self::Struct(_, view) => view.cooked(),
             Self::Tagged(_, view) => view.cooked(),
             Self::Untagged(_, view) => view.cooked(),
+            Self::Tuple(_, view) => view.cooked(),
             Self::Container(_, view) => view.cooked(),
             Self::Primitive(_, view) => view.cooked(),
             Self::Any(_, view) => view.cooked(),
@@ -126,6 +130,7 @@ impl<'graph, 'a> ViewNode<'graph, 'a> for InlineTypeView<'graph, 'a> {
             Self::Struct(_, view) => view.index(),
             Self::Tagged(_, view) => view.index(),
             Self::Untagged(_, view) => view.index(),
+            Self::Tuple(_, view) => view.index(),
             Self::Container(_, view) => view.index(),
             Self::Primitive(_, view) => view.index(),
             Self::Any(_, view) => view.index(),
@@ -53,6 +53,26 @@ impl<'graph, 'a> TypeView<'graph, 'a> {
         }
     }
 
+    /// Returns `true` if this is a view of a named or inline
+    /// [optional][ContainerView::Optional] type.
+    #[inline]
+    pub fn is_nullable(&self) -> bool {
+        matches!(self.as_container(), Some(ContainerView::Optional(_)))
+    }
+
+    /// Returns the inner type after peeling all optional layers.
+    ///
+    /// For example, `Optional(T)` and `Optional(Optional(T))` both return `T`.
+    /// Returns this view unchanged if it's not optional.
+    #[inline]
+    pub fn unwrap_optional(self) -> TypeView<'graph, 'a> {
+        let mut ty = self;
+        while let Some(ContainerView::Optional(inner)) = ty.as_container() {
+            ty = inner.ty();
+        }
+        ty
+    }
+
     /// Returns an iterator over all the types that this type transitively depends on.
     #[inline]
     pub fn dependencies(&self) -> impl Iterator<Item = TypeView<'graph, 'a>> + use<'graph, 'a> {
@@ -53,6 +53,7 @@ pub mod primitive;
 pub mod schema;
 pub mod struct_;
 pub mod tagged;
+pub mod tuple;
 pub mod untagged;
 
 use self::{inline::InlineTypeView, ir::TypeView, operation::OperationView};
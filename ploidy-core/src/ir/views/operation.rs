@@ -1,8 +1,8 @@
 //! Operations: per-path methods with parameter, request, and response schemas.
 //!
 //! In OpenAPI, each path item defines operations for HTTP methods like
-//! `GET` and `POST`. An operation has path and query parameters, an
-//! optional request body, and an optional response body:
+//! `GET` and `POST`. An operation has path, query, header, and cookie
+//! parameters, an optional request body, and an optional response body:
 //!
 //! ```yaml
 //! paths:
@@ -19,6 +19,10 @@
 //!           in: query
 //!           schema:
 //!             type: boolean
+//!         - name: X-Request-Id
+//!           in: header
+//!           schema:
+//!             type: string
 //!       requestBody:
 //!         content:
 //!           application/json:
@@ -30,6 +34,10 @@
 //!             application/json:
 //!               schema:
 //!                 $ref: '#/components/schemas/Pet'
+//!           headers:
+//!             X-Next-Cursor:
+//!               schema:
+//!                 type: string
 //! ```
 //!
 //! Ploidy represents this as an [`OperationView`] with:
@@ -38,10 +46,15 @@
 //!   segments and path parameters.
 //! * [Query parameters], each with a name, type, and
 //!   optional serialization style.
-//! * An optional [request] and [response] body, each wrapping
-//!   a [`TypeView`] of the body schema.
+//! * [Header] and [cookie] parameters, each with a name and type.
+//! * An optional [request] and [response] body. JSON bodies wrap
+//!   a [`TypeView`] of the body schema; other bodies are plain text,
+//!   binary, or (for requests) `multipart/form-data`.
+//! * The [content types] that the response offers, and
+//!   the [content type] of the response body.
+//! * [Response headers] from the same response as the body.
 //! * An optional [resource name] from the `x-resource-name` extension,
-//!   used to group operations by resource.
+//!   or the first tag, used to group operations by resource.
 //!
 //! Unlike types, operations are not nodes in Ploidy's dependency graph,
 //! but they implement [`View`] for traversal.
@@ -50,14 +63,20 @@
 //! [HTTP method]: OperationView::method
 //! [path template]: OperationView::path
 //! [Query parameters]: OperationView::query
+//! [Header]: OperationView::headers
+//! [cookie]: OperationView::cookies
 //! [request]: OperationView::request
 //! [response]: OperationView::response
+//! [content types]: OperationView::response_content_types
+//! [content type]: OperationView::response_content_type
+//! [Response headers]: OperationView::response_headers
 //! [resource name]: OperationView::resource
 
 use std::{
     collections::VecDeque,
     fmt::{Display, Formatter, Result as FmtResult},
     marker::PhantomData,
+    time::Duration,
 };
 
 use petgraph::{
@@ -69,8 +88,8 @@ use crate::{
     ir::{
         graph::CookedGraph,
         types::{
-            GraphOperation, GraphParameter, GraphParameterInfo, GraphRequest, GraphResponse,
-            GraphType, OperationId, ParameterStyle,
+            ExampleValue, GraphOperation, GraphParameter, GraphParameterInfo, GraphRequest,
+            GraphResponse, GraphType, OperationId, ParameterStyle,
         },
     },
     parse::{
@@ -79,7 +98,7 @@ use crate::{
     },
 };
 
-use super::{HasResource, View, inline::InlineTypeView, ir::TypeView};
+use super::{HasResource, View, inline::InlineTypeView, ir::TypeView, schema::SchemaTypeView};
 
 /// A graph-aware view of an [operation][GraphOperation].
 #[derive(Debug)]
@@ -97,7 +116,8 @@ impl<'graph, 'a> OperationView<'graph, 'a> {
         Self { cooked, op }
     }
 
-    /// Returns the `operationId`.
+    /// Returns the `operationId`, or an ID like `get_users_id` for
+    /// `GET /users/{id}` if the operation doesn't have one.
     #[inline]
     pub fn id(&self) -> &'a OperationId {
         OperationId::new(self.op.id)
@@ -121,6 +141,39 @@ impl<'graph, 'a> OperationView<'graph, 'a> {
         self.op.description
     }
 
+    /// Returns the request timeout from the `x-timeout-seconds` extension,
+    /// if present in the spec.
+    #[inline]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.op.timeout
+    }
+
+    /// Returns the request body's `example` and `examples`.
+    #[inline]
+    pub fn request_examples(&self) -> &'a [ExampleValue<'a>] {
+        self.op.request_examples
+    }
+
+    /// Returns the `example` and `examples` for the response
+    /// that the client decodes.
+    #[inline]
+    pub fn response_examples(&self) -> &'a [ExampleValue<'a>] {
+        self.op.response_examples
+    }
+
+    /// Returns `true` if the operation is marked as `deprecated`.
+    #[inline]
+    pub fn deprecated(&self) -> bool {
+        self.op.deprecated
+    }
+
+    /// Returns the URL of the server that overrides the client's base URL
+    /// for this operation, if the operation or its path item declares one.
+    #[inline]
+    pub fn server(&self) -> Option<&'a str> {
+        self.op.server
+    }
+
     /// Returns an iterator over this operation's query parameters.
     #[inline]
     pub fn query(&self) -> impl Iterator<Item = ParameterView<'_, 'graph, 'a, QueryParameter>> {
@@ -130,27 +183,110 @@ impl<'graph, 'a> OperationView<'graph, 'a> {
         })
     }
 
+    /// Returns an iterator over this operation's header parameters.
+    #[inline]
+    pub fn headers(&self) -> impl Iterator<Item = ParameterView<'_, 'graph, 'a, HeaderParameter>> {
+        self.op.params.iter().filter_map(|param| match param {
+            GraphParameter::Header(info) => Some(ParameterView::new(self, info)),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over this operation's cookie parameters.
+    #[inline]
+    pub fn cookies(&self) -> impl Iterator<Item = ParameterView<'_, 'graph, 'a, CookieParameter>> {
+        self.op.params.iter().filter_map(|param| match param {
+            GraphParameter::Cookie(info) => Some(ParameterView::new(self, info)),
+            _ => None,
+        })
+    }
+
     /// Returns a view of the request body, if present.
     #[inline]
     pub fn request(&self) -> Option<RequestView<'graph, 'a>> {
         self.op.request.as_ref().map(|ty| match ty {
             GraphRequest::Json(index) => RequestView::Json(TypeView::new(self.cooked, *index)),
             GraphRequest::Multipart => RequestView::Multipart,
+            GraphRequest::Text => RequestView::Text,
+            GraphRequest::Bytes => RequestView::Bytes,
         })
     }
 
+    /// Returns `true` if the spec marks the request body as `required`.
+    ///
+    /// A request body is optional unless marked as required.
+    #[inline]
+    pub fn request_required(&self) -> bool {
+        self.op.request_required
+    }
+
     /// Returns a view of the response body, if present.
     #[inline]
     pub fn response(&self) -> Option<ResponseView<'graph, 'a>> {
         self.op.response.as_ref().map(|ty| match ty {
             GraphResponse::Json(index) => ResponseView::Json(TypeView::new(self.cooked, *index)),
+            GraphResponse::Text => ResponseView::Text,
+            GraphResponse::Bytes => ResponseView::Bytes,
         })
     }
+
+    /// Returns the lowest explicit `2xx` status code that the operation
+    /// declares, if any.
+    ///
+    /// This is `None` if the successful response is a `2XX` range
+    /// or a `default` response.
+    #[inline]
+    pub fn response_status(&self) -> Option<u16> {
+        self.op.response_status
+    }
+
+    /// Returns the media type of the response body, if the response
+    /// offers a supported media type.
+    ///
+    /// `application/json` is preferred over JSON-based media types,
+    /// like `application/problem+json`, then `*/*`, then `text/plain`,
+    /// then `application/octet-stream`. JSON media types without
+    /// a schema are skipped.
+    #[inline]
+    pub fn response_content_type(&self) -> Option<&'a str> {
+        self.op.response_content_type
+    }
+
+    /// Returns an iterator over all the media types that the response
+    /// offers, in declaration order.
+    #[inline]
+    pub fn response_content_types(&self) -> impl Iterator<Item = &'a str> + use<'a> {
+        self.op.response_content_types.iter().copied()
+    }
+
+    /// Returns an iterator over the headers of the response, in
+    /// declaration order.
+    #[inline]
+    pub fn response_headers(
+        &self,
+    ) -> impl Iterator<Item = ParameterView<'_, 'graph, 'a, ResponseHeader>> {
+        self.op
+            .response_headers
+            .iter()
+            .map(|info| ParameterView::new(self, info))
+    }
+
+    /// Returns an iterator over the named schema types that this operation
+    /// transitively depends on.
+    ///
+    /// These are the minimal set of schemas that this operation needs.
+    #[inline]
+    pub fn type_closure(
+        &self,
+    ) -> impl Iterator<Item = SchemaTypeView<'graph, 'a>> + use<'graph, 'a> {
+        self.dependencies()
+            .filter_map(|ty| ty.into_schema().right())
+    }
 }
 
 impl<'a> HasResource<'a> for OperationView<'_, 'a> {
     /// Returns the resource name that this operation declares
-    /// in its `x-resource-name` extension field.
+    /// in its `x-resource-name` extension field, or its first tag.
     #[inline]
     fn resource(&self) -> Option<&'a str> {
         self.op.resource
@@ -306,6 +442,12 @@ impl<'view, 'graph, 'a, T> ParameterView<'view, 'graph, 'a, T> {
         self.info.required
     }
 
+    /// Returns the description, if present in the spec.
+    #[inline]
+    pub fn description(&self) -> Option<&'a str> {
+        self.info.description
+    }
+
     /// Returns the serialization style, if specified.
     #[inline]
     pub fn style(&self) -> Option<ParameterStyle> {
@@ -387,15 +529,35 @@ pub enum PathParameter {}
 #[derive(Clone, Copy, Debug)]
 pub enum QueryParameter {}
 
+/// A marker type for a header parameter.
+#[derive(Clone, Copy, Debug)]
+pub enum HeaderParameter {}
+
+/// A marker type for a cookie parameter.
+#[derive(Clone, Copy, Debug)]
+pub enum CookieParameter {}
+
+/// A marker type for a response header.
+#[derive(Clone, Copy, Debug)]
+pub enum ResponseHeader {}
+
 /// A graph-aware view of an operation's request body.
 #[derive(Debug)]
 pub enum RequestView<'graph, 'a> {
     Json(TypeView<'graph, 'a>),
     Multipart,
+    /// A `text/plain` body.
+    Text,
+    /// An `application/octet-stream` body.
+    Bytes,
 }
 
 /// A graph-aware view of an operation's response body.
 #[derive(Debug)]
 pub enum ResponseView<'graph, 'a> {
     Json(TypeView<'graph, 'a>),
+    /// A `text/plain` body.
+    Text,
+    /// An `application/octet-stream` body.
+    Bytes,
 }
@@ -8,7 +8,9 @@ use petgraph::visit::EdgeRef;
 use crate::ir::{
     InlineTypeId, InlineTypePathRoot, InlineTypePathSegment, OperationId,
     graph::{CookedGraph, GraphEdge},
-    types::{GraphContainer, GraphInlineType, GraphSchemaType, GraphType, VariantMeta},
+    types::{
+        ElementMeta, GraphContainer, GraphInlineType, GraphSchemaType, GraphType, VariantMeta,
+    },
 };
 
 use super::TypeId;
@@ -67,7 +69,9 @@ impl<'graph, 'a> InlineTypePathView<'graph, 'a> {
                             _ => return None,
                         };
                         match source {
-                            GraphContainer::Array { .. } => InlineTypePathSegment::ArrayItem,
+                            GraphContainer::Array { .. } | GraphContainer::Set { .. } => {
+                                InlineTypePathSegment::ArrayItem
+                            }
                             GraphContainer::Map { .. } => InlineTypePathSegment::MapValue,
                             GraphContainer::Optional { .. } => InlineTypePathSegment::Optional,
                         }
@@ -78,6 +82,10 @@ impl<'graph, 'a> InlineTypePathView<'graph, 'a> {
                     GraphEdge::Variant(VariantMeta::Untagged(m)) => {
                         InlineTypePathSegment::UntaggedVariant(TypeId(from), m.ordinal)
                     }
+                    GraphEdge::Element(ElementMeta::Positional(ordinal)) => {
+                        InlineTypePathSegment::TupleElement(ordinal)
+                    }
+                    GraphEdge::Element(ElementMeta::Rest) => InlineTypePathSegment::ArrayItem,
                     GraphEdge::Inherits { .. } => {
                         let (index, _) = cooked
                             .graph
@@ -16,7 +16,7 @@ use super::ViewNode;
 pub struct PrimitiveView<'graph, 'a> {
     cooked: &'graph CookedGraph<'a>,
     index: NodeIndex<usize>,
-    ty: PrimitiveType,
+    ty: PrimitiveType<'a>,
 }
 
 impl<'graph, 'a> PrimitiveView<'graph, 'a> {
@@ -24,14 +24,14 @@ impl<'graph, 'a> PrimitiveView<'graph, 'a> {
     pub(in crate::ir) fn new(
         cooked: &'graph CookedGraph<'a>,
         index: NodeIndex<usize>,
-        ty: PrimitiveType,
+        ty: PrimitiveType<'a>,
     ) -> Self {
         Self { cooked, index, ty }
     }
 
     /// Returns the primitive type.
     #[inline]
-    pub fn ty(&self) -> PrimitiveType {
+    pub fn ty(&self) -> PrimitiveType<'a> {
         self.ty
     }
 }
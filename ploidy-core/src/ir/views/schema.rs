@@ -11,7 +11,8 @@ use crate::ir::{SchemaTypeInfo, graph::CookedGraph, types::GraphSchemaType};
 
 use super::{
     HasResource, ViewNode, any::AnyView, container::ContainerView, enum_::EnumView,
-    primitive::PrimitiveView, struct_::StructView, tagged::TaggedView, untagged::UntaggedView,
+    primitive::PrimitiveView, struct_::StructView, tagged::TaggedView, tuple::TupleView,
+    untagged::UntaggedView,
 };
 
 /// A graph-aware view of a [schema type][GraphSchemaType].
@@ -21,6 +22,7 @@ pub enum SchemaTypeView<'graph, 'a> {
     Struct(SchemaTypeInfo<'a>, StructView<'graph, 'a>),
     Tagged(SchemaTypeInfo<'a>, TaggedView<'graph, 'a>),
     Untagged(SchemaTypeInfo<'a>, UntaggedView<'graph, 'a>),
+    Tuple(SchemaTypeInfo<'a>, TupleView<'graph, 'a>),
     Container(SchemaTypeInfo<'a>, ContainerView<'graph, 'a>),
     Primitive(SchemaTypeInfo<'a>, PrimitiveView<'graph, 'a>),
     Any(SchemaTypeInfo<'a>, AnyView<'graph, 'a>),
@@ -44,6 +46,9 @@ impl<'graph, 'a> SchemaTypeView<'graph, 'a> {
             GraphSchemaType::Untagged(info, ty) => {
                 Self::Untagged(info, UntaggedView::new(cooked, index, ty))
             }
+            GraphSchemaType::Tuple(info, ty) => {
+                Self::Tuple(info, TupleView::new(cooked, index, ty))
+            }
             GraphSchemaType::Container(info, container) => {
                 Self::Container(info, ContainerView::new(cooked, index, container))
             }
@@ -60,6 +65,7 @@ impl<'graph, 'a> SchemaTypeView<'graph, 'a> {
         | Self::Struct(info, ..)
         | Self::Tagged(info, ..)
         | Self::Untagged(info, ..)
+        | Self::Tuple(info, ..)
         | Self::Container(info, ..)
         | Self::Primitive(info, ..)
         | Self::Any(info, ..)) = self;
@@ -91,6 +97,7 @@ impl<'a> HasResource<'a> for SchemaTypeView<'_, 'a> {
         | &Self::Struct(SchemaTypeInfo { resource, .. }, ..)
         | &Self::Tagged(SchemaTypeInfo { resource, .. }, ..)
         | &Self::Untagged(SchemaTypeInfo { resource, .. }, ..)
+        | &Self::Tuple(SchemaTypeInfo { resource, .. }, ..)
         | &Self::Container(SchemaTypeInfo { resource, .. }, ..)
         | &Self::Primitive(SchemaTypeInfo { resource, .. }, ..)
         | &Self::Any(SchemaTypeInfo { resource, .. }, ..)) = self;
@@ -106,6 +113,7 @@ impl<'graph, 'a> ViewNode<'graph, 'a> for SchemaTypeView<'graph, 'a> {
             Self::Struct(_, view) => view.cooked(),
             Self::Tagged(_, view) => view.cooked(),
             Self::Untagged(_, view) => view.cooked(),
+            Self::Tuple(_, view) => view.cooked(),
             Self::Container(_, view) => view.cooked(),
             Self::Primitive(_, view) => view.cooked(),
             Self::Any(_, view) => view.cooked(),
@@ -119,6 +127,7 @@ impl<'graph, 'a> ViewNode<'graph, 'a> for SchemaTypeView<'graph, 'a> {
             Self::Struct(_, view) => view.index(),
             Self::Tagged(_, view) => view.index(),
             Self::Untagged(_, view) => view.index(),
+            Self::Tuple(_, view) => view.index(),
             Self::Container(_, view) => view.index(),
             Self::Primitive(_, view) => view.index(),
             Self::Any(_, view) => view.index(),
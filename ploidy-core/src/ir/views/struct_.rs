@@ -54,7 +54,10 @@ use rustc_hash::FxHashSet;
 
 use crate::ir::{
     graph::{CookedGraph, GraphEdge},
-    types::{FieldMeta, GraphInlineType, GraphSchemaType, GraphStruct, GraphType, StructFieldName},
+    types::{
+        FieldConstraints, FieldDefault, FieldMeta, GraphInlineType, GraphSchemaType, GraphStruct,
+        GraphType, StructFieldName,
+    },
 };
 
 use super::{ViewNode, container::ContainerView, ir::TypeView};
@@ -83,10 +86,18 @@ impl<'graph, 'a> StructView<'graph, 'a> {
         self.ty.description
     }
 
+    /// Returns `true` if the schema forbids undeclared properties,
+    /// with `additionalProperties: false`.
+    #[inline]
+    pub fn closed(&self) -> bool {
+        self.ty.closed
+    }
+
     /// Returns an iterator over all fields, including fields inherited
     /// from `allOf` schemas. Fields are returned in declaration order:
     /// ancestor fields first, in the order of their parents in `allOf`;
-    /// then this struct's own fields.
+    /// then this struct's own fields. The `additionalProperties` field,
+    /// whether own or inherited, always comes last.
     #[inline]
     pub fn fields(&self) -> impl Iterator<Item = StructFieldView<'_, 'graph, 'a>> {
         let all = self
@@ -103,7 +114,12 @@ impl<'graph, 'a> StructView<'graph, 'a> {
             .rev()
             .filter(|f| seen.insert(f.meta.name))
             .collect_vec();
-        deduped.into_iter().rev()
+        let (mut fields, additional): (Vec<_>, Vec<_>) = deduped
+            .into_iter()
+            .rev()
+            .partition(|f| f.meta.name != StructFieldName::AdditionalProperties);
+        fields.extend(additional);
+        fields.into_iter()
     }
 
     /// Returns an iterator over all fields inherited from
@@ -244,6 +260,38 @@ impl<'view, 'graph, 'a: 'graph, P: ViewNode<'graph, 'a>> FieldView<'view, 'a, P>
         self.meta.description
     }
 
+    /// Returns the scalar `default` value, if present in the schema.
+    #[inline]
+    pub fn default(&self) -> Option<FieldDefault<'a>> {
+        self.meta.default
+    }
+
+    /// Returns the validation constraints on this field's value.
+    #[inline]
+    pub fn constraints(&self) -> FieldConstraints {
+        self.meta.constraints
+    }
+
+    /// Returns `true` if this field is marked as `deprecated`.
+    #[inline]
+    pub fn deprecated(&self) -> bool {
+        self.meta.deprecated
+    }
+
+    /// Returns `true` if this field is marked as `readOnly`,
+    /// and should only be sent by the server.
+    #[inline]
+    pub fn read_only(&self) -> bool {
+        self.meta.read_only
+    }
+
+    /// Returns `true` if this field is marked as `writeOnly`,
+    /// and should only be sent by the client.
+    #[inline]
+    pub fn write_only(&self) -> bool {
+        self.meta.write_only
+    }
+
     /// Returns `true` if this field is flattened from an
     /// `anyOf` parent.
     #[inline]
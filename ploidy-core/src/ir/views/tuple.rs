@@ -0,0 +1,123 @@
+//! Tuples: arrays with `prefixItems`.
+//!
+//! In OpenAPI 3.1, an array schema with `prefixItems` defines a tuple,
+//! with a type for each element by position. An `items` schema
+//! alongside `prefixItems` describes the elements after the
+//! positional ones; `items: false` forbids extra elements:
+//!
+//! ```yaml
+//! components:
+//!   schemas:
+//!     Coordinate:
+//!       type: array
+//!       prefixItems:
+//!         - type: number
+//!         - type: number
+//!       items: false
+//! ```
+//!
+//! Ploidy represents this as a [`TupleView`] with a list of positional
+//! elements, and an optional rest element type.
+
+use std::num::NonZeroUsize;
+
+use petgraph::graph::NodeIndex;
+
+use crate::ir::{
+    graph::CookedGraph,
+    types::{ElementMeta, GraphTuple},
+};
+
+use super::{ViewNode, ir::TypeView};
+
+/// A graph-aware view of a [tuple type][GraphTuple].
+#[derive(Debug)]
+pub struct TupleView<'graph, 'a> {
+    cooked: &'graph CookedGraph<'a>,
+    index: NodeIndex<usize>,
+    ty: GraphTuple<'a>,
+}
+
+impl<'graph, 'a> TupleView<'graph, 'a> {
+    #[inline]
+    pub(in crate::ir) fn new(
+        cooked: &'graph CookedGraph<'a>,
+        index: NodeIndex<usize>,
+        ty: GraphTuple<'a>,
+    ) -> Self {
+        Self { cooked, index, ty }
+    }
+
+    /// Returns the description, if present in the schema.
+    #[inline]
+    pub fn description(&self) -> Option<&'a str> {
+        self.ty.description
+    }
+
+    /// Returns an iterator over this tuple's positional elements.
+    #[inline]
+    pub fn elements(&self) -> impl Iterator<Item = TupleElementView<'_, 'graph, 'a>> {
+        self.cooked
+            .elements(self.index)
+            .filter_map(|info| match info.meta {
+                ElementMeta::Positional(ordinal) => Some(TupleElementView {
+                    parent: self,
+                    ordinal,
+                    index: info.target,
+                }),
+                ElementMeta::Rest => None,
+            })
+    }
+
+    /// Returns a view of the type of the elements after
+    /// the positional ones, or `None` if this tuple doesn't
+    /// allow extra elements.
+    #[inline]
+    pub fn rest(&self) -> Option<TypeView<'graph, 'a>> {
+        self.cooked
+            .elements(self.index)
+            .find(|info| matches!(info.meta, ElementMeta::Rest))
+            .map(|info| TypeView::new(self.cooked, info.target))
+    }
+}
+
+impl<'graph, 'a> ViewNode<'graph, 'a> for TupleView<'graph, 'a> {
+    #[inline]
+    fn cooked(&self) -> &'graph CookedGraph<'a> {
+        self.cooked
+    }
+
+    #[inline]
+    fn index(&self) -> NodeIndex<usize> {
+        self.index
+    }
+}
+
+/// A graph-aware view of a positional tuple element.
+#[derive(Debug)]
+pub struct TupleElementView<'view, 'graph, 'a> {
+    parent: &'view TupleView<'graph, 'a>,
+    ordinal: NonZeroUsize,
+    index: NodeIndex<usize>,
+}
+
+impl<'view, 'graph, 'a> TupleElementView<'view, 'graph, 'a> {
+    /// Returns a view of this element's type.
+    #[inline]
+    pub fn ty(&self) -> TypeView<'graph, 'a> {
+        TypeView::new(self.parent.cooked, self.index)
+    }
+
+    /// Returns this element's position, counted from 1.
+    #[inline]
+    pub fn ordinal(&self) -> NonZeroUsize {
+        self.ordinal
+    }
+
+    /// Returns `true` if this element needs `Box<T>` to break a cycle.
+    #[inline]
+    pub fn needs_box(&self) -> bool {
+        let box_sccs = &self.parent.cooked.metadata.box_sccs;
+        box_sccs[self.parent.index.index()] == box_sccs[self.index.index()]
+    }
+}
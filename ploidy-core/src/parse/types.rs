@@ -3,13 +3,14 @@ use std::{borrow::Cow, str::FromStr};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use ploidy_pointer::{JsonPointee, JsonPointer, JsonPointerBuf, JsonPointerTarget};
+use rustc_hash::FxHashSet;
 use serde::{
     Deserialize, Deserializer, Serialize,
     de::{DeserializeOwned, Error as DeserializeError},
 };
 use serde_json::Value as JsonValue;
 
-use crate::error::SerdeError;
+use crate::error::{JsonError, SerdeError};
 
 /// An OpenAPI document.
 #[derive(Debug, Deserialize, JsonPointee, JsonPointerTarget)]
@@ -18,6 +19,8 @@ pub struct Document {
     #[serde(default)]
     pub info: Info,
     #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(default)]
     pub paths: IndexMap<String, PathItem>,
     #[serde(default)]
     pub components: Option<Components>,
@@ -36,6 +39,99 @@ impl Document {
         )?;
         Ok(result)
     }
+
+    /// Parse an OpenAPI document from a JSON string.
+    ///
+    /// Unlike [`Document::from_yaml()`], this reports errors
+    /// at their location in the JSON source.
+    pub fn from_json(json: &str) -> Result<Self, SerdeError> {
+        serde_json::from_str(json).map_err(|err| JsonError::new(json, err).into())
+    }
+
+    /// Merges the paths and components of another document into this one,
+    /// keeping this document's version and info.
+    ///
+    /// Fails without changing this document if both documents define
+    /// the same path, operation ID, or component.
+    pub fn merge(&mut self, other: Document) -> Result<(), MergeError> {
+        if let Some(path) = other
+            .paths
+            .keys()
+            .find(|&path| self.paths.contains_key(path))
+        {
+            return Err(MergeError::Path(path.clone()));
+        }
+
+        let mut ids: FxHashSet<_> = self
+            .paths
+            .values()
+            .flat_map(|item| item.operations())
+            .filter_map(|(_, op)| op.operation_id.as_deref())
+            .collect();
+        if let Some(id) = other
+            .paths
+            .values()
+            .flat_map(|item| item.operations())
+            .filter_map(|(_, op)| op.operation_id.as_deref())
+            .find(|&id| !ids.insert(id))
+        {
+            return Err(MergeError::OperationId(id.to_owned()));
+        }
+
+        if let Some(theirs) = other.components {
+            // Check every kind of component for conflicts
+            // before changing this document.
+            macro_rules! merge_components {
+                ($($field:ident => $kind:literal),* $(,)?) => {
+                    if let Some(ours) = &self.components {
+                        $(if let Some(name) = theirs
+                            .$field
+                            .keys()
+                            .find(|&name| ours.$field.contains_key(name))
+                        {
+                            return Err(MergeError::Component {
+                                kind: $kind,
+                                name: name.clone(),
+                            });
+                        })*
+                    }
+                    let ours = self.components.get_or_insert_with(Components::default);
+                    $(ours.$field.extend(theirs.$field);)*
+                };
+            }
+            merge_components!(
+                schemas => "schemas",
+                responses => "responses",
+                parameters => "parameters",
+                examples => "examples",
+                request_bodies => "requestBodies",
+                headers => "headers",
+                security_schemes => "securitySchemes",
+                links => "links",
+                callbacks => "callbacks",
+            );
+        }
+
+        // Like `info`, the first document's servers win.
+        if self.servers.is_empty() {
+            self.servers = other.servers;
+        }
+
+        self.paths.extend(other.paths);
+        Ok(())
+    }
+}
+
+/// An error that occurs when merging two documents that define
+/// the same item.
+#[derive(Debug, thiserror::Error)]
+pub enum MergeError {
+    #[error("path `{0}` is defined in more than one document")]
+    Path(String),
+    #[error("operation ID `{0}` is used in more than one document")]
+    OperationId(String),
+    #[error("component `#/components/{kind}/{name}` is defined in more than one document")]
+    Component { kind: &'static str, name: String },
 }
 
 #[derive(Clone, Debug, Default, Deserialize, JsonPointee, JsonPointerTarget)]
@@ -73,6 +169,8 @@ pub struct PathItem {
     #[serde(default)]
     pub parameters: Vec<RefOrParameter>,
     #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(default)]
     pub get: Option<Operation>,
     #[serde(default)]
     pub post: Option<Operation>,
@@ -129,11 +227,17 @@ pub struct Operation {
     pub description: Option<String>,
     pub operation_id: Option<String>,
     #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
     pub parameters: Vec<RefOrParameter>,
     #[serde(default)]
     pub request_body: Option<RefOrRequestBody>,
     #[serde(default)]
     pub responses: IndexMap<String, RefOrResponse>,
+    #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(default)]
+    pub deprecated: bool,
     #[serde(flatten)]
     pub extensions: IndexMap<String, JsonValue>,
 }
@@ -144,6 +248,24 @@ impl Operation {
     }
 }
 
+/// A server that hosts the API.
+#[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
+pub struct Server {
+    pub url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub variables: IndexMap<String, ServerVariable>,
+}
+
+/// A variable for substitution in a [`Server`] URL template.
+#[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
+pub struct ServerVariable {
+    pub default: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
 /// A path, query, header, or cookie parameter.
 #[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
 pub struct Parameter {
@@ -204,20 +326,32 @@ pub struct Response {
     pub description: Option<String>,
     #[serde(default)]
     pub content: Option<IndexMap<String, MediaType>>,
+    #[serde(default)]
+    pub headers: IndexMap<String, RefOrHeader>,
 }
 
-/// Example definition (placeholder).
-#[derive(Debug, Deserialize, JsonPointee, JsonPointerTarget)]
+/// Example definition.
+#[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
 pub struct Example {
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub value: Option<JsonValue>,
     #[serde(flatten)]
     pub extensions: IndexMap<String, JsonValue>,
 }
 
-/// Header definition (placeholder).
-#[derive(Debug, Deserialize, JsonPointee, JsonPointerTarget)]
+/// Response header definition.
+#[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
 pub struct Header {
-    #[serde(flatten)]
-    pub extensions: IndexMap<String, JsonValue>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub schema: Option<RefOrSchema>,
 }
 
 /// Security scheme definition (placeholder).
@@ -246,6 +380,10 @@ pub struct Callback {
 pub struct MediaType {
     #[serde(default)]
     pub schema: Option<RefOrSchema>,
+    #[serde(default)]
+    pub example: Option<JsonValue>,
+    #[serde(default)]
+    pub examples: IndexMap<String, RefOrExample>,
 }
 
 /// Components section containing reusable schemas.
@@ -343,6 +481,12 @@ pub type RefOrRequestBody = RefOr<ComponentRef, RequestBody>;
 /// Either a reference or a response definition.
 pub type RefOrResponse = RefOr<ComponentRef, Response>;
 
+/// Either a reference or an example definition.
+pub type RefOrExample = RefOr<ComponentRef, Example>;
+
+/// Either a reference or a response header definition.
+pub type RefOrHeader = RefOr<ComponentRef, Header>;
+
 /// A reference to another definition.
 #[derive(Clone, Debug, Deserialize)]
 pub struct Ref<R> {
@@ -365,7 +509,7 @@ pub enum Ty {
     Null,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, JsonPointee, JsonPointerTarget)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, JsonPointee, JsonPointerTarget)]
 #[serde(rename_all = "lowercase")]
 #[ploidy(pointer(untagged, rename_all = "lowercase"))]
 pub enum Format {
@@ -388,8 +532,11 @@ pub enum Format {
     UInt64,
     Float,
     Double,
-    #[serde(other)]
-    Other,
+    Decimal,
+    Money,
+    /// A format that Ploidy doesn't recognize.
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
@@ -400,6 +547,14 @@ pub enum AdditionalProperties {
     RefOrSchema(RefOrSchema),
 }
 
+#[derive(Clone, Debug, Deserialize, JsonPointee, JsonPointerTarget)]
+#[serde(untagged)]
+#[ploidy(pointer(untagged))]
+pub enum Items {
+    Bool(bool),
+    RefOrSchema(RefOrSchema),
+}
+
 /// An OpenAPI schema definition.
 #[derive(Debug, Clone, Default, Deserialize, JsonPointee, JsonPointerTarget)]
 #[serde(rename_all = "camelCase")]
@@ -414,6 +569,37 @@ pub struct Schema {
     pub description: Option<String>,
     #[serde(default)]
     pub nullable: bool,
+    #[serde(default)]
+    pub deprecated: bool,
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub write_only: bool,
+    #[serde(default)]
+    pub default: Option<JsonValue>,
+    #[serde(default)]
+    pub example: Option<JsonValue>,
+    /// OpenAPI 3.1 `examples`, which should be an array of values.
+    #[serde(default)]
+    pub examples: Option<JsonValue>,
+
+    // Validation.
+    #[serde(default)]
+    pub minimum: Option<f64>,
+    #[serde(default)]
+    pub maximum: Option<f64>,
+    #[serde(default)]
+    pub min_length: Option<u64>,
+    #[serde(default)]
+    pub max_length: Option<u64>,
+    #[serde(default)]
+    pub min_items: Option<u64>,
+    #[serde(default)]
+    pub max_items: Option<u64>,
+    #[serde(default)]
+    pub min_properties: Option<u64>,
+    #[serde(default)]
+    pub max_properties: Option<u64>,
 
     // Object properties.
     #[serde(default)]
@@ -422,14 +608,22 @@ pub struct Schema {
     pub required: Vec<String>,
     #[serde(default)]
     pub additional_properties: Option<AdditionalProperties>,
+    #[serde(default)]
+    pub pattern_properties: Option<IndexMap<String, RefOrSchema>>,
 
     // Array items.
     #[serde(default)]
-    pub items: Option<RefOrSchema>,
+    pub items: Option<Items>,
+    #[serde(default)]
+    pub unique_items: bool,
+    #[serde(default)]
+    pub prefix_items: Option<Vec<RefOrSchema>>,
 
     // Enum variants.
     #[serde(rename = "enum", default)]
     pub variants: Option<Vec<JsonValue>>,
+    #[serde(rename = "const", default)]
+    pub constant: Option<JsonValue>,
 
     // Composition.
     #[serde(default)]
@@ -615,6 +809,18 @@ impl<'a> FromExtension<'a> for &'a str {
     }
 }
 
+impl FromExtension<'_> for f64 {
+    fn from_extension(value: &JsonValue) -> Option<f64> {
+        value.as_f64()
+    }
+}
+
+impl<'a> FromExtension<'a> for Vec<&'a str> {
+    fn from_extension(value: &'a JsonValue) -> Option<Vec<&'a str>> {
+        value.as_array()?.iter().map(JsonValue::as_str).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -739,4 +945,277 @@ mod tests {
         });
         assert_matches!(serde_json::from_value::<RefOrSchema>(json), Err(_));
     }
+
+    // MARK: Merging
+
+    #[test]
+    fn test_merge_disjoint_documents() {
+        let mut doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Pets
+              version: 1.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Pet'
+            components:
+              schemas:
+                Pet:
+                  type: object
+        "})
+        .unwrap();
+        let other = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Stores
+              version: 2.0.0
+            paths:
+              /stores:
+                get:
+                  operationId: listStores
+                  responses:
+                    '200':
+                      description: OK
+                      content:
+                        application/json:
+                          schema:
+                            $ref: '#/components/schemas/Store'
+            components:
+              schemas:
+                Store:
+                  type: object
+        "})
+        .unwrap();
+
+        doc.merge(other).unwrap();
+
+        assert_eq!(doc.info.title.as_deref(), Some("Pets"));
+        assert_eq!(
+            doc.paths.keys().map(|path| path.as_str()).collect_vec(),
+            ["/pets", "/stores"]
+        );
+        let components = doc.components.as_ref().unwrap();
+        assert_eq!(
+            components
+                .schemas
+                .keys()
+                .map(|name| name.as_str())
+                .collect_vec(),
+            ["Pet", "Store"]
+        );
+    }
+
+    #[test]
+    fn test_merge_rejects_conflicting_schemas() {
+        let mut doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            components:
+              schemas:
+                Error:
+                  type: object
+        "})
+        .unwrap();
+        let other = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            components:
+              schemas:
+                Error:
+                  type: string
+        "})
+        .unwrap();
+
+        let result = doc.merge(other);
+        assert_matches!(
+            result,
+            Err(MergeError::Component {
+                kind: "schemas",
+                name,
+            }) if name == "Error",
+        );
+    }
+
+    #[test]
+    fn test_merge_conflict_leaves_document_unchanged() {
+        let mut doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  responses: {}
+            components:
+              schemas:
+                Pet:
+                  type: object
+              responses:
+                NotFound:
+                  description: Not found
+        "})
+        .unwrap();
+        let other = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /stores:
+                get:
+                  operationId: listStores
+                  responses: {}
+            components:
+              schemas:
+                Store:
+                  type: object
+              responses:
+                NotFound:
+                  description: Missing
+        "})
+        .unwrap();
+
+        let result = doc.merge(other);
+        assert_matches!(
+            result,
+            Err(MergeError::Component {
+                kind: "responses",
+                name,
+            }) if name == "NotFound",
+        );
+        // `Store` doesn't conflict, but isn't merged, either.
+        let components = doc.components.as_ref().unwrap();
+        assert_eq!(
+            components
+                .schemas
+                .keys()
+                .map(|name| name.as_str())
+                .collect_vec(),
+            ["Pet"]
+        );
+        assert_eq!(
+            doc.paths.keys().map(|path| path.as_str()).collect_vec(),
+            ["/pets"]
+        );
+    }
+
+    #[test]
+    fn test_merge_rejects_conflicting_operation_ids() {
+        let mut doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: list
+                  responses: {}
+        "})
+        .unwrap();
+        let other = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /stores:
+                get:
+                  operationId: list
+                  responses: {}
+        "})
+        .unwrap();
+
+        let result = doc.merge(other);
+        assert_matches!(result, Err(MergeError::OperationId(id)) if id == "list");
+        // A failed merge leaves the document unchanged.
+        assert_eq!(
+            doc.paths.keys().map(|path| path.as_str()).collect_vec(),
+            ["/pets"]
+        );
+    }
+
+    #[test]
+    fn test_merge_rejects_conflicting_paths() {
+        let mut doc = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /pets:
+                get:
+                  operationId: listPets
+                  responses: {}
+        "})
+        .unwrap();
+        let other = Document::from_yaml(indoc::indoc! {"
+            openapi: 3.0.0
+            paths:
+              /pets:
+                post:
+                  operationId: createPet
+                  responses: {}
+        "})
+        .unwrap();
+
+        let result = doc.merge(other);
+        assert_matches!(result, Err(MergeError::Path(path)) if path == "/pets");
+    }
+
+    // MARK: JSON
+
+    #[test]
+    fn test_from_json_parses_document() {
+        let doc = Document::from_json(indoc::indoc! {r#"
+            {
+              "openapi": "3.0.0",
+              "info": {"title": "Test API", "version": "1.0"},
+              "paths": {
+                "/pets": {
+                  "get": {"operationId": "listPets", "responses": {}}
+                }
+              }
+            }
+        "#})
+        .unwrap();
+
+        assert_eq!(doc.info.title.as_deref(), Some("Test API"));
+        let ids = doc
+            .paths
+            .values()
+            .flat_map(|item| item.operations())
+            .filter_map(|(_, op)| op.operation_id.as_deref())
+            .collect_vec();
+        assert_eq!(ids, ["listPets"]);
+    }
+
+    #[test]
+    fn test_from_json_labels_error_location() {
+        let json = indoc::indoc! {r#"
+            {
+              "openapi": "3.0.0",
+              "paths": {,}
+            }
+        "#};
+        let err = Document::from_json(json).unwrap_err();
+
+        let labels = miette::Diagnostic::labels(&err)
+            .into_iter()
+            .flatten()
+            .collect_vec();
+        let [label] = &*labels else {
+            panic!("expected one label; got `{labels:?}`");
+        };
+        assert_eq!(&json[label.offset()..], ",}\n}\n");
+    }
+
+    #[test]
+    fn test_from_json_labels_end_of_input() {
+        let json = r#"{"openapi": "3.0.0""#;
+        let err = Document::from_json(json).unwrap_err();
+
+        let labels = miette::Diagnostic::labels(&err)
+            .into_iter()
+            .flatten()
+            .collect_vec();
+        let [label] = &*labels else {
+            panic!("expected one label; got `{labels:?}`");
+        };
+        // Errors at the end of the input point at the last character.
+        assert_eq!(&json[label.offset()..], "\"");
+    }
 }
@@ -22,6 +22,15 @@
 //!   according to the given case. The supported cases are `lowercase`, `UPPERCASE`,
 //!   `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, and
 //!   `SCREAMING-KEBAB-CASE`.
+//! * `#[ploidy(pointer(rename_all_fields = "case"))]` - Rename the fields of all struct-like
+//!   enum variants according to the given case, independently of `rename_all`.
+//!   Supported on enums only.
+//! * `#[ploidy(pointer(transparent))]` - Resolve pointers directly against the struct's
+//!   only non-skipped field, without a segment for the field. Supported on structs only.
+//! * `#[ploidy(pointer(bound = "T: Trait"))]` - Replace the `T: JsonPointee` bounds that
+//!   the derive adds for each type parameter with the given `where` predicates.
+//!   `bound = ""` removes the bounds entirely. Since `JsonPointee` requires `Any`,
+//!   the predicates must still imply `T: 'static`.
 //! * `#[ploidy(pointer(crate = "path::to::ploidy_pointer"))]` - Override the path to the
 //!   `ploidy_pointer` crate. Defaults to `::ploidy_pointer`.
 //!
@@ -33,6 +42,8 @@
 //!   instead of its Rust name.
 //! * `#[ploidy(pointer(skip))]` - Make this variant inaccessible, except for the tag field
 //!   if using the internally or adjacently tagged enum representation.
+//! * `#[ploidy(pointer(alias = "name"))]` - Also access this variant using the given name.
+//!   Can be repeated. Only affects the externally tagged enum representation.
 //!
 //! # Field Attributes
 //!
@@ -41,8 +52,11 @@
 //! * `#[ploidy(pointer(rename = "name"))]` - Access this variant using the given name,
 //!   instead of its Rust name.
 //! * `#[ploidy(pointer(flatten))]` - Remove one layer of structure between the container
-//!   and field. Supported on named fields only.
+//!   and field. Supported on named fields only. The field can be a struct, or a map
+//!   that holds any keys not matched by the container's other fields.
 //! * `#[ploidy(pointer(skip))]` - Exclude the field from pointer access.
+//! * `#[ploidy(pointer(alias = "name"))]` - Also access this field using the given name.
+//!   Can be repeated. Supported on named fields only.
 //!
 //! # Examples
 //!
@@ -225,8 +239,8 @@ use itertools::Itertools;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, TokenStreamExt, format_ident, quote};
 use syn::{
-    Attribute, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, GenericParam, Ident,
-    Lifetime, parse_macro_input,
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Ident, Lifetime, Token,
+    WherePredicate, parse_macro_input, punctuated::Punctuated,
 };
 
 /// Derives the `JsonPointee` trait for JSON Pointer (RFC 6901) traversal.
@@ -263,44 +277,105 @@ fn derive_pointee_for(input: &DeriveInput) -> syn::Result<TokenStream> {
     let container = ContainerInfo::new(name, &root, &attrs)
         .map_err(|err| syn::Error::new_spanned(input, err))?;
 
-    // Hygienic parameter for the generated `resolve` method.
+    // Hygienic parameter for the generated `resolve` and `resolve_mut` methods.
     let pointer = Ident::new("pointer", Span::mixed_site());
 
-    let body = match &input.data {
-        Data::Struct(data) => {
-            if container.tag.is_some() {
-                return Err(syn::Error::new_spanned(input, DeriveError::TagOnNonEnum));
+    // Transparent structs resolve pointers against their only non-skipped field.
+    let transparent = match &input.data {
+        Data::Struct(data) if container.transparent => {
+            let members: Vec<_> = data
+                .fields
+                .iter()
+                .zip(data.fields.members())
+                .map(|(f, member)| {
+                    let attrs: Vec<_> = f
+                        .attrs
+                        .iter()
+                        .map(FieldAttr::parse_one)
+                        .flatten_ok()
+                        .try_collect()?;
+                    let is_skipped = attrs.iter().any(|attr| matches!(attr, FieldAttr::Skip));
+                    syn::Result::Ok((!is_skipped).then_some(member))
+                })
+                .flatten_ok()
+                .try_collect()?;
+            let member = members.into_iter().exactly_one().map_err(|_| {
+                syn::Error::new_spanned(&data.fields, DeriveError::TransparentWithoutOneField)
+            })?;
+            Some(member)
+        }
+        _ => None,
+    };
+
+    let derive = |access| {
+        let container = ContainerInfo {
+            access,
+            ..container
+        };
+        match &input.data {
+            Data::Struct(data) => {
+                if container.tag.is_some() {
+                    return Err(syn::Error::new_spanned(input, DeriveError::TagOnNonEnum));
+                }
+                if container.rename_all_fields.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input,
+                        DeriveError::RenameAllFieldsOnNonEnum,
+                    ));
+                }
+                match &transparent {
+                    Some(member) => {
+                        let method = access.method();
+                        Ok(quote! {
+                            <_ as #root::JsonPointee>::#method(#access self.#member, #pointer)
+                        })
+                    }
+                    None => derive_for_struct(&pointer, container, data),
+                }
             }
-            derive_for_struct(&pointer, container, data)?
+            Data::Enum(_) if container.transparent => Err(syn::Error::new_spanned(
+                input,
+                DeriveError::TransparentOnNonStruct,
+            )),
+            Data::Enum(data) => derive_for_enum(&pointer, container, data),
+            Data::Union(_) => Err(syn::Error::new_spanned(input, DeriveError::Union)),
         }
-        Data::Enum(data) => derive_for_enum(&pointer, container, data)?,
+    };
+    let body = derive(Access::Shared)?;
+    let body_mut = derive(Access::Mut)?;
+    let children = match &input.data {
+        Data::Struct(data) => match &transparent {
+            Some(member) => quote!(<_ as #root::JsonPointee>::child_pointers(&self.#member)),
+            None => derive_children_for_struct(container, data)?,
+        },
+        Data::Enum(data) => derive_children_for_enum(container, data)?,
         Data::Union(_) => return Err(syn::Error::new_spanned(input, DeriveError::Union)),
     };
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-    let where_clause = {
-        // Add or extend the `where` clause with `T: JsonPointee` bounds
-        // for all generic type parameters.
-        let type_param_bounds = input
-            .generics
-            .params
+    let generics = {
+        // Add or extend the `where` clause with the `bound` predicates, if
+        // specified; or `T: JsonPointee` bounds for all generic type parameters.
+        let bounds = attrs
             .iter()
-            .filter_map(|param| match param {
-                GenericParam::Type(param) => {
-                    let ident = &param.ident;
-                    Some(quote! { #ident: #root::JsonPointee })
-                }
+            .find_map(|attr| match attr {
+                ContainerAttr::Bound(bounds) => Some(bounds.clone()),
                 _ => None,
             })
-            .collect_vec();
-        if type_param_bounds.is_empty() {
-            quote! { #where_clause }
-        } else if let Some(where_clause) = where_clause {
-            quote! { #where_clause #(#type_param_bounds),* }
-        } else {
-            quote! { where #(#type_param_bounds),* }
-        }
+            .unwrap_or_else(|| {
+                input
+                    .generics
+                    .type_params()
+                    .map(|param| {
+                        let ident = &param.ident;
+                        syn::parse_quote!(#ident: #root::JsonPointee)
+                    })
+                    .collect_vec()
+            });
+        let mut generics = input.generics.clone();
+        generics.make_where_clause().predicates.extend(bounds);
+        generics
     };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         #[automatically_derived]
@@ -309,6 +384,16 @@ fn derive_pointee_for(input: &DeriveInput) -> syn::Result<TokenStream> {
                 -> ::std::result::Result<&dyn #root::JsonPointee, #root::JsonPointeeError> {
                 #body
             }
+
+            fn resolve_mut(&mut self, #pointer: &#root::JsonPointer)
+                -> ::std::result::Result<&mut dyn #root::JsonPointee, #root::JsonPointeeError> {
+                #body_mut
+            }
+
+            fn child_pointers(&self)
+                -> ::std::option::Option<::std::vec::Vec<#root::JsonPointerBuf>> {
+                #children
+            }
         }
     })
 }
@@ -367,23 +452,30 @@ fn derive_for_struct(
             let fields: Vec<_> = fields
                 .named
                 .iter()
-                .map(|f| NamedFieldInfo::new(container, f))
+                .map(|f| NamedFieldInfo::new(container.rename_all, f))
                 .try_collect()?;
             let bindings = fields.iter().map(|f| {
                 let binding = f.binding;
                 quote! { #binding }
             });
-            let body = NamedPointeeBody::new(NamedPointeeTy::Struct(container), pointer, &fields);
-            quote! {
+            let destructure = quote! {
                 let Self { #(#bindings),* } = self;
-                #body
-            }
+            };
+            let body = NamedPointeeBody::new(
+                NamedPointeeTy::Struct(container),
+                pointer,
+                &destructure,
+                &fields,
+            );
+            quote!(#body)
         }
         Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
             // For newtype structs, resolve the pointer against the inner value.
             let root = container.root;
+            let access = container.access;
+            let method = access.method();
             quote! {
-                <_ as #root::JsonPointee>::resolve(&self.0, #pointer)
+                <_ as #root::JsonPointee>::#method(#access self.0, #pointer)
             }
         }
         Fields::Unnamed(fields) => {
@@ -397,11 +489,16 @@ fn derive_for_struct(
                 let binding = &f.binding;
                 quote! { #binding }
             });
-            let body = TuplePointeeBody::new(TuplePointeeTy::Struct(container), pointer, &fields);
-            quote! {
+            let destructure = quote! {
                 let Self(#(#bindings),*) = self;
-                #body
-            }
+            };
+            let body = TuplePointeeBody::new(
+                TuplePointeeTy::Struct(container),
+                pointer,
+                &destructure,
+                &fields,
+            );
+            quote!(#body)
         }
         Fields::Unit => {
             let body = UnitPointeeBody::new(UnitPointeeTy::Struct(container), pointer);
@@ -426,6 +523,8 @@ fn derive_for_enum(
         .map(|variant| {
             let name = &variant.ident;
             let root = container.root;
+            let access = container.access;
+            let method = access.method();
             let attrs: Vec<_> = variant
                 .attrs
                 .iter()
@@ -451,19 +550,27 @@ fn derive_for_enum(
                     let fields: Vec<_> = fields
                         .named
                         .iter()
-                        .map(|f| NamedFieldInfo::new(container, f))
+                        .map(|f| NamedFieldInfo::new(container.variant_rename_all(), f))
                         .try_collect()?;
                     let bindings = fields.iter().map(|f| {
                         let binding = f.binding;
                         quote! { #binding }
                     });
+                    // Destructure the fields after any early return of `self`,
+                    // so that mutable bindings don't overlap with `self`.
+                    let destructure = quote! {
+                        let Self::#name { #(#bindings),* } = self else {
+                            unreachable!()
+                        };
+                    };
                     let body = NamedPointeeBody::new(
                         NamedPointeeTy::Variant(info, tag),
                         pointer,
+                        &destructure,
                         &fields,
                     );
                     quote! {
-                        Self::#name { #(#bindings),* } => {
+                        Self::#name { .. } => {
                             #body
                         }
                     }
@@ -474,16 +581,19 @@ fn derive_for_enum(
                             // For internally tagged newtype variants, check the tag field
                             // before delegating to the inner value.
                             let key = Ident::new("key", Span::mixed_site());
-                            let effective_name = info.effective_name();
+                            let tag_value = container.tag_value(pointer, &info.effective_name());
                             quote! {
-                                Self::#name(inner) => {
+                                Self::#name(..) => {
                                     let Some(#key) = #pointer.head() else {
-                                        return Ok(self as &dyn #root::JsonPointee);
+                                        return Ok(self as #access dyn #root::JsonPointee);
                                     };
                                     if #key == #tag_field {
-                                        return Ok(&#effective_name as &dyn #root::JsonPointee);
+                                        return #tag_value;
                                     }
-                                    <_ as #root::JsonPointee>::resolve(inner, #pointer)
+                                    let Self::#name(inner) = self else {
+                                        unreachable!()
+                                    };
+                                    <_ as #root::JsonPointee>::#method(inner, #pointer)
                                 }
                             }
                         }
@@ -492,7 +602,7 @@ fn derive_for_enum(
                             // must match the variant name; then the tail should resolve
                             // against the inner value.
                             let key = Ident::new("key", Span::mixed_site());
-                            let effective_name = info.effective_name();
+                            let names = info.names();
                             let pointee_ty = TuplePointeeTy::Variant(info, tag);
                             let key_err = if cfg!(feature = "did-you-mean") {
                                 quote!(#root::JsonPointerKeyError::with_ty(#key, #pointee_ty))
@@ -500,14 +610,18 @@ fn derive_for_enum(
                                 quote!(#root::JsonPointerKeyError::new(#key))
                             };
                             quote! {
-                                Self::#name(inner) => {
+                                Self::#name(..) => {
                                     let Some(#key) = #pointer.head() else {
-                                        return Ok(self as &dyn #root::JsonPointee);
+                                        return Ok(self as #access dyn #root::JsonPointee);
                                     };
-                                    if #key != #effective_name {
+                                    if #(#key != #names)&&* {
                                         return Err(#key_err)?;
                                     }
-                                    <_ as #root::JsonPointee>::resolve(inner, #pointer.tail())
+                                    let Self::#name(inner) = self else {
+                                        unreachable!()
+                                    };
+                                    <_ as #root::JsonPointee>::#method(inner, #pointer.tail())
+                                        .map_err(|err| err.under(#key))
                                 }
                             }
                         }
@@ -515,7 +629,7 @@ fn derive_for_enum(
                             // For adjacently tagged newtype variants, the first segment
                             // must match either the tag or content field.
                             let key = Ident::new("key", Span::mixed_site());
-                            let effective_name = info.effective_name();
+                            let tag_value = container.tag_value(pointer, &info.effective_name());
                             let pointee_ty = TuplePointeeTy::Variant(info, tag);
                             let key_err = if cfg!(feature = "did-you-mean") {
                                 quote!(#root::JsonPointerKeyError::with_suggestions(
@@ -527,13 +641,19 @@ fn derive_for_enum(
                                 quote!(#root::JsonPointerKeyError::new(#key))
                             };
                             quote! {
-                                Self::#name(inner) => {
+                                Self::#name(..) => {
                                     let Some(#key) = #pointer.head() else {
-                                        return Ok(self as &dyn #root::JsonPointee);
+                                        return Ok(self as #access dyn #root::JsonPointee);
                                     };
                                     match &*#key.to_str() {
-                                        #tag_field => Ok(&#effective_name as &dyn #root::JsonPointee),
-                                        #content_field => <_ as #root::JsonPointee>::resolve(inner, #pointer.tail()),
+                                        #tag_field => #tag_value,
+                                        #content_field => {
+                                            let Self::#name(inner) = self else {
+                                                unreachable!()
+                                            };
+                                            <_ as #root::JsonPointee>::#method(inner, #pointer.tail())
+                                                .map_err(|err| err.under(#key))
+                                        }
                                         _ => Err(#key_err)?,
                                     }
                                 }
@@ -544,7 +664,7 @@ fn derive_for_enum(
                             // against the inner value.
                             quote! {
                                 Self::#name(inner) => {
-                                    <_ as #root::JsonPointee>::resolve(
+                                    <_ as #root::JsonPointee>::#method(
                                         inner,
                                         #pointer,
                                     )
@@ -564,13 +684,19 @@ fn derive_for_enum(
                         let binding = &f.binding;
                         quote! { #binding }
                     });
+                    let destructure = quote! {
+                        let Self::#name(#(#bindings),*) = self else {
+                            unreachable!()
+                        };
+                    };
                     let body = TuplePointeeBody::new(
                         TuplePointeeTy::Variant(info, tag),
                         pointer,
+                        &destructure,
                         &fields,
                     );
                     quote! {
-                        Self::#name(#(#bindings),*) => {
+                        Self::#name(..) => {
                             #body
                         }
                     }
@@ -598,6 +724,180 @@ fn derive_for_enum(
     })
 }
 
+fn derive_children_for_struct(
+    container: ContainerInfo<'_>,
+    data: &DataStruct,
+) -> syn::Result<TokenStream> {
+    let root = container.root;
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let fields: Vec<_> = fields
+                .named
+                .iter()
+                .map(|f| NamedFieldInfo::new(container.rename_all, f))
+                .try_collect()?;
+            let flattened = fields
+                .iter()
+                .filter(|f| f.is_flattened)
+                .map(|f| {
+                    let binding = f.binding;
+                    quote!(&self.#binding)
+                })
+                .collect_vec();
+            let body = ChildPointersBody::new(container, None, &fields, &flattened);
+            quote!(#body)
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            // Newtype structs have the same children as their inner value.
+            quote!(<_ as #root::JsonPointee>::child_pointers(&self.0))
+        }
+        Fields::Unnamed(fields) => {
+            let fields: Vec<_> = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(index, f)| TupleFieldInfo::new(index, f))
+                .try_collect()?;
+            let body = ChildPointersBody::new(container, None, &fields, &[]);
+            quote!(#body)
+        }
+        Fields::Unit => quote!(::std::option::Option::None),
+    };
+    Ok(body)
+}
+
+fn derive_children_for_enum(
+    container: ContainerInfo<'_>,
+    data: &DataEnum,
+) -> syn::Result<TokenStream> {
+    let tag = container.tag.unwrap_or(VariantTag::External);
+    let root = container.root;
+
+    let arms: Vec<_> = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let name = &variant.ident;
+            let attrs: Vec<_> = variant
+                .attrs
+                .iter()
+                .map(VariantAttr::parse_one)
+                .flatten_ok()
+                .try_collect()?;
+            let info = VariantInfo::new(container, name, &attrs);
+
+            // Skipped variants never resolve, so they have no children.
+            if info.is_skipped() {
+                return syn::Result::Ok(quote! {
+                    Self::#name { .. } => ::std::option::Option::Some(::std::vec::Vec::new())
+                });
+            }
+
+            let arm = match &variant.fields {
+                Fields::Named(fields) => {
+                    let fields: Vec<_> = fields
+                        .named
+                        .iter()
+                        .map(|f| NamedFieldInfo::new(container.variant_rename_all(), f))
+                        .try_collect()?;
+                    let bindings = fields
+                        .iter()
+                        .filter(|f| f.is_flattened)
+                        .map(|f| f.binding)
+                        .collect_vec();
+                    let flattened = bindings
+                        .iter()
+                        .map(|binding| quote!(#binding))
+                        .collect_vec();
+                    let body =
+                        ChildPointersBody::new(container, Some((info, tag)), &fields, &flattened);
+                    quote! {
+                        Self::#name { #(#bindings,)* .. } => #body
+                    }
+                }
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    let inner = Ident::new("inner", Span::mixed_site());
+                    let children = quote!(<_ as #root::JsonPointee>::child_pointers(#inner));
+                    let empty = quote!(#root::JsonPointer::empty());
+                    let body = match tag {
+                        // Internally tagged newtype variants have the tag field,
+                        // and the inner value's children.
+                        VariantTag::Internal(tag_field) => quote! {
+                            ::std::option::Option::Some(
+                                ::std::iter::once(#empty.join(#tag_field))
+                                    .chain(#children.into_iter().flatten())
+                                    .collect(),
+                            )
+                        },
+                        // The variant name resolves to the inner value.
+                        VariantTag::External => {
+                            let variant_name = info.effective_name();
+                            quote! {
+                                ::std::option::Option::Some(
+                                    ::std::vec![#empty.join(#variant_name)],
+                                )
+                            }
+                        }
+                        // The content field resolves to the inner value.
+                        VariantTag::Adjacent {
+                            tag: tag_field,
+                            content: content_field,
+                        } => quote! {
+                            ::std::option::Option::Some(::std::vec![
+                                #empty.join(#tag_field),
+                                #empty.join(#content_field),
+                            ])
+                        },
+                        VariantTag::Untagged => children,
+                    };
+                    quote! {
+                        Self::#name(#inner) => #body
+                    }
+                }
+                Fields::Unnamed(fields) => {
+                    let fields: Vec<_> = fields
+                        .unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(index, f)| TupleFieldInfo::new(index, f))
+                        .try_collect()?;
+                    let body = ChildPointersBody::new(container, Some((info, tag)), &fields, &[]);
+                    quote! {
+                        Self::#name(..) => #body
+                    }
+                }
+                Fields::Unit => {
+                    let body = match tag {
+                        // Tagged unit variants have just the tag field.
+                        VariantTag::Internal(tag_field)
+                        | VariantTag::Adjacent { tag: tag_field, .. } => {
+                            quote! {
+                                ::std::option::Option::Some(::std::vec![
+                                    #root::JsonPointer::empty().join(#tag_field),
+                                ])
+                            }
+                        }
+                        // Externally tagged and untagged unit variants are leaves.
+                        VariantTag::External | VariantTag::Untagged => {
+                            quote!(::std::option::Option::None)
+                        }
+                    };
+                    quote! {
+                        Self::#name => #body
+                    }
+                }
+            };
+            syn::Result::Ok(arm)
+        })
+        .try_collect()?;
+
+    Ok(quote! {
+        match self {
+            #(#arms,)*
+        }
+    })
+}
+
 /// Extracts the `#[ploidy(pointer(crate = "..."))]` attribute,
 /// falling back to `::ploidy_pointer` if not present.
 fn crate_path(attrs: &[ContainerAttr]) -> Cow<'_, syn::Path> {
@@ -615,7 +915,10 @@ struct ContainerInfo<'a> {
     name: &'a Ident,
     root: &'a syn::Path,
     rename_all: Option<RenameAll>,
+    rename_all_fields: Option<RenameAll>,
     tag: Option<VariantTag<'a>>,
+    transparent: bool,
+    access: Access,
 }
 
 impl<'a> ContainerInfo<'a> {
@@ -628,6 +931,14 @@ impl<'a> ContainerInfo<'a> {
             &ContainerAttr::RenameAll(rename_all) => Some(rename_all),
             _ => None,
         });
+        let rename_all_fields = attrs.iter().find_map(|attr| match attr {
+            &ContainerAttr::RenameAllFields(rename_all) => Some(rename_all),
+            _ => None,
+        });
+
+        let transparent = attrs
+            .iter()
+            .any(|attr| matches!(attr, ContainerAttr::Transparent));
 
         let tag = attrs
             .iter()
@@ -670,21 +981,69 @@ impl<'a> ContainerInfo<'a> {
             name,
             root,
             rename_all,
+            rename_all_fields,
             tag,
+            transparent,
+            access: Access::Shared,
         })
     }
+
+    /// Returns the transform for the fields of struct-like variants:
+    /// `rename_all_fields` if set, or `rename_all` otherwise.
+    fn variant_rename_all(&self) -> Option<RenameAll> {
+        self.rename_all_fields.or(self.rename_all)
+    }
+
+    /// Returns an expression that resolves to the given tag value.
+    ///
+    /// Tag values aren't stored in the container, so they can't be
+    /// borrowed mutably; resolving a tag mutably returns an error.
+    fn tag_value(&self, pointer: &Ident, name: &str) -> TokenStream {
+        let root = self.root;
+        match self.access {
+            Access::Shared => quote!(Ok(&#name as &dyn #root::JsonPointee)),
+            Access::Mut => quote!(Err(#root::JsonPointerReadOnlyError::new(#pointer))?),
+        }
+    }
+}
+
+/// Whether a generated method resolves to a shared or mutable reference.
+#[derive(Clone, Copy)]
+enum Access {
+    Shared,
+    Mut,
+}
+
+impl Access {
+    /// Returns the name of the `JsonPointee` method for this access.
+    fn method(self) -> Ident {
+        match self {
+            Self::Shared => Ident::new("resolve", Span::call_site()),
+            Self::Mut => Ident::new("resolve_mut", Span::call_site()),
+        }
+    }
+}
+
+impl ToTokens for Access {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(match self {
+            Self::Shared => quote!(&),
+            Self::Mut => quote!(&mut),
+        });
+    }
 }
 
 #[derive(Debug)]
 struct NamedFieldInfo<'a> {
     binding: &'a Ident,
     key: String,
+    aliases: Vec<String>,
     is_flattened: bool,
     is_skipped: bool,
 }
 
 impl<'a> NamedFieldInfo<'a> {
-    fn new(container: ContainerInfo<'a>, f: &'a Field) -> syn::Result<Self> {
+    fn new(rename_all: Option<RenameAll>, f: &'a Field) -> syn::Result<Self> {
         let name = f.ident.as_ref().unwrap();
         let attrs: Vec<_> = f
             .attrs
@@ -706,16 +1065,21 @@ impl<'a> NamedFieldInfo<'a> {
                 FieldAttr::Rename(name) => Some(name.clone()),
                 _ => None,
             })
-            .or_else(|| {
-                container
-                    .rename_all
-                    .map(|rename_all| rename_all.apply(&name.to_string()))
-            })
+            .or_else(|| rename_all.map(|rename_all| rename_all.apply(&name.to_string())))
             .unwrap_or_else(|| name.to_string());
 
+        let aliases = attrs
+            .iter()
+            .filter_map(|attr| match attr {
+                FieldAttr::Alias(alias) => Some(alias.clone()),
+                _ => None,
+            })
+            .collect();
+
         Ok(NamedFieldInfo {
             binding: name,
             key,
+            aliases,
             is_flattened,
             is_skipped,
         })
@@ -747,6 +1111,9 @@ impl TupleFieldInfo {
                 FieldAttr::Rename(_) => {
                     Err(syn::Error::new_spanned(f, DeriveError::RenameOnNonNamed))
                 }
+                FieldAttr::Alias(_) => {
+                    Err(syn::Error::new_spanned(f, DeriveError::AliasOnNonNamed))
+                }
                 _ => Ok(()),
             })
             .try_collect()?;
@@ -792,6 +1159,16 @@ impl<'a> VariantInfo<'a> {
             .unwrap_or_else(|| self.name.to_string())
     }
 
+    /// Returns the effective name, followed by any aliases.
+    fn names(&self) -> Vec<String> {
+        std::iter::once(self.effective_name())
+            .chain(self.attrs.iter().filter_map(|attr| match attr {
+                VariantAttr::Alias(alias) => Some(alias.clone()),
+                _ => None,
+            }))
+            .collect()
+    }
+
     fn is_skipped(&self) -> bool {
         self.attrs
             .iter()
@@ -799,18 +1176,124 @@ impl<'a> VariantInfo<'a> {
     }
 }
 
+/// A field that has a key in its container.
+trait FieldKey {
+    /// Returns the key, or `None` if the field is skipped or flattened.
+    fn key(&self) -> Option<String>;
+}
+
+impl FieldKey for NamedFieldInfo<'_> {
+    fn key(&self) -> Option<String> {
+        (!self.is_flattened && !self.is_skipped).then(|| self.key.clone())
+    }
+}
+
+impl FieldKey for TupleFieldInfo {
+    fn key(&self) -> Option<String> {
+        (!self.is_skipped).then(|| self.index.to_string())
+    }
+}
+
+/// Generates the body of `child_pointers` for a struct, or a struct-like
+/// or tuple variant.
+struct ChildPointersBody<'a, F> {
+    container: ContainerInfo<'a>,
+    variant: Option<(VariantInfo<'a>, VariantTag<'a>)>,
+    fields: &'a [F],
+    flattened: &'a [TokenStream],
+}
+
+impl<'a, F> ChildPointersBody<'a, F> {
+    fn new(
+        container: ContainerInfo<'a>,
+        variant: Option<(VariantInfo<'a>, VariantTag<'a>)>,
+        fields: &'a [F],
+        flattened: &'a [TokenStream],
+    ) -> Self {
+        Self {
+            container,
+            variant,
+            fields,
+            flattened,
+        }
+    }
+}
+
+impl<F: FieldKey> ToTokens for ChildPointersBody<'_, F> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let root = self.container.root;
+        let empty = quote!(#root::JsonPointer::empty());
+
+        // The tag field, if any, and the segments that lead to the fields.
+        let (tag, prefix) = match self.variant {
+            Some((_, VariantTag::Internal(tag_field))) => (Some(tag_field), None),
+            Some((info, VariantTag::External)) => (None, Some(info.effective_name())),
+            Some((
+                _,
+                VariantTag::Adjacent {
+                    tag: tag_field,
+                    content: content_field,
+                },
+            )) => (Some(tag_field), Some(content_field.to_owned())),
+            Some((_, VariantTag::Untagged)) | None => (None, None),
+        };
+        let tag = tag
+            .map(|tag_field| quote!(#empty.join(#tag_field)))
+            .into_iter();
+        let base = {
+            let prefix = prefix.into_iter();
+            quote!(#empty #(.join(#prefix))*)
+        };
+
+        let keys = self
+            .fields
+            .iter()
+            .filter_map(FieldKey::key)
+            .map(|key| quote!(#base.join(#key)));
+
+        // Flattened fields contribute their own children.
+        let child = Ident::new("child", Span::mixed_site());
+        let flattened = self.flattened.iter().map(|flattened| {
+            quote! {
+                .chain(
+                    <_ as #root::JsonPointee>::child_pointers(#flattened)
+                        .into_iter()
+                        .flatten()
+                        .map(|#child| #base.concat(&#child))
+                )
+            }
+        });
+
+        tokens.append_all(quote! {
+            ::std::option::Option::Some(
+                [#(#tag,)* #(#keys),*]
+                    .into_iter()
+                    #(#flattened)*
+                    .collect(),
+            )
+        });
+    }
+}
+
 #[derive(Clone, Copy)]
 struct NamedPointeeBody<'a> {
     ty: NamedPointeeTy<'a>,
     pointer: &'a Ident,
+    destructure: &'a TokenStream,
     fields: &'a [NamedFieldInfo<'a>],
 }
 
 impl<'a> NamedPointeeBody<'a> {
-    fn new(ty: NamedPointeeTy<'a>, pointer: &'a Ident, fields: &'a [NamedFieldInfo]) -> Self {
+    fn new(
+        ty: NamedPointeeTy<'a>,
+        pointer: &'a Ident,
+        destructure: &'a TokenStream,
+        fields: &'a [NamedFieldInfo],
+    ) -> Self {
         Self {
             ty,
             pointer,
+            destructure,
             fields,
         }
     }
@@ -818,11 +1301,24 @@ impl<'a> NamedPointeeBody<'a> {
 
 impl ToTokens for NamedPointeeBody<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let root = self.ty.container().root;
+        let container = self.ty.container();
+        let root = container.root;
+        let access = container.access;
+        let method = access.method();
         let pointer = self.pointer;
         let key = Ident::new("key", Span::mixed_site());
+        let variant = Ident::new("variant", Span::mixed_site());
         let pointee_ty = self.ty;
 
+        // Errors from the fields of externally and adjacently tagged
+        // variants are under the segment for the variant, too.
+        let within = match self.ty {
+            NamedPointeeTy::Variant(_, VariantTag::External | VariantTag::Adjacent { .. }) => {
+                quote!(.map_err(|err| err.under(#variant)))
+            }
+            _ => quote!(),
+        };
+
         // Build match arms for fields.
         let arms = self
             .fields
@@ -830,12 +1326,15 @@ impl ToTokens for NamedPointeeBody<'_> {
             .filter(|f| !f.is_flattened && !f.is_skipped)
             .map(|f| {
                 let field_key = &f.key;
+                let aliases = &f.aliases;
                 let binding = f.binding;
                 quote! {
-                    #field_key => <_ as #root::JsonPointee>::resolve(
+                    #field_key #(| #aliases)* => <_ as #root::JsonPointee>::#method(
                         #binding,
                         #pointer.tail(),
                     )
+                    .map_err(|err| err.under(#key))
+                    #within
                 }
             });
 
@@ -844,86 +1343,88 @@ impl ToTokens for NamedPointeeBody<'_> {
             .fields
             .iter()
             .filter(|f| !f.is_flattened && !f.is_skipped)
-            .map(|f| {
-                let key = &f.key;
-                quote! { #key }
-            })
+            .flat_map(|f| std::iter::once(&f.key).chain(&f.aliases))
+            .map(|key| quote! { #key })
             .collect();
         if let NamedPointeeTy::Variant(_, VariantTag::Internal(tag)) = self.ty {
             suggestions.push(quote! { #tag });
         }
 
         let wildcard = {
-            // For flattened fields, we build an `.or_else()` chain bottom-up
-            // using a right fold.
-            let rest = if cfg!(feature = "did-you-mean") {
-                quote!(Err(#root::JsonPointerKeyError::with_suggestions(
+            // For flattened fields, we build a chain of `match`es bottom-up
+            // using a right fold. (An `.or_else()` chain can't borrow
+            // the remaining fields mutably from inside its closures).
+            let key_err = if cfg!(feature = "did-you-mean") {
+                quote!(#root::JsonPointerKeyError::with_suggestions(
                     #key,
                     #pointee_ty,
                     [#(#suggestions),*],
-                ))?)
+                ))
             } else {
-                quote!(Err(#root::JsonPointerKeyError::new(#key))?)
+                quote!(#root::JsonPointerKeyError::new(#key))
             };
+            let rest = quote!(Err(#root::JsonPointeeError::from(#key_err))#within);
             self.fields
                 .iter()
                 .filter(|f| f.is_flattened)
                 .rfold(rest, |rest, f| {
                     let binding = f.binding;
                     quote! {
-                        <_ as #root::JsonPointee>
-                            ::resolve(
-                                #binding,
-                                #pointer
-                            )
-                            .or_else(|_| #rest)
+                        match <_ as #root::JsonPointee>::#method(#binding, #pointer) {
+                            Ok(pointee) => Ok(pointee),
+                            Err(_) => #rest,
+                        }
                     }
                 })
         };
 
+        // Destructure the fields only after any early return of `self`.
+        let destructure = self.destructure;
+        let resolve_fields = quote! {
+            #destructure
+            match &*#key.to_str() {
+                #(#arms,)*
+                _ => #wildcard,
+            }
+        };
+
         let body = match self.ty {
             NamedPointeeTy::Variant(info, VariantTag::Internal(tag_field)) => {
                 // For internally tagged struct-like variants, check the tag field
                 // before resolving against the named fields.
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     if #key == #tag_field {
-                        return Ok(&#variant_name as &dyn #root::JsonPointee);
-                    }
-                    match &*#key.to_str() {
-                        #(#arms,)*
-                        _ => #wildcard,
+                        return #tag_value;
                     }
+                    #resolve_fields
                 }
             }
             NamedPointeeTy::Variant(info, VariantTag::External) => {
                 // For externally tagged struct-like variants, the first segment
                 // must match the variant name; then the tail should resolve
                 // against the named fields.
-                let variant_name = info.effective_name();
+                let names = info.names();
                 let ty_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerTypeError::with_ty(&#pointer, #pointee_ty))
                 } else {
                     quote!(#root::JsonPointerTypeError::new(&#pointer))
                 };
                 quote! {
-                    let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                    let Some(#variant) = #pointer.head() else {
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
-                    if #key != #variant_name {
+                    if #(#variant != #names)&&* {
                         return Err(#ty_err)?;
                     }
                     let #pointer = #pointer.tail();
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
-                    match &*#key.to_str() {
-                        #(#arms,)*
-                        _ => #wildcard,
-                    }
+                    #resolve_fields
                 }
             }
             NamedPointeeTy::Variant(
@@ -935,7 +1436,7 @@ impl ToTokens for NamedPointeeBody<'_> {
             ) => {
                 // For adjacently tagged struct-like variants, the first segment
                 // must match either the tag or content field.
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_suggestions(
                         #key,
@@ -947,21 +1448,19 @@ impl ToTokens for NamedPointeeBody<'_> {
                 };
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     match &*#key.to_str() {
                         #tag_field => {
-                            return Ok(&#variant_name as &dyn #root::JsonPointee);
+                            return #tag_value;
                         }
                         #content_field => {
+                            let #variant = #key;
                             let #pointer = #pointer.tail();
                             let Some(#key) = #pointer.head() else {
-                                return Ok(self as &dyn #root::JsonPointee);
+                                return Ok(self as #access dyn #root::JsonPointee);
                             };
-                            match &*#key.to_str() {
-                                #(#arms,)*
-                                _ => #wildcard,
-                            }
+                            #resolve_fields
                         }
                         _ => {
                             return Err(#key_err)?;
@@ -974,12 +1473,9 @@ impl ToTokens for NamedPointeeBody<'_> {
                 // access the fields directly.
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
-                    match &*#key.to_str() {
-                        #(#arms,)*
-                        _ => #wildcard,
-                    }
+                    #resolve_fields
                 }
             }
         };
@@ -992,14 +1488,21 @@ impl ToTokens for NamedPointeeBody<'_> {
 struct TuplePointeeBody<'a> {
     ty: TuplePointeeTy<'a>,
     pointer: &'a Ident,
+    destructure: &'a TokenStream,
     fields: &'a [TupleFieldInfo],
 }
 
 impl<'a> TuplePointeeBody<'a> {
-    fn new(ty: TuplePointeeTy<'a>, pointer: &'a Ident, fields: &'a [TupleFieldInfo]) -> Self {
+    fn new(
+        ty: TuplePointeeTy<'a>,
+        pointer: &'a Ident,
+        destructure: &'a TokenStream,
+        fields: &'a [TupleFieldInfo],
+    ) -> Self {
         Self {
             ty,
             pointer,
+            destructure,
             fields,
         }
     }
@@ -1007,20 +1510,35 @@ impl<'a> TuplePointeeBody<'a> {
 
 impl ToTokens for TuplePointeeBody<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let root = self.ty.container().root;
+        let container = self.ty.container();
+        let root = container.root;
+        let access = container.access;
+        let method = access.method();
         let pointer = self.pointer;
         let idx = Ident::new("idx", Span::mixed_site());
         let key = Ident::new("key", Span::mixed_site());
+        let variant = Ident::new("variant", Span::mixed_site());
+
+        // Errors from the fields of externally and adjacently tagged
+        // variants are under the segment for the variant, too.
+        let within = match self.ty {
+            TuplePointeeTy::Variant(_, VariantTag::External | VariantTag::Adjacent { .. }) => {
+                quote!(.map_err(|err| err.under(#variant)))
+            }
+            _ => quote!(),
+        };
 
         // Build match arms for tuple indices.
         let arms = self.fields.iter().filter(|f| !f.is_skipped).map(|f| {
             let index = f.index;
             let binding = &f.binding;
             quote! {
-                #index => <_ as #root::JsonPointee>::resolve(
+                #index => <_ as #root::JsonPointee>::#method(
                     #binding,
                     #pointer.tail(),
                 )
+                .map_err(|err| err.under(#key))
+                #within
             }
         });
 
@@ -1032,10 +1550,13 @@ impl ToTokens for TuplePointeeBody<'_> {
         } else {
             quote!(#root::JsonPointerTypeError::new(&#pointer))
         };
+        // Destructure the fields only after any early return of `self`.
+        let destructure = self.destructure;
         let tail = quote! {
             let Some(#idx) = #key.to_index() else {
-                return Err(#ty_err)?;
+                return Err(#root::JsonPointeeError::from(#ty_err))#within;
             };
+            #destructure
             match #idx {
                 #(#arms,)*
                 _ => Err(#root::JsonPointeeError::Index(#idx, 0..#len))
@@ -1046,13 +1567,13 @@ impl ToTokens for TuplePointeeBody<'_> {
             TuplePointeeTy::Variant(info, VariantTag::Internal(tag_field)) => {
                 // For internally tagged tuple variants, check the tag field
                 // before resolving against the tuple indices.
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     if #key == #tag_field {
-                        return Ok(&#variant_name as &dyn #root::JsonPointee);
+                        return #tag_value;
                     }
                     #tail
                 }
@@ -1061,22 +1582,22 @@ impl ToTokens for TuplePointeeBody<'_> {
                 // For externally tagged tuple variants, the first segment
                 // must match the variant name; then the tail should resolve
                 // against the tuple indices.
-                let variant_name = info.effective_name();
+                let names = info.names();
                 let ty_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerTypeError::with_ty(&#pointer, #ty))
                 } else {
                     quote!(#root::JsonPointerTypeError::new(&#pointer))
                 };
                 quote! {
-                    let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                    let Some(#variant) = #pointer.head() else {
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
-                    if #key != #variant_name {
+                    if #(#variant != #names)&&* {
                         return Err(#ty_err)?;
                     }
                     let #pointer = #pointer.tail();
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     #tail
                 }
@@ -1090,7 +1611,7 @@ impl ToTokens for TuplePointeeBody<'_> {
             ) => {
                 // For adjacently tagged tuple variants, the first segment
                 // must match either the tag or content field.
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_suggestions(
                         #key,
@@ -1102,16 +1623,17 @@ impl ToTokens for TuplePointeeBody<'_> {
                 };
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     match &*#key.to_str() {
                         #tag_field => {
-                            return Ok(&#variant_name as &dyn #root::JsonPointee);
+                            return #tag_value;
                         }
                         #content_field => {
+                            let #variant = #key;
                             let #pointer = #pointer.tail();
                             let Some(#key) = #pointer.head() else {
-                                return Ok(self as &dyn #root::JsonPointee);
+                                return Ok(self as #access dyn #root::JsonPointee);
                             };
                             #tail
                         }
@@ -1126,7 +1648,7 @@ impl ToTokens for TuplePointeeBody<'_> {
                 // access the tuple indices directly.
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     #tail
                 }
@@ -1151,13 +1673,15 @@ impl<'a> UnitPointeeBody<'a> {
 
 impl ToTokens for UnitPointeeBody<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let root = self.ty.container().root;
+        let container = self.ty.container();
+        let root = container.root;
+        let access = container.access;
         let pointer = self.pointer;
         let body = match self.ty {
             ty @ UnitPointeeTy::Variant(info, VariantTag::Internal(tag_field)) => {
                 // For internally tagged unit variants, only the tag field is accessible.
                 let key = Ident::new("key", Span::mixed_site());
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_suggestions(
                         #key,
@@ -1169,10 +1693,10 @@ impl ToTokens for UnitPointeeBody<'_> {
                 };
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     if #key == #tag_field {
-                        return Ok(&#variant_name as &dyn #root::JsonPointee);
+                        return #tag_value;
                     }
                     Err(#key_err)?
                 }
@@ -1180,7 +1704,7 @@ impl ToTokens for UnitPointeeBody<'_> {
             ty @ UnitPointeeTy::Variant(info, VariantTag::External) => {
                 // For externally tagged unit variants, allow just the tag field.
                 let key = Ident::new("key", Span::mixed_site());
-                let variant_name = info.effective_name();
+                let names = info.names();
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_ty(#key, #ty))
                 } else {
@@ -1193,21 +1717,21 @@ impl ToTokens for UnitPointeeBody<'_> {
                 };
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
-                    if #key != #variant_name {
+                    if #(#key != #names)&&* {
                         return Err(#key_err)?;
                     }
                     if !#pointer.tail().is_empty() {
-                        return Err(#ty_err)?;
+                        return Err(#root::JsonPointeeError::from(#ty_err).under(#key));
                     }
-                    Ok(self as &dyn #root::JsonPointee)
+                    Ok(self as #access dyn #root::JsonPointee)
                 }
             }
             ty @ UnitPointeeTy::Variant(info, VariantTag::Adjacent { tag: tag_field, .. }) => {
                 // For adjacently tagged unit variants, allow just the tag field.
                 let key = Ident::new("key", Span::mixed_site());
-                let variant_name = info.effective_name();
+                let tag_value = container.tag_value(pointer, &info.effective_name());
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_suggestions(
                         #key,
@@ -1219,11 +1743,11 @@ impl ToTokens for UnitPointeeBody<'_> {
                 };
                 quote! {
                     let Some(#key) = #pointer.head() else {
-                        return Ok(self as &dyn #root::JsonPointee);
+                        return Ok(self as #access dyn #root::JsonPointee);
                     };
                     match &*#key.to_str() {
                         #tag_field => {
-                            return Ok(&#variant_name as &dyn #root::JsonPointee);
+                            return #tag_value;
                         }
                         _ => {
                             return Err(#key_err)?;
@@ -1240,7 +1764,7 @@ impl ToTokens for UnitPointeeBody<'_> {
                 };
                 quote! {
                     if #pointer.is_empty() {
-                        Ok(self as &dyn #root::JsonPointee)
+                        Ok(self as #access dyn #root::JsonPointee)
                     } else {
                         Err(#ty_err)?
                     }
@@ -1445,7 +1969,9 @@ impl<'a> SkippedVariantBody<'a> {
 
 impl ToTokens for SkippedVariantBody<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let root = self.ty.info().container.root;
+        let container = self.ty.info().container;
+        let root = container.root;
+        let access = container.access;
         let pointer = self.pointer;
         let ty = self.ty;
 
@@ -1468,7 +1994,7 @@ impl ToTokens for SkippedVariantBody<'_> {
             VariantTag::Internal(tag_field) => {
                 // Internally tagged skipped variants allow access to the tag field only.
                 let key = Ident::new("key", Span::mixed_site());
-                let effective_name = ty.info().effective_name();
+                let tag_value = container.tag_value(pointer, &ty.info().effective_name());
                 let ty_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerTypeError::with_ty(&#pointer, #ty))
                 } else {
@@ -1477,10 +2003,10 @@ impl ToTokens for SkippedVariantBody<'_> {
                 tokens.append_all(quote! {
                     #pattern => {
                         let Some(#key) = #pointer.head() else {
-                            return Ok(self as &dyn #root::JsonPointee);
+                            return Ok(self as #access dyn #root::JsonPointee);
                         };
                         if #key == #tag_field {
-                            return Ok(&#effective_name as &dyn #root::JsonPointee);
+                            return #tag_value;
                         }
                         Err(#ty_err)?
                     }
@@ -1501,7 +2027,7 @@ impl ToTokens for SkippedVariantBody<'_> {
                 // Adjacently tagged skipped variants allow tag field access,
                 // but content field access errors.
                 let key = Ident::new("key", Span::mixed_site());
-                let effective_name = ty.info().effective_name();
+                let tag_value = container.tag_value(pointer, &ty.info().effective_name());
                 let key_err = if cfg!(feature = "did-you-mean") {
                     quote!(#root::JsonPointerKeyError::with_suggestions(
                         #key,
@@ -1514,11 +2040,11 @@ impl ToTokens for SkippedVariantBody<'_> {
                 tokens.append_all(quote! {
                     #pattern => {
                         let Some(#key) = #pointer.head() else {
-                            return Ok(self as &dyn #root::JsonPointee);
+                            return Ok(self as #access dyn #root::JsonPointee);
                         };
                         match &*#key.to_str() {
                             #tag_field => {
-                                return Ok(&#effective_name as &dyn #root::JsonPointee);
+                                return #tag_value;
                             }
                             _ => {
                                 return Err(#key_err)?;
@@ -1553,10 +2079,13 @@ enum VariantTag<'a> {
 #[derive(Clone)]
 enum ContainerAttr {
     Crate(syn::Path),
+    Bound(Vec<WherePredicate>),
     RenameAll(RenameAll),
+    RenameAllFields(RenameAll),
     Tag(String),
     Content(String),
     Untagged,
+    Transparent,
 }
 
 impl ContainerAttr {
@@ -1580,6 +2109,13 @@ impl ContainerAttr {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
                         attrs.push(Self::Crate(s.parse()?));
+                    } else if meta.path.is_ident("bound") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        let bounds = s.parse_with(
+                            Punctuated::<WherePredicate, Token![,]>::parse_terminated,
+                        )?;
+                        attrs.push(Self::Bound(bounds.into_iter().collect()));
                     } else if meta.path.is_ident("rename_all") {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
@@ -1587,6 +2123,13 @@ impl ContainerAttr {
                             return Err(meta.error(DeriveError::BadRenameAll));
                         };
                         attrs.push(Self::RenameAll(rename));
+                    } else if meta.path.is_ident("rename_all_fields") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        let Some(rename) = RenameAll::from_str(&s.value()) else {
+                            return Err(meta.error(DeriveError::BadRenameAllFields));
+                        };
+                        attrs.push(Self::RenameAllFields(rename));
                     } else if meta.path.is_ident("tag") {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
@@ -1597,6 +2140,8 @@ impl ContainerAttr {
                         attrs.push(Self::Content(s.value()));
                     } else if meta.path.is_ident("untagged") {
                         attrs.push(Self::Untagged);
+                    } else if meta.path.is_ident("transparent") {
+                        attrs.push(Self::Transparent);
                     } else {
                         return Err(meta.error(DeriveError::UnrecognizedPointer));
                     }
@@ -1614,6 +2159,7 @@ impl ContainerAttr {
 #[derive(Clone, Debug)]
 enum FieldAttr {
     Rename(String),
+    Alias(String),
     Flatten,
     Skip,
 }
@@ -1631,6 +2177,10 @@ impl FieldAttr {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
                         attrs.push(Self::Rename(s.value()));
+                    } else if meta.path.is_ident("alias") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        attrs.push(Self::Alias(s.value()));
                     } else if meta.path.is_ident("flatten") {
                         attrs.push(Self::Flatten);
                     } else if meta.path.is_ident("skip") {
@@ -1653,6 +2203,7 @@ impl FieldAttr {
 enum VariantAttr {
     Skip,
     Rename(String),
+    Alias(String),
 }
 
 impl VariantAttr {
@@ -1670,6 +2221,10 @@ impl VariantAttr {
                         let value = meta.value()?;
                         let s: syn::LitStr = value.parse()?;
                         attrs.push(Self::Rename(s.value()));
+                    } else if meta.path.is_ident("alias") {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        attrs.push(Self::Alias(s.value()));
                     } else {
                         return Err(meta.error(DeriveError::UnrecognizedPointer));
                     }
@@ -1760,12 +2315,20 @@ enum DeriveError {
     Union,
     #[error("`rename` is only supported on struct and struct-like enum variant fields")]
     RenameOnNonNamed,
+    #[error("`alias` is only supported on struct and struct-like enum variant fields")]
+    AliasOnNonNamed,
     #[error("`flatten` is only supported on struct and struct-like enum variant fields")]
     FlattenOnNonNamed,
     #[error("`flatten` and `skip` are mutually exclusive")]
     FlattenWithSkip,
     #[error("`tag` is only supported on enums")]
     TagOnNonEnum,
+    #[error("`transparent` is only supported on structs")]
+    TransparentOnNonStruct,
+    #[error("`transparent` requires exactly one non-skipped field")]
+    TransparentWithoutOneField,
+    #[error("`rename_all_fields` is only supported on enums")]
+    RenameAllFieldsOnNonEnum,
     #[error("`content` requires `tag`")]
     ContentWithoutTag,
     #[error("`tag` and `content` must have different field names")]
@@ -1774,6 +2337,8 @@ enum DeriveError {
     ConflictingTagAttributes,
     #[error("`rename_all` must be one of: {}", RenameAll::all().iter().join(","))]
     BadRenameAll,
+    #[error("`rename_all_fields` must be one of: {}", RenameAll::all().iter().join(","))]
+    BadRenameAllFields,
     #[error("unrecognized `#[ploidy(...)]` attribute")]
     UnrecognizedPloidy,
     #[error("unrecognized `#[ploidy(pointer(...))]` attribute")]
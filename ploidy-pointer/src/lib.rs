@@ -1,18 +1,23 @@
 use std::{
-    any::Any,
+    any::{Any, type_name},
     borrow::{Borrow, Cow},
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     fmt::{Debug, Display},
     hash::BuildHasher,
     iter::FusedIterator,
     ops::{Deref, Range},
     rc::Rc,
-    str::Split,
+    str::{FromStr, Split},
     sync::Arc,
 };
 
 use ref_cast::{RefCastCustom, ref_cast_custom};
 
+#[cfg(feature = "serde_json")]
+mod patch;
+
+#[cfg(feature = "serde_json")]
+pub use patch::*;
 #[cfg(feature = "derive")]
 pub use ploidy_pointer_derive::{JsonPointee, JsonPointerTarget};
 
@@ -70,12 +75,50 @@ impl JsonPointer {
             .unwrap_or_else(|| JsonPointer::empty())
     }
 
+    /// Returns the last segment, or `None` for the root pointer.
+    #[inline]
+    pub fn last(&self) -> Option<&JsonPointerSegment> {
+        let (_, raw) = self.0.rsplit_once('/')?;
+        Some(JsonPointerSegment::new(raw))
+    }
+
+    /// Returns the pointer without its last segment,
+    /// or `None` for the root pointer.
+    #[inline]
+    pub fn parent(&self) -> Option<&JsonPointer> {
+        let (rest, _) = self.0.rsplit_once('/')?;
+        Some(JsonPointer::new(rest))
+    }
+
+    /// Returns the segments of this pointer before `rest`,
+    /// which must be a suffix of this pointer.
+    #[cfg(any(feature = "serde_json", feature = "toml"))]
+    #[inline]
+    fn prefix(&self, rest: &JsonPointer) -> &JsonPointer {
+        JsonPointer::new(&self.0[..self.0.len() - rest.0.len()])
+    }
+
     /// Returns a borrowing iterator over the segments.
     #[inline]
     pub fn segments(&self) -> JsonPointerSegments<'_> {
         JsonPointerSegments(self.0.strip_prefix('/').map(|raw| raw.split('/')))
     }
 
+    /// Returns a new owned pointer with an unescaped segment appended.
+    #[inline]
+    pub fn join(&self, segment: impl AsRef<str>) -> JsonPointerBuf {
+        let mut pointer = self.to_owned();
+        pointer.push(segment);
+        pointer
+    }
+
+    /// Returns a new owned pointer with all the segments of `other`
+    /// appended.
+    #[inline]
+    pub fn concat(&self, other: &JsonPointer) -> JsonPointerBuf {
+        JsonPointerBuf(format!("{self}{other}"))
+    }
+
     /// Follows this pointer through `root` and extracts the result as `T`.
     #[inline]
     pub fn follow<'a, T: JsonPointerTarget<'a>>(
@@ -129,6 +172,29 @@ impl JsonPointerBuf {
             Err(JsonPointerSyntaxError)
         }
     }
+
+    /// Builds an owned pointer from unescaped segments.
+    ///
+    /// Each segment is a decoded key or index, like `a/b` or `0`.
+    /// This constructor escapes `~` and `/` in each segment.
+    pub fn from_segments<I, S>(segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        segments.into_iter().map(Into::into).collect()
+    }
+
+    /// Appends an unescaped segment to this pointer.
+    ///
+    /// The segment is a decoded key or index, like `a/b` or `0`.
+    /// This method escapes `~` and `/` in the segment.
+    #[inline]
+    pub fn push(&mut self, segment: impl AsRef<str>) {
+        self.0.push('/');
+        self.0
+            .push_str(&segment.as_ref().replace('~', "~0").replace('/', "~1"));
+    }
 }
 
 impl AsRef<JsonPointer> for JsonPointerBuf {
@@ -175,16 +241,167 @@ impl From<&JsonPointer> for JsonPointerBuf {
     }
 }
 
+impl FromStr for JsonPointerBuf {
+    type Err = JsonPointerSyntaxError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JsonPointer::parse(s).map(JsonPointer::to_owned)
+    }
+}
+
+impl TryFrom<&str> for JsonPointerBuf {
+    type Error = JsonPointerSyntaxError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for JsonPointerBuf {
+    type Error = JsonPointerSyntaxError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+/// Appends already-escaped segments, like those of another pointer.
+impl<'a> Extend<&'a JsonPointerSegment> for JsonPointerBuf {
+    fn extend<I: IntoIterator<Item = &'a JsonPointerSegment>>(&mut self, iter: I) {
+        for segment in iter {
+            self.0.push('/');
+            self.0.push_str(segment.as_escaped());
+        }
+    }
+}
+
+/// Appends unescaped segments, like [`JsonPointerBuf::push`].
+impl Extend<String> for JsonPointerBuf {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        for segment in iter {
+            self.push(segment);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a JsonPointerSegment> for JsonPointerBuf {
+    fn from_iter<I: IntoIterator<Item = &'a JsonPointerSegment>>(iter: I) -> Self {
+        let mut pointer = Self(String::new());
+        pointer.extend(iter);
+        pointer
+    }
+}
+
+impl FromIterator<String> for JsonPointerBuf {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut pointer = Self(String::new());
+        pointer.extend(iter);
+        pointer
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for JsonPointer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for JsonPointerBuf {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <JsonPointer as serde::Serialize>::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JsonPointerBuf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::parse(s).map_err(serde::de::Error::custom)
+    }
+}
+
 /// A value that a [`JsonPointer`] points to.
 pub trait JsonPointee: Any {
     /// Resolves a [`JsonPointer`] against this value.
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError>;
 
+    /// Resolves a [`JsonPointer`] against this value,
+    /// returning a mutable reference to the pointee.
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError>;
+
     /// Returns the concrete type name of this value.
     #[inline]
     fn name(&self) -> &'static str {
         std::any::type_name::<Self>()
     }
+
+    /// Returns pointers, relative to this value, to each of its children,
+    /// or `None` if this value is a leaf.
+    ///
+    /// A child pointer may have more than one segment, like
+    /// `/Variant/field` for a field of an externally tagged enum variant.
+    #[inline]
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        None
+    }
+}
+
+impl dyn JsonPointee {
+    /// Returns a reference to the pointee if it's of type `T`.
+    #[inline]
+    pub fn downcast_ref<T: JsonPointee>(&self) -> Option<&T> {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+
+    /// Returns a reference to the pointee if it's of type `T`,
+    /// or an error with the expected and actual type names if not.
+    #[inline]
+    pub fn downcast_ref_checked<T: JsonPointee>(&self) -> Result<&T, JsonPointerTargetError> {
+        self.downcast_ref().ok_or_else(|| JsonPointerTargetError {
+            expected: type_name::<T>(),
+            actual: self.name(),
+        })
+    }
+
+    /// Returns a mutable reference to the pointee if it's of type `T`.
+    #[inline]
+    pub fn downcast_mut<T: JsonPointee>(&mut self) -> Option<&mut T> {
+        let any: &mut dyn Any = self;
+        any.downcast_mut()
+    }
+
+    /// Returns pointers to every leaf in this value, in order,
+    /// like `jq 'paths(scalars)'`.
+    ///
+    /// Children that don't resolve, like fields set to `None`,
+    /// are skipped.
+    pub fn leaves(&self) -> Vec<JsonPointerBuf> {
+        let mut leaves = vec![];
+        let mut stack = vec![(JsonPointer::empty().to_owned(), self)];
+        while let Some((pointer, value)) = stack.pop() {
+            let Some(children) = value.child_pointers() else {
+                leaves.push(pointer);
+                continue;
+            };
+            // Push in reverse, so that children are visited in order.
+            for child in children.iter().rev() {
+                if let Ok(pointee) = value.resolve(child) {
+                    stack.push((pointer.concat(child), pointee));
+                }
+            }
+        }
+        leaves
+    }
 }
 
 /// Extracts a typed value from a [`JsonPointee`].
@@ -201,6 +418,59 @@ pub trait JsonPointeeExt: JsonPointee {
     fn pointer<'a, T: JsonPointerTarget<'a>>(&'a self, path: &str) -> Result<T, JsonPointerError> {
         JsonPointer::parse(path)?.follow(self)
     }
+
+    /// Returns `pointer` with each key replaced by the key of this value
+    /// that matches it, ignoring ASCII case, for resolving pointers from
+    /// sources that are inconsistent about casing.
+    ///
+    /// Exact matches take precedence. Keys that don't match are kept
+    /// as is, so that resolving the returned pointer reports them.
+    /// A key that matches more than one key of its value, like `/username`
+    /// for a value with `userName` and `UserName`, is an error.
+    fn match_ascii_case(&self, pointer: &JsonPointer) -> Result<JsonPointerBuf, JsonPointeeError> {
+        let mut matched = JsonPointer::empty().to_owned();
+        let mut value = self.resolve(JsonPointer::empty())?;
+        let mut rest = pointer;
+        while let Some(key) = rest.head() {
+            // A child pointer can have more than one segment,
+            // so compare all of its segments.
+            let children = value.child_pointers().unwrap_or_default();
+            let candidates: Vec<_> = children
+                .iter()
+                .filter(|child| {
+                    let mut keys = rest.segments();
+                    child.segments().all(|segment| {
+                        keys.next()
+                            .is_some_and(|key| key.to_str().eq_ignore_ascii_case(&segment.to_str()))
+                    })
+                })
+                .collect();
+            let exact = candidates
+                .iter()
+                .find(|child| child.segments().zip(rest.segments()).all(|(a, b)| a == b));
+            let child = match (exact, &*candidates) {
+                (Some(child), _) | (None, [child]) => child,
+                (None, []) => {
+                    matched = matched.concat(rest);
+                    break;
+                }
+                (None, _) => {
+                    return Err(JsonPointerAmbiguousKeyError {
+                        key: key.to_str().into_owned(),
+                        at: matched,
+                        candidates: candidates.into_iter().cloned().collect(),
+                    }
+                    .into());
+                }
+            };
+            value = value.resolve(child).map_err(|err| err.within(&matched))?;
+            matched = matched.concat(child);
+            for _ in child.segments() {
+                rest = rest.tail();
+            }
+        }
+        Ok(matched)
+    }
 }
 
 impl<P: JsonPointee + ?Sized> JsonPointeeExt for P {}
@@ -214,6 +484,27 @@ impl JsonPointerSegment {
     #[ref_cast_custom]
     fn new(raw: &str) -> &Self;
 
+    /// Builds a segment from a decoded key or index, like `a/b` or `0`.
+    ///
+    /// This constructor escapes `~` and `/` in the segment, borrowing
+    /// the input if it doesn't contain either.
+    #[inline]
+    pub fn new_unescaped(decoded: &str) -> Cow<'_, JsonPointerSegment> {
+        if decoded.contains(['~', '/']) {
+            Cow::Owned(JsonPointerSegmentBuf(
+                decoded.replace('~', "~0").replace('/', "~1"),
+            ))
+        } else {
+            Cow::Borrowed(JsonPointerSegment::new(decoded))
+        }
+    }
+
+    /// Returns the RFC 6901-escaped form of this segment.
+    #[inline]
+    pub fn as_escaped(&self) -> &str {
+        &self.0
+    }
+
     /// Returns the value of this segment as a string.
     #[inline]
     pub fn to_str(&self) -> Cow<'_, str> {
@@ -239,6 +530,13 @@ impl JsonPointerSegment {
         }
     }
 
+    /// Returns `true` if this segment is `-`, which references
+    /// the nonexistent element after the last array element.
+    #[inline]
+    pub fn is_end_of_array(&self) -> bool {
+        &self.0 == "-"
+    }
+
     /// Returns `true` if this segment is empty.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -267,6 +565,49 @@ impl Display for JsonPointerSegment {
     }
 }
 
+impl ToOwned for JsonPointerSegment {
+    type Owned = JsonPointerSegmentBuf;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        JsonPointerSegmentBuf(self.0.to_owned())
+    }
+}
+
+/// An owned [`JsonPointerSegment`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct JsonPointerSegmentBuf(String);
+
+impl AsRef<JsonPointerSegment> for JsonPointerSegmentBuf {
+    #[inline]
+    fn as_ref(&self) -> &JsonPointerSegment {
+        self
+    }
+}
+
+impl Borrow<JsonPointerSegment> for JsonPointerSegmentBuf {
+    #[inline]
+    fn borrow(&self) -> &JsonPointerSegment {
+        self
+    }
+}
+
+impl Deref for JsonPointerSegmentBuf {
+    type Target = JsonPointerSegment;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        JsonPointerSegment::new(&self.0)
+    }
+}
+
+impl Display for JsonPointerSegmentBuf {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <JsonPointerSegment as Display>::fmt(self, f)
+    }
+}
+
 /// A borrowing iterator over the segments of a [`JsonPointer`].
 #[derive(Clone, Debug)]
 pub struct JsonPointerSegments<'a>(Option<Split<'a, char>>);
@@ -320,6 +661,26 @@ macro_rules! impl_pointee_for {
                     })?
                 }
             }
+
+            fn resolve_mut(
+                &mut self,
+                pointer: &JsonPointer,
+            ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+                if pointer.is_empty() {
+                    Ok(self)
+                } else {
+                    Err({
+                        #[cfg(feature = "did-you-mean")]
+                        let err = JsonPointerTypeError::with_ty(
+                            pointer,
+                            JsonPointeeType::Named(stringify!($ty)),
+                        );
+                        #[cfg(not(feature = "did-you-mean"))]
+                        let err = JsonPointerTypeError::new(pointer);
+                        err
+                    })?
+                }
+            }
         }
         $(impl_pointee_for!($($rest)*);)?
     };
@@ -329,6 +690,8 @@ impl_pointee_for!(
     i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64, bool, String, &'static str,
     #[cfg(feature = "chrono")] chrono::DateTime<chrono::Utc>,
     #[cfg(feature = "chrono")] chrono::NaiveDate,
+    #[cfg(feature = "chrono")] chrono::NaiveDateTime,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
     #[cfg(feature = "url")] url::Url,
     #[cfg(feature = "uuid")] uuid::Uuid,
     #[cfg(feature = "serde_bytes")] serde_bytes::ByteBuf,
@@ -360,6 +723,8 @@ impl_copied_pointer_target_for!(
     i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64, bool,
     #[cfg(feature = "chrono")] chrono::DateTime<chrono::Utc>,
     #[cfg(feature = "chrono")] chrono::NaiveDate,
+    #[cfg(feature = "chrono")] chrono::NaiveDateTime,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
     #[cfg(feature = "uuid")] uuid::Uuid,
 );
 
@@ -437,13 +802,17 @@ impl<'a, T: JsonPointee, H: BuildHasher + 'static> JsonPointerTarget<'a>
     }
 }
 
-impl<'a, T: JsonPointee> JsonPointerTarget<'a> for &'a BTreeMap<String, T> {
+impl<'a, K, T> JsonPointerTarget<'a> for &'a BTreeMap<K, T>
+where
+    K: FromStr + Ord + 'static,
+    T: JsonPointee,
+{
     #[inline]
     fn from_pointee(pointee: &'a dyn JsonPointee) -> Result<Self, JsonPointerTargetError> {
         let any: &dyn Any = pointee;
-        any.downcast_ref::<BTreeMap<String, T>>()
+        any.downcast_ref::<BTreeMap<K, T>>()
             .ok_or_else(|| JsonPointerTargetError {
-                expected: ::std::any::type_name::<BTreeMap<String, T>>(),
+                expected: ::std::any::type_name::<BTreeMap<K, T>>(),
                 actual: pointee.name(),
             })
     }
@@ -503,24 +872,94 @@ impl<T: JsonPointee> JsonPointee for Option<T> {
             })?,
         }
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        match self {
+            Some(value) => value.resolve_mut(pointer),
+            None => Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?,
+        }
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        match self {
+            Some(value) => value.child_pointers(),
+            // `None` doesn't resolve, so it has no leaves.
+            None => Some(vec![]),
+        }
+    }
 }
 
 impl<T: JsonPointee> JsonPointee for Box<T> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
         (**self).resolve(pointer)
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        (**self).resolve_mut(pointer)
+    }
+
+    #[inline]
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        (**self).child_pointers()
+    }
 }
 
+/// Mutably resolves a [`JsonPointer`] only if this is
+/// the only reference to the contained value.
 impl<T: JsonPointee> JsonPointee for Arc<T> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
         (**self).resolve(pointer)
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        match Arc::get_mut(self) {
+            Some(value) => value.resolve_mut(pointer),
+            None => Err(JsonPointerReadOnlyError::new(pointer))?,
+        }
+    }
+
+    #[inline]
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        (**self).child_pointers()
+    }
 }
 
+/// Mutably resolves a [`JsonPointer`] only if this is
+/// the only reference to the contained value.
 impl<T: JsonPointee> JsonPointee for Rc<T> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
         (**self).resolve(pointer)
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        match Rc::get_mut(self) {
+            Some(value) => value.resolve_mut(pointer),
+            None => Err(JsonPointerReadOnlyError::new(pointer))?,
+        }
+    }
+
+    #[inline]
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        (**self).child_pointers()
+    }
 }
 
 impl<T: JsonPointee> JsonPointee for Vec<T> {
@@ -530,10 +969,12 @@ impl<T: JsonPointee> JsonPointee for Vec<T> {
         };
         if let Some(index) = key.to_index() {
             if let Some(item) = self.get(index) {
-                item.resolve(pointer.tail())
+                item.resolve(pointer.tail()).map_err(|err| err.under(key))
             } else {
                 Err(JsonPointeeError::Index(index, 0..self.len()))
             }
+        } else if key.is_end_of_array() {
+            Err(JsonPointeeError::PastEnd(self.len()))
         } else {
             Err({
                 #[cfg(feature = "did-you-mean")]
@@ -544,91 +985,403 @@ impl<T: JsonPointee> JsonPointee for Vec<T> {
             })?
         }
     }
-}
 
-impl<T, H> JsonPointee for HashMap<String, T, H>
-where
-    T: JsonPointee,
-    H: BuildHasher + 'static,
-{
-    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
         let Some(key) = pointer.head() else {
             return Ok(self);
         };
-        if let Some(value) = self.get(&*key.to_str()) {
-            value.resolve(pointer.tail())
-        } else {
-            Err({
+        if key.is_end_of_array() {
+            return Err(JsonPointeeError::PastEnd(self.len()));
+        }
+        let Some(index) = key.to_index() else {
+            return Err({
                 #[cfg(feature = "did-you-mean")]
-                let err = JsonPointerKeyError::with_suggestions(
-                    key,
-                    JsonPointeeType::name_of(self),
-                    self.keys().map(|key| key.as_str()),
-                );
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
                 #[cfg(not(feature = "did-you-mean"))]
-                let err = JsonPointerKeyError::new(key);
+                let err = JsonPointerTypeError::new(pointer);
                 err
-            })?
+            })?;
+        };
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(item) => item
+                .resolve_mut(pointer.tail())
+                .map_err(|err| err.under(key)),
+            None => Err(JsonPointeeError::Index(index, 0..len)),
         }
     }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        Some(
+            (0..self.len())
+                .map(|index| JsonPointer::empty().join(index.to_string()))
+                .collect(),
+        )
+    }
 }
 
-impl<T: JsonPointee> JsonPointee for BTreeMap<String, T> {
+impl<T: JsonPointee> JsonPointee for VecDeque<T> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
         let Some(key) = pointer.head() else {
             return Ok(self);
         };
-        if let Some(value) = self.get(&*key.to_str()) {
-            value.resolve(pointer.tail())
+        if let Some(index) = key.to_index() {
+            if let Some(item) = self.get(index) {
+                item.resolve(pointer.tail()).map_err(|err| err.under(key))
+            } else {
+                Err(JsonPointeeError::Index(index, 0..self.len()))
+            }
+        } else if key.is_end_of_array() {
+            Err(JsonPointeeError::PastEnd(self.len()))
         } else {
             Err({
                 #[cfg(feature = "did-you-mean")]
-                let err = JsonPointerKeyError::with_suggestions(
-                    key,
-                    JsonPointeeType::name_of(self),
-                    self.keys().map(|key| key.as_str()),
-                );
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
                 #[cfg(not(feature = "did-you-mean"))]
-                let err = JsonPointerKeyError::new(key);
+                let err = JsonPointerTypeError::new(pointer);
                 err
             })?
         }
     }
-}
 
-#[cfg(feature = "indexmap")]
-impl<T, H> JsonPointee for indexmap::IndexMap<String, T, H>
-where
-    T: JsonPointee,
-    H: BuildHasher + 'static,
-{
-    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
         let Some(key) = pointer.head() else {
             return Ok(self);
         };
-        if let Some(value) = self.get(&*key.to_str()) {
-            value.resolve(pointer.tail())
-        } else {
-            Err({
+        if key.is_end_of_array() {
+            return Err(JsonPointeeError::PastEnd(self.len()));
+        }
+        let Some(index) = key.to_index() else {
+            return Err({
                 #[cfg(feature = "did-you-mean")]
-                let err = JsonPointerKeyError::with_suggestions(
-                    key,
-                    JsonPointeeType::name_of(self),
-                    self.keys().map(|key| key.as_str()),
-                );
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
                 #[cfg(not(feature = "did-you-mean"))]
-                let err = JsonPointerKeyError::new(key);
+                let err = JsonPointerTypeError::new(pointer);
                 err
-            })?
+            })?;
+        };
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(item) => item
+                .resolve_mut(pointer.tail())
+                .map_err(|err| err.under(key)),
+            None => Err(JsonPointeeError::Index(index, 0..len)),
         }
     }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        Some(
+            (0..self.len())
+                .map(|index| JsonPointer::empty().join(index.to_string()))
+                .collect(),
+        )
+    }
 }
 
-#[cfg(feature = "serde")]
-impl JsonPointee for serde::de::IgnoredAny {
+/// Sets aren't indexable, so only the empty pointer resolves,
+/// to the whole set.
+impl<T: 'static, H: 'static> JsonPointee for HashSet<T, H> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
-        Err({
-            #[cfg(feature = "did-you-mean")]
+        if pointer.is_empty() {
+            Ok(self)
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?
+        }
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        if pointer.is_empty() {
+            Ok(self)
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?
+        }
+    }
+}
+
+/// As with [`HashSet`], only the empty pointer resolves.
+impl<T: 'static> JsonPointee for BTreeSet<T> {
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        if pointer.is_empty() {
+            Ok(self)
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?
+        }
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        if pointer.is_empty() {
+            Ok(self)
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?
+        }
+    }
+}
+
+impl<T, H> JsonPointee for HashMap<String, T, H>
+where
+    T: JsonPointee,
+    H: BuildHasher + 'static,
+{
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        if let Some(value) = self.get(&*key.to_str()) {
+            value.resolve(pointer.tail()).map_err(|err| err.under(key))
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    self.keys().map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?
+        }
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        // Check for the key before borrowing the value mutably,
+        // so that the error can borrow the keys for suggestions.
+        if !self.contains_key(&*key.to_str()) {
+            return Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    self.keys().map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?;
+        }
+        match self.get_mut(&*key.to_str()) {
+            Some(value) => value
+                .resolve_mut(pointer.tail())
+                .map_err(|err| err.under(key)),
+            None => Err(JsonPointerKeyError::new(key))?,
+        }
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        Some(
+            self.keys()
+                .map(|key| JsonPointer::empty().join(key))
+                .collect(),
+        )
+    }
+}
+
+/// Resolves keys by parsing each segment into `K`, so that maps with
+/// non-string keys, like integers or enums, are addressable.
+impl<K, T> JsonPointee for BTreeMap<K, T>
+where
+    K: FromStr + Ord + 'static,
+    T: JsonPointee,
+{
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        let Ok(parsed) = key.to_str().parse::<K>() else {
+            return Err(JsonPointerBadKeyError::new(key, type_name::<K>()).into());
+        };
+        if let Some(value) = self.get(&parsed) {
+            value.resolve(pointer.tail()).map_err(|err| err.under(key))
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    // Only string keys can be suggested.
+                    self.keys()
+                        .filter_map(|key| (key as &dyn Any).downcast_ref::<String>())
+                        .map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?
+        }
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        let Ok(parsed) = key.to_str().parse::<K>() else {
+            return Err(JsonPointerBadKeyError::new(key, type_name::<K>()).into());
+        };
+        if !self.contains_key(&parsed) {
+            return Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    // Only string keys can be suggested.
+                    self.keys()
+                        .filter_map(|key| (key as &dyn Any).downcast_ref::<String>())
+                        .map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?;
+        }
+        match self.get_mut(&parsed) {
+            Some(value) => value
+                .resolve_mut(pointer.tail())
+                .map_err(|err| err.under(key)),
+            None => Err(JsonPointerKeyError::new(key))?,
+        }
+    }
+
+    /// Maps with keys other than strings can't turn their keys
+    /// back into segments, so they're leaves.
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        self.keys()
+            .map(|key| {
+                let key = (key as &dyn Any).downcast_ref::<String>()?;
+                Some(JsonPointer::empty().join(key))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, H> JsonPointee for indexmap::IndexMap<String, T, H>
+where
+    T: JsonPointee,
+    H: BuildHasher + 'static,
+{
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        if let Some(value) = self.get(&*key.to_str()) {
+            value.resolve(pointer.tail()).map_err(|err| err.under(key))
+        } else {
+            Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    self.keys().map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?
+        }
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        // Check for the key before borrowing the value mutably,
+        // so that the error can borrow the keys for suggestions.
+        if !self.contains_key(&*key.to_str()) {
+            return Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerKeyError::with_suggestions(
+                    key,
+                    JsonPointeeType::name_of(self),
+                    self.keys().map(|key| key.as_str()),
+                );
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerKeyError::new(key);
+                err
+            })?;
+        }
+        match self.get_mut(&*key.to_str()) {
+            Some(value) => value
+                .resolve_mut(pointer.tail())
+                .map_err(|err| err.under(key)),
+            None => Err(JsonPointerKeyError::new(key))?,
+        }
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        Some(
+            self.keys()
+                .map(|key| JsonPointer::empty().join(key))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl JsonPointee for serde::de::IgnoredAny {
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        Err({
+            #[cfg(feature = "did-you-mean")]
+            let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+            #[cfg(not(feature = "did-you-mean"))]
+            let err = JsonPointerTypeError::new(pointer);
+            err
+        })?
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        Err({
+            #[cfg(feature = "did-you-mean")]
             let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
             #[cfg(not(feature = "did-you-mean"))]
             let err = JsonPointerTypeError::new(pointer);
@@ -638,60 +1391,301 @@ impl JsonPointee for serde::de::IgnoredAny {
 }
 
 #[cfg(feature = "serde_json")]
+/// Resolution walks the pointer in a loop, instead of recursing once per
+/// segment, so that deeply nested values don't overflow the stack.
 impl JsonPointee for serde_json::Value {
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        let (mut value, mut rest) = (self, pointer);
+        while let Some(key) = rest.head() {
+            let child: Result<&serde_json::Value, JsonPointeeError> = match value {
+                serde_json::Value::Object(map) => map.get(&*key.to_str()).ok_or_else(|| {
+                    JsonPointerKeyError::unknown(
+                        key,
+                        JsonPointeeType::name_of(map),
+                        map.keys().map(|key| key.as_str()),
+                    )
+                    .into()
+                }),
+                serde_json::Value::Array(array) if key.is_end_of_array() => {
+                    Err(JsonPointeeError::PastEnd(array.len()))
+                }
+                serde_json::Value::Array(array) => match key.to_index() {
+                    Some(index) => array
+                        .get(index)
+                        .ok_or(JsonPointeeError::Index(index, 0..array.len())),
+                    None => Err({
+                        #[cfg(feature = "did-you-mean")]
+                        let err =
+                            JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(array));
+                        #[cfg(not(feature = "did-you-mean"))]
+                        let err = JsonPointerTypeError::new(rest);
+                        err
+                    }
+                    .into()),
+                },
+                serde_json::Value::Null => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerKeyError::with_ty(key, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerKeyError::new(key);
+                    err
+                }
+                .into()),
+                _ => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerTypeError::new(rest);
+                    err
+                }
+                .into()),
+            };
+            value = child.map_err(|err| err.within(pointer.prefix(rest)))?;
+            rest = rest.tail();
+        }
+        Ok(value)
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let (mut value, mut rest) = (self, pointer);
+        while let Some(key) = rest.head() {
+            let child: Result<&mut serde_json::Value, JsonPointeeError> = match value {
+                // Check for the key before borrowing the value mutably,
+                // so that the error can borrow the keys for suggestions.
+                serde_json::Value::Object(map) if !map.contains_key(&*key.to_str()) => {
+                    Err(JsonPointerKeyError::unknown(
+                        key,
+                        JsonPointeeType::name_of(map),
+                        map.keys().map(|key| key.as_str()),
+                    )
+                    .into())
+                }
+                serde_json::Value::Object(map) => Ok(map.get_mut(&*key.to_str()).unwrap()),
+                serde_json::Value::Array(array) if key.is_end_of_array() => {
+                    Err(JsonPointeeError::PastEnd(array.len()))
+                }
+                serde_json::Value::Array(array) => match key.to_index() {
+                    Some(index) => {
+                        let len = array.len();
+                        array
+                            .get_mut(index)
+                            .ok_or(JsonPointeeError::Index(index, 0..len))
+                    }
+                    None => Err({
+                        #[cfg(feature = "did-you-mean")]
+                        let err =
+                            JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(array));
+                        #[cfg(not(feature = "did-you-mean"))]
+                        let err = JsonPointerTypeError::new(rest);
+                        err
+                    }
+                    .into()),
+                },
+                serde_json::Value::Null => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerKeyError::with_ty(key, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerKeyError::new(key);
+                    err
+                }
+                .into()),
+                _ => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerTypeError::new(rest);
+                    err
+                }
+                .into()),
+            };
+            value = child.map_err(|err| err.within(pointer.prefix(rest)))?;
+            rest = rest.tail();
+        }
+        Ok(value)
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        match self {
+            serde_json::Value::Object(map) => Some(
+                map.keys()
+                    .map(|key| JsonPointer::empty().join(key))
+                    .collect(),
+            ),
+            serde_json::Value::Array(array) => Some(
+                (0..array.len())
+                    .map(|index| JsonPointer::empty().join(index.to_string()))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl JsonPointee for serde_json::Map<String, serde_json::Value> {
     fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
         let Some(key) = pointer.head() else {
             return Ok(self);
         };
-        match self {
-            serde_json::Value::Object(map) => {
-                if let Some(value) = map.get(&*key.to_str()) {
-                    value.resolve(pointer.tail())
-                } else {
-                    Err({
+        let value = self.get(&*key.to_str()).ok_or_else(|| {
+            JsonPointerKeyError::unknown(
+                key,
+                JsonPointeeType::name_of(self),
+                self.keys().map(|key| key.as_str()),
+            )
+        })?;
+        value.resolve(pointer.tail()).map_err(|err| err.under(key))
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let Some(key) = pointer.head() else {
+            return Ok(self);
+        };
+        // Check for the key before borrowing the value mutably,
+        // so that the error can borrow the keys for suggestions.
+        if !self.contains_key(&*key.to_str()) {
+            return Err(JsonPointerKeyError::unknown(
+                key,
+                JsonPointeeType::name_of(self),
+                self.keys().map(|key| key.as_str()),
+            )
+            .into());
+        }
+        self.get_mut(&*key.to_str())
+            .unwrap()
+            .resolve_mut(pointer.tail())
+            .map_err(|err| err.under(key))
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        Some(
+            self.keys()
+                .map(|key| JsonPointer::empty().join(key))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "toml")]
+/// Like `serde_json::Value`, resolution walks the pointer in a loop.
+impl JsonPointee for toml::Value {
+    fn resolve(&self, pointer: &JsonPointer) -> Result<&dyn JsonPointee, JsonPointeeError> {
+        let (mut value, mut rest) = (self, pointer);
+        while let Some(key) = rest.head() {
+            let child: Result<&toml::Value, JsonPointeeError> = match value {
+                toml::Value::Table(table) => table.get(&*key.to_str()).ok_or_else(|| {
+                    JsonPointerKeyError::unknown(
+                        key,
+                        JsonPointeeType::name_of(table),
+                        table.keys().map(|key| key.as_str()),
+                    )
+                    .into()
+                }),
+                toml::Value::Array(array) if key.is_end_of_array() => {
+                    Err(JsonPointeeError::PastEnd(array.len()))
+                }
+                toml::Value::Array(array) => match key.to_index() {
+                    Some(index) => array
+                        .get(index)
+                        .ok_or(JsonPointeeError::Index(index, 0..array.len())),
+                    None => Err({
                         #[cfg(feature = "did-you-mean")]
-                        let err = JsonPointerKeyError::with_suggestions(
-                            key,
-                            JsonPointeeType::name_of(map),
-                            map.keys().map(|key| key.as_str()),
-                        );
+                        let err =
+                            JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(array));
                         #[cfg(not(feature = "did-you-mean"))]
-                        let err = JsonPointerKeyError::new(key);
+                        let err = JsonPointerTypeError::new(rest);
                         err
-                    })?
+                    }
+                    .into()),
+                },
+                _ => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerTypeError::new(rest);
+                    err
                 }
-            }
-            serde_json::Value::Array(array) => {
-                let Some(index) = key.to_index() else {
-                    return Err({
+                .into()),
+            };
+            value = child.map_err(|err| err.within(pointer.prefix(rest)))?;
+            rest = rest.tail();
+        }
+        Ok(value)
+    }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        let (mut value, mut rest) = (self, pointer);
+        while let Some(key) = rest.head() {
+            let child: Result<&mut toml::Value, JsonPointeeError> = match value {
+                // Check for the key before borrowing the value mutably,
+                // so that the error can borrow the keys for suggestions.
+                toml::Value::Table(table) if !table.contains_key(&*key.to_str()) => {
+                    Err(JsonPointerKeyError::unknown(
+                        key,
+                        JsonPointeeType::name_of(table),
+                        table.keys().map(|key| key.as_str()),
+                    )
+                    .into())
+                }
+                toml::Value::Table(table) => Ok(table.get_mut(&*key.to_str()).unwrap()),
+                toml::Value::Array(array) if key.is_end_of_array() => {
+                    Err(JsonPointeeError::PastEnd(array.len()))
+                }
+                toml::Value::Array(array) => match key.to_index() {
+                    Some(index) => {
+                        let len = array.len();
+                        array
+                            .get_mut(index)
+                            .ok_or(JsonPointeeError::Index(index, 0..len))
+                    }
+                    None => Err({
                         #[cfg(feature = "did-you-mean")]
                         let err =
-                            JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(array));
+                            JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(array));
                         #[cfg(not(feature = "did-you-mean"))]
-                        let err = JsonPointerTypeError::new(pointer);
+                        let err = JsonPointerTypeError::new(rest);
                         err
-                    })?;
-                };
-                if let Some(item) = array.get(index) {
-                    item.resolve(pointer.tail())
-                } else {
-                    Err(JsonPointeeError::Index(index, 0..array.len()))
+                    }
+                    .into()),
+                },
+                _ => Err({
+                    #[cfg(feature = "did-you-mean")]
+                    let err = JsonPointerTypeError::with_ty(rest, JsonPointeeType::name_of(value));
+                    #[cfg(not(feature = "did-you-mean"))]
+                    let err = JsonPointerTypeError::new(rest);
+                    err
                 }
-            }
-            serde_json::Value::Null => Err({
-                #[cfg(feature = "did-you-mean")]
-                let err = JsonPointerKeyError::with_ty(key, JsonPointeeType::name_of(self));
-                #[cfg(not(feature = "did-you-mean"))]
-                let err = JsonPointerKeyError::new(key);
-                err
-            })?,
-            _ => Err({
-                #[cfg(feature = "did-you-mean")]
-                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
-                #[cfg(not(feature = "did-you-mean"))]
-                let err = JsonPointerTypeError::new(pointer);
-                err
-            })?,
+                .into()),
+            };
+            value = child.map_err(|err| err.within(pointer.prefix(rest)))?;
+            rest = rest.tail();
+        }
+        Ok(value)
+    }
+
+    fn child_pointers(&self) -> Option<Vec<JsonPointerBuf>> {
+        match self {
+            toml::Value::Table(table) => Some(
+                table
+                    .keys()
+                    .map(|key| JsonPointer::empty().join(key))
+                    .collect(),
+            ),
+            toml::Value::Array(array) => Some(
+                (0..array.len())
+                    .map(|index| JsonPointer::empty().join(index.to_string()))
+                    .collect(),
+            ),
+            _ => None,
         }
     }
 }
@@ -733,10 +1727,42 @@ pub struct JsonPointerTargetError {
 pub enum JsonPointeeError {
     #[error(transparent)]
     Key(#[from] JsonPointerKeyError),
+    #[error(transparent)]
+    BadKey(#[from] JsonPointerBadKeyError),
     #[error("index {} out of range {}..{}", .0, .1.start, .1.end)]
     Index(usize, Range<usize>),
+    #[error("`-` references the element past the end of an array of length {0}")]
+    PastEnd(usize),
     #[error(transparent)]
     Ty(#[from] JsonPointerTypeError),
+    #[error(transparent)]
+    ReadOnly(#[from] JsonPointerReadOnlyError),
+    #[error(transparent)]
+    Ambiguous(#[from] JsonPointerAmbiguousKeyError),
+}
+
+impl JsonPointeeError {
+    /// Prepends a segment that a parent pointee consumed
+    /// to the path where resolution failed.
+    ///
+    /// Pointees call this on errors from their children, so that
+    /// [key](JsonPointerKeyError::at) and [type](JsonPointerTypeError::at)
+    /// errors report the full path from the root.
+    #[cold]
+    pub fn under(self, segment: &JsonPointerSegment) -> Self {
+        self.within(JsonPointer::new(&format!("/{}", segment.as_escaped())))
+    }
+
+    #[cold]
+    fn within(mut self, prefix: &JsonPointer) -> Self {
+        if let Self::Key(JsonPointerKeyError { at, .. })
+        | Self::Ty(JsonPointerTypeError { at, .. })
+        | Self::Ambiguous(JsonPointerAmbiguousKeyError { at, .. }) = &mut self
+        {
+            *at = prefix.concat(at);
+        }
+        self
+    }
 }
 
 /// An error that occurs when a pointed-to value doesn't have a key
@@ -745,6 +1771,8 @@ pub enum JsonPointeeError {
 #[derive(Debug)]
 pub struct JsonPointerKeyError {
     pub key: String,
+    /// The path to the value that doesn't have the key.
+    pub at: JsonPointerBuf,
     pub context: Option<JsonPointerKeyErrorContext>,
 }
 
@@ -752,14 +1780,35 @@ impl JsonPointerKeyError {
     pub fn new(key: &JsonPointerSegment) -> Self {
         Self {
             key: key.to_str().into_owned(),
+            at: JsonPointer::empty().to_owned(),
             context: None,
         }
     }
 
+    /// Creates an error for a `key` that a map of type `ty` doesn't have.
+    /// With the `did-you-mean` feature, the error suggests the closest
+    /// of the map's `keys`.
+    #[cold]
+    pub fn unknown<'a>(
+        key: &JsonPointerSegment,
+        ty: JsonPointeeType,
+        keys: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        #[cfg(feature = "did-you-mean")]
+        let err = Self::with_suggestions(key, ty, keys);
+        #[cfg(not(feature = "did-you-mean"))]
+        let err = {
+            let _ = (ty, keys);
+            Self::new(key)
+        };
+        err
+    }
+
     #[cfg(feature = "did-you-mean")]
     pub fn with_ty(key: &JsonPointerSegment, ty: JsonPointeeType) -> Self {
         Self {
             key: key.to_str().into_owned(),
+            at: JsonPointer::empty().to_owned(),
             context: Some(JsonPointerKeyErrorContext {
                 ty,
                 suggestion: None,
@@ -786,6 +1835,7 @@ impl JsonPointerKeyError {
             .map(|(suggestion, _)| suggestion.to_owned());
         Self {
             key: key.into_owned(),
+            at: JsonPointer::empty().to_owned(),
             context: Some(JsonPointerKeyErrorContext { ty, suggestion }),
         }
     }
@@ -795,20 +1845,64 @@ impl std::error::Error for JsonPointerKeyError {}
 
 impl Display for JsonPointerKeyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.context {
-            Some(JsonPointerKeyErrorContext {
-                ty,
-                suggestion: Some(suggestion),
-            }) => write!(
-                f,
-                "unknown key {:?} for value of {ty}; did you mean {suggestion:?}?",
-                self.key
-            ),
-            Some(JsonPointerKeyErrorContext {
-                ty,
-                suggestion: None,
-            }) => write!(f, "unknown key {:?} for value of {ty}", self.key),
-            None => write!(f, "unknown key {:?}", self.key),
+        write!(f, "unknown key {:?}", self.key)?;
+        if !self.at.is_empty() {
+            write!(f, " at {}", self.at)?;
+        }
+        if let Some(JsonPointerKeyErrorContext { ty, suggestion }) = &self.context {
+            write!(f, " for value of {ty}")?;
+            if let Some(suggestion) = suggestion {
+                write!(f, "; did you mean {suggestion:?}?")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when a key matches more than one key
+/// of the pointed-to value, ignoring ASCII case.
+#[derive(Debug)]
+pub struct JsonPointerAmbiguousKeyError {
+    pub key: String,
+    /// The path to the value with the matching keys.
+    pub at: JsonPointerBuf,
+    /// Pointers, relative to `at`, to each matching child.
+    pub candidates: Vec<JsonPointerBuf>,
+}
+
+impl std::error::Error for JsonPointerAmbiguousKeyError {}
+
+impl Display for JsonPointerAmbiguousKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ambiguous key {:?}", self.key)?;
+        if !self.at.is_empty() {
+            write!(f, " at {}", self.at)?;
+        }
+        write!(f, "; matches ")?;
+        for (index, candidate) in self.candidates.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{candidate}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when a pointer segment can't be parsed
+/// as a key of the pointed-to map.
+#[derive(Debug, thiserror::Error)]
+#[error("can't parse key {key:?} as `{ty}`")]
+pub struct JsonPointerBadKeyError {
+    pub key: String,
+    pub ty: &'static str,
+}
+
+impl JsonPointerBadKeyError {
+    pub fn new(key: &JsonPointerSegment, ty: &'static str) -> Self {
+        Self {
+            key: key.to_str().into_owned(),
+            ty,
         }
     }
 }
@@ -824,6 +1918,8 @@ pub struct JsonPointerKeyErrorContext {
 #[derive(Debug)]
 pub struct JsonPointerTypeError {
     pub pointer: String,
+    /// The path to the value that `pointer` can't be resolved against.
+    pub at: JsonPointerBuf,
     pub ty: Option<JsonPointeeType>,
 }
 
@@ -831,6 +1927,7 @@ impl JsonPointerTypeError {
     pub fn new(pointer: &JsonPointer) -> Self {
         Self {
             pointer: pointer.to_string(),
+            at: JsonPointer::empty().to_owned(),
             ty: None,
         }
     }
@@ -839,6 +1936,7 @@ impl JsonPointerTypeError {
     pub fn with_ty(pointer: &JsonPointer, ty: JsonPointeeType) -> Self {
         Self {
             pointer: pointer.to_string(),
+            at: JsonPointer::empty().to_owned(),
             ty: Some(ty),
         }
     }
@@ -848,9 +1946,30 @@ impl std::error::Error for JsonPointerTypeError {}
 
 impl Display for JsonPointerTypeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.ty {
-            Some(ty) => write!(f, "can't resolve {:?} against value of {ty}", self.pointer),
-            None => write!(f, "can't resolve {:?}", self.pointer),
+        write!(f, "can't resolve {:?}", self.pointer)?;
+        if !self.at.is_empty() {
+            write!(f, " at {}", self.at)?;
+        }
+        if let Some(ty) = self.ty {
+            write!(f, " against value of {ty}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurs when a pointer resolves to a value
+/// that can't be borrowed mutably, like a computed tag,
+/// or a value behind a shared [`Arc`] or [`Rc`].
+#[derive(Debug, thiserror::Error)]
+#[error("can't resolve {pointer:?} mutably")]
+pub struct JsonPointerReadOnlyError {
+    pub pointer: String,
+}
+
+impl JsonPointerReadOnlyError {
+    pub fn new(pointer: &JsonPointer) -> Self {
+        Self {
+            pointer: pointer.to_string(),
         }
     }
 }
@@ -967,6 +2086,27 @@ mod tests {
         assert_eq!(segment.to_string(), "foo/bar");
     }
 
+    #[test]
+    fn test_segment_new_unescaped() {
+        let segment = JsonPointerSegment::new_unescaped("a/b");
+        assert!(matches!(segment, Cow::Owned(_)));
+        assert_eq!(segment.as_escaped(), "a~1b");
+        // `Display` shows the decoded value.
+        assert_eq!(segment.to_string(), "a/b");
+        assert_eq!(*segment, *"a/b");
+
+        let segment = JsonPointerSegment::new_unescaped("~0");
+        assert_eq!(segment.as_escaped(), "~00");
+        assert_eq!(segment.to_str(), "~0");
+    }
+
+    #[test]
+    fn test_segment_new_unescaped_borrows_plain_segment() {
+        let segment = JsonPointerSegment::new_unescaped("foo");
+        assert!(matches!(segment, Cow::Borrowed(_)));
+        assert_eq!(segment.as_escaped(), "foo");
+    }
+
     #[test]
     fn test_pointer_display() {
         let input = "/foo/bar~1baz/0";
@@ -984,6 +2124,107 @@ mod tests {
         assert_eq!(owned.to_string(), "/foo/bar~0baz");
     }
 
+    #[test]
+    fn test_pointer_buf_from_segments() {
+        let pointer = JsonPointerBuf::from_segments(["a/b", "c~d", "0"]);
+        let mut segments = pointer.segments();
+        assert_eq!(segments.next().unwrap(), "a/b");
+        assert_eq!(segments.next().unwrap(), "c~d");
+        assert_eq!(segments.next().unwrap(), "0");
+        assert_eq!(segments.next(), None);
+        // `Display` re-escapes `/` and `~`.
+        assert_eq!(pointer.to_string(), "/a~1b/c~0d/0");
+    }
+
+    #[test]
+    fn test_pointer_buf_push() {
+        let mut pointer = JsonPointerBuf::parse("/foo".to_owned()).unwrap();
+        pointer.push("bar~baz");
+        pointer.push("");
+        let mut segments = pointer.segments();
+        assert_eq!(segments.next().unwrap(), "foo");
+        assert_eq!(segments.next().unwrap(), "bar~baz");
+        assert_eq!(segments.next().unwrap(), "");
+        assert_eq!(segments.next(), None);
+        assert_eq!(pointer.to_string(), "/foo/bar~0baz/");
+    }
+
+    #[test]
+    fn test_pointer_join() {
+        let pointer = JsonPointer::empty().join("a/b");
+        assert_eq!(pointer.head().unwrap(), "a/b");
+        assert_eq!(pointer.to_string(), "/a~1b");
+
+        // Joining doesn't change the original pointer.
+        let parent = JsonPointer::parse("/foo").unwrap();
+        let child = parent.join("0");
+        assert_eq!(parent.to_string(), "/foo");
+        assert_eq!(child.to_string(), "/foo/0");
+    }
+
+    #[test]
+    fn test_pointer_buf_from_str() {
+        let pointer: JsonPointerBuf = "/foo/bar~1baz".parse().unwrap();
+        assert_eq!(pointer.to_string(), "/foo/bar~1baz");
+        assert_eq!(pointer.last().unwrap(), "bar/baz");
+
+        let pointer = JsonPointerBuf::try_from("").unwrap();
+        assert!(pointer.is_empty());
+
+        let pointer = JsonPointerBuf::try_from("/0".to_owned()).unwrap();
+        assert_eq!(pointer.head().unwrap().to_index(), Some(0));
+
+        assert!("foo".parse::<JsonPointerBuf>().is_err());
+        assert!(JsonPointerBuf::try_from("foo").is_err());
+        assert!(JsonPointerBuf::try_from("foo".to_owned()).is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_pointer_serde_round_trip() {
+        let pointer = JsonPointerBuf::parse("/a~1b/0".to_owned()).unwrap();
+        let json = serde_json::to_string(&pointer).unwrap();
+        assert_eq!(json, r#""/a~1b/0""#);
+        let actual: JsonPointerBuf = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, pointer);
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[test]
+    fn test_pointer_deserialize_missing_leading_slash() {
+        let err = serde_json::from_str::<JsonPointerBuf>(r#""a/b""#).unwrap_err();
+        assert!(err.to_string().contains("must start with `/`"));
+    }
+
+    #[test]
+    fn test_pointer_buf_from_iter() {
+        let pointer: JsonPointerBuf = ["a~b".to_owned(), "c/d".to_owned()].into_iter().collect();
+        assert_eq!(pointer.to_string(), "/a~0b/c~1d");
+
+        // Segments are already escaped, so collecting them
+        // doesn't escape them again.
+        let pointer: JsonPointerBuf = pointer.segments().rev().collect();
+        assert_eq!(pointer.to_string(), "/c~1d/a~0b");
+
+        let pointer: JsonPointerBuf = std::iter::empty::<String>().collect();
+        assert!(pointer.is_empty());
+    }
+
+    #[test]
+    fn test_pointer_buf_extend() {
+        let mut pointer = JsonPointerBuf::parse("/a".to_owned()).unwrap();
+        pointer.extend(["b~c".to_owned()]);
+        pointer.extend(JsonPointer::parse("/d~1e/0").unwrap().segments());
+        assert_eq!(pointer.to_string(), "/a/b~0c/d~1e/0");
+        assert_eq!(pointer.last().unwrap().to_index(), Some(0));
+    }
+
+    #[test]
+    fn test_pointer_buf_from_no_segments() {
+        let pointer = JsonPointerBuf::from_segments(Vec::<String>::new());
+        assert!(pointer.is_empty());
+    }
+
     #[test]
     fn test_head_tail_single_segment() {
         let pointer = JsonPointer::parse("/foo").unwrap();
@@ -991,6 +2232,28 @@ mod tests {
         assert!(pointer.tail().is_empty());
     }
 
+    #[test]
+    fn test_parent_last() {
+        let pointer = JsonPointer::parse("/foo/bar~1baz").unwrap();
+        assert_eq!(pointer.last().unwrap(), "bar/baz");
+
+        let parent = pointer.parent().unwrap();
+        assert_eq!(parent.to_string(), "/foo");
+        assert_eq!(parent.last().unwrap(), "foo");
+
+        let root = parent.parent().unwrap();
+        assert!(root.is_empty());
+        assert_eq!(root.last(), None);
+        assert_eq!(root.parent(), None);
+    }
+
+    #[test]
+    fn test_parent_trailing_slash() {
+        let pointer = JsonPointer::parse("/foo/").unwrap();
+        assert_eq!(pointer.last().unwrap(), "");
+        assert_eq!(pointer.parent().unwrap().to_string(), "/foo");
+    }
+
     #[test]
     fn test_tail_root_idempotent() {
         let root = JsonPointer::empty();
@@ -1042,6 +2305,84 @@ mod tests {
         assert_eq!(result.downcast_ref::<i32>(), Some(&2));
     }
 
+    #[test]
+    fn test_resolve_vec_non_index_segment() {
+        let mut data = vec![1, 2, 3];
+        let pointer = JsonPointer::parse("/foo").unwrap();
+
+        let Err(JsonPointeeError::Ty(err)) = data.resolve(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/foo");
+        #[cfg(feature = "did-you-mean")]
+        assert_eq!(err.ty, Some(JsonPointeeType::named::<Vec<i32>>()));
+        #[cfg(not(feature = "did-you-mean"))]
+        assert_eq!(err.ty, None);
+
+        // Mutable resolution should report the same error.
+        let Err(JsonPointeeError::Ty(err)) = data.resolve_mut(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/foo");
+        #[cfg(feature = "did-you-mean")]
+        assert_eq!(err.ty, Some(JsonPointeeType::named::<Vec<i32>>()));
+        #[cfg(not(feature = "did-you-mean"))]
+        assert_eq!(err.ty, None);
+    }
+
+    #[test]
+    fn test_resolve_vec_end_of_array() {
+        let mut data = vec![1, 2, 3];
+        let pointer = JsonPointer::parse("/-").unwrap();
+        assert!(pointer.head().unwrap().is_end_of_array());
+
+        let Err(JsonPointeeError::PastEnd(len)) = data.resolve(pointer) else {
+            panic!("expected past-the-end error");
+        };
+        assert_eq!(len, 3);
+
+        let Err(JsonPointeeError::PastEnd(len)) = data.resolve_mut(pointer) else {
+            panic!("expected past-the-end error");
+        };
+        assert_eq!(len, 3);
+    }
+
+    #[test]
+    fn test_resolve_vec_deque() {
+        let data = VecDeque::from([1, 2, 3]);
+        let pointer = JsonPointer::parse("/1").unwrap();
+        let result = data.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(result.downcast_ref::<i32>(), Some(&2));
+
+        let pointer = JsonPointer::parse("/3").unwrap();
+        assert!(matches!(
+            data.resolve(pointer),
+            Err(JsonPointeeError::Index(3, Range { start: 0, end: 3 }))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_btreeset() {
+        let mut data = BTreeSet::from(["a".to_owned(), "b".to_owned()]);
+
+        let result = data.resolve(JsonPointer::empty()).unwrap() as &dyn Any;
+        assert_eq!(result.downcast_ref::<BTreeSet<String>>(), Some(&data));
+
+        let pointer = JsonPointer::parse("/0").unwrap();
+        let Err(JsonPointeeError::Ty(err)) = data.resolve(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/0");
+        #[cfg(feature = "did-you-mean")]
+        assert_eq!(err.ty, Some(JsonPointeeType::named::<BTreeSet<String>>()));
+        #[cfg(not(feature = "did-you-mean"))]
+        assert_eq!(err.ty, None);
+
+        let Err(JsonPointeeError::Ty(_)) = data.resolve_mut(pointer) else {
+            panic!("expected type error");
+        };
+    }
+
     #[test]
     fn test_resolve_hashmap() {
         let mut data = HashMap::new();
@@ -1052,6 +2393,61 @@ mod tests {
         assert_eq!(result.downcast_ref::<i32>(), Some(&42));
     }
 
+    #[test]
+    fn test_resolve_btreemap_with_parsed_keys() {
+        let mut data = BTreeMap::new();
+        data.insert(1, "one");
+        data.insert(-2, "minus two");
+
+        let pointer = JsonPointer::parse("/-2").unwrap();
+        let result = data.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(result.downcast_ref::<&str>(), Some(&"minus two"));
+
+        let pointer = JsonPointer::parse("/3").unwrap();
+        assert!(matches!(
+            data.resolve(pointer),
+            Err(JsonPointeeError::Key(_))
+        ));
+
+        let pointer = JsonPointer::parse("/one").unwrap();
+        let Err(JsonPointeeError::BadKey(err)) = data.resolve(pointer) else {
+            panic!("expected bad key error");
+        };
+        assert_eq!(err.key, "one");
+        assert_eq!(err.ty, "i32");
+    }
+
+    #[test]
+    fn test_resolve_error_reports_traversed_path() {
+        let mut data = HashMap::new();
+        data.insert("a".to_owned(), vec![HashMap::from([("b".to_owned(), 1)])]);
+
+        let pointer = JsonPointer::parse("/a/0/c").unwrap();
+        let Err(JsonPointeeError::Key(err)) = data.resolve(pointer) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.key, "c");
+        assert_eq!(err.at.to_string(), "/a/0");
+        assert!(err.to_string().starts_with(r#"unknown key "c" at /a/0"#));
+
+        let pointer = JsonPointer::parse("/a/x/y").unwrap();
+        let Err(JsonPointeeError::Ty(err)) = data.resolve_mut(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/x/y");
+        assert_eq!(err.at.to_string(), "/a");
+        assert!(err.to_string().starts_with(r#"can't resolve "/x/y" at /a"#));
+
+        // Errors at the root don't have a path.
+        let pointer = JsonPointer::parse("/b").unwrap();
+        let Err(JsonPointeeError::Key(err)) = data.resolve(pointer) else {
+            panic!("expected key error");
+        };
+        assert!(err.at.is_empty());
+        assert!(err.to_string().starts_with(r#"unknown key "b""#));
+        assert!(!err.to_string().contains(" at "));
+    }
+
     #[test]
     fn test_resolve_option() {
         let data = Some(42);
@@ -1145,4 +2541,177 @@ mod tests {
         let result = bool::from_pointee(pointee);
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_resolve_deeply_nested_json_value() {
+        const DEPTH: usize = 100_000;
+
+        let mut value = serde_json::Value::from(42);
+        for _ in 0..DEPTH {
+            value = serde_json::Value::Array(vec![value]);
+        }
+        let pointer = JsonPointerBuf::from_segments(std::iter::repeat_n("0", DEPTH));
+
+        let result = value.resolve(&pointer).unwrap() as &dyn Any;
+        assert_eq!(
+            result.downcast_ref::<serde_json::Value>(),
+            Some(&serde_json::Value::from(42))
+        );
+        assert!(value.resolve_mut(&pointer).is_ok());
+
+        // Dropping a deeply nested value recurses, so unwrap it
+        // one level at a time.
+        while let serde_json::Value::Array(mut array) = value {
+            value = array.pop().unwrap_or_default();
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_resolve_json_value_error_reports_traversed_path() {
+        let mut value = serde_json::json!({"a": [{"b": {}}]});
+
+        let pointer = JsonPointer::parse("/a/0/b/c").unwrap();
+        let Err(JsonPointeeError::Key(err)) = value.resolve(pointer) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.at.to_string(), "/a/0/b");
+
+        let pointer = JsonPointer::parse("/a/~1/b").unwrap();
+        let Err(JsonPointeeError::Ty(err)) = value.resolve_mut(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/~1/b");
+        assert_eq!(err.at.to_string(), "/a");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_resolve_json_map() {
+        let mut value = serde_json::json!({"a": {"b": [1, {"c": true}]}});
+        let map = value.as_object_mut().unwrap();
+
+        let pointer = JsonPointer::parse("/a/b/1/c").unwrap();
+        let result = map.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(
+            result.downcast_ref::<serde_json::Value>(),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert!(map.resolve_mut(pointer).is_ok());
+
+        let pointer = JsonPointer::parse("/a/b/1/d").unwrap();
+        let Err(JsonPointeeError::Key(err)) = map.resolve(pointer) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.at.to_string(), "/a/b/1");
+
+        assert_eq!(
+            map.child_pointers().unwrap(),
+            [JsonPointerBuf::from_segments(["a"])]
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_resolve_toml_value() {
+        let mut value: toml::Value = toml::from_str("a = { b = [1, { c = true }] }").unwrap();
+
+        let pointer = JsonPointer::parse("/a/b/1/c").unwrap();
+        let result = value.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(
+            result.downcast_ref::<toml::Value>(),
+            Some(&toml::Value::Boolean(true))
+        );
+        assert!(value.resolve_mut(pointer).is_ok());
+
+        let pointer = JsonPointer::parse("/a/b/1/d").unwrap();
+        let Err(JsonPointeeError::Key(err)) = value.resolve(pointer) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.at.to_string(), "/a/b/1");
+
+        let pointer = JsonPointer::parse("/a/b/1/c/d").unwrap();
+        let Err(JsonPointeeError::Ty(err)) = value.resolve_mut(pointer) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.at.to_string(), "/a/b/1/c");
+
+        assert_eq!(
+            value.child_pointers().unwrap(),
+            [JsonPointerBuf::from_segments(["a"])]
+        );
+    }
+
+    #[test]
+    fn test_match_ascii_case() {
+        let map = HashMap::from([
+            ("userName".to_owned(), 1),
+            ("UserName".to_owned(), 2),
+            ("userId".to_owned(), 3),
+        ]);
+
+        let pointer = map.match_ascii_case(JsonPointer::parse("/USERID").unwrap());
+        assert_eq!(pointer.unwrap().to_string(), "/userId");
+
+        // Exact matches take precedence.
+        let pointer = map.match_ascii_case(JsonPointer::parse("/UserName").unwrap());
+        assert_eq!(pointer.unwrap().to_string(), "/UserName");
+
+        let Err(JsonPointeeError::Ambiguous(err)) =
+            map.match_ascii_case(JsonPointer::parse("/username").unwrap())
+        else {
+            panic!("expected ambiguous key error");
+        };
+        assert_eq!(err.key, "username");
+        let mut candidates = err
+            .candidates
+            .iter()
+            .map(|candidate| candidate.to_string())
+            .collect::<Vec<_>>();
+        candidates.sort();
+        assert_eq!(candidates, ["/UserName", "/userName"]);
+
+        // Keys that don't match are kept as is.
+        let pointer = map.match_ascii_case(JsonPointer::parse("/email/0").unwrap());
+        assert_eq!(pointer.unwrap().to_string(), "/email/0");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_match_ascii_case_json_value() {
+        let mut value = serde_json::json!({"Users": [{"userName": "a", "ID": 1}]});
+
+        let pointer = value
+            .match_ascii_case(JsonPointer::parse("/users/0/USERNAME").unwrap())
+            .unwrap();
+        assert_eq!(pointer.to_string(), "/Users/0/userName");
+        *value
+            .resolve_mut(&pointer)
+            .unwrap()
+            .downcast_mut::<serde_json::Value>()
+            .unwrap() = "b".into();
+        assert_eq!(value["Users"][0]["userName"], "b");
+
+        // Resolving the matched pointer reports the missing element.
+        let pointer = value
+            .match_ascii_case(JsonPointer::parse("/users/1/id").unwrap())
+            .unwrap();
+        assert_eq!(pointer.to_string(), "/Users/1/id");
+        assert!(matches!(
+            value.resolve(&pointer),
+            Err(JsonPointeeError::Index(1, _))
+        ));
+    }
+
+    #[test]
+    fn test_downcast_ref_checked() {
+        let data = vec![1i32, 2, 3];
+        let pointee = data.resolve(JsonPointer::parse("/1").unwrap()).unwrap();
+        assert_eq!(pointee.downcast_ref_checked::<i32>().unwrap(), &2);
+
+        let err = pointee.downcast_ref_checked::<bool>().unwrap_err();
+        assert_eq!(err.expected, "bool");
+        assert_eq!(err.actual, "i32");
+    }
 }
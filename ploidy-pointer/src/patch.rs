@@ -0,0 +1,270 @@
+//! Applying JSON Patch ([RFC 6902]) documents to JSON values.
+//!
+//! [RFC 6902]: https://datatracker.ietf.org/doc/html/rfc6902
+
+use serde_json::Value;
+
+use crate::{
+    JsonPointee, JsonPointeeError, JsonPointeeType, JsonPointer, JsonPointerBuf,
+    JsonPointerKeyError, JsonPointerTypeError,
+};
+
+/// A JSON Patch operation.
+///
+/// `move` and `copy` aren't supported yet.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonPatchOperation {
+    /// Adds `value` to an object or array at `path`,
+    /// or replaces the whole document for the root pointer.
+    Add { path: JsonPointerBuf, value: Value },
+    /// Removes the value at `path` from its object or array.
+    Remove { path: JsonPointerBuf },
+    /// Replaces the value at `path` with `value`.
+    Replace { path: JsonPointerBuf, value: Value },
+    /// Checks that the value at `path` equals `value`.
+    Test { path: JsonPointerBuf, value: Value },
+}
+
+/// Applies a sequence of JSON Patch operations to `value`, in order.
+///
+/// Patches are atomic: if any operation fails, `value` is left unchanged.
+pub fn apply_patch(
+    value: &mut Value,
+    patch: impl IntoIterator<Item = JsonPatchOperation>,
+) -> Result<(), JsonPatchError> {
+    // Patch a copy, so that a failed operation doesn't leave
+    // the document partially patched.
+    let mut patched = value.clone();
+    for operation in patch {
+        match operation {
+            JsonPatchOperation::Add { path, value } => {
+                let (Some(parent), Some(last)) = (path.parent(), path.last()) else {
+                    patched = value;
+                    continue;
+                };
+                let target = patched
+                    .resolve_mut(parent)?
+                    .downcast_mut::<Value>()
+                    .unwrap();
+                match (target, last.to_index()) {
+                    (Value::Object(map), _) => {
+                        map.insert(last.to_str().into_owned(), value);
+                    }
+                    (Value::Array(array), _) if last.is_end_of_array() => array.push(value),
+                    (Value::Array(array), Some(index)) if index <= array.len() => {
+                        array.insert(index, value);
+                    }
+                    (Value::Array(array), Some(index)) => {
+                        Err(JsonPointeeError::Index(index, 0..array.len() + 1))?
+                    }
+                    _ => Err(JsonPointeeError::from(JsonPointerTypeError::new(
+                        &JsonPointer::empty().join(&*last.to_str()),
+                    ))
+                    .within(parent))?,
+                }
+            }
+            JsonPatchOperation::Remove { path } => {
+                let (Some(parent), Some(last)) = (path.parent(), path.last()) else {
+                    Err(JsonPatchError::RemoveRoot)?
+                };
+                let target = patched
+                    .resolve_mut(parent)?
+                    .downcast_mut::<Value>()
+                    .unwrap();
+                match (target, last.to_index()) {
+                    (Value::Object(map), _) => {
+                        map.remove(&*last.to_str()).ok_or_else(|| {
+                            JsonPointeeError::from(JsonPointerKeyError::unknown(
+                                last,
+                                JsonPointeeType::name_of(map),
+                                map.keys().map(|key| key.as_str()),
+                            ))
+                            .within(parent)
+                        })?;
+                    }
+                    (Value::Array(array), Some(index)) if index < array.len() => {
+                        array.remove(index);
+                    }
+                    (Value::Array(array), Some(index)) => {
+                        Err(JsonPointeeError::Index(index, 0..array.len()))?
+                    }
+                    (Value::Array(array), None) if last.is_end_of_array() => {
+                        Err(JsonPointeeError::PastEnd(array.len()))?
+                    }
+                    _ => Err(JsonPointeeError::from(JsonPointerTypeError::new(
+                        &JsonPointer::empty().join(&*last.to_str()),
+                    ))
+                    .within(parent))?,
+                }
+            }
+            JsonPatchOperation::Replace { path, value } => {
+                *patched.resolve_mut(&path)?.downcast_mut::<Value>().unwrap() = value;
+            }
+            JsonPatchOperation::Test { path, value } => {
+                if *patched.resolve(&path)?.downcast_ref::<Value>().unwrap() != value {
+                    Err(JsonPatchError::Test(path))?
+                }
+            }
+        }
+    }
+    *value = patched;
+    Ok(())
+}
+
+/// An error that occurs when applying a JSON Patch.
+#[derive(Debug, thiserror::Error)]
+pub enum JsonPatchError {
+    #[error(transparent)]
+    Resolve(#[from] JsonPointeeError),
+    #[error("value at `{0}` doesn't match the tested value")]
+    Test(JsonPointerBuf),
+    #[error("can't remove the root value")]
+    RemoveRoot,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_patch() {
+        let mut value = json!({"pets": [{"name": "Fido"}], "owner": "Alice"});
+
+        apply_patch(
+            &mut value,
+            [
+                JsonPatchOperation::Add {
+                    path: JsonPointerBuf::from_segments(["pets", "-"]),
+                    value: json!({"name": "Rex"}),
+                },
+                JsonPatchOperation::Add {
+                    path: JsonPointerBuf::from_segments(["pets", "0"]),
+                    value: json!({"name": "Spot"}),
+                },
+                JsonPatchOperation::Add {
+                    path: JsonPointerBuf::from_segments(["pets", "1", "age"]),
+                    value: json!(3),
+                },
+                JsonPatchOperation::Replace {
+                    path: JsonPointerBuf::from_segments(["owner"]),
+                    value: json!("Bob"),
+                },
+                JsonPatchOperation::Remove {
+                    path: JsonPointerBuf::from_segments(["pets", "0"]),
+                },
+                JsonPatchOperation::Test {
+                    path: JsonPointerBuf::from_segments(["pets", "1", "name"]),
+                    value: json!("Rex"),
+                },
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            json!({"pets": [{"name": "Fido", "age": 3}, {"name": "Rex"}], "owner": "Bob"})
+        );
+    }
+
+    #[test]
+    fn test_apply_patch_replaces_root() {
+        let mut value = json!({"a": 1});
+        apply_patch(
+            &mut value,
+            [JsonPatchOperation::Add {
+                path: JsonPointer::empty().to_owned(),
+                value: json!([1, 2]),
+            }],
+        )
+        .unwrap();
+        assert_eq!(value, json!([1, 2]));
+    }
+
+    #[test]
+    fn test_apply_patch_is_atomic() {
+        let mut value = json!({"a": 1});
+
+        let Err(JsonPatchError::Test(path)) = apply_patch(
+            &mut value,
+            [
+                JsonPatchOperation::Add {
+                    path: JsonPointerBuf::from_segments(["b"]),
+                    value: json!(2),
+                },
+                JsonPatchOperation::Test {
+                    path: JsonPointerBuf::from_segments(["a"]),
+                    value: json!(2),
+                },
+            ],
+        ) else {
+            panic!("expected test error");
+        };
+        assert_eq!(path.to_string(), "/a");
+
+        // The `add` before the failed `test` isn't applied.
+        assert_eq!(value, json!({"a": 1}));
+    }
+
+    #[test]
+    fn test_apply_patch_errors() {
+        let mut value = json!({"a": {"b": [1, 2]}});
+
+        // Arrays can be added to at their length, but not past it.
+        let Err(JsonPatchError::Resolve(JsonPointeeError::Index(3, range))) = apply_patch(
+            &mut value,
+            [JsonPatchOperation::Add {
+                path: JsonPointerBuf::from_segments(["a", "b", "3"]),
+                value: json!(3),
+            }],
+        ) else {
+            panic!("expected index error");
+        };
+        assert_eq!(range, 0..3);
+
+        let Err(JsonPatchError::Resolve(JsonPointeeError::Key(err))) = apply_patch(
+            &mut value,
+            [JsonPatchOperation::Remove {
+                path: JsonPointerBuf::from_segments(["a", "c"]),
+            }],
+        ) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.key, "c");
+        assert_eq!(err.at.to_string(), "/a");
+
+        let Err(JsonPatchError::Resolve(JsonPointeeError::Ty(err))) = apply_patch(
+            &mut value,
+            [JsonPatchOperation::Add {
+                path: JsonPointerBuf::from_segments(["a", "b", "0", "c"]),
+                value: json!(3),
+            }],
+        ) else {
+            panic!("expected type error");
+        };
+        assert_eq!(err.pointer, "/c");
+        assert_eq!(err.at.to_string(), "/a/b/0");
+
+        let Err(JsonPatchError::Resolve(JsonPointeeError::Key(err))) = apply_patch(
+            &mut value,
+            [JsonPatchOperation::Replace {
+                path: JsonPointerBuf::from_segments(["a", "c"]),
+                value: json!(3),
+            }],
+        ) else {
+            panic!("expected key error");
+        };
+        assert_eq!(err.at.to_string(), "/a");
+
+        assert!(matches!(
+            apply_patch(
+                &mut value,
+                [JsonPatchOperation::Remove {
+                    path: JsonPointer::empty().to_owned(),
+                }],
+            ),
+            Err(JsonPatchError::RemoveRoot),
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+
+use ploidy_pointer::{JsonPointee, JsonPointerBuf};
+
+#[test]
+fn test_leaves_struct() {
+    #[derive(JsonPointee)]
+    struct Metadata {
+        created_by: String,
+    }
+
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(rename_all = "camelCase"))]
+    struct User {
+        user_name: String,
+        tags: Vec<String>,
+        nickname: Option<String>,
+        labels: BTreeMap<String, String>,
+        #[ploidy(pointer(flatten))]
+        metadata: Metadata,
+        #[ploidy(pointer(skip))]
+        #[allow(dead_code)]
+        password: String,
+    }
+
+    let user = User {
+        user_name: "alice".to_owned(),
+        tags: vec!["admin".to_owned(), "staff".to_owned()],
+        nickname: None,
+        labels: BTreeMap::from([("a/b".to_owned(), "c".to_owned())]),
+        metadata: Metadata {
+            created_by: "bob".to_owned(),
+        },
+        password: "hunter2".to_owned(),
+    };
+
+    // `nickname` is `None`, so it doesn't resolve; and `password` is skipped.
+    assert_eq!(
+        (&user as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        [
+            "/userName",
+            "/tags/0",
+            "/tags/1",
+            "/labels/a~1b",
+            "/created_by",
+        ]
+    );
+}
+
+#[test]
+fn test_leaves_flattened_map() {
+    #[derive(JsonPointee)]
+    struct Resource {
+        id: String,
+        #[ploidy(pointer(flatten))]
+        extra: BTreeMap<String, i32>,
+    }
+
+    let resource = Resource {
+        id: "r1".to_owned(),
+        extra: BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+    };
+    assert_eq!(
+        (&resource as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/id", "/a", "/b"]
+    );
+}
+
+#[test]
+fn test_leaves_tuple_and_newtype_structs() {
+    #[derive(JsonPointee)]
+    struct Pair(i32, String);
+
+    #[derive(JsonPointee)]
+    struct Wrapper(Pair);
+
+    let wrapper = Wrapper(Pair(1, "one".to_owned()));
+    assert_eq!(
+        (&wrapper as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/0", "/1"]
+    );
+}
+
+#[test]
+fn test_leaves_externally_tagged_enum() {
+    #[derive(JsonPointee)]
+    enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+        Empty,
+    }
+
+    assert_eq!(
+        (&Shape::Circle { radius: 1.0 } as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/Circle/radius"]
+    );
+    assert_eq!(
+        (&Shape::Square(2.0) as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/Square"]
+    );
+    // Externally tagged unit variants are leaves.
+    assert_eq!(
+        (&Shape::Empty as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        [""]
+    );
+}
+
+#[test]
+fn test_leaves_internally_tagged_enum() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(tag = "type"))]
+    enum Event {
+        Click { x: i32, y: i32 },
+        Close,
+    }
+
+    assert_eq!(
+        (&Event::Click { x: 1, y: 2 } as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/type", "/x", "/y"]
+    );
+    assert_eq!(
+        (&Event::Close as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/type"]
+    );
+}
+
+#[test]
+fn test_leaves_adjacently_tagged_enum() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(tag = "t", content = "c"))]
+    enum Message {
+        Text { body: String },
+        Code(i32),
+    }
+
+    let message = Message::Text {
+        body: "hi".to_owned(),
+    };
+    assert_eq!(
+        (&message as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/t", "/c/body"]
+    );
+    assert_eq!(
+        (&Message::Code(42) as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/t", "/c"]
+    );
+}
+
+#[test]
+fn test_leaves_skipped_variant() {
+    #[derive(JsonPointee)]
+    enum Secret {
+        #[ploidy(pointer(skip))]
+        #[allow(dead_code)]
+        Hidden(String),
+    }
+
+    assert!(
+        (&Secret::Hidden("shh".to_owned()) as &dyn JsonPointee)
+            .leaves()
+            .is_empty()
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_leaves_serde_json() {
+    let value = serde_json::json!({
+        "name": "alice",
+        "roles": ["admin", { "scope": "all" }],
+        "empty": [],
+    });
+
+    // Empty containers have no leaves.
+    assert_eq!(
+        (&value as &dyn JsonPointee)
+            .leaves()
+            .iter()
+            .map(JsonPointerBuf::to_string)
+            .collect::<Vec<_>>(),
+        ["/name", "/roles/0", "/roles/1/scope"]
+    );
+}
@@ -1,6 +1,6 @@
-use std::{any::Any, rc::Rc, sync::Arc};
+use std::{any::Any, collections::BTreeMap, rc::Rc, sync::Arc};
 
-use ploidy_pointer::{JsonPointee, JsonPointer};
+use ploidy_pointer::{JsonPointee, JsonPointeeError, JsonPointeeExt, JsonPointer};
 
 #[test]
 fn test_rename_field() {
@@ -178,6 +178,89 @@ fn test_enum_with_rename() {
     assert_eq!(result.downcast_ref::<i32>(), Some(&123));
 }
 
+#[test]
+fn test_rename_all_fields() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(rename_all = "snake_case", rename_all_fields = "camelCase"))]
+    enum Event {
+        UserCreated { user_name: String },
+        AccountClosed { closed_at_ms: u64 },
+    }
+
+    let e = Event::UserCreated {
+        user_name: "alice".to_owned(),
+    };
+
+    // The variant name uses `rename_all`, and the field uses `rename_all_fields`.
+    let pointer = JsonPointer::parse("/user_created/userName").unwrap();
+    let result = e.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<String>(), Some(&"alice".to_owned()));
+
+    // Neither transform should apply to the other.
+    let pointer = JsonPointer::parse("/user_created/user_name").unwrap();
+    assert!(e.resolve(pointer).is_err());
+    let pointer = JsonPointer::parse("/userCreated/userName").unwrap();
+    assert!(e.resolve(pointer).is_err());
+
+    let e = Event::AccountClosed { closed_at_ms: 42 };
+    let pointer = JsonPointer::parse("/account_closed/closedAtMs").unwrap();
+    let result = e.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<u64>(), Some(&42));
+}
+
+#[test]
+fn test_field_alias() {
+    #[derive(JsonPointee)]
+    struct Contact {
+        #[ploidy(pointer(alias = "e_mail", alias = "mail"))]
+        email: String,
+    }
+
+    let contact = Contact {
+        email: "alice@example.com".to_owned(),
+    };
+
+    // The canonical name and every alias should resolve to the same field.
+    for pointer in ["/email", "/e_mail", "/mail"] {
+        let pointer = JsonPointer::parse(pointer).unwrap();
+        let result = contact.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(
+            result.downcast_ref::<String>(),
+            Some(&"alice@example.com".to_owned())
+        );
+    }
+
+    let pointer = JsonPointer::parse("/emails").unwrap();
+    assert!(contact.resolve(pointer).is_err());
+}
+
+#[test]
+fn test_variant_alias() {
+    #[derive(JsonPointee)]
+    enum Shape {
+        #[ploidy(pointer(rename = "circle", alias = "round"))]
+        Circle { radius: i32 },
+        #[ploidy(pointer(alias = "Box"))]
+        Square(i32),
+    }
+
+    let shape = Shape::Circle { radius: 3 };
+    for pointer in ["/circle/radius", "/round/radius"] {
+        let pointer = JsonPointer::parse(pointer).unwrap();
+        let result = shape.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(result.downcast_ref::<i32>(), Some(&3));
+    }
+    let pointer = JsonPointer::parse("/Circle/radius").unwrap();
+    assert!(shape.resolve(pointer).is_err());
+
+    let shape = Shape::Square(4);
+    for pointer in ["/Square", "/Box"] {
+        let pointer = JsonPointer::parse(pointer).unwrap();
+        let result = shape.resolve(pointer).unwrap() as &dyn Any;
+        assert_eq!(result.downcast_ref::<i32>(), Some(&4));
+    }
+}
+
 #[test]
 fn test_flatten_field() {
     #[derive(JsonPointee)]
@@ -203,6 +286,42 @@ fn test_flatten_field() {
     assert_eq!(result.downcast_ref::<String>(), Some(&"hello".to_owned()));
 }
 
+#[test]
+fn test_flatten_map_field() {
+    #[derive(JsonPointee)]
+    struct Resource {
+        id: String,
+        #[ploidy(pointer(flatten))]
+        extra: BTreeMap<String, i32>,
+    }
+
+    let resource = Resource {
+        id: "r1".to_owned(),
+        extra: BTreeMap::from([("count".to_owned(), 3), ("id".to_owned(), 99)]),
+    };
+
+    // Unknown keys should resolve against the flattened map.
+    let pointer = JsonPointer::parse("/count").unwrap();
+    let result = resource.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<i32>(), Some(&3));
+
+    // Regular fields should take priority over map keys.
+    let pointer = JsonPointer::parse("/id").unwrap();
+    let result = resource.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<String>(), Some(&"r1".to_owned()));
+
+    // Keys that aren't fields or map keys should fail.
+    let pointer = JsonPointer::parse("/missing").unwrap();
+    assert!(resource.resolve(pointer).is_err());
+
+    // Mutable resolution should also reach the map.
+    let mut resource = resource;
+    let pointer = JsonPointer::parse("/count").unwrap();
+    let result = resource.resolve_mut(pointer).unwrap() as &mut dyn Any;
+    *result.downcast_mut::<i32>().unwrap() = 4;
+    assert_eq!(resource.extra["count"], 4);
+}
+
 #[test]
 fn test_multiple_flattened_fields() {
     #[derive(JsonPointee)]
@@ -430,6 +549,56 @@ fn test_flatten_order_matters() {
     assert_eq!(result.downcast_ref::<String>(), Some(&"hello".to_owned()));
 }
 
+#[test]
+fn test_transparent_named_struct() {
+    #[derive(JsonPointee)]
+    struct Payload {
+        id: i32,
+    }
+
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(transparent))]
+    struct Wrapper {
+        inner: Payload,
+    }
+
+    let mut wrapper = Wrapper {
+        inner: Payload { id: 1 },
+    };
+
+    // The pointer should resolve against the field, without an `/inner` segment.
+    let pointer = JsonPointer::parse("/id").unwrap();
+    let result = wrapper.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<i32>(), Some(&1));
+
+    let pointer = JsonPointer::parse("/inner").unwrap();
+    assert!(wrapper.resolve(pointer).is_err());
+
+    let pointer = JsonPointer::parse("/id").unwrap();
+    let result = wrapper.resolve_mut(pointer).unwrap() as &mut dyn Any;
+    *result.downcast_mut::<i32>().unwrap() = 2;
+    assert_eq!(wrapper.inner.id, 2);
+}
+
+#[test]
+fn test_transparent_with_skipped_field() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(transparent))]
+    struct Tagged(
+        #[ploidy(pointer(skip))]
+        #[allow(dead_code)]
+        &'static str,
+        Vec<i32>,
+    );
+
+    let tagged = Tagged("label", vec![10, 20]);
+
+    // The skipped field doesn't count toward the one field.
+    let pointer = JsonPointer::parse("/1").unwrap();
+    let result = tagged.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<i32>(), Some(&20));
+}
+
 #[test]
 #[cfg(feature = "chrono")]
 fn test_pointer_to_chrono_datetime() {
@@ -447,6 +616,27 @@ fn test_pointer_to_chrono_datetime() {
     assert!(timestamp.resolve(pointer).is_err());
 }
 
+#[test]
+#[cfg(feature = "chrono")]
+fn test_pointer_to_chrono_naive() {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let datetime: NaiveDateTime = date.and_hms_opt(10, 30, 0).unwrap();
+
+    // Empty pointers should return the values themselves.
+    let pointer = JsonPointer::parse("").unwrap();
+    let result = date.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<NaiveDate>(), Some(&date));
+    let result = datetime.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<NaiveDateTime>(), Some(&datetime));
+
+    // Non-empty pointers should fail.
+    let pointer = JsonPointer::parse("/foo").unwrap();
+    assert!(date.resolve(pointer).is_err());
+    assert!(datetime.resolve(pointer).is_err());
+}
+
 #[test]
 #[cfg(feature = "url")]
 fn test_pointer_to_url() {
@@ -481,6 +671,23 @@ fn test_pointer_to_uuid() {
     assert!(id.resolve(pointer).is_err());
 }
 
+#[test]
+#[cfg(feature = "rust_decimal")]
+fn test_pointer_to_rust_decimal() {
+    use rust_decimal::Decimal;
+
+    let price = Decimal::new(1999, 2);
+
+    // Empty pointer should return the decimal itself.
+    let pointer = JsonPointer::parse("").unwrap();
+    let result = price.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<Decimal>(), Some(&price));
+
+    // Non-empty pointer should fail.
+    let pointer = JsonPointer::parse("/foo").unwrap();
+    assert!(price.resolve(pointer).is_err());
+}
+
 #[test]
 #[cfg(feature = "serde_json")]
 fn test_pointer_to_serde_json() {
@@ -1012,6 +1219,41 @@ fn test_multiple_generic_parameters_with_bounds() {
     assert_eq!(result.downcast_ref::<i32>(), Some(&100));
 }
 
+#[test]
+fn test_generic_struct_with_custom_bounds() {
+    // Doesn't implement `JsonPointee`.
+    struct Opaque;
+
+    // `Cache<T>` skips its only use of `T`, so it doesn't need
+    // the default `T: JsonPointee` bound.
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(bound = "T: 'static"))]
+    struct Cache<T> {
+        #[ploidy(pointer(skip))]
+        #[allow(dead_code)]
+        entries: Vec<T>,
+        len: usize,
+    }
+
+    // `Wrapper<T>` only needs the bounds for `Cache<T>`.
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(bound = "T: Send + 'static"))]
+    struct Wrapper<T> {
+        inner: Cache<T>,
+    }
+
+    let wrapper = Wrapper {
+        inner: Cache {
+            entries: vec![Opaque, Opaque],
+            len: 2,
+        },
+    };
+
+    let pointer = JsonPointer::parse("/inner/len").unwrap();
+    let result = wrapper.resolve(pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<usize>(), Some(&2));
+}
+
 #[test]
 fn test_downcast_box() {
     #[derive(JsonPointee)]
@@ -1099,3 +1341,194 @@ fn test_option_resolve_is_transparent() {
     let none = None::<Inner>;
     assert!(none.resolve(JsonPointer::empty()).is_err());
 }
+
+#[test]
+fn test_resolve_mut_struct_field() {
+    #[derive(JsonPointee)]
+    struct Inner {
+        values: Vec<i32>,
+    }
+
+    #[derive(JsonPointee)]
+    struct Outer {
+        name: String,
+        inner: Inner,
+        #[ploidy(pointer(flatten))]
+        extra: Inner,
+    }
+
+    let mut outer = Outer {
+        name: "hello".to_owned(),
+        inner: Inner {
+            values: vec![1, 2, 3],
+        },
+        extra: Inner { values: vec![4] },
+    };
+
+    let pointer = JsonPointer::parse("/name").unwrap();
+    let name = outer.resolve_mut(pointer).unwrap();
+    *name.downcast_mut::<String>().unwrap() = "world".to_owned();
+    assert_eq!(outer.name, "world");
+
+    let pointer = JsonPointer::parse("/inner/values/1").unwrap();
+    let value = outer.resolve_mut(pointer).unwrap();
+    *value.downcast_mut::<i32>().unwrap() = 20;
+    assert_eq!(outer.inner.values, [1, 20, 3]);
+
+    // Flattened fields should be mutable, too.
+    let pointer = JsonPointer::parse("/values").unwrap();
+    let values = outer.resolve_mut(pointer).unwrap();
+    values.downcast_mut::<Vec<i32>>().unwrap().push(5);
+    assert_eq!(outer.extra.values, [4, 5]);
+}
+
+#[test]
+fn test_resolve_mut_enum_variant_field() {
+    #[derive(JsonPointee)]
+    enum Shape {
+        Circle { radius: f64 },
+        Line(f64, f64),
+    }
+
+    let mut circle = Shape::Circle { radius: 1.0 };
+    let pointer = JsonPointer::parse("/Circle/radius").unwrap();
+    let radius = circle.resolve_mut(pointer).unwrap();
+    *radius.downcast_mut::<f64>().unwrap() = 2.0;
+    assert!(matches!(circle, Shape::Circle { radius } if radius == 2.0));
+
+    let mut line = Shape::Line(1.0, 2.0);
+    let pointer = JsonPointer::parse("/Line/1").unwrap();
+    let end = line.resolve_mut(pointer).unwrap();
+    *end.downcast_mut::<f64>().unwrap() = 3.0;
+    assert!(matches!(line, Shape::Line(_, end) if end == 3.0));
+
+    // The root pointer should resolve to the variant itself.
+    let pointee = line.resolve_mut(JsonPointer::empty()).unwrap();
+    assert!(pointee.downcast_mut::<Shape>().is_some());
+}
+
+#[test]
+fn test_resolve_mut_vec_out_of_range() {
+    let mut values = vec![1, 2, 3];
+    let pointer = JsonPointer::parse("/3").unwrap();
+    assert!(matches!(
+        values.resolve_mut(pointer),
+        Err(JsonPointeeError::Index(3, range)) if range == (0..3)
+    ));
+}
+
+#[test]
+fn test_resolve_mut_shared_arc_is_read_only() {
+    #[derive(JsonPointee)]
+    struct Inner {
+        value: String,
+    }
+
+    let mut arced = Arc::new(Inner {
+        value: "hello".to_owned(),
+    });
+    let pointer = JsonPointer::parse("/value").unwrap();
+
+    // A uniquely owned `Arc` should be mutable...
+    let value = arced.resolve_mut(pointer).unwrap();
+    *value.downcast_mut::<String>().unwrap() = "world".to_owned();
+    assert_eq!(arced.value, "world");
+
+    // ...but a shared `Arc` shouldn't be.
+    let shared = arced.clone();
+    assert!(matches!(
+        arced.resolve_mut(pointer),
+        Err(JsonPointeeError::ReadOnly(_))
+    ));
+    drop(shared);
+}
+
+#[test]
+fn test_resolve_error_reports_traversed_path() {
+    #[derive(JsonPointee)]
+    enum Shape {
+        Circle { radius: f64 },
+        Polygon(Vec<Point>),
+    }
+
+    #[derive(JsonPointee)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(JsonPointee)]
+    struct Drawing {
+        shapes: Vec<Shape>,
+    }
+
+    let drawing = Drawing {
+        shapes: vec![
+            Shape::Circle { radius: 1.0 },
+            Shape::Polygon(vec![Point { x: 0.0, y: 0.0 }]),
+        ],
+    };
+
+    // Unknown field of an externally tagged struct-like variant.
+    let pointer = JsonPointer::parse("/shapes/0/Circle/diameter").unwrap();
+    let Err(JsonPointeeError::Key(err)) = drawing.resolve(pointer) else {
+        panic!("expected key error");
+    };
+    assert_eq!(err.key, "diameter");
+    assert_eq!(err.at.to_string(), "/shapes/0/Circle");
+    assert!(
+        err.to_string()
+            .starts_with(r#"unknown key "diameter" at /shapes/0/Circle"#)
+    );
+
+    // Unknown field inside an externally tagged newtype variant.
+    let pointer = JsonPointer::parse("/shapes/1/Polygon/0/z").unwrap();
+    let Err(JsonPointeeError::Key(err)) = drawing.resolve(pointer) else {
+        panic!("expected key error");
+    };
+    assert_eq!(err.key, "z");
+    assert_eq!(err.at.to_string(), "/shapes/1/Polygon/0");
+
+    // Type errors report the path, too.
+    let pointer = JsonPointer::parse("/shapes/1/Polygon/first").unwrap();
+    let Err(JsonPointeeError::Ty(err)) = drawing.resolve(pointer) else {
+        panic!("expected type error");
+    };
+    assert_eq!(err.pointer, "/first");
+    assert_eq!(err.at.to_string(), "/shapes/1/Polygon");
+}
+
+#[test]
+fn test_match_ascii_case() {
+    #[derive(JsonPointee)]
+    struct Account {
+        #[ploidy(pointer(rename = "userName"))]
+        user_name: String,
+        profile: Profile,
+    }
+
+    #[derive(JsonPointee)]
+    enum Profile {
+        Public { bio: String },
+    }
+
+    let account = Account {
+        user_name: "alice".to_owned(),
+        profile: Profile::Public {
+            bio: "Hi!".to_owned(),
+        },
+    };
+
+    let pointer = account
+        .match_ascii_case(JsonPointer::parse("/USERNAME").unwrap())
+        .unwrap();
+    assert_eq!(pointer.to_string(), "/userName");
+    let result = account.resolve(&pointer).unwrap() as &dyn Any;
+    assert_eq!(result.downcast_ref::<String>(), Some(&"alice".to_owned()));
+
+    // Externally tagged variants match with their fields.
+    let pointer = account
+        .match_ascii_case(JsonPointer::parse("/Profile/public/BIO").unwrap())
+        .unwrap();
+    assert_eq!(pointer.to_string(), "/profile/Public/bio");
+}
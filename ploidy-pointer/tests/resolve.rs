@@ -0,0 +1,70 @@
+//! Property tests for resolving arbitrary pointers against arbitrary values.
+
+#![cfg(feature = "serde_json")]
+
+use std::any::Any;
+
+use ploidy_pointer::{JsonPointee, JsonPointer, JsonPointerBuf};
+use proptest::prelude::*;
+use serde_json::Value;
+
+/// A small key alphabet that includes the characters that pointers escape,
+/// so that generated pointers often match.
+const KEY: &str = "[ab0-2~/+-]{0,3}";
+
+proptest! {
+    #[test]
+    fn test_resolve_never_panics_for_arbitrary_strings(
+        value in (prop_oneof![
+            Just(Value::Null),
+            any::<bool>().prop_map(Value::Bool),
+            any::<i64>().prop_map(Value::from),
+            ".*".prop_map(Value::String),
+        ])
+        .prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(Value::Array),
+                prop::collection::btree_map(KEY, inner, 0..8)
+                    .prop_map(|map| Value::Object(map.into_iter().collect())),
+            ]
+        }),
+        s in "(/[ab0-2~+-]{0,3}){0,5}|.*",
+    ) {
+        let Ok(pointer) = JsonPointer::parse(&s) else {
+            return Ok(());
+        };
+        if let Err(err) = value.resolve(pointer) {
+            prop_assert!(!err.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_resolve_escaped_segments_agrees_with_serde_json(
+        value in (prop_oneof![
+            Just(Value::Null),
+            any::<bool>().prop_map(Value::Bool),
+            any::<i64>().prop_map(Value::from),
+            ".*".prop_map(Value::String),
+        ])
+        .prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(Value::Array),
+                prop::collection::btree_map(KEY, inner, 0..8)
+                    .prop_map(|map| Value::Object(map.into_iter().collect())),
+            ]
+        }),
+        segments in prop::collection::vec(KEY, 0..6),
+    ) {
+        // `Value::pointer` implements RFC 6901, so both should
+        // resolve the same pointers to the same values.
+        let pointer = JsonPointerBuf::from_segments(segments);
+        let actual = match value.resolve(&pointer) {
+            Ok(pointee) => (pointee as &dyn Any).downcast_ref::<Value>(),
+            Err(err) => {
+                prop_assert!(!err.to_string().is_empty());
+                None
+            }
+        };
+        prop_assert_eq!(actual, value.pointer(&pointer.to_string()));
+    }
+}
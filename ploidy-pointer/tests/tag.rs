@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use ploidy_pointer::{JsonPointee, JsonPointer, JsonPointerTarget};
+use ploidy_pointer::{JsonPointee, JsonPointeeError, JsonPointer, JsonPointerTarget};
 
 #[test]
 fn test_basic_tag_named_variants() {
@@ -832,3 +832,53 @@ fn test_adjacent_tag_skipped_variant() {
     let pointer = JsonPointer::parse("/value").unwrap();
     assert!(response.resolve(pointer).is_err());
 }
+
+#[test]
+fn test_resolve_mut_internal_tag_is_read_only() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(tag = "type"))]
+    enum Response {
+        Success { data: String },
+    }
+
+    let mut response = Response::Success {
+        data: "hello".to_owned(),
+    };
+
+    // Fields next to the tag should be mutable.
+    let pointer = JsonPointer::parse("/data").unwrap();
+    let data = response.resolve_mut(pointer).unwrap();
+    *data.downcast_mut::<String>().unwrap() = "world".to_owned();
+    let Response::Success { data } = &response;
+    assert_eq!(data, "world");
+
+    // The tag is computed from the variant, so it can't be mutated.
+    let pointer = JsonPointer::parse("/type").unwrap();
+    assert!(matches!(
+        response.resolve_mut(pointer),
+        Err(JsonPointeeError::ReadOnly(_))
+    ));
+}
+
+#[test]
+fn test_resolve_mut_adjacent_tag_newtype_content() {
+    #[derive(JsonPointee)]
+    #[ploidy(pointer(tag = "kind", content = "payload"))]
+    enum Event {
+        Count(i32),
+    }
+
+    let mut event = Event::Count(1);
+
+    let pointer = JsonPointer::parse("/payload").unwrap();
+    let count = event.resolve_mut(pointer).unwrap();
+    *count.downcast_mut::<i32>().unwrap() += 1;
+    let Event::Count(count) = event;
+    assert_eq!(count, 2);
+
+    let pointer = JsonPointer::parse("/kind").unwrap();
+    assert!(matches!(
+        event.resolve_mut(pointer),
+        Err(JsonPointeeError::ReadOnly(_))
+    ));
+}
@@ -258,6 +258,22 @@ impl<T: JsonPointee> JsonPointee for AbsentOr<T> {
             })?,
         }
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        match self {
+            Self::Present(value) => value.resolve_mut(pointer),
+            _ => Err({
+                #[cfg(feature = "did-you-mean")]
+                let err = JsonPointerTypeError::with_ty(pointer, JsonPointeeType::name_of(self));
+                #[cfg(not(feature = "did-you-mean"))]
+                let err = JsonPointerTypeError::new(pointer);
+                err
+            })?,
+        }
+    }
 }
 
 impl<T: Serialize> Serialize for AbsentOr<T> {
@@ -22,6 +22,17 @@ impl JsonPointee for Base64 {
             Err(JsonPointerTypeError::new(pointer).into())
         }
     }
+
+    fn resolve_mut(
+        &mut self,
+        pointer: &JsonPointer,
+    ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+        if pointer.is_empty() {
+            Ok(self as &mut dyn JsonPointee)
+        } else {
+            Err(JsonPointerTypeError::new(pointer).into())
+        }
+    }
 }
 
 impl AsRef<[u8]> for Base64 {
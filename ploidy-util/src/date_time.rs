@@ -1,4 +1,6 @@
-use chrono::{DateTime, Utc};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use chrono::{DateTime, SubsecRound, Utc};
 use ploidy_pointer::{JsonPointee, JsonPointeeError, JsonPointer, JsonPointerTypeError};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
@@ -184,6 +186,67 @@ impl<'de> Deserialize<'de> for UnixSeconds {
     }
 }
 
+/// An RFC 3339 timestamp, kept as the original string.
+///
+/// Unlike `DateTime<Utc>`, this type preserves the timestamp's
+/// offset and precision exactly.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rfc3339(String);
+
+impl Rfc3339 {
+    /// Returns the timestamp as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Rfc3339 {
+    type Error = Rfc3339Error;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match DateTime::parse_from_rfc3339(&value) {
+            Ok(_) => Ok(Self(value)),
+            Err(_) => Err(Rfc3339Error(value)),
+        }
+    }
+}
+
+impl From<Rfc3339> for String {
+    #[inline]
+    fn from(value: Rfc3339) -> Self {
+        value.0
+    }
+}
+
+impl Display for Rfc3339 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Rfc3339 {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rfc3339 {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use de::Error;
+        let s = String::deserialize(deserializer)?;
+        s.try_into().map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`{0}` isn't an RFC 3339 timestamp")]
+pub struct Rfc3339Error(pub String);
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum NumericTimestamp<'a> {
@@ -212,10 +275,63 @@ impl TryFrom<NumericTimestamp<'_>> for i64 {
 pub enum TryFromTimestampError {
     #[error("timestamp `{0}` out of range for `DateTime<Utc>`")]
     Range(i128),
+    #[error("timestamp `{0}` overflows `i64`")]
+    Overflow(i128),
     #[error("can't convert `{0}` to timestamp")]
     Str(String),
 }
 
+/// Implements lossy conversions between timestamps.
+///
+/// Converting to a coarser unit rounds toward the past, like
+/// [`DateTime::timestamp()`], so that the converted timestamp
+/// serializes to the same value.
+macro_rules! impl_from_timestamp {
+    ($($from:ty => $to:ty, $digits:literal);* $(;)?) => {$(
+        impl From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                Self(value.0.trunc_subsecs($digits))
+            }
+        }
+    )*};
+}
+
+impl_from_timestamp!(
+    UnixSeconds => UnixMilliseconds, 3;
+    UnixSeconds => UnixMicroseconds, 6;
+    UnixMilliseconds => UnixSeconds, 0;
+    UnixMilliseconds => UnixMicroseconds, 6;
+    UnixMicroseconds => UnixSeconds, 0;
+    UnixMicroseconds => UnixMilliseconds, 3;
+    UnixNanoseconds => UnixSeconds, 0;
+    UnixNanoseconds => UnixMilliseconds, 3;
+    UnixNanoseconds => UnixMicroseconds, 6;
+);
+
+/// Implements conversions to [`UnixNanoseconds`], which
+/// can only represent years between 1677 and 2262.
+macro_rules! impl_try_from_timestamp_for_nanos {
+    ($($from:ty),*) => {$(
+        impl TryFrom<$from> for UnixNanoseconds {
+            type Error = TryFromTimestampError;
+
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                match value.0.timestamp_nanos_opt() {
+                    Some(_) => Ok(Self(value.0)),
+                    None => Err(TryFromTimestampError::Overflow(
+                        i128::from(value.0.timestamp()) * 1_000_000_000
+                            + i128::from(value.0.timestamp_subsec_nanos()),
+                    )),
+                }
+            }
+        }
+    )*};
+}
+
+impl_try_from_timestamp_for_nanos!(UnixSeconds, UnixMilliseconds, UnixMicroseconds);
+
 macro_rules! impl_pointee_for {
     ($($ty:ty),*) => {$(
         impl JsonPointee for $ty {
@@ -226,11 +342,23 @@ macro_rules! impl_pointee_for {
                     Err(JsonPointerTypeError::new(pointer).into())
                 }
             }
+
+            fn resolve_mut(
+                &mut self,
+                pointer: &JsonPointer,
+            ) -> Result<&mut dyn JsonPointee, JsonPointeeError> {
+                if pointer.is_empty() {
+                    Ok(self as &mut dyn JsonPointee)
+                } else {
+                    Err(JsonPointerTypeError::new(pointer).into())
+                }
+            }
         }
     )*};
 }
 
 impl_pointee_for!(
+    Rfc3339,
     UnixMicroseconds,
     UnixMilliseconds,
     UnixNanoseconds,
@@ -372,4 +500,89 @@ mod tests {
         let result: Result<UnixNanoseconds, _> = serde_json::from_str(json);
         assert!(result.is_err());
     }
+
+    // MARK: Conversions
+
+    #[test]
+    fn test_unix_timestamp_conversions_round_toward_past() {
+        let millis = UnixMilliseconds::try_from(1_500).unwrap();
+        assert_eq!(
+            serde_json::to_string(&UnixSeconds::from(millis)).unwrap(),
+            "1"
+        );
+
+        let millis = UnixMilliseconds::try_from(-1_500).unwrap();
+        assert_eq!(
+            serde_json::to_string(&UnixSeconds::from(millis)).unwrap(),
+            "-2"
+        );
+
+        let nanos = UnixNanoseconds::from(-1_234_567);
+        assert_eq!(
+            serde_json::to_string(&UnixMicroseconds::from(nanos)).unwrap(),
+            "-1235"
+        );
+        assert_eq!(
+            serde_json::to_string(&UnixMilliseconds::from(nanos)).unwrap(),
+            "-2"
+        );
+    }
+
+    #[test]
+    fn test_unix_timestamp_conversions_scale_to_finer_units() {
+        let secs = UnixSeconds::try_from(1609459200).unwrap();
+        assert_eq!(
+            serde_json::to_string(&UnixMilliseconds::from(secs)).unwrap(),
+            "1609459200000"
+        );
+        assert_eq!(
+            serde_json::to_string(&UnixMicroseconds::from(secs)).unwrap(),
+            "1609459200000000"
+        );
+        assert_eq!(
+            serde_json::to_string(&UnixNanoseconds::try_from(secs).unwrap()).unwrap(),
+            "1609459200000000000"
+        );
+    }
+
+    #[test]
+    fn test_unix_nanoseconds_conversion_overflow() {
+        // `i64::MAX` nanoseconds is in 2262; one second later overflows.
+        let nanos = UnixNanoseconds::from(i64::MAX);
+        let secs = UnixSeconds::from(nanos);
+        assert!(UnixNanoseconds::try_from(secs).is_ok());
+
+        let secs = UnixSeconds::try_from(i64::MAX / 1_000_000_000 + 1).unwrap();
+        let result = UnixNanoseconds::try_from(secs);
+        assert!(matches!(
+            result,
+            Err(TryFromTimestampError::Overflow(n)) if n == (i128::from(i64::MAX) / 1_000_000_000 + 1) * 1_000_000_000
+        ));
+
+        // `i64::MAX` seconds is out of range for `DateTime<Utc>`, too.
+        assert!(matches!(
+            UnixSeconds::try_from(i64::MAX),
+            Err(TryFromTimestampError::Range(_))
+        ));
+    }
+
+    // MARK: RFC 3339
+
+    #[test]
+    fn test_rfc3339_roundtrips_valid_timestamp() {
+        let json = r#""2021-01-01T00:00:00.123+05:30""#;
+        let result: Rfc3339 = serde_json::from_str(json).unwrap();
+        assert_eq!(result.as_str(), "2021-01-01T00:00:00.123+05:30");
+        assert_eq!(serde_json::to_string(&result).unwrap(), json);
+    }
+
+    #[test]
+    fn test_rfc3339_rejects_malformed_timestamp() {
+        let err = Rfc3339::try_from("2021-13-01 midnight".to_owned()).unwrap_err();
+        assert_eq!(err.0, "2021-13-01 midnight");
+
+        let json = r#""2021-01-01""#;
+        let result: Result<Rfc3339, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
 }
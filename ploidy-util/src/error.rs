@@ -1,9 +1,13 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    string::FromUtf8Error,
+};
 
 use http::{HeaderName, StatusCode};
 use url::ParseError as UrlParseError;
 
-use crate::{query::QueryParamError, url::PathAndQueryError};
+use crate::{path::PathParamError, query::QueryParamError, url::PathAndQueryError};
 
 /// A client error.
 #[derive(Debug, thiserror::Error)]
@@ -12,7 +16,7 @@ pub enum Error {
     Build(#[from] BuildError),
 
     #[error("HTTP transport error")]
-    Transport(#[source] reqwest::Error),
+    Transport(#[from] TransportError),
 
     #[error("HTTP status error ({0})")]
     Status(StatusCode),
@@ -34,13 +38,21 @@ impl Error {
         Self::Build(BuildError::HeaderValue(name, err.into()))
     }
 
+    /// Creates an error for a request body that can't be serialized.
+    #[cold]
+    pub fn bad_request_body(err: serde_json::Error) -> Self {
+        Self::Build(BuildError::Body(err))
+    }
+
     /// Returns the telemetry category for this error.
     pub fn category(&self) -> ErrorCategory {
         match self {
             Self::Build(_) => ErrorCategory::Build,
-            Self::Transport(err) if err.is_timeout() => ErrorCategory::Timeout,
-            Self::Transport(err) if err.is_connect() => ErrorCategory::Connect,
-            Self::Transport(_) => ErrorCategory::Transport,
+            Self::Transport(err) => match err.kind() {
+                TransportErrorKind::Connect => ErrorCategory::Connect,
+                TransportErrorKind::Timeout => ErrorCategory::Timeout,
+                TransportErrorKind::Other => ErrorCategory::Transport,
+            },
             &Self::Status(status) => ErrorCategory::Status(status),
             Self::Body(_) => ErrorCategory::Body,
         }
@@ -53,6 +65,12 @@ impl From<QueryParamError> for Error {
     }
 }
 
+impl From<PathParamError> for Error {
+    fn from(err: PathParamError) -> Self {
+        Self::Build(BuildError::PathParam(err))
+    }
+}
+
 impl From<PathAndQueryError> for Error {
     fn from(err: PathAndQueryError) -> Self {
         Self::Build(BuildError::Path(err))
@@ -65,15 +83,20 @@ impl From<UrlParseError> for Error {
     }
 }
 
+impl From<http::Error> for Error {
+    #[cold]
+    fn from(err: http::Error) -> Self {
+        Self::Build(BuildError::Request(err))
+    }
+}
+
+#[cfg(feature = "reqwest")]
 impl From<reqwest::Error> for Error {
     #[cold]
     fn from(err: reqwest::Error) -> Self {
-        if err.is_builder() {
-            Self::Build(BuildError::Request(err))
-        } else if let Some(status) = err.status() {
-            Self::Status(status)
-        } else {
-            Self::Transport(err)
+        match err.status() {
+            Some(status) => Self::Status(status),
+            None => Self::Transport(err.into()),
         }
     }
 }
@@ -92,20 +115,104 @@ impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
     }
 }
 
+impl From<FromUtf8Error> for Error {
+    #[cold]
+    fn from(err: FromUtf8Error) -> Self {
+        Self::Body(BodyError::Text(err))
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum BuildError {
     #[error("invalid URL")]
     Url(#[source] UrlParseError),
     #[error("invalid query parameter")]
     QueryParam(#[source] QueryParamError),
+    #[error("invalid path parameter")]
+    PathParam(#[source] PathParamError),
     #[error(transparent)]
     Path(PathAndQueryError),
     #[error("invalid header name")]
     HeaderName(#[source] http::Error),
     #[error("invalid value for header `{0}`")]
     HeaderValue(HeaderName, #[source] http::Error),
-    #[error(transparent)]
-    Request(reqwest::Error),
+    #[error("invalid request body")]
+    Body(#[source] serde_json::Error),
+    #[error("invalid request")]
+    Request(#[source] http::Error),
+}
+
+/// An error sending a request or receiving a response.
+///
+/// [`HttpClient`] implementations return this error to describe
+/// transport failures.
+///
+/// [`HttpClient`]: crate::transport::HttpClient
+#[derive(Debug, thiserror::Error)]
+#[error("{kind}")]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    #[source]
+    source: Box<dyn StdError + Send + Sync>,
+}
+
+impl TransportError {
+    /// Creates an error for a failed connection.
+    #[cold]
+    pub fn connect(err: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::new(TransportErrorKind::Connect, err)
+    }
+
+    /// Creates an error for a request that timed out.
+    #[cold]
+    pub fn timeout(err: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::new(TransportErrorKind::Timeout, err)
+    }
+
+    /// Creates an error for any other transport failure.
+    #[cold]
+    pub fn other(err: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::new(TransportErrorKind::Other, err)
+    }
+
+    #[cold]
+    fn new(kind: TransportErrorKind, err: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self {
+            kind,
+            source: err.into(),
+        }
+    }
+
+    /// Returns the kind of transport failure.
+    #[inline]
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for TransportError {
+    #[cold]
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::timeout(err)
+        } else if err.is_connect() {
+            Self::connect(err)
+        } else {
+            Self::other(err)
+        }
+    }
+}
+
+/// The kind of a [`TransportError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TransportErrorKind {
+    #[error("connection failed")]
+    Connect,
+    #[error("request timed out")]
+    Timeout,
+    #[error("transport failed")]
+    Other,
 }
 
 /// Invalid or unexpected response body, with or without a path
@@ -116,6 +223,61 @@ pub enum BodyError {
     Json(serde_json::Error),
     #[error(transparent)]
     JsonWithPath(serde_path_to_error::Error<serde_json::Error>),
+    #[error("response body isn't valid UTF-8")]
+    Text(#[source] FromUtf8Error),
+}
+
+/// A value that violates a constraint in the schema.
+#[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
+#[error("invalid `{field}`: {kind}")]
+pub struct ValidationError {
+    field: &'static str,
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    /// Creates an error for a field that violates a constraint.
+    #[cold]
+    pub fn new(field: &'static str, kind: ValidationErrorKind) -> Self {
+        Self { field, kind }
+    }
+
+    /// Returns the name of the invalid field, as it appears in the schema.
+    #[inline]
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Returns the constraint that the field violates.
+    #[inline]
+    pub fn kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+}
+
+/// The constraint that a [`ValidationError`] violates.
+#[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
+pub enum ValidationErrorKind {
+    #[error("must be at least {0}")]
+    Minimum(f64),
+    #[error("must be at most {0}")]
+    Maximum(f64),
+    #[error("must have at least {0} characters")]
+    MinLength(usize),
+    #[error("must have at most {0} characters")]
+    MaxLength(usize),
+    #[error("must have at least {0} items")]
+    MinItems(usize),
+    #[error("must have at most {0} items")]
+    MaxItems(usize),
+    #[error("must have at least {0} properties")]
+    MinProperties(usize),
+    #[error("must have at most {0} properties")]
+    MaxProperties(usize),
+    #[error("must have unique items")]
+    UniqueItems,
+    #[error("must have property names that match a pattern")]
+    PropertyName,
 }
 
 /// The telemetry category for an [`Error`].
@@ -141,3 +303,90 @@ impl Display for ErrorCategory {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+    #[test]
+    fn test_transport_error_source_chain_includes_inner_error() {
+        let err = Error::from(TransportError::timeout(IoError::from(
+            IoErrorKind::TimedOut,
+        )));
+        assert_eq!(err.category(), ErrorCategory::Timeout);
+
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<TransportError>().unwrap().kind(),
+            TransportErrorKind::Timeout
+        );
+        let inner = source.source().unwrap().downcast_ref::<IoError>().unwrap();
+        assert_eq!(inner.kind(), IoErrorKind::TimedOut);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_reqwest_status_error_is_status_error() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(StatusCode::BAD_GATEWAY)
+                .body("")
+                .unwrap(),
+        );
+        let err = Error::from(response.error_for_status().unwrap_err());
+        assert_eq!(
+            err.category(),
+            ErrorCategory::Status(StatusCode::BAD_GATEWAY)
+        );
+    }
+
+    #[test]
+    fn test_build_error_source_chain_includes_header_error() {
+        let err = Error::bad_header_name(http::HeaderName::from_bytes(b"bad header").unwrap_err());
+
+        let source = err.source().unwrap();
+        assert!(matches!(
+            source.downcast_ref::<BuildError>(),
+            Some(BuildError::HeaderName(_))
+        ));
+        assert!(source.source().unwrap().is::<http::Error>());
+    }
+
+    #[test]
+    fn test_validation_error_display() {
+        let err = ValidationError::new("name", ValidationErrorKind::MaxLength(3));
+        assert_eq!(
+            err.to_string(),
+            "invalid `name`: must have at most 3 characters"
+        );
+    }
+
+    #[test]
+    fn test_body_error_from_question_mark() {
+        fn parse() -> Result<serde_json::Value, Error> {
+            Ok(serde_json::from_str("{")?)
+        }
+
+        let err = parse().unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Body);
+
+        let source = err.source().unwrap();
+        assert!(matches!(
+            source.downcast_ref::<BodyError>(),
+            Some(BodyError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_text_body_error_from_question_mark() {
+        fn parse() -> Result<String, Error> {
+            Ok(String::from_utf8(vec![0xff])?)
+        }
+
+        let err = parse().unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Body);
+        assert!(matches!(err, Error::Body(BodyError::Text(_))));
+    }
+}
@@ -2,23 +2,38 @@ pub mod absent;
 pub mod binary;
 pub mod date_time;
 pub mod error;
+pub mod multipart;
+pub mod pagination;
+pub mod path;
 pub mod query;
+pub mod retry;
 #[cfg(feature = "trace-context")]
 pub mod trace;
+pub mod transport;
 pub mod url;
 
 pub use absent::{AbsentError, AbsentOr, AbsentOrExt, FieldAbsentError};
 pub use binary::{Base64, Base64Error};
 pub use date_time::{
-    TryFromTimestampError, UnixMicroseconds, UnixMilliseconds, UnixNanoseconds, UnixSeconds,
+    Rfc3339, Rfc3339Error, TryFromTimestampError, UnixMicroseconds, UnixMilliseconds,
+    UnixNanoseconds, UnixSeconds,
 };
+pub use path::{PathParamError, PathParamSerializer, PathStyle};
 pub use pointer::{JsonPointeeExt, JsonPointerError};
 pub use query::{QueryParamError, QuerySerializer, QueryStyle};
+pub use retry::RetryPolicy;
+pub use transport::{BlockingHttpClient, HttpClient};
 
+pub use bytes;
 pub use chrono;
 pub use http;
 pub use ploidy_pointer as pointer;
+#[cfg(feature = "regex")]
+pub use regex;
+#[cfg(feature = "reqwest")]
 pub use reqwest;
+#[cfg(feature = "decimal")]
+pub use rust_decimal;
 pub use serde;
 pub use serde_bytes;
 pub use serde_json;
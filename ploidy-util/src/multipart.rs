@@ -0,0 +1,224 @@
+//! `multipart/form-data` request bodies.
+//!
+//! A [`Form`] is encoded into bytes when the request is built,
+//! so any [`HttpClient`] can send it.
+//!
+//! # Examples
+//!
+//! ```
+//! use ploidy_util::multipart::{Form, Part};
+//!
+//! # fn main() -> Result<(), ploidy_util::http::header::InvalidHeaderValue> {
+//! let form = Form::new()
+//!     .text("description", "A cat")
+//!     .part(
+//!         "photo",
+//!         Part::bytes(b"GIF89a".as_slice())
+//!             .file_name("cat.gif")
+//!             .mime_str("image/gif")?,
+//!     );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`HttpClient`]: crate::transport::HttpClient
+
+use std::borrow::Cow;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use http::{HeaderValue, header::InvalidHeaderValue};
+
+/// A `multipart/form-data` request body.
+#[derive(Clone, Debug)]
+pub struct Form {
+    boundary: String,
+    parts: Vec<(Cow<'static, str>, Part)>,
+}
+
+impl Form {
+    /// Creates an empty form with a random boundary.
+    pub fn new() -> Self {
+        Self {
+            boundary: uuid::Uuid::new_v4().simple().to_string(),
+            parts: vec![],
+        }
+    }
+
+    /// Adds a text field.
+    pub fn text(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.part(name, Part::text(value))
+    }
+
+    /// Adds a part.
+    pub fn part(mut self, name: impl Into<Cow<'static, str>>, part: Part) -> Self {
+        self.parts.push((name.into(), part));
+        self
+    }
+
+    /// Returns the boundary that separates the parts.
+    #[inline]
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Returns the `Content-Type` header value for this form.
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Encodes this form into a request body.
+    pub fn into_bytes(self) -> Bytes {
+        let mut buf = BytesMut::new();
+        for (name, part) in self.parts {
+            buf.put_slice(b"--");
+            buf.put_slice(self.boundary.as_bytes());
+            buf.put_slice(b"\r\nContent-Disposition: form-data");
+            let params = [("name", &*name)].into_iter().chain(
+                part.file_name
+                    .as_deref()
+                    .map(|file_name| ("filename", file_name)),
+            );
+            for (key, value) in params {
+                buf.put_slice(b"; ");
+                buf.put_slice(key.as_bytes());
+                buf.put_slice(b"=\"");
+                // Percent-encode quotes and line breaks like browsers do.
+                for byte in value.bytes() {
+                    match byte {
+                        b'"' => buf.put_slice(b"%22"),
+                        b'\r' => buf.put_slice(b"%0D"),
+                        b'\n' => buf.put_slice(b"%0A"),
+                        byte => buf.put_u8(byte),
+                    }
+                }
+                buf.put_u8(b'"');
+            }
+            if let Some(mime) = &part.mime {
+                buf.put_slice(b"\r\nContent-Type: ");
+                buf.put_slice(mime.as_bytes());
+            }
+            buf.put_slice(b"\r\n\r\n");
+            buf.put_slice(&part.body);
+            buf.put_slice(b"\r\n");
+        }
+        buf.put_slice(b"--");
+        buf.put_slice(self.boundary.as_bytes());
+        buf.put_slice(b"--\r\n");
+        buf.freeze()
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A field in a [`Form`].
+#[derive(Clone, Debug)]
+pub struct Part {
+    body: Bytes,
+    file_name: Option<Cow<'static, str>>,
+    mime: Option<HeaderValue>,
+}
+
+impl Part {
+    /// Creates a text part.
+    pub fn text(value: impl Into<Cow<'static, str>>) -> Self {
+        let body = match value.into() {
+            Cow::Borrowed(value) => Bytes::from_static(value.as_bytes()),
+            Cow::Owned(value) => Bytes::from(value),
+        };
+        Self::bytes(body)
+    }
+
+    /// Creates a binary part.
+    pub fn bytes(value: impl Into<Bytes>) -> Self {
+        Self {
+            body: value.into(),
+            file_name: None,
+            mime: None,
+        }
+    }
+
+    /// Sets the file name for this part.
+    pub fn file_name(self, file_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            file_name: Some(file_name.into()),
+            ..self
+        }
+    }
+
+    /// Sets the MIME type for this part.
+    pub fn mime_str(self, mime: &str) -> Result<Self, InvalidHeaderValue> {
+        Ok(Self {
+            mime: Some(HeaderValue::from_str(mime)?),
+            ..self
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_into_bytes() {
+        let form = Form::new().text("name", "Mittens").part(
+            "photo",
+            Part::bytes(b"GIF89a".as_slice())
+                .file_name("cat.gif")
+                .mime_str("image/gif")
+                .unwrap(),
+        );
+        let boundary = form.boundary().to_owned();
+        assert_eq!(
+            form.content_type(),
+            format!("multipart/form-data; boundary={boundary}")
+        );
+
+        let body = form.into_bytes();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            format!(
+                "--{boundary}\r\n\
+                 Content-Disposition: form-data; name=\"name\"\r\n\
+                 \r\n\
+                 Mittens\r\n\
+                 --{boundary}\r\n\
+                 Content-Disposition: form-data; name=\"photo\"; filename=\"cat.gif\"\r\n\
+                 Content-Type: image/gif\r\n\
+                 \r\n\
+                 GIF89a\r\n\
+                 --{boundary}--\r\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_form_escapes_names() {
+        let form = Form::new().part("a\"b", Part::text("").file_name("line\r\nbreak.txt"));
+        let boundary = form.boundary().to_owned();
+
+        let body = form.into_bytes();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            format!(
+                "--{boundary}\r\n\
+                 Content-Disposition: form-data; name=\"a%22b\"; filename=\"line%0D%0Abreak.txt\"\r\n\
+                 \r\n\
+                 \r\n\
+                 --{boundary}--\r\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_part_rejects_invalid_mime() {
+        assert!(Part::text("").mime_str("text/plain\r\nX-Evil: 1").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use std::future::Future;
+
+use futures_util::{TryStreamExt, stream};
+
+pub use futures_util::Stream;
+
+/// Returns a stream of all the items on all the pages of a paginated list.
+///
+/// `fetch` takes the query for a page, and returns the items on that page
+/// and the query for the next page. The first page is fetched with `query`.
+/// The stream ends after the page without a next query,
+/// or after the first error.
+pub fn paginate<Q, T, E, F, Fut>(query: Q, fetch: F) -> impl Stream<Item = Result<T, E>>
+where
+    F: FnMut(Q) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<Q>), E>>,
+{
+    stream::unfold(Some((query, fetch)), |state| async move {
+        let (query, mut fetch) = state?;
+        Some(match fetch(query).await {
+            Ok((items, Some(next))) => (Ok(items), Some((next, fetch))),
+            Ok((items, None)) => (Ok(items), None),
+            Err(err) => (Err(err), None),
+        })
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::{FutureExt, StreamExt};
+
+    #[test]
+    fn test_paginate_follows_next_queries() {
+        let pages = [
+            ("a", (vec![1, 2], Some("b"))),
+            ("b", (vec![], Some("c"))),
+            ("c", (vec![3], None)),
+        ];
+        let mut fetched = vec![];
+        let items = paginate("a", |query| {
+            fetched.push(query);
+            let (_, page) = pages.iter().find(|(q, _)| *q == query).unwrap().clone();
+            async move { Ok::<_, ()>(page) }
+        })
+        .collect::<Vec<_>>()
+        .now_or_never()
+        .unwrap();
+        assert_eq!(items, [Ok(1), Ok(2), Ok(3)]);
+        assert_eq!(fetched, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_paginate_stops_after_error() {
+        let mut calls = 0;
+        let items = paginate(1, |query| {
+            calls += 1;
+            async move {
+                match query {
+                    1 => Ok((vec!["a"], Some(2))),
+                    _ => Err("failed"),
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .now_or_never()
+        .unwrap();
+        assert_eq!(items, [Ok("a"), Err("failed")]);
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,713 @@
+//! OpenAPI path parameter serialization.
+//!
+//! This module provides Serde-based serialization for OpenAPI 3.x
+//! path parameters, and supports all standard path styles:
+//! `simple`, `label`, and `matrix`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Serialize;
+//! use ploidy_util::path::{PathParamSerializer, PathStyle};
+//! # use ploidy_util::path::PathParamError;
+//!
+//! # fn main() -> Result<(), PathParamError> {
+//! // Arrays use comma-separated values in the default
+//! // non-exploded `simple` style.
+//! let ids = ["3", "4", "5"];
+//! let segment = ids.serialize(PathParamSerializer::new("ids", PathStyle::default()))?;
+//! assert_eq!(segment, "3,4,5");
+//!
+//! // The exploded `matrix` style repeats the parameter name.
+//! let segment = ids.serialize(PathParamSerializer::new(
+//!     "ids",
+//!     PathStyle::Matrix { exploded: true },
+//! ))?;
+//! assert_eq!(segment, ";ids=3;ids=4;ids=5");
+//!
+//! // Objects use alternating keys and values, or
+//! // `key=value` pairs if exploded.
+//! #[derive(Serialize)]
+//! struct Color {
+//!     r: u8,
+//!     g: u8,
+//!     b: u8,
+//! }
+//!
+//! let color = Color { r: 100, g: 200, b: 150 };
+//! let segment = color.serialize(PathParamSerializer::new(
+//!     "color",
+//!     PathStyle::Label { exploded: true },
+//! ))?;
+//! assert_eq!(segment, ".r=100.g=200.b=150");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Primitive values, array items, and object keys and values are
+//! percent-encoded before they're joined, so that delimiters inside
+//! values aren't mistaken for separators:
+//!
+//! ```
+//! # use serde::Serialize;
+//! # use ploidy_util::path::{PathParamError, PathParamSerializer, PathStyle};
+//! # fn main() -> Result<(), PathParamError> {
+//! let segment = ["a,b", "c"].serialize(PathParamSerializer::new("ids", PathStyle::default()))?;
+//! assert_eq!(segment, "a%2Cb,c");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Because the result is already encoded, it must be appended to
+//! the URL path as-is, without encoding it again.
+
+use std::marker::PhantomData;
+
+use itertools::Itertools;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC};
+use serde::{
+    Serialize,
+    ser::{Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, Serializer},
+};
+
+use crate::query::{UnsupportedTypeError, ValueSerializer};
+
+/// Styles that describe how to format URL path parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStyle {
+    /// Comma-separated values.
+    ///
+    /// The non-exploded `simple` style is the default.
+    Simple { exploded: bool },
+
+    /// Values prefixed with a `.`.
+    Label { exploded: bool },
+
+    /// `name=value` pairs prefixed with a `;`.
+    Matrix { exploded: bool },
+}
+
+impl Default for PathStyle {
+    fn default() -> Self {
+        Self::Simple { exploded: false }
+    }
+}
+
+/// Characters to percent-encode in path parameter values: everything
+/// except the [unreserved characters][unreserved] from RFC 3986.
+///
+/// [unreserved]: https://datatracker.ietf.org/doc/html/rfc3986#section-2.3
+const VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A [`Serializer`] that formats an OpenAPI-style path parameter
+/// as a string.
+#[derive(Debug)]
+pub struct PathParamSerializer<'a> {
+    name: &'a str,
+    style: PathStyle,
+}
+
+impl<'a> PathParamSerializer<'a> {
+    /// Creates a new serializer for the parameter with the given name.
+    pub fn new(name: &'a str, style: PathStyle) -> Self {
+        Self { name, style }
+    }
+
+    /// Formats a primitive value.
+    fn primitive(&self, value: String) -> String {
+        let encoded = percent_encoding::utf8_percent_encode(&value, VALUE);
+        match self.style {
+            PathStyle::Simple { .. } => encoded.to_string(),
+            PathStyle::Label { .. } => format!(".{encoded}"),
+            // An empty value omits the `=`, per RFC 6570.
+            PathStyle::Matrix { .. } if value.is_empty() => format!(";{}", self.name),
+            PathStyle::Matrix { .. } => format!(";{}={encoded}", self.name),
+        }
+    }
+
+    /// Formats an array of primitive values.
+    fn array(&self, items: Vec<String>) -> String {
+        let items = items
+            .iter()
+            .map(|item| percent_encoding::utf8_percent_encode(item, VALUE))
+            .collect_vec();
+        match self.style {
+            PathStyle::Simple { .. } => items.iter().join(","),
+            PathStyle::Label { exploded: false } => format!(".{}", items.iter().join(",")),
+            PathStyle::Label { exploded: true } => format!(".{}", items.iter().join(".")),
+            PathStyle::Matrix { exploded: false } => {
+                format!(";{}={}", self.name, items.iter().join(","))
+            }
+            PathStyle::Matrix { exploded: true } => items
+                .iter()
+                .map(|item| format!(";{}={item}", self.name))
+                .collect(),
+        }
+    }
+
+    /// Formats an object with primitive values.
+    fn object(&self, pairs: Vec<(String, String)>) -> String {
+        let pairs = pairs
+            .iter()
+            .map(|(key, value)| {
+                (
+                    percent_encoding::utf8_percent_encode(key, VALUE),
+                    percent_encoding::utf8_percent_encode(value, VALUE),
+                )
+            })
+            .collect_vec();
+        let alternating = || pairs.iter().flat_map(|(key, value)| [key, value]).join(",");
+        let exploded = |delimiter| {
+            pairs
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .join(delimiter)
+        };
+        match self.style {
+            PathStyle::Simple { exploded: false } => alternating(),
+            PathStyle::Simple { exploded: true } => exploded(","),
+            PathStyle::Label { exploded: false } => format!(".{}", alternating()),
+            PathStyle::Label { exploded: true } => format!(".{}", exploded(".")),
+            PathStyle::Matrix { exploded: false } => format!(";{}={}", self.name, alternating()),
+            PathStyle::Matrix { exploded: true } => format!(";{}", exploded(";")),
+        }
+    }
+}
+
+impl<'a> Serializer for PathParamSerializer<'a> {
+    type Ok = String;
+    type Error = PathParamError;
+
+    type SerializeSeq = PathSeqSerializer<'a>;
+    type SerializeTuple = PathSeqSerializer<'a>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = PathStructSerializer<'a>;
+    type SerializeStruct = PathStructSerializer<'a>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedTypeError::Bytes)?
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(String::new()))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedTypeError::Unit)?
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedTypeError::UnitStruct(name))?
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(self.primitive(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedTypeError::NewtypeVariant(name, variant))?
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(PathSeqSerializer {
+            serializer: self,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(PathSeqSerializer {
+            serializer: self,
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(UnsupportedTypeError::TupleStruct(name))?
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(UnsupportedTypeError::TupleVariant(name, variant))?
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(PathStructSerializer {
+            serializer: self,
+            pairs: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(PathStructSerializer {
+            serializer: self,
+            pairs: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(UnsupportedTypeError::StructVariant(name, variant))?
+    }
+}
+
+/// A serializer for sequences (arrays) and tuples.
+#[derive(Debug)]
+pub struct PathSeqSerializer<'a> {
+    serializer: PathParamSerializer<'a>,
+    items: Vec<String>,
+}
+
+impl SerializeSeq for PathSeqSerializer<'_> {
+    type Ok = String;
+    type Error = PathParamError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.items
+            .push(value.serialize(ValueSerializer::<PathParamError>(PhantomData))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.serializer.array(self.items))
+    }
+}
+
+impl SerializeTuple for PathSeqSerializer<'_> {
+    type Ok = String;
+    type Error = PathParamError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+/// A serializer for structs and maps (objects).
+#[derive(Debug)]
+pub struct PathStructSerializer<'a> {
+    serializer: PathParamSerializer<'a>,
+    pairs: Vec<(String, String)>,
+    key: Option<String>,
+}
+
+impl SerializeStruct for PathStructSerializer<'_> {
+    type Ok = String;
+    type Error = PathParamError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.pairs.push((
+            key.to_owned(),
+            value.serialize(ValueSerializer::<PathParamError>(PhantomData))?,
+        ));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.serializer.object(self.pairs))
+    }
+}
+
+impl SerializeMap for PathStructSerializer<'_> {
+    type Ok = String;
+    type Error = PathParamError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key.serialize(ValueSerializer::<PathParamError>(PhantomData))?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.pairs.push((
+            key,
+            value.serialize(ValueSerializer::<PathParamError>(PhantomData))?,
+        ));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.serializer.object(self.pairs))
+    }
+}
+
+/// An error that occurs during path parameter serialization.
+#[derive(Debug, thiserror::Error)]
+pub enum PathParamError {
+    #[error("can't serialize {0} as path parameter")]
+    UnsupportedType(#[from] UnsupportedTypeError),
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl serde::ser::Error for PathParamError {
+    fn custom<T: std::fmt::Display>(err: T) -> Self {
+        Self::Custom(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Color {
+        #[serde(rename = "R")]
+        r: u8,
+        #[serde(rename = "G")]
+        g: u8,
+        #[serde(rename = "B")]
+        b: u8,
+    }
+
+    const COLOR: Color = Color {
+        r: 100,
+        g: 200,
+        b: 150,
+    };
+
+    const COLORS: [&str; 3] = ["blue", "black", "brown"];
+
+    // MARK: `simple`
+
+    #[test]
+    fn test_simple() {
+        let style = PathStyle::Simple { exploded: false };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "blue,black,brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "R,100,G,200,B,150"
+        );
+    }
+
+    #[test]
+    fn test_simple_exploded() {
+        let style = PathStyle::Simple { exploded: true };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "blue,black,brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "R=100,G=200,B=150"
+        );
+    }
+
+    // MARK: `label`
+
+    #[test]
+    fn test_label() {
+        let style = PathStyle::Label { exploded: false };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".blue,black,brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".R,100,G,200,B,150"
+        );
+    }
+
+    #[test]
+    fn test_label_exploded() {
+        let style = PathStyle::Label { exploded: true };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".blue.black.brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ".R=100.G=200.B=150"
+        );
+    }
+
+    // MARK: `matrix`
+
+    #[test]
+    fn test_matrix() {
+        let style = PathStyle::Matrix { exploded: false };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color=blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color=blue,black,brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color=R,100,G,200,B,150"
+        );
+    }
+
+    #[test]
+    fn test_matrix_exploded() {
+        let style = PathStyle::Matrix { exploded: true };
+        assert_eq!(
+            "blue"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color=blue"
+        );
+        assert_eq!(
+            COLORS
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color=blue;color=black;color=brown"
+        );
+        assert_eq!(
+            COLOR
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";R=100;G=200;B=150"
+        );
+    }
+
+    #[test]
+    fn test_matrix_empty_string() {
+        let style = PathStyle::Matrix { exploded: false };
+        assert_eq!(
+            "".serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            ";color"
+        );
+    }
+
+    // MARK: Encoding
+
+    #[test]
+    fn test_encodes_items_before_joining() {
+        let style = PathStyle::Simple { exploded: false };
+        assert_eq!(
+            ["a,b", "c"]
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "a%2Cb,c"
+        );
+        assert_eq!(
+            "a/b c"
+                .serialize(PathParamSerializer::new("color", style))
+                .unwrap(),
+            "a%2Fb%20c"
+        );
+    }
+
+    #[test]
+    fn test_encodes_keys_and_values_before_joining() {
+        let map = BTreeMap::from([("a=b", "c;d")]);
+        assert_eq!(
+            map.serialize(PathParamSerializer::new(
+                "color",
+                PathStyle::Matrix { exploded: true }
+            ))
+            .unwrap(),
+            ";a%3Db=c%3Bd"
+        );
+    }
+
+    // MARK: Other types
+
+    #[test]
+    fn test_map() {
+        let map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(
+            map.serialize(PathParamSerializer::new(
+                "color",
+                PathStyle::Simple { exploded: true }
+            ))
+            .unwrap(),
+            "a=1,b=2"
+        );
+    }
+
+    #[test]
+    fn test_unit_variant() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Kind {
+            Dog,
+            Cat,
+        }
+
+        assert_eq!(
+            [Kind::Dog, Kind::Cat]
+                .serialize(PathParamSerializer::new("color", PathStyle::default()))
+                .unwrap(),
+            "dog,cat"
+        );
+    }
+
+    // MARK: Errors
+
+    #[test]
+    fn test_nested_array_fails() {
+        let result = [["a"], ["b"]].serialize(PathParamSerializer::new("x", PathStyle::default()));
+        assert!(matches!(
+            result,
+            Err(PathParamError::UnsupportedType(
+                UnsupportedTypeError::Nested
+            ))
+        ));
+    }
+}
@@ -69,7 +69,7 @@
 //! # }
 //! ```
 
-use std::{borrow::Cow, fmt::Display};
+use std::{borrow::Cow, fmt::Display, marker::PhantomData};
 
 use itertools::Itertools;
 use percent_encoding::{AsciiSet, CONTROLS, PercentEncode};
@@ -810,7 +810,9 @@ impl<'a, 'b> SerializeMap for QueryStructSerializer<'a, 'b> {
     type Error = QueryParamError;
 
     fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
-        self.serializer.path.push(key.serialize(KeyExtractor)?);
+        self.serializer
+            .path
+            .push(key.serialize(ValueSerializer::<QueryParamError>(PhantomData))?);
         Ok(())
     }
 
@@ -833,12 +835,14 @@ impl<'a, 'b> SerializeMap for QueryStructSerializer<'a, 'b> {
     }
 }
 
-/// A helper [`Serializer`] for extracting string keys from maps.
-struct KeyExtractor;
+/// A helper [`Serializer`] for formatting primitive values: the keys of
+/// query parameter objects, and the items, keys, and values of
+/// path parameter arrays and objects.
+pub(crate) struct ValueSerializer<E>(pub(crate) PhantomData<E>);
 
-impl Serializer for KeyExtractor {
+impl<E: serde::ser::Error + From<UnsupportedTypeError>> Serializer for ValueSerializer<E> {
     type Ok = String;
-    type Error = QueryParamError;
+    type Error = E;
 
     type SerializeSeq = Impossible<Self::Ok, Self::Error>;
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
@@ -848,111 +852,111 @@ impl Serializer for KeyExtractor {
     type SerializeStruct = Impossible<Self::Ok, Self::Error>;
     type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
 
-    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        Ok(v.to_owned())
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_bool(self, _: bool) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_i8(self, _: i8) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_i16(self, _: i16) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_i32(self, _: i32) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_i64(self, _: i64) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_u8(self, _: u8) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_u16(self, _: u16) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_u32(self, _: u32) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_u64(self, _: u64) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_f32(self, _: f32) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_f64(self, _: f64) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
     }
 
-    fn serialize_char(self, _: char) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_owned())
     }
 
     fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Bytes)?
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Ok(String::new())
     }
 
-    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Unit)?
     }
 
-    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(UnsupportedTypeError::UnitStruct(name))?
     }
 
     fn serialize_unit_variant(
         self,
-        _: &'static str,
-        _: u32,
-        _: &'static str,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Ok(variant.to_owned())
     }
 
     fn serialize_newtype_struct<T: ?Sized + Serialize>(
         self,
-        _: &'static str,
-        _: &T,
+        _name: &'static str,
+        value: &T,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        value.serialize(self)
     }
 
     fn serialize_newtype_variant<T: ?Sized + Serialize>(
         self,
-        _: &'static str,
-        _: u32,
-        _: &'static str,
-        _: &T,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _value: &T,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::NewtypeVariant(name, variant))?
     }
 
     fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_tuple_struct(
@@ -960,7 +964,7 @@ impl Serializer for KeyExtractor {
         _: &'static str,
         _: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_tuple_variant(
@@ -970,11 +974,11 @@ impl Serializer for KeyExtractor {
         _: &'static str,
         _: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_struct(
@@ -982,7 +986,7 @@ impl Serializer for KeyExtractor {
         _: &'static str,
         _: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 
     fn serialize_struct_variant(
@@ -992,7 +996,7 @@ impl Serializer for KeyExtractor {
         _: &'static str,
         _: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        Err(QueryParamError::ExpectedStringKey)
+        Err(UnsupportedTypeError::Nested)?
     }
 }
 
@@ -1003,8 +1007,6 @@ pub enum QueryParamError {
     UnsupportedType(#[from] UnsupportedTypeError),
     #[error("style-exploded combination not defined by OpenAPI")]
     UnspecifiedStyleExploded,
-    #[error("map keys must serialize as strings")]
-    ExpectedStringKey,
     #[error("query parameters must serialize as a struct")]
     ExpectedStruct,
     #[error("{0}")]
@@ -1033,6 +1035,8 @@ pub enum UnsupportedTypeError {
     TupleVariant(&'static str, &'static str),
     #[error("struct variant `{1}` of `{0}`")]
     StructVariant(&'static str, &'static str),
+    #[error("nested array or object")]
+    Nested,
 }
 
 #[cfg(test)]
@@ -0,0 +1,349 @@
+//! Retrying requests that fail with transient errors.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use http::{StatusCode, header::RETRY_AFTER};
+
+use crate::{
+    error::TransportError,
+    transport::{BlockingHttpClient, HttpClient, Request, Response},
+};
+
+/// How a client retries requests that fail with a transient error,
+/// like a reset connection, or a transient status, like
+/// `429 Too Many Requests` or `503 Service Unavailable`.
+///
+/// Before each retry, the client waits for the duration in the response's
+/// `Retry-After` header, if it has one; otherwise, for the base delay,
+/// doubled after each retry. Either way, the client waits no longer
+/// than the maximum delay. Async clients wait with [`HttpClient::sleep`].
+///
+/// The default policy doesn't retry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    statuses: Vec<StatusCode>,
+}
+
+impl RetryPolicy {
+    /// Returns a policy that sends each request up to `max_attempts` times,
+    /// retrying transport errors and `429` and `503` responses after
+    /// 100 milliseconds, and waiting at most a minute before each retry.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ],
+        }
+    }
+
+    /// Sets how long to wait before the first retry.
+    pub fn with_base_delay(self, base_delay: Duration) -> Self {
+        Self { base_delay, ..self }
+    }
+
+    /// Sets the longest time to wait before a retry. Longer `Retry-After`
+    /// durations and backoff delays are shortened to this delay.
+    pub fn with_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    /// Sets the response statuses to retry.
+    pub fn with_statuses(self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        Self {
+            statuses: statuses.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns how long to wait before retrying a request that
+    /// returned `result` after `attempts` attempts, or `None`
+    /// if the request shouldn't be retried.
+    pub fn delay(
+        &self,
+        attempts: u32,
+        result: &Result<Response, TransportError>,
+    ) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+        let retry_after = match result {
+            Ok(response) if self.statuses.contains(&response.status()) => {
+                // Parse the `Retry-After` header, in seconds
+                // or as an HTTP date, into a duration from now.
+                response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| match value.trim().parse::<u64>() {
+                        Ok(secs) => Some(Duration::from_secs(secs)),
+                        Err(_) => {
+                            let date = DateTime::parse_from_rfc2822(value).ok()?;
+                            // A date in the past means "retry now".
+                            Some(
+                                (date.with_timezone(&Utc) - Utc::now())
+                                    .to_std()
+                                    .unwrap_or_default(),
+                            )
+                        }
+                    })
+            }
+            Ok(_) => return None,
+            // Transport errors, like reset connections, are transient.
+            Err(_) => None,
+        };
+        let delay = retry_after.unwrap_or_else(|| {
+            let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+            self.base_delay.saturating_mul(factor)
+        });
+        Some(delay.min(self.max_delay))
+    }
+
+    /// Sends a request with an async client, retrying it as needed.
+    pub async fn execute(
+        &self,
+        client: &dyn HttpClient,
+        request: Request,
+    ) -> Result<Response, TransportError> {
+        let mut attempts = 1;
+        loop {
+            let result = client.execute(request.clone()).await;
+            let Some(delay) = self.delay(attempts, &result) else {
+                return result;
+            };
+            if !delay.is_zero() {
+                client.sleep(delay).await;
+            }
+            attempts += 1;
+        }
+    }
+
+    /// Sends a request with a blocking client, retrying it as needed.
+    pub fn execute_blocking(
+        &self,
+        client: &dyn BlockingHttpClient,
+        request: Request,
+    ) -> Result<Response, TransportError> {
+        let mut attempts = 1;
+        loop {
+            let result = client.execute(request.clone());
+            let Some(delay) = self.delay(attempts, &result) else {
+                return result;
+            };
+            std::thread::sleep(delay);
+            attempts += 1;
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use bytes::Bytes;
+    use futures_util::FutureExt;
+    use http::response::Builder;
+
+    use crate::{error::TransportErrorKind, transport::BoxFuture};
+
+    /// A transport that returns `503 Service Unavailable` twice,
+    /// then `200 OK`.
+    #[derive(Debug, Default)]
+    struct Flaky {
+        attempts: AtomicU32,
+    }
+
+    impl HttpClient for Flaky {
+        fn execute(&self, _: Request) -> BoxFuture<'_, Result<Response, TransportError>> {
+            let status = if self.attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::OK
+            };
+            let response = Builder::new().status(status).body(Bytes::new()).unwrap();
+            Box::pin(std::future::ready(Ok(response)))
+        }
+
+        fn sleep(&self, _: Duration) -> BoxFuture<'_, ()> {
+            Box::pin(std::future::ready(()))
+        }
+    }
+
+    impl BlockingHttpClient for Flaky {
+        fn execute(&self, _: Request) -> Result<Response, TransportError> {
+            let status = if self.attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::OK
+            };
+            Ok(Builder::new().status(status).body(Bytes::new()).unwrap())
+        }
+    }
+
+    /// A transport that fails to connect once, then returns `200 OK`.
+    #[derive(Debug, Default)]
+    struct Reset {
+        attempts: AtomicU32,
+    }
+
+    impl BlockingHttpClient for Reset {
+        fn execute(&self, _: Request) -> Result<Response, TransportError> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 1 {
+                return Err(TransportError::connect(std::io::Error::from(
+                    std::io::ErrorKind::ConnectionReset,
+                )));
+            }
+            Ok(Builder::new()
+                .status(StatusCode::OK)
+                .body(Bytes::new())
+                .unwrap())
+        }
+    }
+
+    #[test]
+    fn test_execute_retries_until_success() {
+        let client = Flaky::default();
+        let policy = RetryPolicy::new(3).with_base_delay(Duration::ZERO);
+        let response = policy
+            .execute(&client, Request::new(Bytes::new()))
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(client.attempts.into_inner(), 3);
+    }
+
+    #[test]
+    fn test_execute_returns_last_response_after_max_attempts() {
+        let client = Flaky::default();
+        let policy = RetryPolicy::new(2).with_base_delay(Duration::ZERO);
+        let response = policy
+            .execute_blocking(&client, Request::new(Bytes::new()))
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(client.attempts.into_inner(), 2);
+    }
+
+    #[test]
+    fn test_execute_retries_transport_errors() {
+        let client = Reset::default();
+        let policy = RetryPolicy::new(2).with_base_delay(Duration::ZERO);
+        let response = policy
+            .execute_blocking(&client, Request::new(Bytes::new()))
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(client.attempts.into_inner(), 2);
+
+        // Without retries, the error is returned.
+        let client = Reset::default();
+        let result = RetryPolicy::default().execute_blocking(&client, Request::new(Bytes::new()));
+        assert!(matches!(
+            result,
+            Err(err) if err.kind() == TransportErrorKind::Connect,
+        ));
+    }
+
+    #[test]
+    fn test_default_policy_doesnt_retry() {
+        let client = Flaky::default();
+        let response = RetryPolicy::default()
+            .execute_blocking(&client, Request::new(Bytes::new()))
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(client.attempts.into_inner(), 1);
+    }
+
+    #[test]
+    fn test_delay_doubles_base_delay() {
+        let policy = RetryPolicy::new(4);
+        let response = Builder::new()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(
+            policy.delay(1, &Ok(response.clone())),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay(3, &Ok(response.clone())),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.delay(4, &Ok(response.clone())), None);
+    }
+
+    #[test]
+    fn test_delay_honors_retry_after() {
+        let policy = RetryPolicy::new(3);
+        let response = Builder::new()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header(RETRY_AFTER, "7")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(
+            policy.delay(1, &Ok(response.clone())),
+            Some(Duration::from_secs(7))
+        );
+
+        // Dates in the past retry immediately.
+        let response = Builder::new()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header(RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(policy.delay(1, &Ok(response.clone())), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_delay_clamps_to_max_delay() {
+        let policy = RetryPolicy::new(40).with_max_delay(Duration::from_secs(5));
+        let response = Builder::new()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header(RETRY_AFTER, "999999999")
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(
+            policy.delay(1, &Ok(response.clone())),
+            Some(Duration::from_secs(5))
+        );
+
+        let response = Builder::new()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(
+            policy.delay(30, &Ok(response.clone())),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_delay_skips_other_statuses() {
+        let policy = RetryPolicy::new(3).with_statuses([StatusCode::BAD_GATEWAY]);
+        let response = Builder::new()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Bytes::new())
+            .unwrap();
+        assert_eq!(policy.delay(1, &Ok(response.clone())), None);
+
+        let response = Builder::new()
+            .status(StatusCode::BAD_GATEWAY)
+            .body(Bytes::new())
+            .unwrap();
+        assert!(policy.delay(1, &Ok(response.clone())).is_some());
+    }
+}
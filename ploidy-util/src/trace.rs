@@ -3,10 +3,11 @@
 use http::HeaderMap;
 use opentelemetry::global::get_text_map_propagator;
 use opentelemetry_http::HeaderInjector;
-use reqwest::RequestBuilder;
 use tracing::Span;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
+use crate::transport::RequestBuilder;
+
 /// Adds trace context request headers, if there is one and
 /// a global [`TextMapPropagator`] is [set].
 ///
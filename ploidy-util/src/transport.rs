@@ -0,0 +1,381 @@
+//! Pluggable HTTP clients for generated clients.
+//!
+//! Generated clients build a [`Request`] for each operation, and send it
+//! with an [`HttpClient`], or a [`BlockingHttpClient`] for blocking clients.
+//! The `reqwest` feature implements [`HttpClient`] for
+//! [`reqwest::Client`][reqwest-client], and the `blocking` feature
+//! implements [`BlockingHttpClient`] for
+//! [`reqwest::blocking::Client`][reqwest-blocking-client].
+//!
+//! To use a different HTTP client crate, implement these traits for
+//! a type that wraps it.
+//!
+//! [reqwest-client]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+//! [reqwest-blocking-client]: https://docs.rs/reqwest/latest/reqwest/blocking/struct.Client.html
+
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+
+use bytes::Bytes;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, Method, Uri,
+    header::{CONTENT_TYPE, Entry, OccupiedEntry},
+};
+use serde::Serialize;
+use url::Url;
+
+use crate::{
+    error::{Error, TransportError},
+    multipart::Form,
+};
+
+/// An HTTP request with a complete body.
+pub type Request = http::Request<Bytes>;
+
+/// An HTTP response with a complete body.
+pub type Response = http::Response<Bytes>;
+
+/// A boxed future that [`HttpClient::execute`] returns.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sends requests for an async client.
+///
+/// Implementations should apply the [`RequestTimeout`] in the request's
+/// extensions, if there is one, and read the full response body.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Debug)]
+/// struct MyHttpClient(/* ... */);
+///
+/// impl HttpClient for MyHttpClient {
+///     fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, TransportError>> {
+///         Box::pin(async move {
+///             // ...
+///         })
+///     }
+///
+///     fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+///         Box::pin(async move {
+///             // ...
+///         })
+///     }
+/// }
+///
+/// let client = Client::with_http_client(MyHttpClient(/* ... */), base_url);
+/// ```
+pub trait HttpClient: Debug + Send + Sync {
+    /// Sends a request, and returns the response.
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, TransportError>>;
+
+    /// Waits for `duration` on the client's runtime.
+    /// [`RetryPolicy`][crate::retry::RetryPolicy] waits with this
+    /// before each retry.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()>;
+}
+
+/// Sends requests for a blocking client.
+///
+/// Like [`HttpClient`], implementations should apply the
+/// [`RequestTimeout`], and read the full response body.
+pub trait BlockingHttpClient: Debug + Send + Sync {
+    /// Sends a request, and returns the response.
+    fn execute(&self, request: Request) -> Result<Response, TransportError>;
+}
+
+/// The timeout for a [`Request`], stored in its extensions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RequestTimeout(pub Duration);
+
+/// A builder for a [`Request`].
+///
+/// Like Reqwest's `RequestBuilder`, errors are deferred until
+/// the request is [built][Self::build].
+#[derive(Debug)]
+pub struct RequestBuilder {
+    request: Result<Request, Error>,
+}
+
+impl RequestBuilder {
+    /// Creates a builder for a request with an empty body.
+    pub fn new(method: Method, url: Url) -> Self {
+        let request = Uri::try_from(url.as_str())
+            .map(|uri| {
+                let mut request = Request::new(Bytes::new());
+                *request.method_mut() = method;
+                *request.uri_mut() = uri;
+                request
+            })
+            .map_err(|err| Error::from(http::Error::from(err)));
+        Self { request }
+    }
+
+    /// Adds a header, keeping any existing values for the same name.
+    pub fn header<K, V>(self, name: K, value: V) -> Self
+    where
+        K: TryInto<HeaderName>,
+        V: TryInto<HeaderValue>,
+        K::Error: Into<http::Error>,
+        V::Error: Into<http::Error>,
+    {
+        self.and_then(|mut request| {
+            let name = name.try_into().map_err(Error::bad_header_name)?;
+            let value = value
+                .try_into()
+                .map_err(|err| Error::bad_header_value(name.clone(), err))?;
+            request.headers_mut().append(name, value);
+            Ok(request)
+        })
+    }
+
+    /// Adds headers, replacing any existing values for the same names.
+    pub fn headers(self, headers: HeaderMap) -> Self {
+        self.and_then(|mut request| {
+            let dst = request.headers_mut();
+            // `HeaderMap::into_iter` yields the name only for the first value
+            // of each header; subsequent values have no name.
+            let mut prev: Option<OccupiedEntry<'_, HeaderValue>> = None;
+            for (name, value) in headers {
+                match name {
+                    Some(name) => match dst.entry(name) {
+                        Entry::Occupied(mut entry) => {
+                            entry.insert(value);
+                            prev = Some(entry);
+                        }
+                        Entry::Vacant(entry) => {
+                            prev = Some(entry.insert_entry(value));
+                        }
+                    },
+                    None => {
+                        if let Some(entry) = &mut prev {
+                            entry.append(value);
+                        }
+                    }
+                }
+            }
+            Ok(request)
+        })
+    }
+
+    /// Sets a JSON request body.
+    pub fn json<T: Serialize + ?Sized>(self, body: &T) -> Self {
+        self.and_then(|mut request| {
+            let body = serde_json::to_vec(body).map_err(Error::bad_request_body)?;
+            if !request.headers().contains_key(CONTENT_TYPE) {
+                request
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            }
+            *request.body_mut() = body.into();
+            Ok(request)
+        })
+    }
+
+    /// Sets a `text/plain` request body.
+    pub fn text(self, body: impl Into<String>) -> Self {
+        self.body("text/plain; charset=utf-8", body.into())
+    }
+
+    /// Sets an `application/octet-stream` request body.
+    pub fn bytes(self, body: impl Into<Bytes>) -> Self {
+        self.body("application/octet-stream", body)
+    }
+
+    /// Sets a `multipart/form-data` request body.
+    pub fn multipart(self, form: Form) -> Self {
+        self.and_then(|mut request| {
+            let content_type = HeaderValue::try_from(form.content_type())
+                .map_err(|err| Error::bad_header_value(CONTENT_TYPE, err))?;
+            request.headers_mut().insert(CONTENT_TYPE, content_type);
+            *request.body_mut() = form.into_bytes();
+            Ok(request)
+        })
+    }
+
+    /// Sets the timeout for this request.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.and_then(|mut request| {
+            request.extensions_mut().insert(RequestTimeout(timeout));
+            Ok(request)
+        })
+    }
+
+    /// Builds the request.
+    pub fn build(self) -> Result<Request, Error> {
+        self.request
+    }
+
+    /// Sets a request body, and a `Content-Type` header
+    /// if the request doesn't have one.
+    fn body(self, content_type: &'static str, body: impl Into<Bytes>) -> Self {
+        self.and_then(|mut request| {
+            if !request.headers().contains_key(CONTENT_TYPE) {
+                request
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+            }
+            *request.body_mut() = body.into();
+            Ok(request)
+        })
+    }
+
+    fn and_then(self, f: impl FnOnce(Request) -> Result<Request, Error>) -> Self {
+        Self {
+            request: self.request.and_then(f),
+        }
+    }
+}
+
+/// Returns an error if the response has a client or server error status.
+pub fn error_for_status(response: Response) -> Result<Response, Error> {
+    let status = response.status();
+    if status.is_client_error() || status.is_server_error() {
+        Err(Error::Status(status))
+    } else {
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpClient for reqwest::Client {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response, TransportError>> {
+        Box::pin(async move {
+            let timeout = request.extensions().get::<RequestTimeout>().copied();
+            let mut request = reqwest::Request::try_from(request)?;
+            if let Some(RequestTimeout(timeout)) = timeout {
+                *request.timeout_mut() = Some(timeout);
+            }
+            let response = reqwest::Client::execute(self, request).await?;
+            let mut parts = Response::new(Bytes::new());
+            *parts.status_mut() = response.status();
+            *parts.version_mut() = response.version();
+            *parts.headers_mut() = response.headers().clone();
+            *parts.body_mut() = response.bytes().await?;
+            Ok(parts)
+        })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingHttpClient for reqwest::blocking::Client {
+    fn execute(&self, request: Request) -> Result<Response, TransportError> {
+        let timeout = request.extensions().get::<RequestTimeout>().copied();
+        let mut request = reqwest::blocking::Request::try_from(request)?;
+        if let Some(RequestTimeout(timeout)) = timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+        let response = reqwest::blocking::Client::execute(self, request)?;
+        let mut parts = Response::new(Bytes::new());
+        *parts.status_mut() = response.status();
+        *parts.version_mut() = response.version();
+        *parts.headers_mut() = response.headers().clone();
+        *parts.body_mut() = response.bytes()?;
+        Ok(parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use http::{StatusCode, header::COOKIE};
+
+    use crate::error::{BuildError, ErrorCategory};
+
+    #[test]
+    fn test_request_builder_builds_request() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let request = RequestBuilder::new(
+            Method::POST,
+            "https://api.example.com/v1/pets?limit=1".parse().unwrap(),
+        )
+        .headers(headers)
+        .header(COOKIE, "a=1")
+        .json(&serde_json::json!({"name": "Mittens"}))
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(
+            request.uri().to_string(),
+            "https://api.example.com/v1/pets?limit=1"
+        );
+        assert_eq!(request.headers()["x-api-key"], "secret");
+        assert_eq!(request.headers()[COOKIE], "a=1");
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+        assert_eq!(request.body().as_ref(), br#"{"name":"Mittens"}"#);
+        assert_eq!(
+            request.extensions().get::<RequestTimeout>(),
+            Some(&RequestTimeout(Duration::from_secs(5)))
+        );
+    }
+
+    #[test]
+    fn test_request_builder_headers_replace_existing_values() {
+        let mut headers = HeaderMap::new();
+        headers.append("accept", HeaderValue::from_static("text/plain"));
+        headers.append("accept", HeaderValue::from_static("text/html"));
+
+        let request = RequestBuilder::new(Method::GET, "https://example.com".parse().unwrap())
+            .header("accept", "application/json")
+            .header("x-trace", "1")
+            .headers(headers)
+            .build()
+            .unwrap();
+
+        let accept = request
+            .headers()
+            .get_all("accept")
+            .iter()
+            .map(|value| value.to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(accept, ["text/plain", "text/html"]);
+        assert_eq!(request.headers()["x-trace"], "1");
+    }
+
+    #[test]
+    fn test_request_builder_sets_text_and_bytes_bodies() {
+        let request = RequestBuilder::new(Method::PUT, "https://example.com".parse().unwrap())
+            .text("Hello")
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()[CONTENT_TYPE], "text/plain; charset=utf-8");
+        assert_eq!(request.body().as_ref(), b"Hello");
+
+        let request = RequestBuilder::new(Method::PUT, "https://example.com".parse().unwrap())
+            .header(CONTENT_TYPE, "image/png")
+            .bytes(b"\x89PNG".as_slice())
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()[CONTENT_TYPE], "image/png");
+        assert_eq!(request.body().as_ref(), b"\x89PNG");
+    }
+
+    #[test]
+    fn test_request_builder_defers_header_errors() {
+        let err = RequestBuilder::new(Method::GET, "https://example.com".parse().unwrap())
+            .header("x-id", "bad\nvalue")
+            .json(&1)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::Build(BuildError::HeaderValue(..))));
+    }
+
+    #[test]
+    fn test_error_for_status() {
+        let response = Response::new(Bytes::new());
+        assert!(error_for_status(response).is_ok());
+
+        let mut response = Response::new(Bytes::new());
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        let err = error_for_status(response).unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Status(StatusCode::NOT_FOUND));
+    }
+}
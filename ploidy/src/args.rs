@@ -6,6 +6,27 @@ pub enum RawMain {
     /// Generate code from an OpenAPI spec.
     #[command(subcommand)]
     Generate(RawGenerate),
+
+    /// Check an OpenAPI spec for problems, without generating code.
+    Validate(RawValidateArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct RawValidateArgs {
+    /// The paths to one or more OpenAPI specs (`.yaml` or `.json`).
+    /// Multiple specs are merged, like for `generate`.
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Fail if the spec uses any constructs that can't be
+    /// represented faithfully, instead of warning about them.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Read options that affect the spec, like `format-overrides`,
+    /// from `[package.metadata.ploidy]` in this `Cargo.toml`.
+    #[arg(long, value_name = "PATH")]
+    pub manifest_path: Option<PathBuf>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -16,11 +37,13 @@ pub enum RawGenerate {
 
 #[derive(Debug, clap::Args)]
 pub struct RawGenerateArgs<T: clap::Args> {
-    /// The path to the OpenAPI spec (`.yaml` or `.json`).
-    pub input: PathBuf,
+    /// The paths to one or more OpenAPI specs (`.yaml` or `.json`).
+    /// Multiple specs are merged into a single crate.
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
 
     /// The output directory. Defaults to a subdirectory
-    /// named after the spec file.
+    /// named after the first spec file.
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
@@ -28,6 +51,20 @@ pub struct RawGenerateArgs<T: clap::Args> {
     #[arg(long)]
     pub stats: bool,
 
+    /// Fail if the spec uses any constructs that can't be
+    /// represented faithfully, instead of warning about them.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Watch the specs, and regenerate whenever they change.
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Skip generation if the specs and options haven't changed
+    /// since the last run, using this directory to remember runs.
+    #[arg(long, value_name = "DIR")]
+    pub cache_dir: Option<PathBuf>,
+
     #[command(flatten)]
     pub language: T,
 }
@@ -48,6 +85,20 @@ pub struct RawGenerateRustArgs {
     /// Verify the generated crate compiles.
     #[arg(short, long)]
     pub check: bool,
+
+    /// Generate only the types, without the client or resource features.
+    #[arg(long, conflicts_with = "client_only")]
+    pub types_only: bool,
+
+    /// Generate only the client, using types from the crate
+    /// named by `types-crate` in `[package.metadata.ploidy]`.
+    #[arg(long)]
+    pub client_only: bool,
+
+    /// Generate a `wiremock` helper for each operation,
+    /// behind the crate's `mocks` feature.
+    #[arg(long, conflicts_with = "types_only")]
+    pub emit_mocks: bool,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use miette::{Context, IntoDiagnostic, Result};
+use ploidy_core::codegen::WrittenFile;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::{Xxh3, xxh3_128};
+
+/// Remembers the last run that generated code into an output directory,
+/// so that a run with the same inputs can skip generating it again.
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+}
+
+impl Cache {
+    /// Returns the cache for `output`, stored in `dir`.
+    pub fn new(dir: &Path, output: &Path) -> Result<Self> {
+        // Name the entry after the output directory,
+        // so that one cache can serve many crates.
+        let output = std::path::absolute(output).into_diagnostic()?;
+        let name = format!(
+            "{:032x}.json",
+            xxh3_128(output.as_os_str().as_encoded_bytes())
+        );
+        Ok(Self {
+            path: dir.join(name),
+        })
+    }
+
+    /// Returns `true` if the last run had the same `key`,
+    /// and all the files that it wrote still exist.
+    pub fn is_fresh(&self, key: &CacheKey, output: &Path) -> bool {
+        let Ok(contents) = std::fs::read(&self.path) else {
+            return false;
+        };
+        let Ok(entry) = serde_json::from_slice::<CacheEntry>(&contents) else {
+            return false;
+        };
+        entry.key == key.0 && entry.files.iter().all(|file| output.join(file).is_file())
+    }
+
+    /// Records a run with `key` that wrote `files`.
+    pub fn store(&self, key: &CacheKey, files: &[WrittenFile]) -> Result<()> {
+        let entry = CacheEntry {
+            key: key.0.clone(),
+            files: files.iter().map(|file| file.path.clone()).collect(),
+        };
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .into_diagnostic()
+                .with_context(|| format!("Failed to create cache directory `{}`", dir.display()))?;
+        }
+        let contents = serde_json::to_vec(&entry).into_diagnostic()?;
+        std::fs::write(&self.path, contents)
+            .into_diagnostic()
+            .with_context(|| format!("Failed to write cache entry `{}`", self.path.display()))
+    }
+}
+
+/// A hash of everything that affects the generated code.
+#[derive(Debug, Eq, PartialEq)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// Hashes the Ploidy version, followed by each of the `parts`.
+    pub fn new<'a>(parts: impl IntoIterator<Item = &'a [u8]>) -> Self {
+        let mut hasher = Xxh3::new();
+        for part in std::iter::once(env!("CARGO_PKG_VERSION").as_bytes()).chain(parts) {
+            // Prefix each part with its length, so that moving bytes
+            // from one part to the next changes the hash.
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+        Self(format!("{:032x}", hasher.digest128()))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CacheEntry {
+    key: String,
+    files: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key_separates_parts() {
+        assert_ne!(
+            CacheKey::new([b"ab".as_slice(), b"c"]),
+            CacheKey::new([b"a".as_slice(), b"bc"]),
+        );
+        assert_eq!(
+            CacheKey::new([b"ab".as_slice(), b"c"]),
+            CacheKey::new([b"ab".as_slice(), b"c"]),
+        );
+    }
+
+    #[test]
+    fn test_cache_is_fresh_after_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        std::fs::write(output.path().join("Cargo.toml"), "").unwrap();
+        let files = [WrittenFile {
+            path: "Cargo.toml".to_owned(),
+            size: 0,
+        }];
+
+        let cache = Cache::new(dir.path(), output.path()).unwrap();
+        let key = CacheKey::new([b"openapi: 3.0.0".as_slice()]);
+        assert!(!cache.is_fresh(&key, output.path()));
+
+        cache.store(&key, &files).unwrap();
+        assert!(cache.is_fresh(&key, output.path()));
+
+        // A changed spec invalidates the entry.
+        let changed = CacheKey::new([b"openapi: 3.1.0".as_slice()]);
+        assert!(!cache.is_fresh(&changed, output.path()));
+    }
+
+    #[test]
+    fn test_cache_is_stale_without_written_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        let files = [WrittenFile {
+            path: "src/lib.rs".to_owned(),
+            size: 0,
+        }];
+
+        let cache = Cache::new(dir.path(), output.path()).unwrap();
+        let key = CacheKey::new([b"openapi: 3.0.0".as_slice()]);
+        cache.store(&key, &files).unwrap();
+        assert!(!cache.is_fresh(&key, output.path()));
+    }
+}
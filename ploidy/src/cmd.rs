@@ -1,4 +1,6 @@
 use std::{
+    ffi::OsStr,
+    fmt::{self, Display},
     io::ErrorKind as IoErrorKind,
     path::{Path, PathBuf},
 };
@@ -7,7 +9,9 @@ use clap::{
     CommandFactory, FromArgMatches,
     error::{Error as ClapError, ErrorKind as ClapErrorKind, Result as ClapResult},
 };
-use ploidy_codegen_rust::{CargoManifest, CargoManifestDiff, CargoManifestError};
+use itertools::Itertools;
+use miette::{Context, IntoDiagnostic, Result};
+use ploidy_codegen_rust::{CargoManifest, CargoManifestDiff, CargoManifestError, CodegenScope};
 use semver::Version;
 
 use super::args::{RawGenerate, RawGenerateRustArgs, RawMain, VersionBump};
@@ -16,7 +20,8 @@ const DEFAULT_VERSION: Version = Version::new(0, 1, 0);
 
 #[derive(Debug)]
 pub enum Main {
-    Generate(Generate),
+    Generate(Box<Generate>),
+    Validate(ValidateArgs),
 }
 
 impl Main {
@@ -30,8 +35,13 @@ impl Main {
         Ok(match main {
             RawMain::Generate(args) => {
                 let args = Generate::try_new(args).map_err(|err| err.format(&mut cmd))?;
-                Self::Generate(args)
+                Self::Generate(args.into())
             }
+            RawMain::Validate(args) => Self::Validate(ValidateArgs {
+                inputs: Input::parse_all(args.inputs).map_err(|err| err.format(&mut cmd))?,
+                strict: args.strict,
+                manifest_path: args.manifest_path,
+            }),
         })
     }
 }
@@ -45,28 +55,44 @@ impl Generate {
     pub fn try_new(args: RawGenerate) -> ClapResult<Self> {
         match args {
             RawGenerate::Rust(args) => {
-                let input = args.input;
+                let inputs = Input::parse_all(args.inputs)?;
+                let Some(input) = inputs.first() else {
+                    return Err(ClapError::raw(
+                        ClapErrorKind::MissingRequiredArgument,
+                        "at least one input spec is required",
+                    ));
+                };
                 let output = match args.output {
                     Some(output) => output,
                     None => input
-                        .file_stem()
+                        .file_name()
+                        .and_then(|name| Path::new(name).file_stem())
                         .ok_or_else(|| {
                             ClapError::raw(
                                 ClapErrorKind::ValueValidation,
                                 format!(
                                     "couldn't infer output directory from `{}`; \
                                         please specify one with `--output`",
-                                    input.display()
+                                    input
                                 ),
                             )
                         })?
                         .into(),
                 };
+                if args.watch && inputs.iter().any(|input| input.path().is_none()) {
+                    return Err(ClapError::raw(
+                        ClapErrorKind::ArgumentConflict,
+                        "`--watch` can only watch files, not standard input or URLs",
+                    ));
+                }
                 let language = GenerateRustArgs::try_new(&output, args.language)?;
                 Ok(Self::Rust(GenerateArgs {
-                    input,
+                    inputs,
                     output,
                     stats: args.stats,
+                    strict: args.strict,
+                    watch: args.watch,
+                    cache_dir: args.cache_dir,
                     language,
                 }))
             }
@@ -76,20 +102,137 @@ impl Generate {
 
 #[derive(Debug)]
 pub struct GenerateArgs<T> {
-    pub input: PathBuf,
+    pub inputs: Vec<Input>,
     pub output: PathBuf,
     pub stats: bool,
+    pub strict: bool,
+    pub watch: bool,
+    pub cache_dir: Option<PathBuf>,
     pub language: T,
 }
 
+/// Where to read an OpenAPI spec from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    /// A file.
+    Path(PathBuf),
+    /// Standard input, written as `-`.
+    Stdin,
+    /// An `http` or `https` URL.
+    Url(String),
+}
+
+impl Input {
+    /// Classifies each input argument, allowing at most one `-`.
+    pub fn parse_all(args: Vec<PathBuf>) -> ClapResult<Vec<Self>> {
+        let inputs = args.into_iter().map(Self::new).collect_vec();
+        if inputs.iter().filter(|&input| *input == Self::Stdin).count() > 1 {
+            return Err(ClapError::raw(
+                ClapErrorKind::ValueValidation,
+                "standard input (`-`) can only be read once",
+            ));
+        }
+        Ok(inputs)
+    }
+
+    fn new(arg: PathBuf) -> Self {
+        if arg.as_os_str() == "-" {
+            return Self::Stdin;
+        }
+        match arg.to_str() {
+            Some(url) if url.starts_with("http://") || url.starts_with("https://") => {
+                Self::Url(url.to_owned())
+            }
+            _ => Self::Path(arg),
+        }
+    }
+
+    /// Reads the spec.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Path(path) => std::fs::read_to_string(path).into_diagnostic(),
+            Self::Stdin => std::io::read_to_string(std::io::stdin()).into_diagnostic(),
+            #[cfg(feature = "remote")]
+            Self::Url(url) => reqwest::blocking::get(url)
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.text())
+                .into_diagnostic(),
+            #[cfg(not(feature = "remote"))]
+            Self::Url(_) => Err(miette::miette!(
+                "Reading specs from URLs requires the `remote` feature"
+            )),
+        }
+        .with_context(|| format!("Failed to read `{self}`"))
+    }
+
+    /// Returns the path of a file input.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Stdin | Self::Url(_) => None,
+        }
+    }
+
+    /// Returns the last component of a file or URL path, if it has one.
+    pub fn file_name(&self) -> Option<&OsStr> {
+        match self {
+            Self::Path(path) => path.file_name(),
+            Self::Stdin => None,
+            Self::Url(url) => {
+                let (_, rest) = url.split_once("://")?;
+                let rest = rest.split(['?', '#']).next()?;
+                let (_, path) = rest.split_once('/')?;
+                let name = path.rsplit('/').next()?;
+                (!name.is_empty()).then(|| OsStr::new(name))
+            }
+        }
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => f.write_str("<stdin>"),
+            Self::Url(url) => f.write_str(url),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ValidateArgs {
+    pub inputs: Vec<Input>,
+    pub strict: bool,
+    pub manifest_path: Option<PathBuf>,
+}
+
 #[derive(Debug)]
 pub struct GenerateRustArgs {
     pub manifest: CargoManifest,
     pub check: bool,
+    pub scope: CodegenScope,
+    pub emit_mocks: bool,
 }
 
 impl GenerateRustArgs {
     pub fn try_new(output: &Path, args: RawGenerateRustArgs) -> ClapResult<Self> {
+        let scope = match (args.types_only, args.client_only) {
+            (false, false) => CodegenScope::All,
+            (true, false) => CodegenScope::Types,
+            (false, true) => CodegenScope::Client,
+            (true, true) => {
+                return Err(ClapError::raw(
+                    ClapErrorKind::ArgumentConflict,
+                    "`--types-only` and `--client-only` can't be used together",
+                ));
+            }
+        };
+        if args.emit_mocks && scope == CodegenScope::Types {
+            return Err(ClapError::raw(
+                ClapErrorKind::ArgumentConflict,
+                "`--emit-mocks` and `--types-only` can't be used together",
+            ));
+        }
         let path = output.join("Cargo.toml");
         match CargoManifest::from_disk(&path) {
             Ok(manifest) => {
@@ -128,6 +271,8 @@ impl GenerateRustArgs {
                 Ok(Self {
                     manifest,
                     check: args.check,
+                    scope,
+                    emit_mocks: args.emit_mocks,
                 })
             }
             Err(CargoManifestError::Io(err)) if err.kind() == IoErrorKind::NotFound => {
@@ -154,6 +299,8 @@ impl GenerateRustArgs {
                 Ok(Self {
                     manifest,
                     check: args.check,
+                    scope,
+                    emit_mocks: args.emit_mocks,
                 })
             }
             Err(err) => Err(ClapError::raw(
@@ -186,9 +333,30 @@ mod tests {
     #[test]
     fn test_generate_infers_output_from_input_stem() {
         let args = RawGenerate::Rust(RawGenerateArgs {
-            input: PathBuf::from("specs/petstore.yaml"),
+            inputs: vec![PathBuf::from("specs/petstore.yaml")],
             output: None,
             stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        });
+        let Generate::Rust(result) = Generate::try_new(args).unwrap();
+        assert_eq!(result.output, PathBuf::from("petstore"));
+    }
+
+    #[test]
+    fn test_generate_infers_output_from_first_input_stem() {
+        let args = RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![
+                PathBuf::from("specs/petstore.yaml"),
+                PathBuf::from("specs/stores.yaml"),
+            ],
+            output: None,
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
             language: RawGenerateRustArgs::default(),
         });
         let Generate::Rust(result) = Generate::try_new(args).unwrap();
@@ -198,9 +366,12 @@ mod tests {
     #[test]
     fn test_generate_respects_explicit_output() {
         let args = RawGenerate::Rust(RawGenerateArgs {
-            input: PathBuf::from("specs/petstore.yaml"),
+            inputs: vec![PathBuf::from("specs/petstore.yaml")],
             output: Some(PathBuf::from("my-output")),
             stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
             language: RawGenerateRustArgs::default(),
         });
         let Generate::Rust(result) = Generate::try_new(args).unwrap();
@@ -210,12 +381,90 @@ mod tests {
     #[test]
     fn test_generate_fails_without_file_stem() {
         let args = RawGenerate::Rust(RawGenerateArgs {
-            input: PathBuf::from("/"),
+            inputs: vec![PathBuf::from("/")],
+            output: None,
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        });
+        let err = Generate::try_new(args).unwrap_err();
+        assert_eq!(err.kind(), ClapErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_generate_infers_output_from_url_path() {
+        let args = RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![PathBuf::from(
+                "https://example.com/specs/petstore.json?version=2#paths",
+            )],
             output: None,
             stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        });
+        let Generate::Rust(result) = Generate::try_new(args).unwrap();
+        assert_eq!(result.output, PathBuf::from("petstore"));
+    }
+
+    #[test]
+    fn test_generate_fails_for_stdin_without_output() {
+        let args = RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![PathBuf::from("-")],
+            output: None,
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        });
+        let err = Generate::try_new(args).unwrap_err();
+        assert_eq!(err.kind(), ClapErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_generate_rejects_watch_with_stdin() {
+        let args = RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![PathBuf::from("-")],
+            output: Some(PathBuf::from("my-output")),
+            stats: false,
+            strict: false,
+            watch: true,
+            cache_dir: None,
             language: RawGenerateRustArgs::default(),
         });
         let err = Generate::try_new(args).unwrap_err();
+        assert_eq!(err.kind(), ClapErrorKind::ArgumentConflict);
+    }
+
+    // MARK: Inputs
+
+    #[test]
+    fn test_input_parse_all() {
+        let inputs = Input::parse_all(vec![
+            PathBuf::from("specs/petstore.yaml"),
+            PathBuf::from("-"),
+            PathBuf::from("http://example.com/petstore.yaml"),
+            PathBuf::from("https://example.com/petstore.yaml"),
+        ])
+        .unwrap();
+        assert_eq!(
+            inputs,
+            [
+                Input::Path(PathBuf::from("specs/petstore.yaml")),
+                Input::Stdin,
+                Input::Url("http://example.com/petstore.yaml".to_owned()),
+                Input::Url("https://example.com/petstore.yaml".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_parse_all_rejects_repeated_stdin() {
+        let err = Input::parse_all(vec![PathBuf::from("-"), PathBuf::from("-")]).unwrap_err();
         assert_eq!(err.kind(), ClapErrorKind::ValueValidation);
     }
 
@@ -347,6 +596,79 @@ mod tests {
         assert!(result.check);
     }
 
+    #[test]
+    fn test_generate_rust_generates_all_by_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            ..Default::default()
+        };
+        let result = GenerateRustArgs::try_new(dir.path(), args).unwrap();
+        assert_eq!(result.scope, CodegenScope::All);
+    }
+
+    #[test]
+    fn test_generate_rust_types_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            types_only: true,
+            ..Default::default()
+        };
+        let result = GenerateRustArgs::try_new(dir.path(), args).unwrap();
+        assert_eq!(result.scope, CodegenScope::Types);
+    }
+
+    #[test]
+    fn test_generate_rust_client_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            client_only: true,
+            ..Default::default()
+        };
+        let result = GenerateRustArgs::try_new(dir.path(), args).unwrap();
+        assert_eq!(result.scope, CodegenScope::Client);
+    }
+
+    #[test]
+    fn test_generate_rust_rejects_types_only_with_client_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            types_only: true,
+            client_only: true,
+            ..Default::default()
+        };
+        let err = GenerateRustArgs::try_new(dir.path(), args).unwrap_err();
+        assert_eq!(err.kind(), ClapErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_generate_rust_emit_mocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            emit_mocks: true,
+            ..Default::default()
+        };
+        let result = GenerateRustArgs::try_new(dir.path(), args).unwrap();
+        assert!(result.emit_mocks);
+    }
+
+    #[test]
+    fn test_generate_rust_rejects_emit_mocks_with_types_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = RawGenerateRustArgs {
+            name: Some("pkg".to_owned()),
+            types_only: true,
+            emit_mocks: true,
+            ..Default::default()
+        };
+        let err = GenerateRustArgs::try_new(dir.path(), args).unwrap_err();
+        assert_eq!(err.kind(), ClapErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn test_bump_version() {
         let base = Version::new(1, 2, 3);
@@ -1,22 +1,27 @@
+use std::path::Path;
+
 use itertools::Itertools;
 use miette::{Context, IntoDiagnostic, Result};
 use ploidy_codegen_rust::{
-    CodegenCargoManifest, CodegenErrorModule, CodegenGraph, CodegenIdentUsage, CodegenLibrary,
-    ResourceGroup,
+    CargoManifest, CodegenCargoManifest, CodegenConfig, CodegenErrorModule, CodegenGraph,
+    CodegenIdentUsage, CodegenLibrary, CodegenMocksModule, CodegenScope, ResourceGroup,
 };
 use ploidy_core::{
     arena::Arena,
     codegen::write_to_disk,
-    ir::{RawGraph, Spec},
+    ir::{CookedGraph, RawGraph, Spec, SpecConfig},
     parse::Document,
 };
 
 mod args;
+mod cache;
 mod cmd;
 mod stats;
+mod watch;
 
 use self::{
-    cmd::{Generate, GenerateArgs, Main},
+    cache::{Cache, CacheKey},
+    cmd::{Generate, GenerateArgs, GenerateRustArgs, Input, Main},
     stats::{GenerateStats, OutputStats, Timings, timed},
 };
 
@@ -27,155 +32,696 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 fn main() -> Result<()> {
     let Ok(main) = Main::parse().map_err(|err| err.exit());
     match main {
-        Main::Generate(Generate::Rust(GenerateArgs {
-            input,
-            output,
-            stats,
-            language,
-        })) => {
-            let mut timings = Timings::default();
-
-            let source = std::fs::read_to_string(&input)
-                .into_diagnostic()
-                .with_context(|| format!("Failed to read `{}`", input.display()))?;
-
-            let doc = {
-                let timing = timed(|| {
-                    Document::from_yaml(&source)
-                        .into_diagnostic()
-                        .context("Failed to parse OpenAPI document")
-                });
-                timings.parse = timing.as_secs_f64();
-                timing.into_inner()
-            }?;
-
-            let label = doc.info.label();
-            if let Some(label) = label {
-                match label.version {
-                    Some(version) => eprintln!("OpenAPI: {} (version {version})", label.title),
-                    None => eprintln!("OpenAPI: {}", label.title),
-                }
+        Main::Generate(generate) => {
+            let Generate::Rust(args) = *generate;
+            if args.watch {
+                // `Generate::try_new()` rejects `--watch` with non-file inputs.
+                let paths = args.inputs.iter().filter_map(Input::path).collect_vec();
+                watch::watch(&paths, || generate_rust(&args))?;
+            } else {
+                generate_rust(&args)?;
             }
-
-            let arena = Arena::new();
-            let spec = {
-                let timing = timed(|| Spec::from_doc(&arena, &doc).into_diagnostic());
-                timings.ir = timing.as_secs_f64();
-                timing.into_inner()
-            }?;
-
-            let raw = {
-                let timing = timed(|| {
-                    let mut raw = RawGraph::new(&arena, &spec);
-                    raw.collapse_trivial_inlines();
-                    raw.inline_tagged_variants();
-                    raw.inline_untagged_variants();
-                    raw
-                });
-                timings.ir += timing.as_secs_f64();
-                timing.into_inner()
+        }
+        Main::Validate(args) => {
+            let config = match &args.manifest_path {
+                Some(path) => CargoManifest::from_disk(path)
+                    .into_diagnostic()
+                    .with_context(|| format!("Failed to read `{}`", path.display()))?
+                    .package()
+                    .map(|p| p.config())
+                    .transpose()?
+                    .flatten()
+                    .unwrap_or_default(),
+                None => CodegenConfig::default(),
             };
-
-            let config = language
-                .manifest
-                .package()
-                .map(|p| p.config())
-                .transpose()?
-                .flatten();
-            let graph = {
-                let timing = timed(|| {
-                    let graph = raw.cook();
-                    match config.as_ref() {
-                        Some(config) => CodegenGraph::with_config(graph, config),
-                        None => CodegenGraph::new(graph),
-                    }
-                });
-                timings.cook = timing.as_secs_f64();
-                timing.into_inner()
+            let sources = args
+                .inputs
+                .iter()
+                .map(|input| Ok((input, input.read()?)))
+                .collect::<Result<Vec<_>>>()?;
+            let config = SpecConfig {
+                strict: args.strict,
+                ..SpecConfig::from(&config)
             };
+            validate(&sources, config, |_, graph, _| {
+                eprintln!(
+                    "No problems found in {} schemas and {} operations",
+                    graph.schemas().count(),
+                    graph.operations().count(),
+                );
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(())
+}
 
-            eprintln!("Writing generated code to `{}`...", output.display());
+fn generate_rust(args: &GenerateArgs<GenerateRustArgs>) -> Result<()> {
+    let GenerateArgs {
+        inputs,
+        output,
+        stats,
+        strict,
+        cache_dir,
+        language,
+        ..
+    } = args;
 
-            let schemas = graph.schemas().count();
-            let counts = graph
-                .operations()
-                .into_grouping_map_by(|op| graph.resource_for(op))
-                .fold(0, |count, _, _| count + 1);
+    let sources = inputs
+        .iter()
+        .map(|input| Ok((input, input.read()?)))
+        .collect::<Result<Vec<_>>>()?;
 
-            let written = {
-                let timing = timed(|| -> Result<_> {
-                    let mut written = Vec::new();
+    let config = CodegenConfig {
+        scope: language.scope,
+        emit_mocks: language.emit_mocks,
+        ..language
+            .manifest
+            .package()
+            .map(|p| p.config())
+            .transpose()?
+            .flatten()
+            .unwrap_or_default()
+    };
+    if config.scope == CodegenScope::Client && config.types_crate.is_none() {
+        miette::bail!("`--client-only` requires `types-crate` in `[package.metadata.ploidy]`");
+    }
+
+    // The key covers everything that affects the generated crate:
+    // the specs, the resolved configuration, the other options,
+    // and the manifest that we'll update.
+    let cache_key = |manifest: &str| {
+        let options = format!("{strict:?} {:?} {config:?}", language.check);
+        CacheKey::new(itertools::chain!(
+            sources.iter().map(|(_, source)| source.as_bytes()),
+            [options.as_bytes(), manifest.as_bytes()],
+        ))
+    };
+    let cache = cache_dir
+        .as_deref()
+        .map(|dir| Cache::new(dir, output))
+        .transpose()?;
+    if let Some(cache) = &cache
+        && cache.is_fresh(&cache_key(&language.manifest.to_string()), output)
+    {
+        // The last run generated the same crate, and, with `--check`,
+        // checked it, so there's nothing to do.
+        eprintln!("`{}` is up to date", output.display());
+        return Ok(());
+    }
+
+    let spec_config = SpecConfig {
+        strict: *strict,
+        ..SpecConfig::from(&config)
+    };
+    let written = validate(&sources, spec_config, |doc, graph, mut timings| {
+        let graph = {
+            let timing = timed(|| CodegenGraph::with_config(graph, &config));
+            timings.cook += timing.as_secs_f64();
+            timing.into_inner()
+        };
+
+        eprintln!("Writing generated code to `{}`...", output.display());
+
+        let schemas = graph.schemas().count();
+        let counts = graph
+            .operations()
+            .into_grouping_map_by(|op| graph.resource_for(op))
+            .fold(0, |count, _, _| count + 1);
 
-                    eprintln!("Generating `Cargo.toml`...");
-                    written.push(write_to_disk(
-                        &output,
-                        CodegenCargoManifest::new(&graph, &language.manifest),
-                    )?);
+        let written = {
+            let timing = timed(|| -> Result<_> {
+                let mut written = Vec::new();
 
-                    eprintln!("Generating `lib.rs`...");
-                    written.push(write_to_disk(&output, CodegenLibrary)?);
+                eprintln!("Generating `Cargo.toml`...");
+                written.push(write_to_disk(
+                    output,
+                    CodegenCargoManifest::new(&graph, &language.manifest),
+                )?);
 
-                    eprintln!("Generating `error.rs`...");
-                    written.push(write_to_disk(&output, CodegenErrorModule)?);
+                eprintln!("Generating `lib.rs`...");
+                written.push(write_to_disk(output, CodegenLibrary::new(&graph))?);
 
+                eprintln!("Generating `error.rs`...");
+                written.push(write_to_disk(output, CodegenErrorModule)?);
+
+                if config.scope != CodegenScope::Client {
                     eprintln!("Generating {schemas} types...");
-                    written.extend(ploidy_codegen_rust::write_types_to_disk(&output, &graph)?);
+                    written.extend(ploidy_codegen_rust::write_types_to_disk(output, &graph)?);
+                }
 
+                if config.scope != CodegenScope::Types {
                     eprintln!(
                         "Generating {} client methods across {} resources...",
                         counts.values().copied().sum::<usize>(),
                         counts.len(),
                     );
-                    written.extend(ploidy_codegen_rust::write_client_to_disk(&output, &graph)?);
-
-                    Ok(written)
-                });
-                timings.codegen = timing.as_secs_f64();
-                timing.into_inner()
-            }?;
-
-            eprintln!("Generation complete");
-
-            if stats {
-                let stats = GenerateStats {
-                    spec: label,
-                    schemas,
-                    operations: counts
-                        .iter()
-                        .map(|(&resource, &count)| {
-                            let key = match resource {
-                                ResourceGroup::Named(name) => {
-                                    CodegenIdentUsage::Module(name).display().to_string()
-                                }
-                                ResourceGroup::Default => "default".to_owned(),
-                            };
-                            (key, count)
-                        })
-                        .collect(),
-                    timings,
-                    output: OutputStats {
-                        files: written.len(),
-                        size: written.iter().map(|file| file.size).sum(),
-                    },
-                };
-                println!("{}", serde_json::to_string(&stats).into_diagnostic()?);
-            }
+                    written.extend(ploidy_codegen_rust::write_client_to_disk(output, &graph)?);
+                }
 
-            if language.check {
-                eprintln!("Running `cargo check`...");
-                let status = std::process::Command::new("cargo")
-                    .arg("check")
-                    .arg("--all-targets")
-                    .current_dir(&output)
-                    .status()
-                    .into_diagnostic()?;
-
-                if !status.success() {
-                    miette::bail!("`cargo check` exited with status {status}");
+                if graph.emit_mocks() {
+                    eprintln!("Generating mocks...");
+                    written.push(write_to_disk(output, CodegenMocksModule::new(&graph))?);
                 }
-            }
+
+                Ok(written)
+            });
+            timings.codegen = timing.as_secs_f64();
+            timing.into_inner()
+        }?;
+
+        eprintln!("Generation complete");
+
+        if *stats {
+            let stats = GenerateStats {
+                spec: doc.info.label(),
+                schemas,
+                operations: counts
+                    .iter()
+                    .map(|(&resource, &count)| {
+                        let key = match resource {
+                            ResourceGroup::Named(name) => {
+                                CodegenIdentUsage::Module(name).display().to_string()
+                            }
+                            ResourceGroup::Default => "default".to_owned(),
+                        };
+                        (key, count)
+                    })
+                    .collect(),
+                timings,
+                output: OutputStats {
+                    files: written.len(),
+                    size: written.iter().map(|file| file.size).sum(),
+                },
+            };
+            println!("{}", serde_json::to_string(&stats).into_diagnostic()?);
         }
+
+        Ok(written)
+    })?;
+
+    if language.check {
+        eprintln!("Running `cargo check`...");
+        let status = std::process::Command::new("cargo")
+            .arg("check")
+            .arg("--all-targets")
+            .current_dir(output)
+            .status()
+            .into_diagnostic()?;
+
+        if !status.success() {
+            miette::bail!("`cargo check` exited with status {status}");
+        }
+    }
+
+    if let Some(cache) = &cache {
+        // Remember the run only once the crate is generated and checked.
+        // The next run starts from the manifest that we just wrote.
+        let manifest = std::fs::read_to_string(output.join("Cargo.toml")).into_diagnostic()?;
+        cache.store(&cache_key(&manifest), &written)?;
     }
 
     Ok(())
 }
+
+/// Parses and merges the specs, and lowers them to a graph, like
+/// `generate` does, reporting every problem in the specs at once.
+/// Then calls `then` with the merged document, the graph,
+/// and the time that each phase took.
+fn validate<T>(
+    sources: &[(&Input, String)],
+    config: SpecConfig,
+    then: impl FnOnce(&Document, CookedGraph<'_>, Timings) -> Result<T>,
+) -> Result<T> {
+    let mut timings = Timings::default();
+
+    let doc = {
+        let timing = timed(|| -> Result<_> {
+            let mut docs = sources.iter().map(|&(input, ref source)| -> Result<_> {
+                // Parse as JSON or YAML, based on the extension, or the
+                // first non-whitespace character if the extension is unknown.
+                let extension = input
+                    .file_name()
+                    .and_then(|name| Path::new(name).extension())
+                    .and_then(|ext| ext.to_str());
+                let is_json = match extension {
+                    Some(ext) if ext.eq_ignore_ascii_case("json") => true,
+                    Some(ext)
+                        if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") =>
+                    {
+                        false
+                    }
+                    _ => source.trim_start().starts_with('{'),
+                };
+                let doc = if is_json {
+                    Document::from_json(source)
+                } else {
+                    Document::from_yaml(source)
+                }
+                .map_err(miette::Report::new)
+                .with_context(|| format!("Failed to parse OpenAPI document `{input}`"))?;
+                Ok((input, doc))
+            });
+            let (_, mut doc) = docs
+                .next()
+                .ok_or_else(|| miette::miette!("No OpenAPI documents to read"))??;
+            // Merge the remaining documents into the first, so that
+            // all their types and operations share one crate.
+            for result in docs {
+                let (input, other) = result?;
+                doc.merge(other)
+                    .into_diagnostic()
+                    .with_context(|| format!("Failed to merge OpenAPI document `{input}`"))?;
+            }
+            Ok(doc)
+        });
+        timings.parse = timing.as_secs_f64();
+        timing.into_inner()
+    }?;
+
+    if let Some(label) = doc.info.label() {
+        match label.version {
+            Some(version) => eprintln!("OpenAPI: {} (version {version})", label.title),
+            None => eprintln!("OpenAPI: {}", label.title),
+        }
+    }
+
+    let arena = Arena::new();
+    let spec = {
+        let timing = timed(|| Spec::from_doc_with_config(&arena, &doc, config).into_diagnostic());
+        timings.ir = timing.as_secs_f64();
+        timing.into_inner()
+    }?;
+    for feature in &spec.unsupported {
+        eprintln!("Warning: {feature}");
+    }
+
+    let raw = {
+        let timing = timed(|| {
+            let mut raw = RawGraph::new(&arena, &spec);
+            raw.collapse_trivial_inlines();
+            raw.inline_tagged_variants();
+            raw.inline_untagged_variants();
+            raw
+        });
+        timings.ir += timing.as_secs_f64();
+        timing.into_inner()
+    };
+
+    let graph = {
+        let timing = timed(|| raw.cook());
+        timings.cook = timing.as_secs_f64();
+        timing.into_inner()
+    };
+
+    then(&doc, graph, timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ploidy_core::error::SerdeError;
+
+    use crate::args::{RawGenerate, RawGenerateArgs, RawGenerateRustArgs};
+
+    #[test]
+    fn test_validate_parses_by_extension() {
+        let json = r#"{"openapi": "3.0.0", "paths": {"#;
+
+        let input = Input::Path("spec.json".into());
+        let err = validate(
+            &[(&input, json.to_owned())],
+            SpecConfig::default(),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SerdeError>(),
+            Some(SerdeError::Json(_))
+        ));
+
+        let input = Input::Path("spec.YAML".into());
+        let err = validate(
+            &[(&input, json.to_owned())],
+            SpecConfig::default(),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SerdeError>(),
+            Some(SerdeError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_sniffs_unknown_extension() {
+        let input = Input::Path("spec.txt".into());
+        let json = "\n  {\"openapi\": \"3.0.0\", \"paths\": {";
+        let err = validate(
+            &[(&input, json.to_owned())],
+            SpecConfig::default(),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SerdeError>(),
+            Some(SerdeError::Json(_))
+        ));
+
+        let input = Input::Path("spec".into());
+        let yaml = "openapi: 3.0.0\npaths: [";
+        let err = validate(
+            &[(&input, yaml.to_owned())],
+            SpecConfig::default(),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SerdeError>(),
+            Some(SerdeError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_reports_all_problems() {
+        let input = Input::Path("spec.yaml".into());
+        let source = indoc::indoc! {"
+            openapi: 3.0.0
+            info:
+              title: Test API
+              version: 1.0
+            paths:
+              /users:
+                get:
+                  operationId: getUsers
+                  responses:
+                    '200':
+                      $ref: '#/components/responses/Missing'
+              /people:
+                get:
+                  operationId: getUsers
+                  responses:
+                    '204':
+                      description: OK
+        "};
+
+        let err = validate(
+            &[(&input, source.to_owned())],
+            SpecConfig::default(),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("`#/components/responses/Missing`"),
+            "{message}"
+        );
+        assert!(message.contains("`#/paths/~1people/get`"), "{message}");
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_spec() {
+        let input = Input::Path("spec.json".into());
+        let source =
+            r#"{"openapi": "3.0.0", "info": {"title": "Test API", "version": "1.0"}, "paths": {}}"#;
+
+        let config = SpecConfig {
+            strict: true,
+            ..Default::default()
+        };
+        let operations = validate(&[(&input, source.to_owned())], config, |_, graph, _| {
+            Ok(graph.operations().count())
+        })
+        .unwrap();
+        assert_eq!(operations, 0);
+    }
+
+    // MARK: Generation scope
+
+    #[test]
+    fn test_generate_types_only_skips_client() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("spec.yaml");
+        std::fs::write(
+            &input,
+            indoc::indoc! {"
+                openapi: 3.0.0
+                info:
+                  title: Test API
+                  version: 1.0
+                paths:
+                  /pets:
+                    get:
+                      operationId: listPets
+                      responses:
+                        '200':
+                          description: OK
+                          content:
+                            application/json:
+                              schema:
+                                $ref: '#/components/schemas/Pet'
+                components:
+                  schemas:
+                    Pet:
+                      type: object
+                      properties:
+                        name:
+                          type: string
+            "},
+        )
+        .unwrap();
+        let output = dir.path().join("pets");
+
+        let Generate::Rust(args) = Generate::try_new(RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![input],
+            output: Some(output.clone()),
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs {
+                types_only: true,
+                ..Default::default()
+            },
+        }))
+        .unwrap();
+        generate_rust(&args).unwrap();
+
+        assert!(output.join("src/lib.rs").exists());
+        assert!(output.join("src/types/pet.rs").exists());
+        assert!(!output.join("src/client").exists());
+    }
+
+    #[test]
+    fn test_generate_client_only_skips_types() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("spec.yaml");
+        std::fs::write(
+            &input,
+            indoc::indoc! {"
+                openapi: 3.0.0
+                info:
+                  title: Test API
+                  version: 1.0
+                paths:
+                  /pets:
+                    get:
+                      operationId: listPets
+                      responses:
+                        '200':
+                          description: OK
+                          content:
+                            application/json:
+                              schema:
+                                $ref: '#/components/schemas/Pet'
+                components:
+                  schemas:
+                    Pet:
+                      type: object
+                      properties:
+                        name:
+                          type: string
+            "},
+        )
+        .unwrap();
+        let output = dir.path().join("pets");
+        std::fs::create_dir(&output).unwrap();
+        std::fs::write(
+            output.join("Cargo.toml"),
+            indoc::indoc! {r#"
+                [package]
+                name = "pets"
+                version = "0.1.0"
+                edition = "2024"
+
+                [package.metadata.ploidy]
+                types-crate = "pets-types"
+            "#},
+        )
+        .unwrap();
+
+        let Generate::Rust(args) = Generate::try_new(RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![input],
+            output: Some(output.clone()),
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs {
+                client_only: true,
+                ..Default::default()
+            },
+        }))
+        .unwrap();
+        generate_rust(&args).unwrap();
+
+        assert!(output.join("src/lib.rs").exists());
+        assert!(output.join("src/client/mod.rs").exists());
+        assert!(!output.join("src/types").exists());
+    }
+
+    #[test]
+    fn test_generate_client_only_rejects_invalid_types_crate() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("spec.yaml");
+        std::fs::write(
+            &input,
+            indoc::indoc! {"
+                openapi: 3.0.0
+                info:
+                  title: Test API
+                  version: 1.0
+                paths: {}
+            "},
+        )
+        .unwrap();
+        let output = dir.path().join("pets");
+        std::fs::create_dir(&output).unwrap();
+        std::fs::write(
+            output.join("Cargo.toml"),
+            indoc::indoc! {r#"
+                [package]
+                name = "pets"
+                version = "0.1.0"
+                edition = "2024"
+
+                [package.metadata.ploidy]
+                types-crate = "my.types"
+            "#},
+        )
+        .unwrap();
+
+        let Generate::Rust(args) = Generate::try_new(RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![input],
+            output: Some(output.clone()),
+            stats: false,
+            strict: false,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs {
+                client_only: true,
+                ..Default::default()
+            },
+        }))
+        .unwrap();
+        assert!(generate_rust(&args).is_err());
+        assert!(!output.join("src").exists());
+    }
+
+    // MARK: Strict mode
+
+    #[test]
+    fn test_generate_strict_accepts_overridden_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("spec.yaml");
+        std::fs::write(
+            &input,
+            indoc::indoc! {"
+                openapi: 3.0.0
+                info:
+                  title: Test API
+                  version: 1.0
+                paths: {}
+                components:
+                  schemas:
+                    Contact:
+                      type: object
+                      properties:
+                        phone:
+                          type: string
+                          format: phone
+            "},
+        )
+        .unwrap();
+        let output = dir.path().join("contacts");
+        std::fs::create_dir(&output).unwrap();
+        std::fs::write(
+            output.join("Cargo.toml"),
+            indoc::indoc! {r#"
+                [package]
+                name = "contacts"
+                version = "0.1.0"
+                edition = "2024"
+
+                [package.metadata.ploidy.format-overrides]
+                phone = "crate::phone::PhoneNumber"
+            "#},
+        )
+        .unwrap();
+
+        let Generate::Rust(args) = Generate::try_new(RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![input],
+            output: Some(output.clone()),
+            stats: false,
+            strict: true,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        }))
+        .unwrap();
+        generate_rust(&args).unwrap();
+
+        assert!(output.join("src/types/contact.rs").exists());
+    }
+
+    #[test]
+    fn test_generate_strict_rejects_unknown_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("spec.yaml");
+        std::fs::write(
+            &input,
+            indoc::indoc! {"
+                openapi: 3.0.0
+                info:
+                  title: Test API
+                  version: 1.0
+                paths: {}
+                components:
+                  schemas:
+                    Contact:
+                      type: object
+                      properties:
+                        phone:
+                          type: string
+                          format: phone
+            "},
+        )
+        .unwrap();
+        let output = dir.path().join("contacts");
+
+        let Generate::Rust(args) = Generate::try_new(RawGenerate::Rust(RawGenerateArgs {
+            inputs: vec![input],
+            output: Some(output.clone()),
+            stats: false,
+            strict: true,
+            watch: false,
+            cache_dir: None,
+            language: RawGenerateRustArgs::default(),
+        }))
+        .unwrap();
+        assert!(generate_rust(&args).is_err());
+        assert!(!output.join("src").exists());
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    path::Path,
+    sync::mpsc::{self, RecvTimeoutError},
+    time::Duration,
+};
+
+use itertools::Itertools;
+use miette::{IntoDiagnostic, Report, Result};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+/// How long to wait for changes to settle before regenerating.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// An event that the watch loop handles.
+enum WatchEvent {
+    Changed(notify::Result<Event>),
+    Stop,
+}
+
+/// Runs `generate`, then runs it again whenever any of the `inputs`
+/// change, until interrupted with Ctrl-C.
+///
+/// Errors from `generate` are reported, but don't stop the watcher.
+pub fn watch(inputs: &[&Path], mut generate: impl FnMut() -> Result<()>) -> Result<()> {
+    // Change events carry absolute paths.
+    let inputs: Vec<_> = inputs
+        .iter()
+        .map(std::path::absolute)
+        .try_collect()
+        .into_diagnostic()?;
+
+    let (tx, rx) = mpsc::channel();
+    ctrlc::set_handler({
+        let tx = tx.clone();
+        move || {
+            let _ = tx.send(WatchEvent::Stop);
+        }
+    })
+    .into_diagnostic()?;
+
+    let mut watcher = notify::recommended_watcher(move |result| {
+        let _ = tx.send(WatchEvent::Changed(result));
+    })
+    .into_diagnostic()?;
+    // Watch the directories that contain the inputs, instead of the inputs
+    // themselves, to see changes from editors that save by replacing files.
+    for dir in inputs.iter().filter_map(|input| input.parent()).unique() {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .into_diagnostic()?;
+    }
+
+    if let Err(err) = generate() {
+        eprintln!("{err:?}");
+    }
+    eprintln!("Watching for changes; press Ctrl-C to stop");
+
+    loop {
+        let input = match rx.recv() {
+            // Reading an input isn't a change, so generating
+            // doesn't trigger another run.
+            Ok(WatchEvent::Changed(Ok(event))) if matches!(event.kind, EventKind::Access(_)) => {
+                continue;
+            }
+            Ok(WatchEvent::Changed(Ok(event))) => {
+                match inputs.iter().find(|input| event.paths.contains(input)) {
+                    Some(input) => input,
+                    None => continue,
+                }
+            }
+            Ok(WatchEvent::Changed(Err(err))) => {
+                eprintln!("{:?}", Report::from_err(err));
+                continue;
+            }
+            Ok(WatchEvent::Stop) | Err(_) => break,
+        };
+        // Skip events until none arrive for `DEBOUNCE_TIMEOUT`,
+        // since editors often save in several steps.
+        let settled = loop {
+            match rx.recv_timeout(DEBOUNCE_TIMEOUT) {
+                Ok(WatchEvent::Changed(_)) => continue,
+                Ok(WatchEvent::Stop) | Err(RecvTimeoutError::Disconnected) => break false,
+                Err(RecvTimeoutError::Timeout) => break true,
+            }
+        };
+        if !settled {
+            break;
+        }
+        eprintln!("`{}` changed; regenerating...", input.display());
+        if let Err(err) = generate() {
+            eprintln!("{err:?}");
+        }
+        eprintln!("Watching for changes; press Ctrl-C to stop");
+    }
+
+    eprintln!("Stopped watching");
+    Ok(())
+}